@@ -0,0 +1,5 @@
+// sqlx::migrate! はコンパイル時にマイグレーションを埋め込むため、
+// migrationsディレクトリの変更時に再ビルドさせる
+fn main() {
+    println!("cargo:rerun-if-changed=migrations");
+}
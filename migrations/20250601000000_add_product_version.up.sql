@@ -0,0 +1,2 @@
+-- 楽観的排他制御用のバージョン
+ALTER TABLE products ADD COLUMN version INTEGER NOT NULL DEFAULT 0;
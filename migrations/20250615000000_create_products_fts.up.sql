@@ -0,0 +1,25 @@
+-- 商品名・説明の全文検索用FTS5仮想テーブル（productsテーブルを外部コンテンツとして参照）
+CREATE VIRTUAL TABLE products_fts USING fts5(
+    name,
+    description,
+    content = 'products',
+    content_rowid = 'id',
+    tokenize = 'unicode61'
+);
+
+-- 既存の商品をインデックスに取り込む
+INSERT INTO products_fts(products_fts) VALUES ('rebuild');
+
+-- productsテーブルの変更をFTSインデックスに同期するトリガー
+CREATE TRIGGER products_fts_after_insert AFTER INSERT ON products BEGIN
+    INSERT INTO products_fts(rowid, name, description) VALUES (new.id, new.name, new.description);
+END;
+
+CREATE TRIGGER products_fts_after_delete AFTER DELETE ON products BEGIN
+    INSERT INTO products_fts(products_fts, rowid, name, description) VALUES ('delete', old.id, old.name, old.description);
+END;
+
+CREATE TRIGGER products_fts_after_update AFTER UPDATE OF name, description ON products BEGIN
+    INSERT INTO products_fts(products_fts, rowid, name, description) VALUES ('delete', old.id, old.name, old.description);
+    INSERT INTO products_fts(rowid, name, description) VALUES (new.id, new.name, new.description);
+END;
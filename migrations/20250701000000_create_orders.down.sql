@@ -0,0 +1,3 @@
+DROP INDEX IF EXISTS idx_order_lines_order_id;
+DROP TABLE IF EXISTS order_lines;
+DROP TABLE IF EXISTS orders;
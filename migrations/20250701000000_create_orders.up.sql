@@ -0,0 +1,20 @@
+-- 注文
+CREATE TABLE orders (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    status TEXT NOT NULL,
+    total INTEGER NOT NULL,
+    created_at TEXT NOT NULL
+);
+
+-- 注文明細（商品名・単価は注文時点のスナップショット）
+-- 商品が削除されても注文履歴を残すため、productsへの外部キーは張らない
+CREATE TABLE order_lines (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    order_id INTEGER NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    product_name TEXT NOT NULL,
+    unit_price INTEGER NOT NULL,
+    quantity INTEGER NOT NULL
+);
+
+CREATE INDEX idx_order_lines_order_id ON order_lines(order_id);
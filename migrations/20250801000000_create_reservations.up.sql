@@ -0,0 +1,25 @@
+-- 予約により確保されている在庫数（quantityは販売可能な在庫数のまま）
+ALTER TABLE products ADD COLUMN reserved INTEGER NOT NULL DEFAULT 0;
+
+-- 在庫予約
+-- expires_atは文字列比較できるよう、UTCのマイクロ秒精度固定フォーマットで保存する
+CREATE TABLE reservations (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    status TEXT NOT NULL,
+    expires_at TEXT NOT NULL,
+    order_id INTEGER REFERENCES orders(id),
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+-- 予約された商品
+CREATE TABLE reservation_items (
+    reservation_id INTEGER NOT NULL REFERENCES reservations(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    quantity INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (reservation_id, product_id)
+);
+
+-- 期限切れ予約の検索用
+CREATE INDEX idx_reservations_status_expires_at ON reservations(status, expires_at);
@@ -0,0 +1,18 @@
+-- Idempotency-Keyごとの最初のレスポンス
+-- 日時は文字列比較できるよう、UTCのマイクロ秒精度固定フォーマットで保存する
+CREATE TABLE idempotency_keys (
+    key TEXT PRIMARY KEY,
+    -- リクエストのメソッド・パス・ボディのハッシュ
+    fingerprint TEXT NOT NULL,
+    status TEXT NOT NULL,
+    response_status INTEGER,
+    -- ヘッダー名と値の組のJSON配列
+    response_headers TEXT,
+    response_body BLOB,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    expires_at TEXT NOT NULL
+);
+
+-- 期限切れの記録の削除用
+CREATE INDEX idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
@@ -0,0 +1,5 @@
+-- 価格・金額は通貨の最小単位（JPYは円、USDはセント）の整数で保持し、通貨コードを別カラムに持つ
+ALTER TABLE products ADD COLUMN currency TEXT NOT NULL DEFAULT 'JPY';
+
+-- 注文明細の単価は注文と同じ通貨
+ALTER TABLE orders ADD COLUMN currency TEXT NOT NULL DEFAULT 'JPY';
@@ -0,0 +1,3 @@
+ALTER TABLE order_lines DROP COLUMN promotion_id;
+ALTER TABLE order_lines DROP COLUMN discount;
+DROP TABLE IF EXISTS promotions;
@@ -0,0 +1,28 @@
+-- プロモーション（割引ルール）
+-- kindごとに使う列が異なる: percent_off は percent、amount_off は amount と currency、buy_n_get_m は buy_quantity と get_quantity
+CREATE TABLE promotions (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    percent INTEGER,
+    amount INTEGER,
+    currency TEXT,
+    buy_quantity INTEGER,
+    get_quantity INTEGER,
+    -- NULLは全商品が対象
+    product_id INTEGER REFERENCES products(id) ON DELETE CASCADE,
+    -- NULLはクーポン不要（自動適用）
+    coupon_code TEXT UNIQUE,
+    starts_at TEXT,
+    ends_at TEXT,
+    usage_limit INTEGER,
+    usage_count INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_promotions_product_id ON promotions(product_id);
+
+-- 注文明細の割引（明細全体の割引額と適用されたプロモーション）
+-- プロモーションが削除されても注文履歴を残すため、外部キーは張らない
+ALTER TABLE order_lines ADD COLUMN discount INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE order_lines ADD COLUMN promotion_id INTEGER;
@@ -0,0 +1,8 @@
+ALTER TABLE order_lines DROP COLUMN tax;
+ALTER TABLE order_lines DROP COLUMN tax_rate;
+ALTER TABLE order_lines DROP COLUMN tax_category;
+ALTER TABLE orders DROP COLUMN tax;
+ALTER TABLE orders DROP COLUMN subtotal;
+ALTER TABLE orders DROP COLUMN tax_mode;
+ALTER TABLE orders DROP COLUMN tax_region;
+ALTER TABLE products DROP COLUMN tax_category;
@@ -0,0 +1,15 @@
+-- 商品の課税区分（standard / reduced / exempt）
+ALTER TABLE products ADD COLUMN tax_category TEXT NOT NULL DEFAULT 'standard';
+
+-- 注文は税額の計算に使った地域・方式と、小計・税額を保持する
+-- 既存の注文は税額0の税抜として扱う
+ALTER TABLE orders ADD COLUMN tax_region TEXT;
+ALTER TABLE orders ADD COLUMN tax_mode TEXT NOT NULL DEFAULT 'exclusive';
+ALTER TABLE orders ADD COLUMN subtotal INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE orders ADD COLUMN tax INTEGER NOT NULL DEFAULT 0;
+UPDATE orders SET subtotal = total;
+
+-- 明細の税率は100万分率（10% = 100000）
+ALTER TABLE order_lines ADD COLUMN tax_category TEXT NOT NULL DEFAULT 'standard';
+ALTER TABLE order_lines ADD COLUMN tax_rate INTEGER NOT NULL DEFAULT 0;
+ALTER TABLE order_lines ADD COLUMN tax INTEGER NOT NULL DEFAULT 0;
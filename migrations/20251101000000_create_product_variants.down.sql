@@ -0,0 +1,2 @@
+ALTER TABLE order_lines DROP COLUMN sku;
+DROP TABLE IF EXISTS product_variants;
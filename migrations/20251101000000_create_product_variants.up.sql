@@ -0,0 +1,20 @@
+-- 商品のバリエーション（SKUごとの属性・価格・在庫）
+-- バリエーションのある商品の products.quantity はバリエーションの在庫の合計
+CREATE TABLE product_variants (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    product_id INTEGER NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    sku TEXT NOT NULL UNIQUE,
+    -- 属性名と値のJSONオブジェクト（例: {"colour":"black","layout":"US"}）
+    attributes TEXT NOT NULL,
+    -- 商品と同じ通貨の最小単位
+    price INTEGER NOT NULL,
+    quantity INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL
+);
+
+CREATE INDEX idx_product_variants_product_id ON product_variants(product_id);
+
+-- 注文明細に購入したバリエーションを記録する
+-- バリエーションが削除されても注文履歴を残すため、外部キーは張らない
+ALTER TABLE order_lines ADD COLUMN sku TEXT;
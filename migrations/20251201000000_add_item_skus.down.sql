@@ -0,0 +1,25 @@
+-- SKUの異なる同じ商品の行は1行にまとめる
+
+CREATE TABLE cart_items_old (
+    cart_id INTEGER NOT NULL REFERENCES carts(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    quantity INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (cart_id, product_id)
+);
+INSERT INTO cart_items_old (cart_id, product_id, quantity, position)
+    SELECT cart_id, product_id, SUM(quantity), MIN(position) FROM cart_items GROUP BY cart_id, product_id;
+DROP TABLE cart_items;
+ALTER TABLE cart_items_old RENAME TO cart_items;
+
+CREATE TABLE reservation_items_old (
+    reservation_id INTEGER NOT NULL REFERENCES reservations(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    quantity INTEGER NOT NULL,
+    position INTEGER NOT NULL,
+    PRIMARY KEY (reservation_id, product_id)
+);
+INSERT INTO reservation_items_old (reservation_id, product_id, quantity, position)
+    SELECT reservation_id, product_id, SUM(quantity), MIN(position) FROM reservation_items GROUP BY reservation_id, product_id;
+DROP TABLE reservation_items;
+ALTER TABLE reservation_items_old RENAME TO reservation_items;
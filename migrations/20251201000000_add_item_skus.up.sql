@@ -0,0 +1,32 @@
+-- カート・予約の商品にバリエーションのSKUを記録する
+-- 同じ商品の異なるバリエーションを別の行にするため、(親ID, product_id) の主キーを作り直す
+-- SQLiteでは主キーを変更できないため、テーブルを作り直して既存の行を移す
+
+CREATE TABLE cart_items_new (
+    cart_id INTEGER NOT NULL REFERENCES carts(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    -- バリエーションのない商品はNULL
+    sku TEXT,
+    quantity INTEGER NOT NULL,
+    position INTEGER NOT NULL
+);
+INSERT INTO cart_items_new (cart_id, product_id, quantity, position)
+    SELECT cart_id, product_id, quantity, position FROM cart_items;
+DROP TABLE cart_items;
+ALTER TABLE cart_items_new RENAME TO cart_items;
+-- NULL同士は一意制約で区別されないため、空文字として比較する
+CREATE UNIQUE INDEX idx_cart_items_item ON cart_items(cart_id, product_id, COALESCE(sku, ''));
+
+CREATE TABLE reservation_items_new (
+    reservation_id INTEGER NOT NULL REFERENCES reservations(id) ON DELETE CASCADE,
+    product_id INTEGER NOT NULL,
+    -- バリエーションのない商品はNULL
+    sku TEXT,
+    quantity INTEGER NOT NULL,
+    position INTEGER NOT NULL
+);
+INSERT INTO reservation_items_new (reservation_id, product_id, quantity, position)
+    SELECT reservation_id, product_id, quantity, position FROM reservation_items;
+DROP TABLE reservation_items;
+ALTER TABLE reservation_items_new RENAME TO reservation_items;
+CREATE UNIQUE INDEX idx_reservation_items_item ON reservation_items(reservation_id, product_id, COALESCE(sku, ''));
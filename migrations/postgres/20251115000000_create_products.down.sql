@@ -0,0 +1,5 @@
+DROP TABLE IF EXISTS product_variants;
+DROP TABLE IF EXISTS product_tags;
+DROP TABLE IF EXISTS tags;
+DROP TABLE IF EXISTS product_categories;
+DROP TABLE IF EXISTS products;
@@ -0,0 +1,64 @@
+-- PostgreSQLに保存する商品（`database.product_url` に指定した場合のみ使う）
+-- カテゴリ・注文などはSQLite（`database.url`）に残るため、それらへの外部キーは張らない
+CREATE TABLE products (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    name TEXT NOT NULL,
+    -- 通貨の最小単位
+    price BIGINT NOT NULL,
+    currency TEXT NOT NULL DEFAULT 'JPY',
+    tax_category TEXT NOT NULL DEFAULT 'standard',
+    description TEXT NOT NULL,
+    quantity BIGINT NOT NULL,
+    -- 予約により確保されている在庫数
+    reserved BIGINT NOT NULL DEFAULT 0,
+    -- 楽観的排他制御用のバージョン
+    version BIGINT NOT NULL DEFAULT 0,
+    -- 全文検索用（商品名を説明より重く評価する）
+    search_vector TSVECTOR GENERATED ALWAYS AS (
+        setweight(to_tsvector('simple', name), 'A') || setweight(to_tsvector('simple', description), 'B')
+    ) STORED,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_products_search_vector ON products USING GIN (search_vector);
+
+-- 商品とカテゴリの関連（カテゴリはSQLiteにある）
+CREATE TABLE product_categories (
+    product_id BIGINT NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    category_id BIGINT NOT NULL,
+    PRIMARY KEY (product_id, category_id)
+);
+
+CREATE INDEX idx_product_categories_category_id ON product_categories(category_id);
+
+-- タグ（小文字に正規化した名前で一意）
+CREATE TABLE tags (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    name TEXT NOT NULL UNIQUE
+);
+
+-- 商品とタグの関連（多対多）
+CREATE TABLE product_tags (
+    product_id BIGINT NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    tag_id BIGINT NOT NULL REFERENCES tags(id) ON DELETE CASCADE,
+    PRIMARY KEY (product_id, tag_id)
+);
+
+CREATE INDEX idx_product_tags_tag_id ON product_tags(tag_id);
+
+-- 商品のバリエーション（SKUごとの属性・価格・在庫）
+CREATE TABLE product_variants (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    product_id BIGINT NOT NULL REFERENCES products(id) ON DELETE CASCADE,
+    sku TEXT NOT NULL UNIQUE,
+    -- 属性名と値のJSONオブジェクト（例: {"colour":"black","layout":"US"}）
+    attributes TEXT NOT NULL,
+    -- 商品と同じ通貨の最小単位
+    price BIGINT NOT NULL,
+    quantity BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_product_variants_product_id ON product_variants(product_id);
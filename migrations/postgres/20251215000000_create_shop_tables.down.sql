@@ -0,0 +1,10 @@
+DROP TABLE IF EXISTS promotions;
+DROP TABLE IF EXISTS idempotency_keys;
+DROP TABLE IF EXISTS reservation_items;
+DROP TABLE IF EXISTS reservations;
+DROP TABLE IF EXISTS cart_items;
+DROP TABLE IF EXISTS carts;
+DROP TABLE IF EXISTS order_lines;
+DROP TABLE IF EXISTS orders;
+ALTER TABLE product_categories DROP CONSTRAINT IF EXISTS product_categories_category_id_fkey;
+DROP TABLE IF EXISTS categories;
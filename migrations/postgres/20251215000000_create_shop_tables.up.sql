@@ -0,0 +1,141 @@
+-- 商品以外のデータもPostgreSQLに保存する（`database.url` にPostgreSQLのURLを指定した場合）
+-- 列はSQLiteのマイグレーションをすべて適用した時点と同じ。整数はBIGINT、日時はTIMESTAMPTZで保存する
+
+-- 商品カテゴリ（parent_idで木構造を表す）
+-- 子カテゴリがある間は親カテゴリを削除できない
+CREATE TABLE categories (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    name TEXT NOT NULL,
+    parent_id BIGINT REFERENCES categories(id),
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_categories_parent_id ON categories(parent_id);
+
+-- これまでのカテゴリはSQLiteにあり、ここで作るカテゴリとIDが対応しないため、既存の関連付けは引き継がない
+DELETE FROM product_categories;
+ALTER TABLE product_categories
+    ADD CONSTRAINT product_categories_category_id_fkey
+    FOREIGN KEY (category_id) REFERENCES categories(id) ON DELETE CASCADE;
+
+-- 注文（税額の計算に使った地域・方式と、小計・税額・総額を保持する）
+CREATE TABLE orders (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    status TEXT NOT NULL,
+    tax_region TEXT,
+    tax_mode TEXT NOT NULL,
+    subtotal BIGINT NOT NULL,
+    tax BIGINT NOT NULL,
+    total BIGINT NOT NULL,
+    currency TEXT NOT NULL,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+-- 注文明細（商品名・単価は注文時点のスナップショット）
+-- 商品・バリエーション・プロモーションが削除されても注文履歴を残すため、外部キーは張らない
+-- 税率は100万分率（10% = 100000）
+CREATE TABLE order_lines (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    order_id BIGINT NOT NULL REFERENCES orders(id) ON DELETE CASCADE,
+    product_id BIGINT NOT NULL,
+    product_name TEXT NOT NULL,
+    sku TEXT,
+    unit_price BIGINT NOT NULL,
+    quantity BIGINT NOT NULL,
+    discount BIGINT NOT NULL DEFAULT 0,
+    promotion_id BIGINT,
+    tax_category TEXT NOT NULL DEFAULT 'standard',
+    tax_rate BIGINT NOT NULL DEFAULT 0,
+    tax BIGINT NOT NULL DEFAULT 0
+);
+
+CREATE INDEX idx_order_lines_order_id ON order_lines(order_id);
+
+-- カート
+CREATE TABLE carts (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    status TEXT NOT NULL,
+    order_id BIGINT REFERENCES orders(id),
+    version BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+-- カート内の商品（バリエーションのない商品のskuはNULL）
+CREATE TABLE cart_items (
+    cart_id BIGINT NOT NULL REFERENCES carts(id) ON DELETE CASCADE,
+    product_id BIGINT NOT NULL,
+    sku TEXT,
+    quantity BIGINT NOT NULL,
+    position BIGINT NOT NULL
+);
+
+-- NULL同士は一意制約で区別されないため、空文字として比較する
+CREATE UNIQUE INDEX idx_cart_items_item ON cart_items(cart_id, product_id, COALESCE(sku, ''));
+
+-- 在庫予約
+CREATE TABLE reservations (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    status TEXT NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL,
+    order_id BIGINT REFERENCES orders(id),
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL
+);
+
+-- 期限切れ予約の検索用
+CREATE INDEX idx_reservations_status_expires_at ON reservations(status, expires_at);
+
+-- 予約された商品（バリエーションのない商品のskuはNULL）
+CREATE TABLE reservation_items (
+    reservation_id BIGINT NOT NULL REFERENCES reservations(id) ON DELETE CASCADE,
+    product_id BIGINT NOT NULL,
+    sku TEXT,
+    quantity BIGINT NOT NULL,
+    position BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX idx_reservation_items_item ON reservation_items(reservation_id, product_id, COALESCE(sku, ''));
+
+-- Idempotency-Keyごとの最初のレスポンス
+CREATE TABLE idempotency_keys (
+    key TEXT PRIMARY KEY,
+    -- リクエストのメソッド・パス・ボディのハッシュ
+    fingerprint TEXT NOT NULL,
+    status TEXT NOT NULL,
+    response_status BIGINT,
+    -- ヘッダー名と値の組のJSON配列
+    response_headers TEXT,
+    response_body BYTEA,
+    created_at TIMESTAMPTZ NOT NULL,
+    updated_at TIMESTAMPTZ NOT NULL,
+    expires_at TIMESTAMPTZ NOT NULL
+);
+
+-- 期限切れの記録の削除用
+CREATE INDEX idx_idempotency_keys_expires_at ON idempotency_keys(expires_at);
+
+-- プロモーション（割引ルール）
+-- kindごとに使う列が異なる: percent_off は percent、amount_off は amount と currency、buy_n_get_m は buy_quantity と get_quantity
+CREATE TABLE promotions (
+    id BIGINT GENERATED BY DEFAULT AS IDENTITY PRIMARY KEY,
+    name TEXT NOT NULL,
+    kind TEXT NOT NULL,
+    percent BIGINT,
+    amount BIGINT,
+    currency TEXT,
+    buy_quantity BIGINT,
+    get_quantity BIGINT,
+    -- NULLは全商品が対象
+    product_id BIGINT REFERENCES products(id) ON DELETE CASCADE,
+    -- NULLはクーポン不要（自動適用）
+    coupon_code TEXT UNIQUE,
+    starts_at TIMESTAMPTZ,
+    ends_at TIMESTAMPTZ,
+    usage_limit BIGINT,
+    usage_count BIGINT NOT NULL DEFAULT 0,
+    created_at TIMESTAMPTZ NOT NULL
+);
+
+CREATE INDEX idx_promotions_product_id ON promotions(product_id);
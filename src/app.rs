@@ -0,0 +1,95 @@
+use axum::http::HeaderMap;
+use axum::middleware;
+use axum::response::Response;
+use axum::Router;
+use std::sync::Arc;
+
+use crate::error::{self, Error};
+use crate::frameworks_and_drivers::config::Config;
+use crate::frameworks_and_drivers::database::db::Database;
+use crate::frameworks_and_drivers::{self, Container};
+use crate::interface_adapters;
+
+/// 設定のデータベースに接続し、アプリケーションのルーターを組み立てます
+/// データベースはアプリケーションごとに持つため、1つのプロセスで別々のデータベースを使うアプリケーションを起動できます
+/// マイグレーションは適用しません
+pub async fn build_app(config: Arc<Config>) -> anyhow::Result<Router> {
+    let database = Arc::new(Database::new(&config.database).await?);
+    let container = Arc::new(frameworks_and_drivers::get_container(config, database));
+    Ok(router(container))
+}
+
+/// コンテナの依存関係を使うルーターを組み立てます
+pub fn router(container: Arc<Container>) -> Router {
+    let app = Router::new()
+        .merge(interface_adapters::products::routes(&container.config.features))
+        .merge(interface_adapters::orders::routes())
+        .merge(interface_adapters::carts::routes())
+        .merge(interface_adapters::reservations::routes())
+        .merge(interface_adapters::promotions::routes())
+        .merge(interface_adapters::categories::routes())
+        .merge(interface_adapters::openapi::routes())
+        .fallback(|| async { Error::NotFound })
+        .layer(middleware::from_fn_with_state(container.clone(), interface_adapters::idempotency::idempotency))
+        .layer(middleware::map_response(main_response_mapper));
+
+    frameworks_and_drivers::telemetry::with_request_tracing(app)
+        .with_state(container)  // アプリケーション状態としてコンテナを追加
+}
+
+/// エラーレスポンスのProblem DetailsにリクエストIDをinstanceとして埋め込む
+async fn main_response_mapper(headers: HeaderMap, res: Response) -> Response {
+    let request_id = headers
+        .get(frameworks_and_drivers::telemetry::REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok());
+
+    error::with_problem_instance(res, request_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::body::Body;
+    use axum::http::{Request, StatusCode};
+    use tower::ServiceExt;
+
+    use super::*;
+    use crate::frameworks_and_drivers::config::ConfigArgs;
+    use crate::frameworks_and_drivers::database::migrations::run_migrations;
+
+    // 一時ファイルのSQLiteにマイグレーションを適用し、そのデータベースを使うアプリケーションを組み立てる
+    async fn isolated_app(name: &str) -> Router {
+        let path = std::env::temp_dir()
+            .join(format!("axum-mini-template-app-{}-{}.sqlite", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        let args = ConfigArgs {
+            database_url: Some(format!("sqlite://{}", path.display())),
+            ..Default::default()
+        };
+        let config = Arc::new(Config::load(&args).unwrap());
+        run_migrations(&config.database).await.unwrap();
+
+        build_app(config).await.unwrap()
+    }
+
+    async fn product_status(app: Router, id: u32) -> StatusCode {
+        let request = Request::get(format!("/products/{}", id)).body(Body::empty()).unwrap();
+        app.oneshot(request).await.unwrap().status()
+    }
+
+    /// 1つのプロセスで起動したアプリケーションが、それぞれ別のデータベースを使うこと
+    #[tokio::test]
+    async fn apps_use_their_own_database() {
+        let first = isolated_app("first").await;
+        let second = isolated_app("second").await;
+
+        let request = Request::post("/products")
+            .header("content-type", "application/json")
+            .body(Body::from(r#"{"name":"Lamp","price":1200,"description":"desk lamp","quantity":3}"#))
+            .unwrap();
+        let response = first.clone().oneshot(request).await.unwrap();
+        assert_eq!(response.status(), StatusCode::CREATED);
+
+        assert_eq!(product_status(first, 1).await, StatusCode::OK);
+        assert_eq!(product_status(second, 1).await, StatusCode::NOT_FOUND);
+    }
+}
@@ -0,0 +1,8 @@
+/// Application層でのカート追加コマンド
+#[derive(Debug)]
+pub struct AddCartItemCommand {
+    pub product_id: u32,
+    /// 追加するバリエーションのSKU（バリエーションのある商品では必須）
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
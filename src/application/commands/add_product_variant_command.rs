@@ -0,0 +1,13 @@
+use std::collections::BTreeMap;
+
+/// Application層での商品バリエーション追加コマンド
+#[derive(Debug)]
+pub struct AddProductVariantCommand {
+    pub sku: String,
+    pub attributes: BTreeMap<String, String>,
+    /// 商品と同じ通貨の最小単位での価格
+    pub price: u64,
+    pub quantity: u32,
+    /// クライアントが期待する商品のバージョン（If-Match）
+    pub expected_version: Option<u32>,
+}
@@ -3,4 +3,12 @@
 #[derive(Debug)]
 pub struct BuyProductCommand {
     pub quantity: u32,
+    /// 購入するバリエーションのSKU（バリエーションのある商品では必須）
+    pub sku: Option<String>,
+    /// クーポンコード
+    pub coupon_code: Option<String>,
+    /// 税額の計算に使う地域（省略時は既定の地域）
+    pub region: Option<String>,
+    /// クライアントが期待する商品のバージョン（If-Match）
+    pub expected_version: Option<u32>,
 }
@@ -0,0 +1,7 @@
+/// Application層でのカテゴリ作成コマンド
+#[derive(Debug)]
+pub struct CreateCategoryCommand {
+    pub name: String,
+    /// 親カテゴリ（`None` はルート）
+    pub parent_id: Option<u32>,
+}
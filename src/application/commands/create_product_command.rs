@@ -0,0 +1,18 @@
+use crate::domain::models::Currency;
+use crate::domain::tax::TaxCategory;
+
+/// Application層での商品作成コマンド
+/// HTTPの詳細には依存しない
+#[derive(Debug)]
+pub struct CreateProductCommand {
+    pub name: String,
+    /// 通貨の最小単位での価格
+    pub price: u64,
+    pub currency: Currency,
+    pub tax_category: TaxCategory,
+    pub description: String,
+    pub quantity: u32,
+    /// 所属させるカテゴリ
+    pub category_ids: Vec<u32>,
+    pub tags: Vec<String>,
+}
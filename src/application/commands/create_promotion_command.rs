@@ -0,0 +1,17 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::DiscountRule;
+
+/// Application層でのプロモーション作成コマンド
+#[derive(Debug)]
+pub struct CreatePromotionCommand {
+    pub name: String,
+    pub rule: DiscountRule,
+    /// 対象商品（`None` は全商品）
+    pub product_id: Option<u32>,
+    /// クーポンコード（`None` は自動適用）
+    pub coupon_code: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub usage_limit: Option<u32>,
+}
@@ -0,0 +1,14 @@
+/// Application層での在庫予約コマンド
+#[derive(Debug)]
+pub struct CreateReservationCommand {
+    pub items: Vec<ReservationItemCommand>,
+}
+
+/// 予約する商品1件分のコマンド
+#[derive(Debug)]
+pub struct ReservationItemCommand {
+    pub product_id: u32,
+    /// 予約するバリエーションのSKU（バリエーションのある商品では必須）
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
@@ -0,0 +1,6 @@
+/// Application層での商品削除コマンド
+#[derive(Debug)]
+pub struct DeleteProductCommand {
+    /// クライアントが期待する商品のバージョン（If-Match）
+    pub expected_version: Option<u32>,
+}
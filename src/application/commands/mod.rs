@@ -1,3 +1,27 @@
 mod buy_product_command;
+mod create_product_command;
+mod update_product_command;
+mod delete_product_command;
+mod add_cart_item_command;
+mod update_cart_item_command;
+mod create_reservation_command;
+mod create_promotion_command;
+mod create_category_command;
+mod update_category_command;
+mod add_product_variant_command;
+mod update_product_variant_command;
+mod remove_product_variant_command;
 
 pub use self::buy_product_command::BuyProductCommand;
+pub use self::create_product_command::CreateProductCommand;
+pub use self::update_product_command::UpdateProductCommand;
+pub use self::delete_product_command::DeleteProductCommand;
+pub use self::add_cart_item_command::AddCartItemCommand;
+pub use self::update_cart_item_command::UpdateCartItemCommand;
+pub use self::create_reservation_command::{CreateReservationCommand, ReservationItemCommand};
+pub use self::create_promotion_command::CreatePromotionCommand;
+pub use self::create_category_command::CreateCategoryCommand;
+pub use self::update_category_command::UpdateCategoryCommand;
+pub use self::add_product_variant_command::AddProductVariantCommand;
+pub use self::update_product_variant_command::UpdateProductVariantCommand;
+pub use self::remove_product_variant_command::RemoveProductVariantCommand;
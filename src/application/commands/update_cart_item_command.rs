@@ -0,0 +1,5 @@
+/// Application層でのカート内商品の数量変更コマンド
+#[derive(Debug)]
+pub struct UpdateCartItemCommand {
+    pub quantity: u32,
+}
@@ -0,0 +1,8 @@
+/// Application層でのカテゴリ更新コマンド
+/// 名前と親カテゴリを置き換える
+#[derive(Debug)]
+pub struct UpdateCategoryCommand {
+    pub name: String,
+    /// 親カテゴリ（`None` はルート）
+    pub parent_id: Option<u32>,
+}
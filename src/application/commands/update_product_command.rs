@@ -0,0 +1,22 @@
+use crate::domain::models::Currency;
+use crate::domain::tax::TaxCategory;
+
+/// Application層での商品更新コマンド
+/// `None` の項目は変更しない（部分更新）
+#[derive(Debug)]
+pub struct UpdateProductCommand {
+    pub name: Option<String>,
+    /// 通貨の最小単位での価格
+    pub price: Option<u64>,
+    /// 省略時は現在の通貨のまま
+    pub currency: Option<Currency>,
+    pub tax_category: Option<TaxCategory>,
+    pub description: Option<String>,
+    pub quantity: Option<u32>,
+    /// 指定した場合はカテゴリをすべて置き換える
+    pub category_ids: Option<Vec<u32>>,
+    /// 指定した場合はタグをすべて置き換える
+    pub tags: Option<Vec<String>>,
+    /// クライアントが期待する商品のバージョン（If-Match）
+    pub expected_version: Option<u32>,
+}
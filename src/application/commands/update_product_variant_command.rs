@@ -0,0 +1,14 @@
+use std::collections::BTreeMap;
+
+/// Application層での商品バリエーション更新コマンド
+/// `None` の項目は変更しない（部分更新）
+#[derive(Debug)]
+pub struct UpdateProductVariantCommand {
+    /// 指定した場合は属性をすべて置き換える
+    pub attributes: Option<BTreeMap<String, String>>,
+    /// 商品と同じ通貨の最小単位での価格
+    pub price: Option<u64>,
+    pub quantity: Option<u32>,
+    /// クライアントが期待する商品のバージョン（If-Match）
+    pub expected_version: Option<u32>,
+}
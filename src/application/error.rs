@@ -8,6 +8,32 @@ pub enum ApplicationError {
     Repository(RepositoryError),
     /// 商品が見つからない
     ProductNotFound(u32),
+    /// 注文が見つからない
+    OrderNotFound(u32),
+    /// カートが見つからない
+    CartNotFound(u32),
+    /// カートの読み込み後に、他のリクエストでカートや商品の在庫が変わった
+    CartChanged(u32),
+    /// 在庫予約が見つからない
+    ReservationNotFound(u32),
+    /// 予約の作成・確定・取り消し中に、他のリクエストで予約や商品の在庫が変わった（作成時は予約IDなし）
+    ReservationConflict(Option<u32>),
+    /// プロモーションが見つからない
+    PromotionNotFound(u32),
+    /// クーポンコードが他のプロモーションで使われている
+    CouponCodeTaken(String),
+    /// カテゴリが見つからない
+    CategoryNotFound(u32),
+    /// 子カテゴリがあるため削除できない
+    CategoryHasChildren(u32),
+    /// SKUが他のバリエーションで使われている
+    SkuTaken(String),
+    /// 同じIdempotency-Keyの最初のリクエストが処理中
+    IdempotencyKeyInProgress(String),
+    /// 同じIdempotency-Keyが異なるリクエストに使われた
+    IdempotencyKeyMismatch(String),
+    /// 購入中に他の購入で在庫が変わり、やり直しても注文を確定できなかった
+    StockChanged(u32),
     /// バリデーションエラー
     Validation(String),
 }
@@ -20,6 +46,8 @@ pub enum RepositoryError {
     QueryExecution(String),
     /// データが見つからない
     NotFound,
+    /// 他の更新と競合した（バージョン不一致）
+    Conflict,
     /// その他のエラー
     Unknown(String),
 }
@@ -30,6 +58,20 @@ impl std::fmt::Display for ApplicationError {
             ApplicationError::Domain(err) => write!(f, "Domain error: {}", err),
             ApplicationError::Repository(err) => write!(f, "Repository error: {}", err),
             ApplicationError::ProductNotFound(id) => write!(f, "Product not found: {}", id),
+            ApplicationError::OrderNotFound(id) => write!(f, "Order not found: {}", id),
+            ApplicationError::CartNotFound(id) => write!(f, "Cart not found: {}", id),
+            ApplicationError::CartChanged(id) => write!(f, "Cart changed concurrently: {}", id),
+            ApplicationError::ReservationNotFound(id) => write!(f, "Reservation not found: {}", id),
+            ApplicationError::ReservationConflict(Some(id)) => write!(f, "Reservation changed concurrently: {}", id),
+            ApplicationError::ReservationConflict(None) => write!(f, "Stock changed concurrently while reserving"),
+            ApplicationError::PromotionNotFound(id) => write!(f, "Promotion not found: {}", id),
+            ApplicationError::CouponCodeTaken(code) => write!(f, "Coupon code is already in use: {}", code),
+            ApplicationError::CategoryNotFound(id) => write!(f, "Category not found: {}", id),
+            ApplicationError::CategoryHasChildren(id) => write!(f, "Category has subcategories: {}", id),
+            ApplicationError::SkuTaken(sku) => write!(f, "SKU is already in use: {}", sku),
+            ApplicationError::IdempotencyKeyInProgress(key) => write!(f, "Idempotency key is in progress: {}", key),
+            ApplicationError::IdempotencyKeyMismatch(key) => write!(f, "Idempotency key was used for a different request: {}", key),
+            ApplicationError::StockChanged(id) => write!(f, "Stock changed concurrently: {}", id),
             ApplicationError::Validation(msg) => write!(f, "Validation error: {}", msg),
         }
     }
@@ -41,6 +83,7 @@ impl std::fmt::Display for RepositoryError {
             RepositoryError::DatabaseConnection(msg) => write!(f, "Database connection error: {}", msg),
             RepositoryError::QueryExecution(msg) => write!(f, "Query execution error: {}", msg),
             RepositoryError::NotFound => write!(f, "Data not found"),
+            RepositoryError::Conflict => write!(f, "Data was modified by another request"),
             RepositoryError::Unknown(msg) => write!(f, "Unknown error: {}", msg),
         }
     }
@@ -0,0 +1,39 @@
+use crate::domain::models::{Cart, CartItem};
+
+/// Application層でのカートクエリオブジェクト
+pub struct GetCartQuery {
+    pub id: u32,
+    pub status: String,
+    pub order_id: Option<u32>,
+    pub version: u32,
+    pub items: Vec<CartItemQuery>,
+}
+
+/// カート内商品のクエリオブジェクト
+pub struct CartItemQuery {
+    pub product_id: u32,
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+impl From<CartItem> for CartItemQuery {
+    fn from(item: CartItem) -> Self {
+        CartItemQuery {
+            product_id: item.product_id,
+            sku: item.sku,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<Cart> for GetCartQuery {
+    fn from(cart: Cart) -> Self {
+        GetCartQuery {
+            id: cart.id,
+            status: cart.status.as_str().to_string(),
+            order_id: cart.order_id,
+            version: cart.version,
+            items: cart.items.into_iter().map(|item| item.into()).collect(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use crate::domain::models::Category;
+
+/// Application層でのカテゴリクエリオブジェクト
+pub struct GetCategoryQuery {
+    pub id: u32,
+    pub name: String,
+    pub parent_id: Option<u32>,
+}
+
+impl From<Category> for GetCategoryQuery {
+    fn from(category: Category) -> Self {
+        GetCategoryQuery {
+            id: category.id,
+            name: category.name,
+            parent_id: category.parent_id,
+        }
+    }
+}
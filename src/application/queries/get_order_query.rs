@@ -0,0 +1,73 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::DomainError;
+use crate::domain::models::{Money, Order, OrderLine};
+use crate::domain::tax::TaxRate;
+
+/// Application層での注文クエリオブジェクト
+pub struct GetOrderQuery {
+    pub id: u32,
+    pub status: String,
+    pub tax_region: Option<String>,
+    pub tax_mode: String,
+    pub subtotal: Money,
+    pub tax: Money,
+    pub total: Money,
+    pub placed_at: DateTime<Utc>,
+    pub lines: Vec<OrderLineQuery>,
+}
+
+/// 注文明細のクエリオブジェクト
+pub struct OrderLineQuery {
+    pub product_id: u32,
+    pub product_name: String,
+    pub sku: Option<String>,
+    pub unit_price: Money,
+    pub quantity: u32,
+    /// 明細全体の割引額
+    pub discount: Money,
+    pub promotion_id: Option<u32>,
+    /// 割引後の小計
+    pub subtotal: Money,
+    pub tax_category: String,
+    pub tax_rate: TaxRate,
+    pub tax: Money,
+}
+
+impl TryFrom<OrderLine> for OrderLineQuery {
+    type Error = DomainError;
+
+    fn try_from(line: OrderLine) -> Result<Self, DomainError> {
+        Ok(OrderLineQuery {
+            subtotal: line.subtotal()?,
+            product_id: line.product_id,
+            product_name: line.product_name,
+            sku: line.sku,
+            unit_price: line.unit_price,
+            quantity: line.quantity,
+            discount: line.discount,
+            promotion_id: line.promotion_id,
+            tax_category: line.tax_category.as_str().to_string(),
+            tax_rate: line.tax_rate,
+            tax: line.tax,
+        })
+    }
+}
+
+impl TryFrom<Order> for GetOrderQuery {
+    type Error = DomainError;
+
+    fn try_from(order: Order) -> Result<Self, DomainError> {
+        Ok(GetOrderQuery {
+            id: order.id,
+            status: order.status.as_str().to_string(),
+            tax_region: order.tax_region,
+            tax_mode: order.tax_mode.as_str().to_string(),
+            subtotal: order.subtotal,
+            tax: order.tax,
+            total: order.total,
+            placed_at: order.placed_at,
+            lines: order.lines.into_iter().map(OrderLineQuery::try_from).collect::<Result<_, _>>()?,
+        })
+    }
+}
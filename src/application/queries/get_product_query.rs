@@ -1,23 +1,78 @@
-use crate::domain::models::Product;
+use std::collections::BTreeMap;
+
+use chrono::{DateTime, Utc};
+
+use crate::application::queries::GetCategoryQuery;
+use crate::domain::DomainError;
+use crate::domain::models::{Money, Product, Promotion};
+use crate::domain::pricing;
 
 /// Application層での商品クエリオブジェクト
 /// シリアライゼーションの詳細は含まない
 pub struct GetProductQuery {
     pub id: u32,
     pub name: String,
-    pub price: u32,
+    /// 定価
+    pub price: Money,
+    /// 1点購入時の割引後の価格（クーポンなし）
+    pub discounted_price: Money,
+    /// 割引後の価格に適用されたプロモーション
+    pub promotion_id: Option<u32>,
+    pub tax_category: String,
     pub description: String,
     pub quantity: u32,
+    pub reserved: u32,
+    pub categories: Vec<GetCategoryQuery>,
+    pub tags: Vec<String>,
+    /// 属性ごとの選択肢（バリエーションの行列の軸）
+    pub variant_attributes: BTreeMap<String, Vec<String>>,
+    pub variants: Vec<ProductVariantQuery>,
+    pub version: u32,
 }
 
-impl From<Product> for GetProductQuery {
-    fn from(product: Product) -> GetProductQuery {
-        GetProductQuery {
+/// 商品のバリエーションのクエリオブジェクト
+pub struct ProductVariantQuery {
+    pub sku: String,
+    pub attributes: BTreeMap<String, String>,
+    pub price: Money,
+    /// 1点購入時の割引後の価格（クーポンなし）
+    pub discounted_price: Money,
+    pub quantity: u32,
+}
+
+impl GetProductQuery {
+    /// 自動適用されるプロモーションを反映したクエリを作成します
+    pub fn priced(product: Product, promotions: &[Promotion], now: DateTime<Utc>) -> Result<Self, DomainError> {
+        let quote = pricing::quote(&product, 1, promotions, None, now)?;
+        let variants = product.variants
+            .iter()
+            .map(|variant| {
+                let quote = pricing::quote_variant(&product, variant, 1, promotions, None, now)?;
+                Ok(ProductVariantQuery {
+                    sku: variant.sku.clone(),
+                    attributes: variant.attributes.clone(),
+                    price: variant.price,
+                    discounted_price: quote.total()?,
+                    quantity: variant.quantity,
+                })
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+
+        Ok(GetProductQuery {
+            variant_attributes: product.variant_attributes(),
+            variants,
             id: product.id,
             name: product.name,
             price: product.price,
+            discounted_price: quote.total()?,
+            promotion_id: quote.promotion_id,
+            tax_category: product.tax_category.as_str().to_string(),
             description: product.description,
             quantity: product.quantity,
-        }
+            reserved: product.reserved,
+            categories: product.categories.into_iter().map(GetCategoryQuery::from).collect(),
+            tags: product.tags,
+            version: product.version,
+        })
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{DiscountRule, Promotion};
+
+/// Application層でのプロモーションクエリオブジェクト
+pub struct GetPromotionQuery {
+    pub id: u32,
+    pub name: String,
+    pub rule: DiscountRule,
+    pub product_id: Option<u32>,
+    pub coupon_code: Option<String>,
+    pub starts_at: Option<DateTime<Utc>>,
+    pub ends_at: Option<DateTime<Utc>>,
+    pub usage_limit: Option<u32>,
+    pub usage_count: u32,
+}
+
+impl From<Promotion> for GetPromotionQuery {
+    fn from(promotion: Promotion) -> Self {
+        GetPromotionQuery {
+            id: promotion.id,
+            name: promotion.name,
+            rule: promotion.rule,
+            product_id: promotion.product_id,
+            coupon_code: promotion.coupon_code,
+            starts_at: promotion.starts_at,
+            ends_at: promotion.ends_at,
+            usage_limit: promotion.usage_limit,
+            usage_count: promotion.usage_count,
+        }
+    }
+}
@@ -0,0 +1,41 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{Reservation, ReservationItem};
+
+/// Application層での在庫予約クエリオブジェクト
+pub struct GetReservationQuery {
+    pub id: u32,
+    pub status: String,
+    pub expires_at: DateTime<Utc>,
+    pub order_id: Option<u32>,
+    pub items: Vec<ReservationItemQuery>,
+}
+
+/// 予約された商品のクエリオブジェクト
+pub struct ReservationItemQuery {
+    pub product_id: u32,
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+impl From<ReservationItem> for ReservationItemQuery {
+    fn from(item: ReservationItem) -> Self {
+        ReservationItemQuery {
+            product_id: item.product_id,
+            sku: item.sku,
+            quantity: item.quantity,
+        }
+    }
+}
+
+impl From<Reservation> for GetReservationQuery {
+    fn from(reservation: Reservation) -> Self {
+        GetReservationQuery {
+            id: reservation.id,
+            status: reservation.status.as_str().to_string(),
+            expires_at: reservation.expires_at,
+            order_id: reservation.order_id,
+            items: reservation.items.into_iter().map(|item| item.into()).collect(),
+        }
+    }
+}
@@ -1,3 +1,15 @@
 mod get_product_query;
+mod search_product_query;
+mod get_order_query;
+mod get_cart_query;
+mod get_reservation_query;
+mod get_promotion_query;
+mod get_category_query;
 
-pub use self::get_product_query::GetProductQuery;
+pub use self::get_product_query::{GetProductQuery, ProductVariantQuery};
+pub use self::search_product_query::SearchProductQuery;
+pub use self::get_order_query::{GetOrderQuery, OrderLineQuery};
+pub use self::get_cart_query::{GetCartQuery, CartItemQuery};
+pub use self::get_reservation_query::{GetReservationQuery, ReservationItemQuery};
+pub use self::get_promotion_query::GetPromotionQuery;
+pub use self::get_category_query::GetCategoryQuery;
@@ -0,0 +1,26 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::queries::GetProductQuery;
+use crate::application::repositories::ProductSearchHit;
+use crate::domain::DomainError;
+use crate::domain::models::Promotion;
+
+/// Application層での商品検索結果オブジェクト
+pub struct SearchProductQuery {
+    pub product: GetProductQuery,
+    pub score: f64,
+    pub highlighted_name: String,
+    pub snippet: String,
+}
+
+impl SearchProductQuery {
+    /// 自動適用されるプロモーションを反映した検索結果を作成します
+    pub fn priced(hit: ProductSearchHit, promotions: &[Promotion], now: DateTime<Utc>) -> Result<Self, DomainError> {
+        Ok(SearchProductQuery {
+            product: GetProductQuery::priced(hit.product, promotions, now)?,
+            score: hit.score,
+            highlighted_name: hit.highlighted_name,
+            snippet: hit.snippet,
+        })
+    }
+}
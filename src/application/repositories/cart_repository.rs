@@ -0,0 +1,15 @@
+use crate::application::error::RepositoryError;
+use crate::domain::models::{Cart, Order};
+
+#[async_trait::async_trait]
+pub trait CartRepository {
+    async fn find_by_id(&self, id: u32) -> Result<Option<Cart>, RepositoryError>;
+    /// カートを保存し、採番されたIDと更新後のバージョンを反映したカートを返す
+    /// 既存のカートは保存済みのバージョンが一致し、注文確定前の場合のみ更新する
+    /// 条件を満たさない場合は `RepositoryError::Conflict` を返す
+    async fn save(&self, cart: Cart) -> Result<Cart, RepositoryError>;
+    /// カートを注文確定済みにし、明細の在庫の減算と注文の保存を1つのトランザクションで行う
+    /// カートが読み込み後に変更されていた場合や、いずれかの明細で在庫が足りない場合は
+    /// 何も反映せず `RepositoryError::Conflict` を返す
+    async fn check_out(&self, cart: &Cart, order: Order) -> Result<Order, RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use crate::application::error::RepositoryError;
+use crate::domain::models::Category;
+
+#[async_trait::async_trait]
+pub trait CategoryRepository {
+    async fn find_all(&self) -> Result<Vec<Category>, RepositoryError>;
+    async fn find_by_id(&self, id: u32) -> Result<Option<Category>, RepositoryError>;
+    /// 指定したIDのカテゴリを返す（存在しないIDは結果に含まれない）
+    async fn find_by_ids(&self, ids: &[u32]) -> Result<Vec<Category>, RepositoryError>;
+    /// IDが0なら新規作成、それ以外は更新する
+    /// 更新対象が存在しない場合は `RepositoryError::NotFound` を返す
+    /// 保存時点の木構造で親カテゴリが自分自身か子孫になる場合は `RepositoryError::Conflict` を返す
+    /// （読み込み後に他の更新で木構造が変わっても循環を保存しないよう、判定と更新を1つの文で行う）
+    async fn save(&self, category: Category) -> Result<Category, RepositoryError>;
+    /// カテゴリを削除する（商品との関連付けも削除される）
+    /// 子カテゴリがある場合は `RepositoryError::Conflict` を返す
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError>;
+}
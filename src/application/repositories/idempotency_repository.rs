@@ -0,0 +1,69 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::error::RepositoryError;
+
+/// Idempotency-Keyの処理状況
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdempotencyStatus {
+    /// 最初のリクエストを処理中
+    InProgress,
+    /// レスポンスを保存済み
+    Completed,
+}
+
+impl IdempotencyStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IdempotencyStatus::InProgress => "in_progress",
+            IdempotencyStatus::Completed => "completed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "in_progress" => Some(IdempotencyStatus::InProgress),
+            "completed" => Some(IdempotencyStatus::Completed),
+            _ => None,
+        }
+    }
+}
+
+/// 再送時にそのまま返すために保存したレスポンス
+#[derive(Debug, Clone, PartialEq)]
+pub struct StoredResponse {
+    pub status: u16,
+    /// ヘッダー名と値の組（保存時の順序のまま）
+    pub headers: Vec<(String, String)>,
+    pub body: Vec<u8>,
+}
+
+/// Idempotency-Keyの記録
+#[derive(Debug, Clone)]
+pub struct IdempotencyRecord {
+    /// 最初のリクエストのフィンガープリント
+    pub fingerprint: String,
+    pub status: IdempotencyStatus,
+    /// 処理が完了している場合のレスポンス
+    pub response: Option<StoredResponse>,
+}
+
+#[async_trait::async_trait]
+pub trait IdempotencyRepository {
+    /// キーを処理中として確保する
+    /// 未使用・期限切れ・`stale_before` 以前から処理中のまま放置されたキーは確保して `None` を返し、
+    /// それ以外は既存の記録を返す
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, RepositoryError>;
+    /// 処理中のキーにレスポンスを保存して完了にする
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), RepositoryError>;
+    /// 処理中のキーを削除し、同じキーで再実行できるようにする
+    async fn release(&self, key: &str) -> Result<(), RepositoryError>;
+    /// 期限切れの記録を削除し、削除した件数を返す
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError>;
+}
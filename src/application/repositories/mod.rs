@@ -1,3 +1,17 @@
+mod pagination;
 mod product_repository;
+mod order_repository;
+mod cart_repository;
+mod reservation_repository;
+mod idempotency_repository;
+mod promotion_repository;
+mod category_repository;
 
+pub use pagination::*;
 pub use product_repository::*;
+pub use order_repository::*;
+pub use cart_repository::*;
+pub use reservation_repository::*;
+pub use idempotency_repository::*;
+pub use promotion_repository::*;
+pub use category_repository::*;
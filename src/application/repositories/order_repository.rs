@@ -0,0 +1,14 @@
+use std::collections::HashMap;
+
+use crate::application::error::RepositoryError;
+use crate::domain::models::Order;
+
+#[async_trait::async_trait]
+pub trait OrderRepository {
+    /// 注文明細の在庫を減らし、注文を保存する（1つのトランザクションで行う）
+    /// `expected_versions` に含まれる商品はバージョンの一致も条件に含める
+    /// いずれかの明細で在庫不足・バージョン不一致・商品が存在しない場合は何も反映せず
+    /// `RepositoryError::Conflict` を返す
+    async fn place(&self, order: Order, expected_versions: &HashMap<u32, u32>) -> Result<Order, RepositoryError>;
+    async fn find_by_id(&self, id: u32) -> Result<Option<Order>, RepositoryError>;
+}
@@ -0,0 +1,72 @@
+/// ページ番号ベースのページネーション条件
+#[derive(Debug, Clone, Copy)]
+pub struct Pagination {
+    /// 1始まりのページ番号
+    pub page: u32,
+    /// 1ページあたりの件数
+    pub per_page: u32,
+}
+
+impl Pagination {
+    pub fn new(page: u32, per_page: u32) -> Self {
+        Self { page, per_page }
+    }
+
+    /// 読み飛ばす件数（u32の積はu64に収まるため溢れない）
+    pub fn offset(&self) -> u64 {
+        u64::from(self.page.max(1) - 1) * u64::from(self.per_page)
+    }
+
+    pub fn limit(&self) -> u32 {
+        self.per_page
+    }
+}
+
+/// ページ単位の取得結果
+pub struct Page<T> {
+    pub items: Vec<T>,
+    /// 条件に一致する全件数
+    pub total: u64,
+    pub pagination: Pagination,
+}
+
+impl<T> Page<T> {
+    /// 次のページがある場合はそのページ番号を返す
+    pub fn next_page(&self) -> Option<u32> {
+        let fetched = self.pagination.offset().saturating_add(self.items.len() as u64);
+        // 最終ページ番号（u32::MAX）の次はない
+        (fetched < self.total).then(|| self.pagination.page.checked_add(1)).flatten()
+    }
+
+    /// 要素を変換したページを返す（変換に失敗した場合はそのエラーを返す）
+    pub fn try_map<U, E>(self, f: impl FnMut(T) -> Result<U, E>) -> Result<Page<U>, E> {
+        Ok(Page {
+            items: self.items.into_iter().map(f).collect::<Result<_, _>>()?,
+            total: self.total,
+            pagination: self.pagination,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(page: u32, per_page: u32, fetched: usize, total: u64) -> Page<()> {
+        Page { items: vec![(); fetched], total, pagination: Pagination::new(page, per_page) }
+    }
+
+    #[test]
+    fn offset_does_not_overflow_for_the_last_page() {
+        assert_eq!(Pagination::new(1, 20).offset(), 0);
+        assert_eq!(Pagination::new(3, 20).offset(), 40);
+        assert_eq!(Pagination::new(u32::MAX, 100).offset(), (u64::from(u32::MAX) - 1) * 100);
+    }
+
+    #[test]
+    fn next_page_stops_at_the_end_and_at_the_last_page_number() {
+        assert_eq!(page(1, 2, 2, 3).next_page(), Some(2));
+        assert_eq!(page(2, 2, 1, 3).next_page(), None);
+        assert_eq!(page(u32::MAX, 1, 0, u64::MAX).next_page(), None);
+    }
+}
@@ -1,9 +1,78 @@
 use crate::application::error::RepositoryError;
-use crate::domain::models::Product;
+use crate::application::repositories::{Page, Pagination};
+use crate::domain::models::{Currency, Product};
+
+/// 商品一覧の並び替えキー
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProductSortKey {
+    Id,
+    Name,
+    /// 通貨ごとにまとめ（通貨コード順）、その中で価格順に並べる
+    Price,
+    CreatedAt,
+}
+
+/// 並び順
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+/// 商品一覧の絞り込み条件
+#[derive(Debug, Clone, Default)]
+pub struct ProductFilter {
+    /// 指定した通貨の商品のみ
+    /// 価格は通貨ごとの最小単位のため、価格で絞り込む場合は通貨も指定する
+    pub currency: Option<Currency>,
+    /// 最低価格（通貨の最小単位、この価格を含む）
+    pub min_price: Option<u32>,
+    /// 最高価格（通貨の最小単位、この価格を含む）
+    pub max_price: Option<u32>,
+    /// 在庫がある商品のみ
+    pub in_stock_only: bool,
+    /// 指定したカテゴリ（子孫のカテゴリを含む）に属する商品のみ
+    pub category_id: Option<u32>,
+    /// 指定したタグ（正規化済み）が付いた商品のみ
+    pub tag: Option<String>,
+}
+
+/// 商品一覧の取得条件
+#[derive(Debug, Clone)]
+pub struct ProductListCriteria {
+    pub filter: ProductFilter,
+    pub sort_key: ProductSortKey,
+    pub sort_order: SortOrder,
+    pub pagination: Pagination,
+}
+
+/// 全文検索のヒット
+pub struct ProductSearchHit {
+    pub product: Product,
+    /// 関連度スコア（大きいほど関連度が高い）
+    pub score: f64,
+    /// 検索語をハイライトした商品名
+    pub highlighted_name: String,
+    /// 検索語をハイライトした本文の抜粋
+    pub snippet: String,
+}
 
 #[async_trait::async_trait]
 pub trait ProductRepository {
-    async fn find_all(&self) -> Result<Vec<Product>, RepositoryError>;
+    /// 条件に一致する商品をページ単位で取得する
+    async fn find_all(&self, criteria: &ProductListCriteria) -> Result<Page<Product>, RepositoryError>;
+    /// 商品名・説明をキーワードで全文検索し、関連度の高い順に返す
+    async fn search(&self, keyword: &str, limit: u32) -> Result<Vec<ProductSearchHit>, RepositoryError>;
     async fn find_by_id(&self, id: u32) -> Result<Option<Product>, RepositoryError>;
-    async fn save(&self, product: Product) -> Result<(), RepositoryError>;
+    /// 指定したSKU（大文字・小文字は区別しない）のバリエーションを持つ商品を返す
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<Product>, RepositoryError>;
+    /// 商品を保存し、採番されたIDと更新後のバージョンを反映した商品を返す
+    /// 既存の商品は保存済みのバージョンが一致する場合のみ更新する
+    /// バージョンが一致しない場合は `RepositoryError::Conflict` を返す
+    /// カテゴリとタグの関連付け、バリエーションは商品の内容で置き換える
+    async fn save(&self, product: Product) -> Result<Product, RepositoryError>;
+    /// 商品を削除する
+    /// `expected_version` が指定された場合はバージョンが一致する場合のみ削除する
+    /// 商品が存在しない場合は `RepositoryError::NotFound` を返す
+    async fn delete(&self, id: u32, expected_version: Option<u32>) -> Result<(), RepositoryError>;
 }
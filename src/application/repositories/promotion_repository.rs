@@ -0,0 +1,16 @@
+use crate::application::error::RepositoryError;
+use crate::domain::models::Promotion;
+
+#[async_trait::async_trait]
+pub trait PromotionRepository {
+    async fn find_all(&self) -> Result<Vec<Promotion>, RepositoryError>;
+    async fn find_by_id(&self, id: u32) -> Result<Option<Promotion>, RepositoryError>;
+    /// 指定した商品に適用され得るプロモーションを返す
+    /// 全商品・指定商品が対象のもののうち、クーポン不要のものと `coupon_code` に一致するもの（大文字・小文字は区別しない）
+    /// 適用期間・利用回数の判定はドメイン側で行う
+    async fn find_candidates(&self, product_ids: &[u32], coupon_code: Option<&str>) -> Result<Vec<Promotion>, RepositoryError>;
+    /// 新しいプロモーションを保存する
+    /// クーポンコードが他のプロモーションで使われている場合は `RepositoryError::Conflict` を返す
+    async fn save(&self, promotion: Promotion) -> Result<Promotion, RepositoryError>;
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,21 @@
+use chrono::{DateTime, Utc};
+
+use crate::application::error::RepositoryError;
+use crate::domain::models::{Order, Reservation};
+
+#[async_trait::async_trait]
+pub trait ReservationRepository {
+    async fn find_by_id(&self, id: u32) -> Result<Option<Reservation>, RepositoryError>;
+    /// 指定時刻までに期限切れとなった有効な予約を、期限の古い順に最大 `limit` 件返す
+    async fn find_expired(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<Reservation>, RepositoryError>;
+    /// 販売可能な在庫から予約分を確保し、予約を保存する
+    /// SKUを指定した商品はバリエーションの在庫から確保する
+    /// いずれかの商品で在庫が足りない場合は何も反映せず `RepositoryError::Conflict` を返す
+    async fn reserve(&self, reservation: Reservation) -> Result<Reservation, RepositoryError>;
+    /// 予約を確定済みにし、確保していた在庫を注文として保存する
+    /// 予約が既に有効でない場合は何も反映せず `RepositoryError::Conflict` を返す
+    async fn confirm(&self, reservation: &Reservation, order: Order) -> Result<Order, RepositoryError>;
+    /// 取り消し・失効した予約の状態を保存し、確保していた在庫を販売可能な在庫に戻す
+    /// 予約が既に有効でない場合は何も反映せず `RepositoryError::Conflict` を返す
+    async fn release(&self, reservation: &Reservation) -> Result<(), RepositoryError>;
+}
@@ -0,0 +1,65 @@
+use std::sync::Arc;
+
+use crate::application::repositories::{CartRepository, ProductRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::AddCartItemCommand;
+use crate::application::queries::GetCartQuery;
+use crate::domain::models::ProductVariant;
+use crate::domain::DomainError;
+
+pub struct AddCartItemUseCase {
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+}
+
+impl AddCartItemUseCase {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository + Send + Sync>,
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            product_repository,
+        }
+    }
+
+    /// カートに商品を追加する
+    /// 在庫はチェックアウト時に検証するため、ここでは商品とバリエーションの存在のみ確認する
+    /// バリエーションのある商品はSKUの指定が必要
+    #[tracing::instrument(name = "add_cart_item_usecase", skip(self))]
+    pub async fn add(&self, cart_id: u32, command: AddCartItemCommand) -> Result<GetCartQuery, ApplicationError> {
+        let mut cart = match self.cart_repository.find_by_id(cart_id).await? {
+            Some(cart) => cart,
+            None => return Err(ApplicationError::CartNotFound(cart_id)),
+        };
+        let product = match self.product_repository.find_by_id(command.product_id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(command.product_id)),
+        };
+        // 同じバリエーションを1行にまとめるため、SKUは商品に登録された表記にそろえる
+        let sku = match (&command.sku, product.has_variants()) {
+            (Some(sku), _) => match product.variant(sku) {
+                Some(variant) => Some(variant.sku.clone()),
+                None => {
+                    return Err(DomainError::VariantNotFound {
+                        product_id: product.id,
+                        sku: ProductVariant::normalize_sku(sku),
+                    }
+                    .into());
+                }
+            },
+            (None, true) => return Err(DomainError::VariantRequired(product.id).into()),
+            (None, false) => None,
+        };
+
+        cart.add_item(command.product_id, sku.as_deref(), command.quantity)?;
+
+        let cart = match self.cart_repository.save(cart).await {
+            Ok(cart) => cart,
+            // 読み込み後に他のリクエストでカートが変更された・注文が確定した
+            Err(RepositoryError::Conflict) => return Err(ApplicationError::CartChanged(cart_id)),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(cart.into())
+    }
+}
@@ -1,30 +1,136 @@
+use std::collections::HashMap;
 use std::sync::Arc;
 
-use crate::application::repositories::ProductRepository;
-use crate::application::error::ApplicationError;
+use chrono::Utc;
+
+use crate::application::repositories::{OrderRepository, ProductRepository, PromotionRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
 use crate::application::commands::BuyProductCommand;
+use crate::application::queries::GetOrderQuery;
+use crate::domain::models::{Order, OrderLine};
+use crate::domain::DomainError;
+use crate::domain::pricing;
+use crate::domain::tax::{TaxRegion, TaxTable};
+
+/// If-Matchなしの購入で、同時購入と競合した場合に読み込みからやり直す回数の上限
+const PLACE_ATTEMPTS: u32 = 2;
 
 pub struct BuyProductUseCase {
     product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    order_repository: Arc<dyn OrderRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    tax_table: Arc<TaxTable>,
 }
 
 impl BuyProductUseCase {
-    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        order_repository: Arc<dyn OrderRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        tax_table: Arc<TaxTable>,
+    ) -> Self {
         Self {
             product_repository,
+            order_repository,
+            promotion_repository,
+            tax_table,
+        }
+    }
+
+    /// 商品を購入し、確定した注文を返す
+    #[tracing::instrument(name = "buy_product_usecase", skip(self))]
+    pub async fn buy(&self, product_id: u32, command: BuyProductCommand) -> Result<GetOrderQuery, ApplicationError> {
+        if command.quantity == 0 {
+            return Err(ApplicationError::Validation("quantity must be greater than 0".to_string()));
+        }
+        let region = self.tax_table.region(command.region.as_deref())?;
+
+        // If-Matchなしの購入は特定のバージョンを前提にしていないため、
+        // 読み込み後に他の購入で在庫が変わっただけなら最新の在庫で注文をやり直す
+        let mut attempt = 1;
+        loop {
+            match self.try_buy(product_id, &command, region).await {
+                Err(ApplicationError::Repository(RepositoryError::Conflict)) if command.expected_version.is_none() => {
+                    if attempt == PLACE_ATTEMPTS {
+                        return Err(ApplicationError::StockChanged(product_id));
+                    }
+                    attempt += 1;
+                }
+                result => return result,
+            }
         }
     }
 
-    pub async fn buy(&self, product_id: u32, command: BuyProductCommand) -> Result<(), ApplicationError> {
-        print!("->> buy_product_usecase");
-        
+    /// 商品を読み込み、その時点の在庫・価格で注文を確定する
+    /// 読み込み後に他のリクエストと競合した場合は `RepositoryError::Conflict` を返す
+    async fn try_buy(
+        &self,
+        product_id: u32,
+        command: &BuyProductCommand,
+        region: &TaxRegion,
+    ) -> Result<GetOrderQuery, ApplicationError> {
+        let mut product = match self.product_repository.find_by_id(product_id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(product_id)),
+        };
+        // クライアントが古いバージョンを元に購入しようとしている場合は競合
+        if command.expected_version.is_some_and(|version| version != product.version) {
+            return Err(RepositoryError::Conflict.into());
+        }
+        // 読み込んだ時点の在庫に対してドメインルールを検証
+        // バリエーションを指定した場合はバリエーションの在庫から販売する
+        match &command.sku {
+            Some(sku) => product.sell_variant(sku, command.quantity)?,
+            None => product.sell(command.quantity)?,
+        }
+
+        // 単価と割引は読み込んだ時点の価格・プロモーションをスナップショットとして注文に残す
+        let promotions = self.promotion_repository
+            .find_candidates(&[product.id], command.coupon_code.as_deref())
+            .await?;
+        let coupon_code = command.coupon_code.as_deref();
+        let (quote, sku) = match command.sku.as_deref().and_then(|sku| product.variant(sku)) {
+            Some(variant) => (
+                pricing::quote_variant(&product, variant, command.quantity, &promotions, coupon_code, Utc::now())?,
+                Some(variant.sku.clone()),
+            ),
+            None => (pricing::quote(&product, command.quantity, &promotions, coupon_code, Utc::now())?, None),
+        };
+        let line = OrderLine::from_quote(product.id, product.name, product.tax_category, &quote).with_sku(sku);
+        let order = Order::place(vec![line], region)?;
+        let expected_versions: HashMap<u32, u32> = command.expected_version
+            .map(|version| (product_id, version))
+            .into_iter()
+            .collect();
+
+        // 在庫の減算と注文の保存は同一トランザクションで行い、同時購入による売り越しを防ぐ
+        match self.order_repository.place(order, &expected_versions).await {
+            Ok(order) => return Ok(order.try_into()?),
+            Err(RepositoryError::Conflict) => {}
+            Err(e) => return Err(e.into()),
+        }
+
+        // 読み込み後に他のリクエストで商品やプロモーションが更新されていた場合は最新の状態からエラーを判定する
         match self.product_repository.find_by_id(product_id).await? {
-            Some(mut product) => {
-                product.sell(command.quantity)?;
-                self.product_repository.save(product).await?;
-                Ok(())
+            Some(latest) if command.expected_version.is_some_and(|version| version != latest.version) => {
+                return Err(RepositoryError::Conflict.into());
+            }
+            // 在庫不足やバリエーションの削除など、最新の在庫に対して販売できない理由を返す
+            Some(mut latest) => match &command.sku {
+                Some(sku) => latest.sell_variant(sku, command.quantity)?,
+                None => latest.sell(command.quantity)?,
+            },
+            None => return Err(ApplicationError::ProductNotFound(product_id)),
+        }
+        if let Some(promotion_id) = quote.promotion_id {
+            let available = self.promotion_repository
+                .find_by_id(promotion_id)
+                .await?
+                .is_some_and(|promotion| promotion.is_available(Utc::now()));
+            if !available {
+                return Err(DomainError::PromotionUnavailable(promotion_id).into());
             }
-            None => Err(ApplicationError::ProductNotFound(product_id)),
         }
+        Err(RepositoryError::Conflict.into())
     }
 }
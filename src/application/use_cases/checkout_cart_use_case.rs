@@ -0,0 +1,129 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{CartRepository, ProductRepository, PromotionRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::queries::GetOrderQuery;
+use crate::domain::models::{Cart, CartStatus, Order, OrderLine};
+use crate::domain::DomainError;
+use crate::domain::pricing;
+use crate::domain::tax::TaxTable;
+
+pub struct CheckoutCartUseCase {
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    tax_table: Arc<TaxTable>,
+}
+
+impl CheckoutCartUseCase {
+    pub fn new(
+        cart_repository: Arc<dyn CartRepository + Send + Sync>,
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        tax_table: Arc<TaxTable>,
+    ) -> Self {
+        Self {
+            cart_repository,
+            product_repository,
+            promotion_repository,
+            tax_table,
+        }
+    }
+
+    /// カート内の全商品を1つの注文として確定する
+    /// 全明細の在庫を減らせる場合のみ注文し、1つでも足りなければ何も反映しない
+    /// 税額は `region`（省略時は既定の地域）の税制で求める
+    #[tracing::instrument(name = "checkout_cart_usecase", skip(self))]
+    pub async fn checkout(&self, cart_id: u32, region: Option<&str>) -> Result<GetOrderQuery, ApplicationError> {
+        let region = self.tax_table.region(region)?;
+        let cart = match self.cart_repository.find_by_id(cart_id).await? {
+            Some(cart) => cart,
+            None => return Err(ApplicationError::CartNotFound(cart_id)),
+        };
+        cart.ensure_checkoutable()?;
+
+        // 読み込んだ時点の在庫に対して全明細のドメインルールを検証し、単価と割引をスナップショットする
+        let product_ids: Vec<u32> = cart.items.iter().map(|item| item.product_id).collect();
+        let promotions = self.promotion_repository.find_candidates(&product_ids, None).await?;
+        let now = Utc::now();
+        let mut lines = Vec::with_capacity(cart.items.len());
+        for item in &cart.items {
+            let mut product = match self.product_repository.find_by_id(item.product_id).await? {
+                Some(product) => product,
+                None => return Err(ApplicationError::ProductNotFound(item.product_id)),
+            };
+            // バリエーションを選んだ明細はバリエーションの在庫・価格で注文する
+            let quote = match &item.sku {
+                Some(sku) => {
+                    product.sell_variant(sku, item.quantity)?;
+                    let variant = product.variant(sku).ok_or_else(|| DomainError::VariantNotFound {
+                        product_id: product.id,
+                        sku: sku.clone(),
+                    })?;
+                    pricing::quote_variant(&product, variant, item.quantity, &promotions, None, now)?
+                }
+                None => {
+                    product.sell(item.quantity)?;
+                    pricing::quote(&product, item.quantity, &promotions, None, now)?
+                }
+            };
+            lines.push(
+                OrderLine::from_quote(product.id, product.name, product.tax_category, &quote).with_sku(item.sku.clone()),
+            );
+        }
+        let order = Order::place(lines, region)?;
+        let promotion_ids: Vec<u32> = order.lines.iter().filter_map(|line| line.promotion_id).collect();
+
+        match self.cart_repository.check_out(&cart, order).await {
+            Ok(order) => Ok(order.try_into()?),
+            Err(RepositoryError::Conflict) => Err(self.diagnose_conflict(&cart, &promotion_ids).await),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// チェックアウトが競合した理由を最新の状態から判定する
+    async fn diagnose_conflict(&self, cart: &Cart, promotion_ids: &[u32]) -> ApplicationError {
+        let latest = match self.cart_repository.find_by_id(cart.id).await {
+            Ok(latest) => latest,
+            Err(e) => return e.into(),
+        };
+        match latest {
+            None => return ApplicationError::CartNotFound(cart.id),
+            Some(latest) if latest.status == CartStatus::CheckedOut => {
+                return DomainError::CartAlreadyCheckedOut(cart.id).into();
+            }
+            Some(latest) if latest.version != cart.version => return ApplicationError::CartChanged(cart.id),
+            Some(_) => {}
+        }
+
+        for item in &cart.items {
+            // 在庫不足やバリエーションの削除など、最新の在庫に対して販売できない理由を返す
+            let mut product = match self.product_repository.find_by_id(item.product_id).await {
+                Ok(Some(product)) => product,
+                Ok(None) => return ApplicationError::ProductNotFound(item.product_id),
+                Err(e) => return e.into(),
+            };
+            let sold = match &item.sku {
+                Some(sku) => product.sell_variant(sku, item.quantity),
+                None => product.sell(item.quantity),
+            };
+            if let Err(e) = sold {
+                return e.into();
+            }
+        }
+
+        // 見積もったプロモーションが利用上限に達した・終了した場合
+        for &promotion_id in promotion_ids {
+            match self.promotion_repository.find_by_id(promotion_id).await {
+                Ok(Some(promotion)) if promotion.is_available(Utc::now()) => {}
+                Ok(_) => return DomainError::PromotionUnavailable(promotion_id).into(),
+                Err(e) => return e.into(),
+            }
+        }
+
+        // 判定した時点では競合が解消している場合は、読み直してのやり直しを促す
+        ApplicationError::CartChanged(cart.id)
+    }
+}
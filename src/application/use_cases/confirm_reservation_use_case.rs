@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{ProductRepository, PromotionRepository, ReservationRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::queries::GetOrderQuery;
+use crate::domain::models::{Order, OrderLine, ReservationStatus};
+use crate::domain::DomainError;
+use crate::domain::pricing;
+use crate::domain::tax::TaxTable;
+
+pub struct ConfirmReservationUseCase {
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    tax_table: Arc<TaxTable>,
+}
+
+impl ConfirmReservationUseCase {
+    pub fn new(
+        reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        tax_table: Arc<TaxTable>,
+    ) -> Self {
+        Self {
+            reservation_repository,
+            product_repository,
+            promotion_repository,
+            tax_table,
+        }
+    }
+
+    /// 予約していた在庫を1つの注文として確定する
+    /// 在庫は予約時に確保済みのため、ここでは在庫数を検証しない
+    /// 税額は `region`（省略時は既定の地域）の税制で求める
+    #[tracing::instrument(name = "confirm_reservation_usecase", skip(self))]
+    pub async fn confirm(&self, reservation_id: u32, region: Option<&str>) -> Result<GetOrderQuery, ApplicationError> {
+        let region = self.tax_table.region(region)?;
+        let mut reservation = match self.reservation_repository.find_by_id(reservation_id).await? {
+            Some(reservation) => reservation,
+            None => return Err(ApplicationError::ReservationNotFound(reservation_id)),
+        };
+        let now = Utc::now();
+        reservation.confirm(now)?;
+
+        // 確定時点の商品名・単価・割引を注文明細にスナップショットする
+        let product_ids: Vec<u32> = reservation.items.iter().map(|item| item.product_id).collect();
+        let promotions = self.promotion_repository.find_candidates(&product_ids, None).await?;
+        let mut lines = Vec::with_capacity(reservation.items.len());
+        for item in &reservation.items {
+            let product = match self.product_repository.find_by_id(item.product_id).await? {
+                Some(product) => product,
+                None => return Err(ApplicationError::ProductNotFound(item.product_id)),
+            };
+            // バリエーションを予約した明細はバリエーションの価格で注文する
+            let quote = match &item.sku {
+                Some(sku) => {
+                    let variant = product.variant(sku).ok_or_else(|| DomainError::VariantNotFound {
+                        product_id: product.id,
+                        sku: sku.clone(),
+                    })?;
+                    pricing::quote_variant(&product, variant, item.quantity, &promotions, None, now)?
+                }
+                None => pricing::quote(&product, item.quantity, &promotions, None, now)?,
+            };
+            lines.push(
+                OrderLine::from_quote(product.id, product.name, product.tax_category, &quote).with_sku(item.sku.clone()),
+            );
+        }
+        let order = Order::place(lines, region)?;
+        let promotion_ids: Vec<u32> = order.lines.iter().filter_map(|line| line.promotion_id).collect();
+
+        match self.reservation_repository.confirm(&reservation, order).await {
+            Ok(order) => Ok(order.try_into()?),
+            Err(RepositoryError::Conflict) => Err(self.diagnose_conflict(reservation_id, &promotion_ids).await),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// 確定が競合した理由を最新の状態から判定する
+    async fn diagnose_conflict(&self, reservation_id: u32, promotion_ids: &[u32]) -> ApplicationError {
+        match self.reservation_repository.find_by_id(reservation_id).await {
+            Ok(None) => return ApplicationError::ReservationNotFound(reservation_id),
+            Ok(Some(latest)) if latest.status != ReservationStatus::Active => {
+                return DomainError::ReservationNotActive(reservation_id).into();
+            }
+            Ok(Some(latest)) if latest.is_expired(Utc::now()) => {
+                return DomainError::ReservationExpired(reservation_id).into();
+            }
+            Ok(Some(_)) => {}
+            Err(e) => return e.into(),
+        }
+
+        // 見積もったプロモーションが利用上限に達した・終了した場合
+        for &promotion_id in promotion_ids {
+            match self.promotion_repository.find_by_id(promotion_id).await {
+                Ok(Some(promotion)) if promotion.is_available(Utc::now()) => {}
+                Ok(_) => return DomainError::PromotionUnavailable(promotion_id).into(),
+                Err(e) => return e.into(),
+            }
+        }
+
+        ApplicationError::ReservationConflict(Some(reservation_id))
+    }
+}
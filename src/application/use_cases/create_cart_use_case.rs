@@ -0,0 +1,24 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CartRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetCartQuery;
+use crate::domain::models::Cart;
+
+pub struct CreateCartUseCase {
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+}
+
+impl CreateCartUseCase {
+    pub fn new(cart_repository: Arc<dyn CartRepository + Send + Sync>) -> Self {
+        Self {
+            cart_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "create_cart_usecase", skip(self))]
+    pub async fn create(&self) -> Result<GetCartQuery, ApplicationError> {
+        let cart = self.cart_repository.save(Cart::create()).await?;
+        Ok(cart.into())
+    }
+}
@@ -0,0 +1,33 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::ApplicationError;
+use crate::application::commands::CreateCategoryCommand;
+use crate::application::queries::GetCategoryQuery;
+use crate::domain::models::Category;
+
+pub struct CreateCategoryUseCase {
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl CreateCategoryUseCase {
+    pub fn new(category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "create_category_usecase", skip(self))]
+    pub async fn create(&self, command: CreateCategoryCommand) -> Result<GetCategoryQuery, ApplicationError> {
+        if let Some(parent_id) = command.parent_id
+            && self.category_repository.find_by_id(parent_id).await?.is_none()
+        {
+            return Err(ApplicationError::CategoryNotFound(parent_id));
+        }
+
+        let category = Category::create(command.name, command.parent_id)?;
+        let category = self.category_repository.save(category).await?;
+
+        Ok(category.into())
+    }
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{CategoryRepository, ProductRepository, PromotionRepository};
+use crate::application::error::ApplicationError;
+use crate::application::commands::CreateProductCommand;
+use crate::application::queries::GetProductQuery;
+use crate::domain::models::{Category, Money, Product};
+
+pub struct CreateProductUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl CreateProductUseCase {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            product_repository,
+            promotion_repository,
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "create_product_usecase", skip(self))]
+    pub async fn create(&self, command: CreateProductCommand) -> Result<GetProductQuery, ApplicationError> {
+        let mut product = Product::create(
+            command.name,
+            Money::new(command.price, command.currency),
+            command.description,
+            command.quantity,
+        )?
+        .with_tax_category(command.tax_category);
+        product.categories = self.find_categories(&command.category_ids).await?;
+        product.set_tags(command.tags);
+        product.validate()?;
+
+        let product = self.product_repository.save(product).await?;
+        let promotions = self.promotion_repository.find_candidates(&[product.id], None).await?;
+
+        Ok(GetProductQuery::priced(product, &promotions, Utc::now())?)
+    }
+
+    /// 指定されたIDのカテゴリを取得する（存在しないIDがあればエラー）
+    async fn find_categories(&self, ids: &[u32]) -> Result<Vec<Category>, ApplicationError> {
+        let categories = self.category_repository.find_by_ids(ids).await?;
+        if let Some(missing) = ids.iter().find(|id| !categories.iter().any(|category| category.id == **id)) {
+            return Err(ApplicationError::CategoryNotFound(*missing));
+        }
+        Ok(categories)
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::application::repositories::{ProductRepository, PromotionRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::CreatePromotionCommand;
+use crate::application::queries::GetPromotionQuery;
+use crate::domain::models::Promotion;
+
+pub struct CreatePromotionUseCase {
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+}
+
+impl CreatePromotionUseCase {
+    pub fn new(
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            promotion_repository,
+            product_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "create_promotion_usecase", skip(self))]
+    pub async fn create(&self, command: CreatePromotionCommand) -> Result<GetPromotionQuery, ApplicationError> {
+        if let Some(product_id) = command.product_id
+            && self.product_repository.find_by_id(product_id).await?.is_none()
+        {
+            return Err(ApplicationError::ProductNotFound(product_id));
+        }
+
+        let promotion = Promotion::create(
+            command.name,
+            command.rule,
+            command.product_id,
+            command.coupon_code,
+            command.starts_at,
+            command.ends_at,
+            command.usage_limit,
+        )?;
+        let coupon_code = promotion.coupon_code.clone();
+
+        match self.promotion_repository.save(promotion).await {
+            Ok(promotion) => Ok(promotion.into()),
+            Err(RepositoryError::Conflict) => Err(ApplicationError::CouponCodeTaken(coupon_code.unwrap_or_default())),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::application::commands::CreateReservationCommand;
+use crate::application::repositories::{ProductRepository, ReservationRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::queries::GetReservationQuery;
+use crate::domain::models::{Product, ProductVariant, Reservation, ReservationItem};
+use crate::domain::DomainError;
+
+pub struct CreateReservationUseCase {
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    /// 予約の有効期間
+    ttl: Duration,
+}
+
+impl CreateReservationUseCase {
+    pub fn new(
+        reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        ttl: Duration,
+    ) -> Self {
+        Self {
+            reservation_repository,
+            product_repository,
+            ttl,
+        }
+    }
+
+    /// 指定された商品の在庫を有効期間の間確保する
+    /// 全商品の在庫を確保できる場合のみ予約し、1つでも足りなければ何も反映しない
+    #[tracing::instrument(name = "create_reservation_usecase", skip(self))]
+    pub async fn create(&self, command: CreateReservationCommand) -> Result<GetReservationQuery, ApplicationError> {
+        let items = command.items
+            .into_iter()
+            .map(|item| ReservationItem {
+                product_id: item.product_id,
+                // 同じバリエーションを1件にまとめるため、SKUは大文字・小文字をそろえる
+                sku: item.sku.as_deref().map(ProductVariant::normalize_sku),
+                quantity: item.quantity,
+            })
+            .collect();
+        let reservation = Reservation::create(items, Utc::now() + self.ttl)?;
+
+        // 読み込んだ時点の在庫に対して全商品のドメインルールを検証する
+        for item in &reservation.items {
+            let mut product = match self.product_repository.find_by_id(item.product_id).await? {
+                Some(product) => product,
+                None => return Err(ApplicationError::ProductNotFound(item.product_id)),
+            };
+            Self::reserve(&mut product, item)?;
+        }
+
+        let items = reservation.items.clone();
+        match self.reservation_repository.reserve(reservation).await {
+            Ok(reservation) => Ok(reservation.into()),
+            Err(RepositoryError::Conflict) => Err(self.diagnose_conflict(&items).await),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// バリエーションを指定した商品はバリエーションの在庫から確保する
+    fn reserve(product: &mut Product, item: &ReservationItem) -> Result<(), DomainError> {
+        match &item.sku {
+            Some(sku) => product.reserve_variant(sku, item.quantity),
+            None => product.reserve(item.quantity),
+        }
+    }
+
+    /// 予約が競合した理由を最新の在庫から判定する
+    async fn diagnose_conflict(&self, items: &[ReservationItem]) -> ApplicationError {
+        for item in items {
+            let mut product = match self.product_repository.find_by_id(item.product_id).await {
+                Ok(Some(product)) => product,
+                Ok(None) => return ApplicationError::ProductNotFound(item.product_id),
+                Err(e) => return e.into(),
+            };
+            if let Err(e) = Self::reserve(&mut product, item) {
+                return e.into();
+            }
+        }
+
+        // 判定した時点では在庫が足りている場合は、やり直しを促す
+        ApplicationError::ReservationConflict(None)
+    }
+}
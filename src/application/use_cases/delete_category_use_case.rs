@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+
+pub struct DeleteCategoryUseCase {
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl DeleteCategoryUseCase {
+    pub fn new(category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    /// カテゴリを削除する
+    /// 商品は削除されず、カテゴリとの関連付けだけが外れる
+    #[tracing::instrument(name = "delete_category_usecase", skip(self))]
+    pub async fn delete(&self, id: u32) -> Result<(), ApplicationError> {
+        match self.category_repository.delete(id).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::CategoryNotFound(id)),
+            Err(RepositoryError::Conflict) => Err(ApplicationError::CategoryHasChildren(id)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
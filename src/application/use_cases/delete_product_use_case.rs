@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use crate::application::repositories::ProductRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::DeleteProductCommand;
+
+pub struct DeleteProductUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+}
+
+impl DeleteProductUseCase {
+    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+        Self {
+            product_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "delete_product_usecase", skip(self))]
+    pub async fn delete(&self, id: u32, command: DeleteProductCommand) -> Result<(), ApplicationError> {
+        match self.product_repository.delete(id, command.expected_version).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::ProductNotFound(id)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+
+pub struct DeletePromotionUseCase {
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+}
+
+impl DeletePromotionUseCase {
+    pub fn new(promotion_repository: Arc<dyn PromotionRepository + Send + Sync>) -> Self {
+        Self {
+            promotion_repository,
+        }
+    }
+
+    /// プロモーションを削除する
+    /// 適用済みの注文明細には割引とプロモーションIDが残る
+    #[tracing::instrument(name = "delete_promotion_usecase", skip(self))]
+    pub async fn delete(&self, id: u32) -> Result<(), ApplicationError> {
+        match self.promotion_repository.delete(id).await {
+            Ok(()) => Ok(()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::PromotionNotFound(id)),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+
+/// 1回の問い合わせで取得する期限切れ予約の件数
+const BATCH_SIZE: u32 = 100;
+
+pub struct ExpireReservationsUseCase {
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+}
+
+impl ExpireReservationsUseCase {
+    pub fn new(reservation_repository: Arc<dyn ReservationRepository + Send + Sync>) -> Self {
+        Self {
+            reservation_repository,
+        }
+    }
+
+    /// 期限切れの予約を全て失効させて在庫を戻し、失効させた件数を返す
+    /// 同時に確定・取り消しされた予約はそちらを優先して読み飛ばす
+    #[tracing::instrument(name = "expire_reservations_usecase", skip(self))]
+    pub async fn expire_stale(&self) -> Result<usize, ApplicationError> {
+        let now = Utc::now();
+        let mut expired = 0;
+
+        loop {
+            let reservations = self.reservation_repository.find_expired(now, BATCH_SIZE).await?;
+            if reservations.is_empty() {
+                return Ok(expired);
+            }
+
+            for mut reservation in reservations {
+                reservation.expire(now)?;
+                match self.reservation_repository.release(&reservation).await {
+                    Ok(()) => expired += 1,
+                    Err(RepositoryError::Conflict) => {}
+                    Err(e) => return Err(e.into()),
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetCategoryQuery;
+
+pub struct GetAllCategoriesUseCase {
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl GetAllCategoriesUseCase {
+    pub fn new(category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_all_categories_usecase", skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<GetCategoryQuery>, ApplicationError> {
+        let categories = self.category_repository.find_all().await?;
+        Ok(categories.into_iter().map(|category| category.into()).collect())
+    }
+}
@@ -1,24 +1,42 @@
 use std::sync::Arc;
 
-use crate::application::repositories::ProductRepository;
+use chrono::Utc;
+
+use crate::application::repositories::{Page, ProductListCriteria, ProductRepository, PromotionRepository};
 use crate::application::error::ApplicationError;
 use crate::application::queries::GetProductQuery;
 
 pub struct GetAllProductsUseCase {
     product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
 }
 
 impl GetAllProductsUseCase {
-    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    ) -> Self {
         Self {
             product_repository,
+            promotion_repository,
         }
     }
 
-    pub async fn get_all(&self) -> Result<Vec<GetProductQuery>, ApplicationError> {
-        print!("->> get_all_products_usecase");
-        let products = self.product_repository.find_all().await?;
-        let result = products.into_iter().map(|p| p.into()).collect();
-        Ok(result)
+    #[tracing::instrument(name = "get_all_products_usecase", skip(self))]
+    pub async fn get_all(&self, criteria: ProductListCriteria) -> Result<Page<GetProductQuery>, ApplicationError> {
+        if let (Some(min), Some(max)) = (criteria.filter.min_price, criteria.filter.max_price)
+            && min > max
+        {
+            return Err(ApplicationError::Validation(
+                "min_price must not be greater than max_price".to_string(),
+            ));
+        }
+
+        let products = self.product_repository.find_all(&criteria).await?;
+        let product_ids: Vec<u32> = products.items.iter().map(|p| p.id).collect();
+        let promotions = self.promotion_repository.find_candidates(&product_ids, None).await?;
+
+        let now = Utc::now();
+        Ok(products.try_map(|p| GetProductQuery::priced(p, &promotions, now))?)
     }
-}
\ No newline at end of file
+}
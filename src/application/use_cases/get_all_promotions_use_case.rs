@@ -0,0 +1,23 @@
+use std::sync::Arc;
+
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetPromotionQuery;
+
+pub struct GetAllPromotionsUseCase {
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+}
+
+impl GetAllPromotionsUseCase {
+    pub fn new(promotion_repository: Arc<dyn PromotionRepository + Send + Sync>) -> Self {
+        Self {
+            promotion_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_all_promotions_usecase", skip(self))]
+    pub async fn get_all(&self) -> Result<Vec<GetPromotionQuery>, ApplicationError> {
+        let promotions = self.promotion_repository.find_all().await?;
+        Ok(promotions.into_iter().map(|promotion| promotion.into()).collect())
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CartRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetCartQuery;
+
+pub struct GetCartUseCase {
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+}
+
+impl GetCartUseCase {
+    pub fn new(cart_repository: Arc<dyn CartRepository + Send + Sync>) -> Self {
+        Self {
+            cart_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_cart_usecase", skip(self))]
+    pub async fn get_by_id(&self, id: u32) -> Result<GetCartQuery, ApplicationError> {
+        match self.cart_repository.find_by_id(id).await? {
+            Some(cart) => Ok(cart.into()),
+            None => Err(ApplicationError::CartNotFound(id)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetCategoryQuery;
+
+pub struct GetCategoryUseCase {
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl GetCategoryUseCase {
+    pub fn new(category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_category_usecase", skip(self))]
+    pub async fn get_by_id(&self, id: u32) -> Result<GetCategoryQuery, ApplicationError> {
+        match self.category_repository.find_by_id(id).await? {
+            Some(category) => Ok(category.into()),
+            None => Err(ApplicationError::CategoryNotFound(id)),
+        }
+    }
+}
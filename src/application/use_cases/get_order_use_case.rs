@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::repositories::OrderRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetOrderQuery;
+
+pub struct GetOrderUseCase {
+    order_repository: Arc<dyn OrderRepository + Send + Sync>,
+}
+
+impl GetOrderUseCase {
+    pub fn new(order_repository: Arc<dyn OrderRepository + Send + Sync>) -> Self {
+        Self {
+            order_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_order_usecase", skip(self))]
+    pub async fn get_by_id(&self, id: u32) -> Result<GetOrderQuery, ApplicationError> {
+        match self.order_repository.find_by_id(id).await? {
+            Some(order) => Ok(order.try_into()?),
+            None => Err(ApplicationError::OrderNotFound(id)),
+        }
+    }
+}
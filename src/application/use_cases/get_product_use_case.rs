@@ -1,26 +1,35 @@
 use std::sync::Arc;
 
-use crate::application::repositories::ProductRepository;
+use chrono::Utc;
+
+use crate::application::repositories::{ProductRepository, PromotionRepository};
 use crate::application::error::ApplicationError;
 use crate::application::queries::GetProductQuery;
 
 pub struct GetProductUseCase {
     product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
 }
 
 impl GetProductUseCase {
-    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    ) -> Self {
         Self {
             product_repository,
+            promotion_repository,
         }
     }
 
+    #[tracing::instrument(name = "get_product_usecase", skip(self))]
     pub async fn get_by_id(&self, id: u32) -> Result<GetProductQuery, ApplicationError> {
-        print!("->> get_product_usecase");
-        
-        match self.product_repository.find_by_id(id).await? {
-            Some(product) => Ok(product.into()),
-            None => Err(ApplicationError::ProductNotFound(id)),
-        }
+        let product = match self.product_repository.find_by_id(id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(id)),
+        };
+        let promotions = self.promotion_repository.find_candidates(&[product.id], None).await?;
+
+        Ok(GetProductQuery::priced(product, &promotions, Utc::now())?)
     }
 }
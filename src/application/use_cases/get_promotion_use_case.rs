@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetPromotionQuery;
+
+pub struct GetPromotionUseCase {
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+}
+
+impl GetPromotionUseCase {
+    pub fn new(promotion_repository: Arc<dyn PromotionRepository + Send + Sync>) -> Self {
+        Self {
+            promotion_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_promotion_usecase", skip(self))]
+    pub async fn get_by_id(&self, id: u32) -> Result<GetPromotionQuery, ApplicationError> {
+        match self.promotion_repository.find_by_id(id).await? {
+            Some(promotion) => Ok(promotion.into()),
+            None => Err(ApplicationError::PromotionNotFound(id)),
+        }
+    }
+}
@@ -0,0 +1,25 @@
+use std::sync::Arc;
+
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::ApplicationError;
+use crate::application::queries::GetReservationQuery;
+
+pub struct GetReservationUseCase {
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+}
+
+impl GetReservationUseCase {
+    pub fn new(reservation_repository: Arc<dyn ReservationRepository + Send + Sync>) -> Self {
+        Self {
+            reservation_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "get_reservation_usecase", skip(self))]
+    pub async fn get_by_id(&self, id: u32) -> Result<GetReservationQuery, ApplicationError> {
+        match self.reservation_repository.find_by_id(id).await? {
+            Some(reservation) => Ok(reservation.into()),
+            None => Err(ApplicationError::ReservationNotFound(id)),
+        }
+    }
+}
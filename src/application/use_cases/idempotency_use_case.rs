@@ -0,0 +1,81 @@
+use std::sync::Arc;
+
+use chrono::{Duration, Utc};
+
+use crate::application::repositories::{IdempotencyRepository, IdempotencyStatus, StoredResponse};
+use crate::application::error::{ApplicationError, RepositoryError};
+
+/// 処理中のまま更新されないキーを放棄されたとみなすまでの時間
+/// プロセスの停止などでレスポンスを保存できなかったキーを再実行できるようにする
+const IN_PROGRESS_TIMEOUT_SECONDS: i64 = 60;
+
+/// Idempotency-Key付きリクエストの扱い
+#[derive(Debug)]
+pub enum IdempotencyOutcome {
+    /// 初めてのリクエストのため処理を実行する
+    Proceed,
+    /// 同じリクエストの再送のため保存済みのレスポンスを返す
+    Replay(StoredResponse),
+}
+
+pub struct IdempotencyUseCase {
+    idempotency_repository: Arc<dyn IdempotencyRepository + Send + Sync>,
+    /// 最初のレスポンスを保持する期間
+    ttl: Duration,
+}
+
+impl IdempotencyUseCase {
+    pub fn new(idempotency_repository: Arc<dyn IdempotencyRepository + Send + Sync>, ttl: Duration) -> Self {
+        Self {
+            idempotency_repository,
+            ttl,
+        }
+    }
+
+    /// キーを確保し、処理を実行するか保存済みのレスポンスを返すかを判定する
+    /// 同じキーが異なるリクエストに使われた場合や、最初のリクエストが処理中の場合はエラーにする
+    #[tracing::instrument(name = "idempotency_usecase.begin", skip(self, fingerprint))]
+    pub async fn begin(&self, key: &str, fingerprint: &str) -> Result<IdempotencyOutcome, ApplicationError> {
+        let now = Utc::now();
+        let stale_before = now - Duration::seconds(IN_PROGRESS_TIMEOUT_SECONDS);
+
+        let Some(record) = self.idempotency_repository
+            .claim(key, fingerprint, now, now + self.ttl, stale_before)
+            .await?
+        else {
+            return Ok(IdempotencyOutcome::Proceed);
+        };
+
+        if record.fingerprint != fingerprint {
+            return Err(ApplicationError::IdempotencyKeyMismatch(key.to_string()));
+        }
+        match (record.status, record.response) {
+            (IdempotencyStatus::Completed, Some(response)) => Ok(IdempotencyOutcome::Replay(response)),
+            (IdempotencyStatus::Completed, None) => {
+                Err(RepositoryError::Unknown(format!("idempotency key {} has no stored response", key)).into())
+            }
+            (IdempotencyStatus::InProgress, _) => Err(ApplicationError::IdempotencyKeyInProgress(key.to_string())),
+        }
+    }
+
+    /// 最初のレスポンスを保存する
+    #[tracing::instrument(name = "idempotency_usecase.complete", skip(self, response), fields(status = response.status))]
+    pub async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), ApplicationError> {
+        self.idempotency_repository.complete(key, response).await?;
+        Ok(())
+    }
+
+    /// レスポンスを保存せずにキーを解放し、同じキーで再実行できるようにする
+    #[tracing::instrument(name = "idempotency_usecase.abandon", skip(self))]
+    pub async fn abandon(&self, key: &str) -> Result<(), ApplicationError> {
+        self.idempotency_repository.release(key).await?;
+        Ok(())
+    }
+
+    /// 期限切れの記録を削除し、削除した件数を返す
+    #[tracing::instrument(name = "idempotency_usecase.purge_expired", skip(self))]
+    pub async fn purge_expired(&self) -> Result<u64, ApplicationError> {
+        let deleted = self.idempotency_repository.delete_expired(Utc::now()).await?;
+        Ok(deleted)
+    }
+}
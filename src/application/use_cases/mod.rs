@@ -1,7 +1,65 @@
 mod buy_product_use_case;
 mod get_product_use_case;
 mod get_all_products_use_case;
+mod create_product_use_case;
+mod update_product_use_case;
+mod delete_product_use_case;
+mod search_products_use_case;
+mod get_order_use_case;
+mod create_cart_use_case;
+mod get_cart_use_case;
+mod add_cart_item_use_case;
+mod update_cart_item_use_case;
+mod remove_cart_item_use_case;
+mod checkout_cart_use_case;
+mod create_reservation_use_case;
+mod get_reservation_use_case;
+mod confirm_reservation_use_case;
+mod release_reservation_use_case;
+mod expire_reservations_use_case;
+mod idempotency_use_case;
+mod create_promotion_use_case;
+mod get_promotion_use_case;
+mod get_all_promotions_use_case;
+mod delete_promotion_use_case;
+mod create_category_use_case;
+mod get_category_use_case;
+mod get_all_categories_use_case;
+mod update_category_use_case;
+mod delete_category_use_case;
+mod add_product_variant_use_case;
+mod update_product_variant_use_case;
+mod remove_product_variant_use_case;
 
 pub use buy_product_use_case::BuyProductUseCase;
 pub use get_product_use_case::GetProductUseCase;
-pub use get_all_products_use_case::GetAllProductsUseCase; 
\ No newline at end of file
+pub use get_all_products_use_case::GetAllProductsUseCase;
+pub use create_product_use_case::CreateProductUseCase;
+pub use update_product_use_case::UpdateProductUseCase;
+pub use delete_product_use_case::DeleteProductUseCase;
+pub use search_products_use_case::SearchProductsUseCase;
+pub use get_order_use_case::GetOrderUseCase;
+pub use create_cart_use_case::CreateCartUseCase;
+pub use get_cart_use_case::GetCartUseCase;
+pub use add_cart_item_use_case::AddCartItemUseCase;
+pub use update_cart_item_use_case::UpdateCartItemUseCase;
+pub use remove_cart_item_use_case::RemoveCartItemUseCase;
+pub use checkout_cart_use_case::CheckoutCartUseCase;
+pub use create_reservation_use_case::CreateReservationUseCase;
+pub use get_reservation_use_case::GetReservationUseCase;
+pub use confirm_reservation_use_case::ConfirmReservationUseCase;
+pub use release_reservation_use_case::ReleaseReservationUseCase;
+pub use expire_reservations_use_case::ExpireReservationsUseCase;
+pub use idempotency_use_case::{IdempotencyOutcome, IdempotencyUseCase};
+pub use create_promotion_use_case::CreatePromotionUseCase;
+pub use get_promotion_use_case::GetPromotionUseCase;
+pub use get_all_promotions_use_case::GetAllPromotionsUseCase;
+pub use delete_promotion_use_case::DeletePromotionUseCase;
+pub use create_category_use_case::CreateCategoryUseCase;
+pub use get_category_use_case::GetCategoryUseCase;
+pub use get_all_categories_use_case::GetAllCategoriesUseCase;
+pub use update_category_use_case::UpdateCategoryUseCase;
+pub use delete_category_use_case::DeleteCategoryUseCase;
+pub use add_product_variant_use_case::AddProductVariantUseCase;
+pub use update_product_variant_use_case::UpdateProductVariantUseCase;
+pub use remove_product_variant_use_case::RemoveProductVariantUseCase;
@@ -0,0 +1,41 @@
+use std::sync::Arc;
+
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::queries::GetReservationQuery;
+use crate::domain::models::ReservationStatus;
+use crate::domain::DomainError;
+
+pub struct ReleaseReservationUseCase {
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+}
+
+impl ReleaseReservationUseCase {
+    pub fn new(reservation_repository: Arc<dyn ReservationRepository + Send + Sync>) -> Self {
+        Self {
+            reservation_repository,
+        }
+    }
+
+    /// 予約を取り消し、確保していた在庫を販売可能な在庫に戻す
+    #[tracing::instrument(name = "release_reservation_usecase", skip(self))]
+    pub async fn release(&self, reservation_id: u32) -> Result<GetReservationQuery, ApplicationError> {
+        let mut reservation = match self.reservation_repository.find_by_id(reservation_id).await? {
+            Some(reservation) => reservation,
+            None => return Err(ApplicationError::ReservationNotFound(reservation_id)),
+        };
+        reservation.release()?;
+
+        match self.reservation_repository.release(&reservation).await {
+            Ok(()) => Ok(reservation.into()),
+            Err(RepositoryError::Conflict) => match self.reservation_repository.find_by_id(reservation_id).await? {
+                Some(latest) if latest.status != ReservationStatus::Active => {
+                    Err(DomainError::ReservationNotActive(reservation_id).into())
+                }
+                Some(_) => Err(ApplicationError::ReservationConflict(Some(reservation_id))),
+                None => Err(ApplicationError::ReservationNotFound(reservation_id)),
+            },
+            Err(e) => Err(e.into()),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CartRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::queries::GetCartQuery;
+use crate::domain::models::ProductVariant;
+
+pub struct RemoveCartItemUseCase {
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+}
+
+impl RemoveCartItemUseCase {
+    pub fn new(cart_repository: Arc<dyn CartRepository + Send + Sync>) -> Self {
+        Self {
+            cart_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "remove_cart_item_usecase", skip(self))]
+    pub async fn remove(&self, cart_id: u32, product_id: u32, sku: Option<&str>) -> Result<GetCartQuery, ApplicationError> {
+        let mut cart = match self.cart_repository.find_by_id(cart_id).await? {
+            Some(cart) => cart,
+            None => return Err(ApplicationError::CartNotFound(cart_id)),
+        };
+
+        // SKUは大文字・小文字を区別しない
+        let sku = sku.map(ProductVariant::normalize_sku);
+        cart.remove_item(product_id, sku.as_deref())?;
+
+        let cart = match self.cart_repository.save(cart).await {
+            Ok(cart) => cart,
+            // 読み込み後に他のリクエストでカートが変更された・注文が確定した
+            Err(RepositoryError::Conflict) => return Err(ApplicationError::CartChanged(cart_id)),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(cart.into())
+    }
+}
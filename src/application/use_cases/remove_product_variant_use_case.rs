@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use crate::application::repositories::ProductRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::RemoveProductVariantCommand;
+
+pub struct RemoveProductVariantUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+}
+
+impl RemoveProductVariantUseCase {
+    pub fn new(product_repository: Arc<dyn ProductRepository + Send + Sync>) -> Self {
+        Self {
+            product_repository,
+        }
+    }
+
+    /// バリエーションを削除する（バリエーションの在庫も商品から取り除かれる）
+    #[tracing::instrument(name = "remove_product_variant_usecase", skip(self))]
+    pub async fn remove(&self, product_id: u32, sku: &str, command: RemoveProductVariantCommand) -> Result<(), ApplicationError> {
+        let mut product = match self.product_repository.find_by_id(product_id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(product_id)),
+        };
+        if command.expected_version.is_some_and(|version| version != product.version) {
+            return Err(RepositoryError::Conflict.into());
+        }
+
+        product.remove_variant(sku)?;
+        self.product_repository.save(product).await?;
+
+        Ok(())
+    }
+}
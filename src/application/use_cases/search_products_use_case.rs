@@ -0,0 +1,43 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{ProductRepository, PromotionRepository};
+use crate::application::error::ApplicationError;
+use crate::application::queries::SearchProductQuery;
+
+pub struct SearchProductsUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+}
+
+impl SearchProductsUseCase {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            product_repository,
+            promotion_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "search_products_usecase", skip(self))]
+    pub async fn search(&self, keyword: &str, limit: u32) -> Result<Vec<SearchProductQuery>, ApplicationError> {
+        let keyword = keyword.trim();
+        if keyword.is_empty() {
+            return Err(ApplicationError::Validation("Search keyword must not be empty".to_string()));
+        }
+
+        let hits = self.product_repository.search(keyword, limit).await?;
+        let product_ids: Vec<u32> = hits.iter().map(|hit| hit.product.id).collect();
+        let promotions = self.promotion_repository.find_candidates(&product_ids, None).await?;
+
+        let now = Utc::now();
+        let hits = hits
+            .into_iter()
+            .map(|hit| SearchProductQuery::priced(hit, &promotions, now))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(hits)
+    }
+}
@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::UpdateCategoryCommand;
+use crate::application::queries::GetCategoryQuery;
+use crate::domain::DomainError;
+
+pub struct UpdateCategoryUseCase {
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl UpdateCategoryUseCase {
+    pub fn new(category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            category_repository,
+        }
+    }
+
+    /// カテゴリの名前と親カテゴリを更新する
+    /// 自分自身や子孫の下への移動は循環するため拒否する
+    #[tracing::instrument(name = "update_category_usecase", skip(self))]
+    pub async fn update(&self, id: u32, command: UpdateCategoryCommand) -> Result<GetCategoryQuery, ApplicationError> {
+        let categories = self.category_repository.find_all().await?;
+        let mut category = match categories.iter().find(|category| category.id == id) {
+            Some(category) => category.clone(),
+            None => return Err(ApplicationError::CategoryNotFound(id)),
+        };
+        if let Some(parent_id) = command.parent_id
+            && !categories.iter().any(|category| category.id == parent_id)
+        {
+            return Err(ApplicationError::CategoryNotFound(parent_id));
+        }
+
+        category.name = command.name;
+        category.move_to(command.parent_id, &categories)?;
+        category.validate()?;
+
+        match self.category_repository.save(category).await {
+            Ok(category) => Ok(category.into()),
+            Err(RepositoryError::NotFound) => Err(ApplicationError::CategoryNotFound(id)),
+            // 読み込み後に他の更新で移動先が子孫になった
+            Err(RepositoryError::Conflict) => Err(DomainError::InvalidCategoryData(
+                "a category cannot be moved under itself or its descendants".to_string(),
+            )
+            .into()),
+            Err(e) => Err(e.into()),
+        }
+    }
+}
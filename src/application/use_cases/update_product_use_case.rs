@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{CategoryRepository, ProductRepository, PromotionRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::UpdateProductCommand;
+use crate::application::queries::GetProductQuery;
+use crate::domain::models::{Category, Money};
+
+pub struct UpdateProductUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl UpdateProductUseCase {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+        category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            product_repository,
+            promotion_repository,
+            category_repository,
+        }
+    }
+
+    #[tracing::instrument(name = "update_product_usecase", skip(self))]
+    pub async fn update(&self, id: u32, command: UpdateProductCommand) -> Result<GetProductQuery, ApplicationError> {
+        let mut product = match self.product_repository.find_by_id(id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(id)),
+        };
+        if command.expected_version.is_some_and(|version| version != product.version) {
+            return Err(RepositoryError::Conflict.into());
+        }
+
+        if let Some(name) = command.name {
+            product.name = name;
+        }
+        if let Some(price) = command.price {
+            let currency = command.currency.unwrap_or(product.price.currency());
+            product.price = Money::new(price, currency);
+        }
+        if let Some(tax_category) = command.tax_category {
+            product.tax_category = tax_category;
+        }
+        if let Some(description) = command.description {
+            product.description = description;
+        }
+        if let Some(quantity) = command.quantity {
+            product.quantity = quantity;
+        }
+        if let Some(category_ids) = command.category_ids {
+            product.categories = self.find_categories(&category_ids).await?;
+        }
+        if let Some(tags) = command.tags {
+            product.set_tags(tags);
+        }
+        product.validate()?;
+
+        // 読み込み後に他のリクエストで更新されていた場合はsaveが競合を返す
+        let product = self.product_repository.save(product).await?;
+        let promotions = self.promotion_repository.find_candidates(&[product.id], None).await?;
+
+        Ok(GetProductQuery::priced(product, &promotions, Utc::now())?)
+    }
+
+    /// 指定されたIDのカテゴリを取得する（存在しないIDがあればエラー）
+    async fn find_categories(&self, ids: &[u32]) -> Result<Vec<Category>, ApplicationError> {
+        let categories = self.category_repository.find_by_ids(ids).await?;
+        if let Some(missing) = ids.iter().find(|id| !categories.iter().any(|category| category.id == **id)) {
+            return Err(ApplicationError::CategoryNotFound(*missing));
+        }
+        Ok(categories)
+    }
+}
@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::application::repositories::{ProductRepository, PromotionRepository};
+use crate::application::error::{ApplicationError, RepositoryError};
+use crate::application::commands::UpdateProductVariantCommand;
+use crate::application::queries::GetProductQuery;
+use crate::domain::models::{Money, ProductVariant};
+
+pub struct UpdateProductVariantUseCase {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+}
+
+impl UpdateProductVariantUseCase {
+    pub fn new(
+        product_repository: Arc<dyn ProductRepository + Send + Sync>,
+        promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    ) -> Self {
+        Self {
+            product_repository,
+            promotion_repository,
+        }
+    }
+
+    /// バリエーションの属性・価格・在庫を更新し、更新後の商品を返す
+    #[tracing::instrument(name = "update_product_variant_usecase", skip(self))]
+    pub async fn update(
+        &self,
+        product_id: u32,
+        sku: &str,
+        command: UpdateProductVariantCommand,
+    ) -> Result<GetProductQuery, ApplicationError> {
+        let mut product = match self.product_repository.find_by_id(product_id).await? {
+            Some(product) => product,
+            None => return Err(ApplicationError::ProductNotFound(product_id)),
+        };
+        if command.expected_version.is_some_and(|version| version != product.version) {
+            return Err(RepositoryError::Conflict.into());
+        }
+
+        let price = command.price.map(|price| Money::new(price, product.price.currency()));
+        product.update_variant(sku, command.attributes, price, command.quantity)?;
+
+        let product = match self.product_repository.save(product).await {
+            Ok(product) => product,
+            // 読み込み後に他のリクエストが同じSKUを登録した場合は、バージョン不一致ではなくSKUの重複として返す
+            Err(RepositoryError::Conflict) => return Err(self.diagnose_conflict(product_id, sku).await),
+            Err(e) => return Err(e.into()),
+        };
+        let promotions = self.promotion_repository.find_candidates(&[product.id], None).await?;
+
+        Ok(GetProductQuery::priced(product, &promotions, Utc::now())?)
+    }
+
+    /// 保存が競合した理由を判定する（SKUが他の商品に使われていればSKUの重複）
+    async fn diagnose_conflict(&self, product_id: u32, sku: &str) -> ApplicationError {
+        let sku = ProductVariant::normalize_sku(sku);
+        match self.product_repository.find_by_sku(&sku).await {
+            Ok(Some(owner)) if owner.id != product_id => ApplicationError::SkuTaken(sku),
+            Ok(_) => RepositoryError::Conflict.into(),
+            Err(e) => e.into(),
+        }
+    }
+}
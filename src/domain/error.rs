@@ -1,23 +1,114 @@
+use crate::domain::models::Currency;
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DomainError {
     /// 在庫不足エラー
     InsufficientQuantity { 
+        product_id: u32,
         requested: u32, 
         available: u32 
     },
     /// 無効な商品データエラー
     InvalidProductData(String),
+    /// 無効な注文データエラー
+    InvalidOrderData(String),
+    /// 無効なカートデータエラー
+    InvalidCartData(String),
+    /// カートに指定された商品が入っていない
+    CartItemNotFound(u32),
+    /// 注文確定済みのカートは変更できない
+    CartAlreadyCheckedOut(u32),
+    /// 無効な在庫予約データエラー
+    InvalidReservationData(String),
+    /// 確定済み・取り消し済みなど、有効でない予約は操作できない
+    ReservationNotActive(u32),
+    /// 期限切れの予約は確定できない
+    ReservationExpired(u32),
+    /// 異なる通貨の金額は計算できない
+    CurrencyMismatch {
+        expected: Currency,
+        actual: Currency,
+    },
+    /// 金額が表現できる範囲を超えた（オーバーフロー・負の金額）
+    AmountOutOfRange,
+    /// 無効なプロモーションデータエラー
+    InvalidPromotionData(String),
+    /// 指定されたクーポンが存在しない・期間外・上限到達・対象外
+    CouponNotApplicable(String),
+    /// 注文確定までの間にプロモーションが利用できなくなった
+    PromotionUnavailable(u32),
+    /// 税制が設定されていない地域
+    UnknownTaxRegion(String),
+    /// 無効なカテゴリデータエラー（循環する親子関係を含む）
+    InvalidCategoryData(String),
+    /// 無効なバリエーションデータエラー
+    InvalidVariantData(String),
+    /// 商品に指定されたSKUのバリエーションがない
+    VariantNotFound { product_id: u32, sku: String },
+    /// バリエーションのある商品はSKUを指定しないと販売・予約できない
+    VariantRequired(u32),
 }
 
 impl std::fmt::Display for DomainError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            DomainError::InsufficientQuantity { requested, available } => {
-                write!(f, "Insufficient quantity: requested {}, available {}", requested, available)
+            DomainError::InsufficientQuantity { product_id, requested, available } => {
+                write!(f, "Insufficient quantity for product {}: requested {}, available {}", product_id, requested, available)
             }
             DomainError::InvalidProductData(msg) => {
                 write!(f, "Invalid product data: {}", msg)
             }
+            DomainError::InvalidOrderData(msg) => {
+                write!(f, "Invalid order data: {}", msg)
+            }
+            DomainError::InvalidCartData(msg) => {
+                write!(f, "Invalid cart data: {}", msg)
+            }
+            DomainError::CartItemNotFound(product_id) => {
+                write!(f, "Product {} is not in the cart", product_id)
+            }
+            DomainError::CartAlreadyCheckedOut(cart_id) => {
+                write!(f, "Cart {} is already checked out", cart_id)
+            }
+            DomainError::InvalidReservationData(msg) => {
+                write!(f, "Invalid reservation data: {}", msg)
+            }
+            DomainError::ReservationNotActive(reservation_id) => {
+                write!(f, "Reservation {} is no longer active", reservation_id)
+            }
+            DomainError::ReservationExpired(reservation_id) => {
+                write!(f, "Reservation {} has expired", reservation_id)
+            }
+            DomainError::CurrencyMismatch { expected, actual } => {
+                write!(f, "Currency mismatch: expected {}, got {}", expected.code(), actual.code())
+            }
+            DomainError::AmountOutOfRange => {
+                write!(f, "Amount is out of range")
+            }
+            DomainError::InvalidPromotionData(msg) => {
+                write!(f, "Invalid promotion data: {}", msg)
+            }
+            DomainError::CouponNotApplicable(code) => {
+                write!(f, "Coupon {} cannot be applied", code)
+            }
+            DomainError::PromotionUnavailable(promotion_id) => {
+                write!(f, "Promotion {} is no longer available", promotion_id)
+            }
+            DomainError::UnknownTaxRegion(code) => {
+                write!(f, "No tax rules are configured for region {}", code)
+            }
+            DomainError::InvalidCategoryData(msg) => {
+                write!(f, "Invalid category data: {}", msg)
+            }
+            DomainError::InvalidVariantData(msg) => {
+                write!(f, "Invalid variant data: {}", msg)
+            }
+            DomainError::VariantNotFound { product_id, sku } => {
+                write!(f, "Product {} has no variant {}", product_id, sku)
+            }
+            DomainError::VariantRequired(product_id) => {
+                write!(f, "Product {} has variants; a SKU must be specified", product_id)
+            }
         }
     }
 }
@@ -1,4 +1,6 @@
 pub mod models;
 pub mod error;
+pub mod pricing;
+pub mod tax;
 
 pub use error::DomainError;
\ No newline at end of file
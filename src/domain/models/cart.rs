@@ -0,0 +1,136 @@
+use crate::domain::error::DomainError;
+
+/// カートステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CartStatus {
+    /// 商品の追加・変更が可能
+    Open,
+    /// 注文確定済み（以降は変更できない）
+    CheckedOut,
+}
+
+impl CartStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CartStatus::Open => "open",
+            CartStatus::CheckedOut => "checked_out",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "open" => Some(CartStatus::Open),
+            "checked_out" => Some(CartStatus::CheckedOut),
+            _ => None,
+        }
+    }
+}
+
+/// カート内の商品
+/// 同じ商品でもバリエーション（SKU）が異なれば別の行になる
+#[derive(Debug, Clone, PartialEq)]
+pub struct CartItem {
+    pub product_id: u32,
+    /// バリエーションのSKU（バリエーションのない商品は `None`）
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+impl CartItem {
+    fn is(&self, product_id: u32, sku: Option<&str>) -> bool {
+        self.product_id == product_id && self.sku.as_deref() == sku
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Cart {
+    pub id: u32,
+    pub items: Vec<CartItem>,
+    pub status: CartStatus,
+    /// チェックアウトで作成された注文のID
+    pub order_id: Option<u32>,
+    /// 楽観的排他制御用のバージョン
+    pub version: u32,
+}
+
+impl Cart {
+    pub fn new(id: u32, items: Vec<CartItem>, status: CartStatus, order_id: Option<u32>, version: u32) -> Self {
+        Self {
+            id,
+            items,
+            status,
+            order_id,
+            version,
+        }
+    }
+
+    /// 空のカートを作成します
+    /// IDとバージョンは永続化時に確定します
+    pub fn create() -> Self {
+        Self::new(0, Vec::new(), CartStatus::Open, None, 0)
+    }
+
+    /// 商品をカートに追加します
+    /// 既にカートにある商品（同じSKU）の場合は数量を加算します
+    pub fn add_item(&mut self, product_id: u32, sku: Option<&str>, quantity: u32) -> Result<(), DomainError> {
+        self.ensure_open()?;
+        Self::validate_quantity(quantity)?;
+
+        match self.items.iter_mut().find(|item| item.is(product_id, sku)) {
+            Some(item) => {
+                item.quantity = item.quantity.checked_add(quantity)
+                    .ok_or_else(|| DomainError::InvalidCartData("quantity is too large".to_string()))?;
+            }
+            None => self.items.push(CartItem { product_id, sku: sku.map(str::to_string), quantity }),
+        }
+        Ok(())
+    }
+
+    /// カート内の商品の数量を変更します
+    pub fn update_quantity(&mut self, product_id: u32, sku: Option<&str>, quantity: u32) -> Result<(), DomainError> {
+        self.ensure_open()?;
+        Self::validate_quantity(quantity)?;
+
+        let item = self.items
+            .iter_mut()
+            .find(|item| item.is(product_id, sku))
+            .ok_or(DomainError::CartItemNotFound(product_id))?;
+        item.quantity = quantity;
+        Ok(())
+    }
+
+    /// カートから商品を取り除きます
+    pub fn remove_item(&mut self, product_id: u32, sku: Option<&str>) -> Result<(), DomainError> {
+        self.ensure_open()?;
+
+        let before = self.items.len();
+        self.items.retain(|item| !item.is(product_id, sku));
+        if self.items.len() == before {
+            return Err(DomainError::CartItemNotFound(product_id));
+        }
+        Ok(())
+    }
+
+    /// チェックアウトできる状態かを検証します
+    pub fn ensure_checkoutable(&self) -> Result<(), DomainError> {
+        self.ensure_open()?;
+        if self.items.is_empty() {
+            return Err(DomainError::InvalidCartData("cart is empty".to_string()));
+        }
+        Ok(())
+    }
+
+    fn ensure_open(&self) -> Result<(), DomainError> {
+        if self.status != CartStatus::Open {
+            return Err(DomainError::CartAlreadyCheckedOut(self.id));
+        }
+        Ok(())
+    }
+
+    fn validate_quantity(quantity: u32) -> Result<(), DomainError> {
+        if quantity == 0 {
+            return Err(DomainError::InvalidCartData("quantity must be greater than 0".to_string()));
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashSet;
+
+use crate::domain::error::DomainError;
+
+/// 商品カテゴリ
+/// `parent_id` で親子関係を持ち、ルートのカテゴリは `None`
+#[derive(Debug, Clone, PartialEq)]
+pub struct Category {
+    pub id: u32,
+    pub name: String,
+    pub parent_id: Option<u32>,
+}
+
+impl Category {
+    pub fn new(id: u32, name: String, parent_id: Option<u32>) -> Self {
+        Self { id, name, parent_id }
+    }
+
+    /// 新しいカテゴリを作成します
+    /// IDは永続化時に確定します
+    pub fn create(name: String, parent_id: Option<u32>) -> Result<Self, DomainError> {
+        let category = Self::new(0, name, parent_id);
+        category.validate()?;
+        Ok(category)
+    }
+
+    /// カテゴリデータの整合性を検証します
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::InvalidCategoryData("name must not be empty".to_string()));
+        }
+        Ok(())
+    }
+
+    /// 親カテゴリを付け替えます
+    /// 自分自身や子孫の下には移動できない（木構造が循環するため）
+    /// `categories` には移動先の祖先をたどれるだけのカテゴリが含まれている必要がある
+    /// `categories` 自体が循環していても、同じカテゴリを2度たどった時点で打ち切る
+    pub fn move_to(&mut self, parent_id: Option<u32>, categories: &[Category]) -> Result<(), DomainError> {
+        let mut visited = HashSet::new();
+        let mut ancestor = parent_id;
+        while let Some(id) = ancestor {
+            if id == self.id || !visited.insert(id) {
+                return Err(DomainError::InvalidCategoryData(
+                    "a category cannot be moved under itself or its descendants".to_string(),
+                ));
+            }
+            ancestor = categories
+                .iter()
+                .find(|category| category.id == id)
+                .and_then(|category| category.parent_id);
+        }
+        self.parent_id = parent_id;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn tree() -> Vec<Category> {
+        vec![
+            Category::new(1, "Food".to_string(), None),
+            Category::new(2, "Drinks".to_string(), Some(1)),
+            Category::new(3, "Tea".to_string(), Some(2)),
+            Category::new(4, "Books".to_string(), None),
+        ]
+    }
+
+    /// 別の枝やルートには移動でき、自分自身や子孫の下には移動できないこと
+    #[test]
+    fn move_to_rejects_cycles() {
+        let categories = tree();
+        let mut drinks = categories[1].clone();
+
+        assert!(drinks.move_to(Some(4), &categories).is_ok());
+        assert_eq!(drinks.parent_id, Some(4));
+        assert!(drinks.move_to(None, &categories).is_ok());
+        assert_eq!(drinks.parent_id, None);
+
+        let mut food = categories[0].clone();
+        assert!(matches!(food.move_to(Some(1), &categories), Err(DomainError::InvalidCategoryData(_))));
+        assert!(matches!(food.move_to(Some(3), &categories), Err(DomainError::InvalidCategoryData(_))));
+        assert_eq!(food.parent_id, None);
+    }
+
+    /// すでに循環しているカテゴリの一覧でも無限ループしないこと
+    #[test]
+    fn move_to_stops_on_existing_cycles() {
+        let categories = vec![
+            Category::new(1, "A".to_string(), Some(2)),
+            Category::new(2, "B".to_string(), Some(1)),
+        ];
+        let mut books = Category::new(4, "Books".to_string(), None);
+
+        assert!(matches!(books.move_to(Some(1), &categories), Err(DomainError::InvalidCategoryData(_))));
+        assert_eq!(books.parent_id, None);
+    }
+}
@@ -1,3 +1,17 @@
+mod money;
 mod product;
+mod promotion;
+mod order;
+mod cart;
+mod reservation;
+mod category;
+mod variant;
 
+pub use self::money::{Currency, Money, RoundingMode};
 pub use self::product::Product;
+pub use self::promotion::{DiscountRule, Promotion};
+pub use self::order::{Order, OrderLine, OrderStatus};
+pub use self::cart::{Cart, CartItem, CartStatus};
+pub use self::reservation::{Reservation, ReservationItem, ReservationStatus};
+pub use self::category::Category;
+pub use self::variant::ProductVariant;
@@ -0,0 +1,256 @@
+use std::fmt;
+
+use crate::domain::error::DomainError;
+
+/// 通貨（ISO 4217）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Currency {
+    /// 日本円
+    #[default]
+    Jpy,
+    /// 米ドル
+    Usd,
+    /// ユーロ
+    Eur,
+}
+
+/// 端数の丸め方
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// 切り捨て
+    Down,
+    /// 切り上げ
+    Up,
+    /// 四捨五入
+    HalfUp,
+    /// 偶数丸め（銀行丸め）
+    HalfEven,
+}
+
+impl RoundingMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            RoundingMode::Down => "down",
+            RoundingMode::Up => "up",
+            RoundingMode::HalfUp => "half_up",
+            RoundingMode::HalfEven => "half_even",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "down" => Some(RoundingMode::Down),
+            "up" => Some(RoundingMode::Up),
+            "half_up" => Some(RoundingMode::HalfUp),
+            "half_even" => Some(RoundingMode::HalfEven),
+            _ => None,
+        }
+    }
+}
+
+impl Currency {
+    /// 対応している通貨
+    pub const ALL: [Currency; 3] = [Currency::Jpy, Currency::Usd, Currency::Eur];
+
+    /// ISO 4217の通貨コード
+    pub fn code(&self) -> &'static str {
+        match self {
+            Currency::Jpy => "JPY",
+            Currency::Usd => "USD",
+            Currency::Eur => "EUR",
+        }
+    }
+
+    pub fn parse(code: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|currency| currency.code() == code)
+    }
+
+    /// 補助単位の桁数（JPYは0、USD・EURは2）
+    pub fn minor_unit_digits(&self) -> u32 {
+        match self {
+            Currency::Jpy => 0,
+            Currency::Usd | Currency::Eur => 2,
+        }
+    }
+
+    /// 割合の計算などで生じる端数の丸め方
+    /// JPYは消費税などの慣行に合わせて切り捨て、EURは偶数丸めとする
+    pub fn rounding_mode(&self) -> RoundingMode {
+        match self {
+            Currency::Jpy => RoundingMode::Down,
+            Currency::Usd => RoundingMode::HalfUp,
+            Currency::Eur => RoundingMode::HalfEven,
+        }
+    }
+
+    // 表示形式（記号・桁区切り・小数点・記号を後置するか）
+    fn format_style(&self) -> (&'static str, char, char, bool) {
+        match self {
+            Currency::Jpy => ("¥", ',', '.', false),
+            Currency::Usd => ("$", ',', '.', false),
+            Currency::Eur => ("€", '.', ',', true),
+        }
+    }
+}
+
+/// 金額
+/// 浮動小数点の誤差を避けるため、通貨の最小単位（JPYは円、USDはセント）の整数で保持する
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Money {
+    amount: u64,
+    currency: Currency,
+}
+
+impl Money {
+    /// 最小単位の金額から作成します
+    pub fn new(amount: u64, currency: Currency) -> Self {
+        Self { amount, currency }
+    }
+
+    pub fn zero(currency: Currency) -> Self {
+        Self::new(0, currency)
+    }
+
+    /// 最小単位の金額
+    pub fn amount(&self) -> u64 {
+        self.amount
+    }
+
+    pub fn currency(&self) -> Currency {
+        self.currency
+    }
+
+    pub fn checked_add(&self, other: Money) -> Result<Money, DomainError> {
+        self.ensure_same_currency(other)?;
+        let amount = self.amount.checked_add(other.amount).ok_or(DomainError::AmountOutOfRange)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// 結果が負になる場合はエラーにします
+    pub fn checked_sub(&self, other: Money) -> Result<Money, DomainError> {
+        self.ensure_same_currency(other)?;
+        let amount = self.amount.checked_sub(other.amount).ok_or(DomainError::AmountOutOfRange)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    pub fn checked_mul(&self, quantity: u32) -> Result<Money, DomainError> {
+        let amount = self.amount.checked_mul(quantity as u64).ok_or(DomainError::AmountOutOfRange)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    /// `numerator / denominator` 倍した金額を、通貨の丸め方で最小単位に丸めます
+    pub fn mul_ratio(&self, numerator: u64, denominator: u64) -> Result<Money, DomainError> {
+        self.mul_ratio_with(numerator, denominator, self.currency.rounding_mode())
+    }
+
+    /// `numerator / denominator` 倍した金額を、指定した丸め方で最小単位に丸めます
+    pub fn mul_ratio_with(&self, numerator: u64, denominator: u64, mode: RoundingMode) -> Result<Money, DomainError> {
+        if denominator == 0 {
+            return Err(DomainError::AmountOutOfRange);
+        }
+        let product = self.amount as u128 * numerator as u128;
+        let denominator = denominator as u128;
+        let quotient = product / denominator;
+        let remainder = product % denominator;
+
+        let round_up = match mode {
+            RoundingMode::Down => false,
+            RoundingMode::Up => remainder > 0,
+            RoundingMode::HalfUp => remainder * 2 >= denominator,
+            RoundingMode::HalfEven => {
+                remainder * 2 > denominator || (remainder * 2 == denominator && quotient % 2 == 1)
+            }
+        };
+        let amount = if round_up { quotient + 1 } else { quotient };
+
+        let amount = u64::try_from(amount).map_err(|_| DomainError::AmountOutOfRange)?;
+        Ok(Money::new(amount, self.currency))
+    }
+
+    fn ensure_same_currency(&self, other: Money) -> Result<(), DomainError> {
+        if self.currency != other.currency {
+            return Err(DomainError::CurrencyMismatch {
+                expected: self.currency,
+                actual: other.currency,
+            });
+        }
+        Ok(())
+    }
+
+    // 主単位と、補助単位がある通貨の場合はゼロ埋めした補助単位に分ける
+    fn split(&self) -> (u64, Option<String>) {
+        let digits = self.currency.minor_unit_digits();
+        if digits == 0 {
+            return (self.amount, None);
+        }
+        let scale = 10u64.pow(digits);
+        let minor = format!("{:0width$}", self.amount % scale, width = digits as usize);
+        (self.amount / scale, Some(minor))
+    }
+}
+
+/// 通貨ごとの表示形式（例: `¥1,200` / `$12.34` / `1.234,56 €`）
+impl fmt::Display for Money {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (symbol, group_separator, decimal_separator, symbol_after) = self.currency.format_style();
+        let (major, minor) = self.split();
+
+        let digits = major.to_string();
+        let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+        for (index, digit) in digits.chars().enumerate() {
+            if index > 0 && (digits.len() - index) % 3 == 0 {
+                grouped.push(group_separator);
+            }
+            grouped.push(digit);
+        }
+        if let Some(minor) = minor {
+            grouped.push(decimal_separator);
+            grouped.push_str(&minor);
+        }
+
+        if symbol_after {
+            write!(f, "{} {}", grouped, symbol)
+        } else {
+            write!(f, "{}{}", symbol, grouped)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_per_currency() {
+        assert_eq!(Money::new(1200, Currency::Jpy).to_string(), "¥1,200");
+        assert_eq!(Money::new(123456, Currency::Usd).to_string(), "$1,234.56");
+        assert_eq!(Money::new(123456, Currency::Eur).to_string(), "1.234,56 €");
+        assert_eq!(Money::new(5, Currency::Usd).to_string(), "$0.05");
+    }
+
+    #[test]
+    fn arithmetic_is_checked() {
+        let yen = Money::new(100, Currency::Jpy);
+        assert_eq!(yen.checked_add(yen), Ok(Money::new(200, Currency::Jpy)));
+        assert_eq!(yen.checked_mul(3), Ok(Money::new(300, Currency::Jpy)));
+        assert_eq!(yen.checked_sub(Money::new(101, Currency::Jpy)), Err(DomainError::AmountOutOfRange));
+        assert_eq!(Money::new(u64::MAX, Currency::Jpy).checked_mul(2), Err(DomainError::AmountOutOfRange));
+        assert_eq!(
+            yen.checked_add(Money::new(100, Currency::Usd)),
+            Err(DomainError::CurrencyMismatch { expected: Currency::Jpy, actual: Currency::Usd })
+        );
+    }
+
+    #[test]
+    fn mul_ratio_rounds_per_currency() {
+        // 105 * 10% = 10.5
+        assert_eq!(Money::new(105, Currency::Jpy).mul_ratio(10, 100), Ok(Money::new(10, Currency::Jpy)));
+        assert_eq!(Money::new(105, Currency::Usd).mul_ratio(10, 100), Ok(Money::new(11, Currency::Usd)));
+        assert_eq!(Money::new(105, Currency::Eur).mul_ratio(10, 100), Ok(Money::new(10, Currency::Eur)));
+        assert_eq!(Money::new(115, Currency::Eur).mul_ratio(10, 100), Ok(Money::new(12, Currency::Eur)));
+        assert_eq!(
+            Money::new(101, Currency::Jpy).mul_ratio_with(1, 100, RoundingMode::Up),
+            Ok(Money::new(2, Currency::Jpy))
+        );
+    }
+}
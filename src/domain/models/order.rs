@@ -0,0 +1,171 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::models::Money;
+use crate::domain::pricing::PriceQuote;
+use crate::domain::tax::{TaxCategory, TaxMode, TaxRate, TaxRegion};
+
+/// 注文ステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderStatus {
+    /// 注文確定（在庫引当済み）
+    Placed,
+}
+
+impl OrderStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderStatus::Placed => "placed",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "placed" => Some(OrderStatus::Placed),
+            _ => None,
+        }
+    }
+}
+
+/// 注文明細
+/// 商品名・単価（定価）・割引・税率は注文時点のスナップショットを保持する
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderLine {
+    pub product_id: u32,
+    pub product_name: String,
+    /// 購入したバリエーションのSKU（バリエーションのない商品は `None`）
+    pub sku: Option<String>,
+    pub unit_price: Money,
+    pub quantity: u32,
+    /// 明細全体の割引額
+    pub discount: Money,
+    /// 適用されたプロモーション
+    pub promotion_id: Option<u32>,
+    pub tax_category: TaxCategory,
+    pub tax_rate: TaxRate,
+    /// 明細の税額（税込方式では小計に含まれる）
+    pub tax: Money,
+}
+
+impl OrderLine {
+    pub fn new(product_id: u32, product_name: String, unit_price: Money, quantity: u32) -> Self {
+        Self {
+            product_id,
+            product_name,
+            sku: None,
+            unit_price,
+            quantity,
+            discount: Money::zero(unit_price.currency()),
+            promotion_id: None,
+            tax_category: TaxCategory::default(),
+            tax_rate: TaxRate::ZERO,
+            tax: Money::zero(unit_price.currency()),
+        }
+    }
+
+    /// 見積もった価格から明細を作成します
+    /// 税額は注文の確定時に地域の税制から求めます
+    pub fn from_quote(product_id: u32, product_name: String, tax_category: TaxCategory, quote: &PriceQuote) -> Self {
+        Self::new(product_id, product_name, quote.unit_price, quote.quantity)
+            .with_discount(quote.discount, quote.promotion_id)
+            .with_tax(tax_category, TaxRate::ZERO, Money::zero(quote.unit_price.currency()))
+    }
+
+    pub fn with_sku(mut self, sku: Option<String>) -> Self {
+        self.sku = sku;
+        self
+    }
+
+    pub fn with_discount(mut self, discount: Money, promotion_id: Option<u32>) -> Self {
+        self.discount = discount;
+        self.promotion_id = promotion_id;
+        self
+    }
+
+    pub fn with_tax(mut self, tax_category: TaxCategory, tax_rate: TaxRate, tax: Money) -> Self {
+        self.tax_category = tax_category;
+        self.tax_rate = tax_rate;
+        self.tax = tax;
+        self
+    }
+
+    /// 明細の小計（割引後）
+    pub fn subtotal(&self) -> Result<Money, DomainError> {
+        self.unit_price.checked_mul(self.quantity)?.checked_sub(self.discount)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Order {
+    pub id: u32,
+    pub lines: Vec<OrderLine>,
+    /// 税額の計算に使った地域（税計算の導入前の注文は `None`）
+    pub tax_region: Option<String>,
+    pub tax_mode: TaxMode,
+    /// 明細の小計の合計（税込方式では税額を含む）
+    pub subtotal: Money,
+    pub tax: Money,
+    /// 支払総額（税抜方式では小計に税額を加えたもの、税込方式では小計と同じ）
+    pub total: Money,
+    pub status: OrderStatus,
+    pub placed_at: DateTime<Utc>,
+}
+
+impl Order {
+    /// 明細から小計・税額・総額を集計して注文を組み立てます
+    pub fn new(
+        id: u32,
+        lines: Vec<OrderLine>,
+        tax_region: Option<String>,
+        tax_mode: TaxMode,
+        status: OrderStatus,
+        placed_at: DateTime<Utc>,
+    ) -> Result<Self, DomainError> {
+        let Some(first) = lines.first() else {
+            return Err(DomainError::InvalidOrderData("order must have at least one line".to_string()));
+        };
+
+        // 通貨の異なる商品は1つの注文にまとめられない
+        let currency = first.unit_price.currency();
+        let mut subtotal = Money::zero(currency);
+        let mut tax = Money::zero(currency);
+        for line in &lines {
+            subtotal = subtotal.checked_add(line.subtotal()?)?;
+            tax = tax.checked_add(line.tax)?;
+        }
+        let total = match tax_mode {
+            TaxMode::Exclusive => subtotal.checked_add(tax)?,
+            TaxMode::Inclusive => subtotal,
+        };
+
+        Ok(Self {
+            id,
+            lines,
+            tax_region,
+            tax_mode,
+            subtotal,
+            tax,
+            total,
+            status,
+            placed_at,
+        })
+    }
+
+    /// 明細から新しい注文を確定します
+    /// 税額は地域の税制に従って明細ごとに求めて端数を丸めます。IDは永続化時に確定します
+    pub fn place(lines: Vec<OrderLine>, region: &TaxRegion) -> Result<Self, DomainError> {
+        if lines.iter().any(|line| line.quantity == 0) {
+            return Err(DomainError::InvalidOrderData("line quantity must be greater than 0".to_string()));
+        }
+
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                let tax = region.tax_on(line.tax_category, line.subtotal()?)?;
+                let (category, rate) = (line.tax_category, region.rate(line.tax_category));
+                Ok(line.with_tax(category, rate, tax))
+            })
+            .collect::<Result<Vec<_>, DomainError>>()?;
+        Self::new(0, lines, Some(region.code.clone()), region.mode, OrderStatus::Placed, Utc::now())
+    }
+}
@@ -1,33 +1,331 @@
+use std::collections::{BTreeMap, BTreeSet, HashSet};
+
 use crate::domain::error::DomainError;
+use crate::domain::models::{Category, Money, ProductVariant};
+use crate::domain::tax::TaxCategory;
 
 pub struct Product {
     pub id: u32,
     pub name: String,
-    pub price: u32,
+    pub price: Money,
+    /// 課税区分
+    pub tax_category: TaxCategory,
     pub description: String,
+    /// 販売可能な在庫数（バリエーションがある場合はその合計）
     pub quantity: u32,
+    /// 予約により確保されている在庫数（販売可能な在庫数には含まない）
+    pub reserved: u32,
+    /// 所属するカテゴリ
+    pub categories: Vec<Category>,
+    /// タグ（`set_tags` で正規化して保持する）
+    pub tags: Vec<String>,
+    /// バリエーション（ある場合、在庫はバリエーションごとに管理する）
+    pub variants: Vec<ProductVariant>,
+    /// 楽観的排他制御用のバージョン
+    pub version: u32,
 }
 
 impl Product {
-    pub fn new(id: u32, name: String, price: u32, description: String, quantity: u32) -> Self {
+    pub fn new(id: u32, name: String, price: Money, description: String, quantity: u32, reserved: u32, version: u32) -> Self {
         Self {
             id,
             name,
             price,
+            tax_category: TaxCategory::default(),
             description,
             quantity,
+            reserved,
+            categories: Vec::new(),
+            tags: Vec::new(),
+            variants: Vec::new(),
+            version,
+        }
+    }
+
+    /// 1商品に付けられるタグの上限
+    pub const MAX_TAGS: usize = 20;
+    /// タグの最大文字数
+    pub const MAX_TAG_LENGTH: usize = 50;
+
+    pub fn with_tax_category(mut self, tax_category: TaxCategory) -> Self {
+        self.tax_category = tax_category;
+        self
+    }
+
+    /// タグを設定します
+    /// 前後の空白を除いて小文字に揃え、重複を取り除きます
+    pub fn set_tags(&mut self, tags: Vec<String>) {
+        let mut tags: Vec<String> = tags.iter().map(|tag| Self::normalize_tag(tag)).collect();
+        tags.sort();
+        tags.dedup();
+        self.tags = tags;
+    }
+
+    /// タグは大文字・小文字を区別しない
+    pub fn normalize_tag(tag: &str) -> String {
+        tag.trim().to_lowercase()
+    }
+
+    /// 新しい商品を作成します
+    /// IDとバージョンは永続化時に確定します
+    pub fn create(name: String, price: Money, description: String, quantity: u32) -> Result<Self, DomainError> {
+        let product = Self::new(0, name, price, description, quantity, 0, 0);
+        product.validate()?;
+        Ok(product)
+    }
+
+    /// 商品データの整合性を検証します
+    pub fn validate(&self) -> Result<(), DomainError> {
+        if self.name.trim().is_empty() {
+            return Err(DomainError::InvalidProductData("name must not be empty".to_string()));
+        }
+        if self.tags.len() > Self::MAX_TAGS {
+            return Err(DomainError::InvalidProductData(format!("a product can have at most {} tags", Self::MAX_TAGS)));
+        }
+        if self.tags.iter().any(|tag| tag.is_empty() || tag.chars().count() > Self::MAX_TAG_LENGTH) {
+            return Err(DomainError::InvalidProductData(format!(
+                "tags must be between 1 and {} characters",
+                Self::MAX_TAG_LENGTH
+            )));
+        }
+        self.validate_variants()
+    }
+
+    // バリエーション同士の整合性を検証
+    // 属性の組み合わせで一意に選べるよう、全バリエーションが同じ属性名を持つ必要がある
+    fn validate_variants(&self) -> Result<(), DomainError> {
+        let invalid = |msg: String| Err(DomainError::InvalidVariantData(msg));
+
+        let Some(first) = self.variants.first() else {
+            return Ok(());
+        };
+        let mut skus = HashSet::new();
+        let mut combinations = HashSet::new();
+        for variant in &self.variants {
+            variant.validate()?;
+            if variant.price.currency() != self.price.currency() {
+                return invalid(format!("variant {} must be priced in {}", variant.sku, self.price.currency().code()));
+            }
+            if !variant.attributes.keys().eq(first.attributes.keys()) {
+                return invalid(format!(
+                    "every variant must have the attributes {}",
+                    first.attributes.keys().cloned().collect::<Vec<_>>().join(", ")
+                ));
+            }
+            if !skus.insert(&variant.sku) {
+                return invalid(format!("sku {} is used more than once", variant.sku));
+            }
+            if !combinations.insert(&variant.attributes) {
+                return invalid(format!("variant {} duplicates the attributes of another variant", variant.sku));
+            }
+        }
+        if self.quantity != self.variant_quantity() {
+            return Err(DomainError::InvalidProductData(
+                "the stock of a product with variants is managed per variant".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    pub fn has_variants(&self) -> bool {
+        !self.variants.is_empty()
+    }
+
+    /// SKUでバリエーションを探します（大文字・小文字は区別しない）
+    pub fn variant(&self, sku: &str) -> Option<&ProductVariant> {
+        let sku = ProductVariant::normalize_sku(sku);
+        self.variants.iter().find(|variant| variant.sku == sku)
+    }
+
+    fn variant_mut(&mut self, sku: &str) -> Result<&mut ProductVariant, DomainError> {
+        let product_id = self.id;
+        let sku = ProductVariant::normalize_sku(sku);
+        self.variants
+            .iter_mut()
+            .find(|variant| variant.sku == sku)
+            .ok_or(DomainError::VariantNotFound { product_id, sku })
+    }
+
+    /// 属性ごとの選択肢（バリエーションの行列の軸）
+    pub fn variant_attributes(&self) -> BTreeMap<String, Vec<String>> {
+        let mut options: BTreeMap<String, BTreeSet<String>> = BTreeMap::new();
+        for variant in &self.variants {
+            for (name, value) in &variant.attributes {
+                options.entry(name.clone()).or_default().insert(value.clone());
+            }
+        }
+        options.into_iter().map(|(name, values)| (name, values.into_iter().collect())).collect()
+    }
+
+    fn variant_quantity(&self) -> u32 {
+        self.variants.iter().map(|variant| variant.quantity).sum()
+    }
+
+    /// バリエーションを追加します
+    /// 最初のバリエーションを追加すると、商品の在庫はバリエーションの在庫の合計になります
+    pub fn add_variant(&mut self, variant: ProductVariant) -> Result<(), DomainError> {
+        // 予約済みの在庫はどのバリエーションのものか決められない
+        if !self.has_variants() && self.reserved > 0 {
+            return Err(DomainError::InvalidProductData(
+                "variants cannot be added while stock is reserved".to_string(),
+            ));
         }
+        self.variants.push(variant);
+        self.quantity = self.variant_quantity();
+        self.validate()
+    }
+
+    /// バリエーションの属性・価格・在庫を変更します（`None` の項目は変更しない）
+    pub fn update_variant(
+        &mut self,
+        sku: &str,
+        attributes: Option<BTreeMap<String, String>>,
+        price: Option<Money>,
+        quantity: Option<u32>,
+    ) -> Result<(), DomainError> {
+        let variant = self.variant_mut(sku)?;
+        if let Some(attributes) = attributes {
+            variant.attributes = attributes;
+        }
+        if let Some(price) = price {
+            variant.price = price;
+        }
+        if let Some(quantity) = quantity {
+            variant.quantity = quantity;
+        }
+        self.quantity = self.variant_quantity();
+        self.validate()
+    }
+
+    /// バリエーションを削除します（在庫も商品から取り除かれます）
+    pub fn remove_variant(&mut self, sku: &str) -> Result<(), DomainError> {
+        let sku = self.variant_mut(sku)?.sku.clone();
+        self.variants.retain(|variant| variant.sku != sku);
+        self.quantity = self.variant_quantity();
+        Ok(())
+    }
+
+    /// バリエーションの在庫から販売します
+    pub fn sell_variant(&mut self, sku: &str, quantity: u32) -> Result<(), DomainError> {
+        let product_id = self.id;
+        let variant = self.variant_mut(sku)?;
+        if quantity > variant.quantity {
+            return Err(DomainError::InsufficientQuantity {
+                product_id,
+                requested: quantity,
+                available: variant.quantity,
+            });
+        }
+        variant.quantity -= quantity;
+        self.quantity -= quantity;
+
+        Ok(())
     }
 
     pub fn sell(&mut self, quantity: u32) -> Result<(), DomainError> {
+        self.ensure_available(quantity)?;
+        self.quantity -= quantity;
+
+        Ok(())
+    }
+
+    /// 販売可能な在庫から予約分を確保します
+    pub fn reserve(&mut self, quantity: u32) -> Result<(), DomainError> {
+        self.ensure_available(quantity)?;
+        self.quantity -= quantity;
+        self.reserved += quantity;
+
+        Ok(())
+    }
+
+    /// バリエーションの在庫から予約分を確保します
+    /// 予約数は商品単位で持ち、取り消し時に戻す先は予約に記録したSKUで決める
+    pub fn reserve_variant(&mut self, sku: &str, quantity: u32) -> Result<(), DomainError> {
+        self.sell_variant(sku, quantity)?;
+        self.reserved += quantity;
+
+        Ok(())
+    }
+
+    // 商品単位の在庫操作（バリエーションのある商品では使えない）
+    fn ensure_available(&self, quantity: u32) -> Result<(), DomainError> {
+        if self.has_variants() {
+            return Err(DomainError::VariantRequired(self.id));
+        }
         if quantity > self.quantity {
             return Err(DomainError::InsufficientQuantity {
+                product_id: self.id,
                 requested: quantity,
                 available: self.quantity,
             });
         }
-        self.quantity -= quantity;
-
         Ok(())
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Currency;
+
+    fn yen(amount: u64) -> Money {
+        Money::new(amount, Currency::Jpy)
+    }
+
+    fn variant(sku: &str, layout: &str, quantity: u32) -> ProductVariant {
+        let attributes = BTreeMap::from([("layout".to_string(), layout.to_string())]);
+        ProductVariant::new(sku, attributes, yen(8000), quantity)
+    }
+
+    fn keyboard() -> Product {
+        let mut product = Product::create("Keyboard".to_string(), yen(8000), "test".to_string(), 0).unwrap();
+        product.add_variant(variant("kb-us", "US", 3)).unwrap();
+        product.add_variant(variant("kb-jis", "JIS", 2)).unwrap();
+        product
+    }
+
+    /// 商品の在庫はバリエーションの在庫の合計になり、販売はバリエーション単位で行うこと
+    #[test]
+    fn stock_is_kept_per_variant() {
+        let mut product = keyboard();
+        assert_eq!(product.quantity, 5);
+        assert_eq!(product.variant_attributes()["layout"], vec!["JIS".to_string(), "US".to_string()]);
+
+        product.sell_variant("KB-US", 2).unwrap();
+        assert_eq!(product.variant("kb-us").unwrap().quantity, 1);
+        assert_eq!(product.quantity, 3);
+        assert!(matches!(
+            product.sell_variant("kb-us", 2),
+            Err(DomainError::InsufficientQuantity { requested: 2, available: 1, .. })
+        ));
+        assert!(matches!(product.sell_variant("kb-uk", 1), Err(DomainError::VariantNotFound { .. })));
+        assert!(matches!(product.sell(1), Err(DomainError::VariantRequired(_))));
+        assert!(matches!(product.reserve(1), Err(DomainError::VariantRequired(_))));
+
+        product.reserve_variant("kb-jis", 1).unwrap();
+        assert_eq!(product.variant("kb-jis").unwrap().quantity, 1);
+        assert_eq!((product.quantity, product.reserved), (2, 1));
+
+        product.remove_variant("kb-jis").unwrap();
+        assert_eq!(product.quantity, 1);
+    }
+
+    /// SKU・属性の組み合わせの重複、属性名の不一致、商品と異なる通貨は拒否されること
+    #[test]
+    fn add_variant_rejects_inconsistent_variants() {
+        let mut product = keyboard();
+
+        assert!(matches!(keyboard().add_variant(variant("KB-US", "UK", 1)), Err(DomainError::InvalidVariantData(_))));
+        assert!(matches!(keyboard().add_variant(variant("kb-us-2", "US", 1)), Err(DomainError::InvalidVariantData(_))));
+        let colour = BTreeMap::from([("colour".to_string(), "black".to_string())]);
+        let mismatched = ProductVariant::new("kb-black", colour, yen(8000), 1);
+        assert!(matches!(keyboard().add_variant(mismatched), Err(DomainError::InvalidVariantData(_))));
+        let mut dollars = variant("kb-uk", "UK", 1);
+        dollars.price = Money::new(80, Currency::Usd);
+        assert!(matches!(keyboard().add_variant(dollars), Err(DomainError::InvalidVariantData(_))));
+
+        // バリエーションのある商品の在庫は直接変更できない
+        product.quantity = 10;
+        assert!(matches!(product.validate(), Err(DomainError::InvalidProductData(_))));
+    }
+}
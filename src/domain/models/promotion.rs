@@ -0,0 +1,164 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{Money, Product};
+
+/// 割引ルール
+#[derive(Debug, Clone, PartialEq)]
+pub enum DiscountRule {
+    /// 定率割引（1〜100%）
+    PercentOff { percent: u32 },
+    /// 1点あたりの定額割引（商品と同じ通貨の場合のみ適用）
+    AmountOff { amount: Money },
+    /// `buy` 点ごとに `get` 点を無料にする（例: 2点買うと1点無料）
+    BuyNGetM { buy: u32, get: u32 },
+}
+
+impl DiscountRule {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            DiscountRule::PercentOff { .. } => "percent_off",
+            DiscountRule::AmountOff { .. } => "amount_off",
+            DiscountRule::BuyNGetM { .. } => "buy_n_get_m",
+        }
+    }
+}
+
+/// プロモーション
+/// クーポンコードがないものは条件を満たす購入に自動で適用される
+#[derive(Debug, Clone)]
+pub struct Promotion {
+    pub id: u32,
+    pub name: String,
+    pub rule: DiscountRule,
+    /// 対象商品（`None` は全商品）
+    pub product_id: Option<u32>,
+    /// クーポンコード（大文字に正規化して保持する）
+    pub coupon_code: Option<String>,
+    /// 適用開始日時（この日時を含む）
+    pub starts_at: Option<DateTime<Utc>>,
+    /// 適用終了日時（この日時を含まない）
+    pub ends_at: Option<DateTime<Utc>>,
+    /// 利用回数の上限（注文単位）
+    pub usage_limit: Option<u32>,
+    /// 利用された回数
+    pub usage_count: u32,
+}
+
+impl Promotion {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        id: u32,
+        name: String,
+        rule: DiscountRule,
+        product_id: Option<u32>,
+        coupon_code: Option<String>,
+        starts_at: Option<DateTime<Utc>>,
+        ends_at: Option<DateTime<Utc>>,
+        usage_limit: Option<u32>,
+        usage_count: u32,
+    ) -> Self {
+        Self {
+            id,
+            name,
+            rule,
+            product_id,
+            coupon_code,
+            starts_at,
+            ends_at,
+            usage_limit,
+            usage_count,
+        }
+    }
+
+    /// 新しいプロモーションを作成します
+    /// IDは永続化時に確定します
+    pub fn create(
+        name: String,
+        rule: DiscountRule,
+        product_id: Option<u32>,
+        coupon_code: Option<String>,
+        starts_at: Option<DateTime<Utc>>,
+        ends_at: Option<DateTime<Utc>>,
+        usage_limit: Option<u32>,
+    ) -> Result<Self, DomainError> {
+        let coupon_code = coupon_code.map(|code| Self::normalize_code(&code));
+        let promotion = Self::new(0, name, rule, product_id, coupon_code, starts_at, ends_at, usage_limit, 0);
+        promotion.validate()?;
+        Ok(promotion)
+    }
+
+    /// クーポンコードは大文字・小文字を区別しない
+    pub fn normalize_code(code: &str) -> String {
+        code.trim().to_ascii_uppercase()
+    }
+
+    fn validate(&self) -> Result<(), DomainError> {
+        let invalid = |msg: &str| Err(DomainError::InvalidPromotionData(msg.to_string()));
+
+        if self.name.trim().is_empty() {
+            return invalid("name must not be empty");
+        }
+        match self.rule {
+            DiscountRule::PercentOff { percent } if percent == 0 || percent > 100 => {
+                return invalid("percent must be between 1 and 100");
+            }
+            DiscountRule::AmountOff { amount } if amount.amount() == 0 => {
+                return invalid("amount must be greater than 0");
+            }
+            DiscountRule::BuyNGetM { buy, get } if buy == 0 || get == 0 => {
+                return invalid("buy and get quantities must be greater than 0");
+            }
+            _ => {}
+        }
+        if self.coupon_code.as_deref() == Some("") {
+            return invalid("coupon code must not be empty");
+        }
+        if let (Some(starts_at), Some(ends_at)) = (self.starts_at, self.ends_at)
+            && ends_at <= starts_at
+        {
+            return invalid("ends_at must be after starts_at");
+        }
+        if self.usage_limit == Some(0) {
+            return invalid("usage limit must be greater than 0");
+        }
+        Ok(())
+    }
+
+    /// 指定時刻が適用期間内で、利用回数の上限に達していないか
+    pub fn is_available(&self, now: DateTime<Utc>) -> bool {
+        let started = self.starts_at.is_none_or(|starts_at| starts_at <= now);
+        let not_ended = self.ends_at.is_none_or(|ends_at| now < ends_at);
+        let within_limit = self.usage_limit.is_none_or(|limit| self.usage_count < limit);
+        started && not_ended && within_limit
+    }
+
+    /// 商品がこのプロモーションの対象か
+    pub fn applies_to(&self, product: &Product) -> bool {
+        if self.product_id.is_some_and(|product_id| product_id != product.id) {
+            return false;
+        }
+        match self.rule {
+            DiscountRule::AmountOff { amount } => amount.currency() == product.price.currency(),
+            _ => true,
+        }
+    }
+
+    /// 単価と数量に対する割引額（小計を超えない）
+    pub fn discount_for(&self, unit_price: Money, quantity: u32) -> Result<Money, DomainError> {
+        let subtotal = unit_price.checked_mul(quantity)?;
+        let discount = match self.rule {
+            // 割引額の端数は通貨の丸め方に従う
+            DiscountRule::PercentOff { percent } => subtotal.mul_ratio(percent as u64, 100)?,
+            DiscountRule::AmountOff { amount } => {
+                let per_unit = Money::new(amount.amount().min(unit_price.amount()), unit_price.currency());
+                per_unit.checked_mul(quantity)?
+            }
+            DiscountRule::BuyNGetM { buy, get } => {
+                let free = quantity / (buy + get) * get;
+                unit_price.checked_mul(free)?
+            }
+        };
+        Ok(discount)
+    }
+}
@@ -0,0 +1,140 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+
+/// 在庫予約ステータス
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReservationStatus {
+    /// 在庫を確保中
+    Active,
+    /// 注文として確定済み
+    Confirmed,
+    /// 取り消されて在庫を戻した
+    Released,
+    /// 期限切れにより在庫を戻した
+    Expired,
+}
+
+impl ReservationStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReservationStatus::Active => "active",
+            ReservationStatus::Confirmed => "confirmed",
+            ReservationStatus::Released => "released",
+            ReservationStatus::Expired => "expired",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "active" => Some(ReservationStatus::Active),
+            "confirmed" => Some(ReservationStatus::Confirmed),
+            "released" => Some(ReservationStatus::Released),
+            "expired" => Some(ReservationStatus::Expired),
+            _ => None,
+        }
+    }
+}
+
+/// 予約された商品
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReservationItem {
+    pub product_id: u32,
+    /// バリエーションのSKU（バリエーションのない商品は `None`）
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+/// 在庫予約
+/// 支払いまでの間、商品の在庫を確保しておく
+#[derive(Debug, Clone)]
+pub struct Reservation {
+    pub id: u32,
+    pub items: Vec<ReservationItem>,
+    pub status: ReservationStatus,
+    /// この時刻を過ぎると確定できず、在庫が戻される
+    pub expires_at: DateTime<Utc>,
+    /// 確定で作成された注文のID
+    pub order_id: Option<u32>,
+}
+
+impl Reservation {
+    pub fn new(
+        id: u32,
+        items: Vec<ReservationItem>,
+        status: ReservationStatus,
+        expires_at: DateTime<Utc>,
+        order_id: Option<u32>,
+    ) -> Self {
+        Self {
+            id,
+            items,
+            status,
+            expires_at,
+            order_id,
+        }
+    }
+
+    /// 新しい予約を作成します
+    /// 同じ商品（同じSKU）が複数含まれる場合は数量をまとめます。IDは永続化時に確定します
+    pub fn create(items: Vec<ReservationItem>, expires_at: DateTime<Utc>) -> Result<Self, DomainError> {
+        if items.is_empty() {
+            return Err(DomainError::InvalidReservationData("reservation has no items".to_string()));
+        }
+
+        let mut merged: Vec<ReservationItem> = Vec::with_capacity(items.len());
+        for item in items {
+            if item.quantity == 0 {
+                return Err(DomainError::InvalidReservationData("quantity must be greater than 0".to_string()));
+            }
+            match merged.iter_mut().find(|merged| merged.product_id == item.product_id && merged.sku == item.sku) {
+                Some(merged) => {
+                    merged.quantity = merged.quantity.checked_add(item.quantity)
+                        .ok_or_else(|| DomainError::InvalidReservationData("quantity is too large".to_string()))?;
+                }
+                None => merged.push(item),
+            }
+        }
+
+        Ok(Self::new(0, merged, ReservationStatus::Active, expires_at, None))
+    }
+
+    /// 指定時刻の時点で期限切れかを判定します
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        self.expires_at <= now
+    }
+
+    /// 予約を注文として確定します
+    pub fn confirm(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_active()?;
+        if self.is_expired(now) {
+            return Err(DomainError::ReservationExpired(self.id));
+        }
+        self.status = ReservationStatus::Confirmed;
+        Ok(())
+    }
+
+    /// 予約を取り消します
+    pub fn release(&mut self) -> Result<(), DomainError> {
+        self.ensure_active()?;
+        self.status = ReservationStatus::Released;
+        Ok(())
+    }
+
+    /// 期限切れの予約を失効させます
+    pub fn expire(&mut self, now: DateTime<Utc>) -> Result<(), DomainError> {
+        self.ensure_active()?;
+        if !self.is_expired(now) {
+            return Err(DomainError::InvalidReservationData("reservation has not expired yet".to_string()));
+        }
+        self.status = ReservationStatus::Expired;
+        Ok(())
+    }
+
+    fn ensure_active(&self) -> Result<(), DomainError> {
+        if self.status != ReservationStatus::Active {
+            return Err(DomainError::ReservationNotActive(self.id));
+        }
+        Ok(())
+    }
+}
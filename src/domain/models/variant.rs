@@ -0,0 +1,52 @@
+use std::collections::BTreeMap;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::Money;
+
+/// 商品のバリエーション（例: キーボードの配列・色違い）
+/// 在庫と価格はバリエーションごとに持ち、SKUで識別する
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProductVariant {
+    /// SKU（大文字に正規化して保持する。全商品で一意）
+    pub sku: String,
+    /// 属性（例: `layout` = `US`、`colour` = `black`）
+    pub attributes: BTreeMap<String, String>,
+    pub price: Money,
+    /// 販売可能な在庫数
+    pub quantity: u32,
+}
+
+impl ProductVariant {
+    /// SKUの最大文字数
+    pub const MAX_SKU_LENGTH: usize = 64;
+
+    pub fn new(sku: &str, attributes: BTreeMap<String, String>, price: Money, quantity: u32) -> Self {
+        Self {
+            sku: Self::normalize_sku(sku),
+            attributes,
+            price,
+            quantity,
+        }
+    }
+
+    /// SKUは大文字・小文字を区別しない
+    pub fn normalize_sku(sku: &str) -> String {
+        sku.trim().to_ascii_uppercase()
+    }
+
+    /// バリエーション単体の整合性を検証します
+    pub fn validate(&self) -> Result<(), DomainError> {
+        let invalid = |msg: String| Err(DomainError::InvalidVariantData(msg));
+
+        if self.sku.is_empty() || self.sku.chars().count() > Self::MAX_SKU_LENGTH {
+            return invalid(format!("sku must be between 1 and {} characters", Self::MAX_SKU_LENGTH));
+        }
+        if self.attributes.is_empty() {
+            return invalid(format!("variant {} must have at least one attribute", self.sku));
+        }
+        if self.attributes.iter().any(|(name, value)| name.trim().is_empty() || value.trim().is_empty()) {
+            return invalid("attribute names and values must not be empty".to_string());
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,193 @@
+use chrono::{DateTime, Utc};
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{Money, Product, ProductVariant, Promotion};
+
+/// 商品・数量に対する価格の見積もり
+#[derive(Debug, Clone, PartialEq)]
+pub struct PriceQuote {
+    /// 定価（単価）
+    pub unit_price: Money,
+    pub quantity: u32,
+    /// 割引額（明細全体）
+    pub discount: Money,
+    /// 適用されたプロモーション
+    pub promotion_id: Option<u32>,
+}
+
+impl PriceQuote {
+    /// 割引前の金額
+    pub fn list_total(&self) -> Result<Money, DomainError> {
+        self.unit_price.checked_mul(self.quantity)
+    }
+
+    /// 割引後の金額
+    pub fn total(&self) -> Result<Money, DomainError> {
+        self.list_total()?.checked_sub(self.discount)
+    }
+}
+
+/// 商品を指定数量購入する場合の価格を求めます
+///
+/// 適用できるプロモーションのうち最も割引額の大きいものを1つだけ適用します（併用はしない）。
+/// クーポンコードのあるプロモーションは、そのコードが指定された場合のみ対象になります。
+/// 指定されたクーポンが使えない場合は `CouponNotApplicable` を返します。
+pub fn quote(
+    product: &Product,
+    quantity: u32,
+    promotions: &[Promotion],
+    coupon_code: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<PriceQuote, DomainError> {
+    quote_at(product, product.price, quantity, promotions, coupon_code, now)
+}
+
+/// 商品のバリエーションを指定数量購入する場合の価格を求めます
+/// 適用されるプロモーションは商品と同じで、単価にバリエーションの価格を使います
+pub fn quote_variant(
+    product: &Product,
+    variant: &ProductVariant,
+    quantity: u32,
+    promotions: &[Promotion],
+    coupon_code: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<PriceQuote, DomainError> {
+    quote_at(product, variant.price, quantity, promotions, coupon_code, now)
+}
+
+fn quote_at(
+    product: &Product,
+    unit_price: Money,
+    quantity: u32,
+    promotions: &[Promotion],
+    coupon_code: Option<&str>,
+    now: DateTime<Utc>,
+) -> Result<PriceQuote, DomainError> {
+    let coupon_code = coupon_code.map(Promotion::normalize_code);
+    let applicable: Vec<&Promotion> = promotions
+        .iter()
+        .filter(|promotion| match &promotion.coupon_code {
+            Some(code) => Some(code) == coupon_code.as_ref(),
+            None => true,
+        })
+        .filter(|promotion| promotion.is_available(now) && promotion.applies_to(product))
+        .collect();
+
+    if let Some(code) = coupon_code
+        && !applicable.iter().any(|promotion| promotion.coupon_code.as_ref() == Some(&code))
+    {
+        return Err(DomainError::CouponNotApplicable(code));
+    }
+
+    let mut best = PriceQuote {
+        unit_price,
+        quantity,
+        discount: Money::zero(unit_price.currency()),
+        promotion_id: None,
+    };
+    for promotion in applicable {
+        let discount = promotion.discount_for(unit_price, quantity)?;
+        if discount.amount() > best.discount.amount() {
+            best.discount = discount;
+            best.promotion_id = Some(promotion.id);
+        }
+    }
+
+    Ok(best)
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::domain::models::{Currency, DiscountRule};
+
+    fn product(price: u64, currency: Currency) -> Product {
+        Product::new(1, "pricing".to_string(), Money::new(price, currency), String::new(), 10, 0, 0)
+    }
+
+    fn promotion(id: u32, rule: DiscountRule, coupon_code: Option<&str>) -> Promotion {
+        Promotion::create(format!("promotion {}", id), rule, None, coupon_code.map(str::to_string), None, None, None)
+            .map(|mut promotion| {
+                promotion.id = id;
+                promotion
+            })
+            .unwrap()
+    }
+
+    /// 割引の大きいプロモーションが1つだけ適用されること
+    #[test]
+    fn applies_best_single_promotion() {
+        let product = product(1000, Currency::Jpy);
+        let promotions = vec![
+            promotion(1, DiscountRule::PercentOff { percent: 10 }, None),
+            promotion(2, DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) }, None),
+            promotion(3, DiscountRule::BuyNGetM { buy: 2, get: 1 }, None),
+        ];
+
+        let single = quote(&product, 1, &promotions, None, Utc::now()).unwrap();
+        assert_eq!(single.promotion_id, Some(2));
+        assert_eq!(single.total(), Ok(Money::new(850, Currency::Jpy)));
+
+        // 3点なら1点無料（1000円引き）が最大
+        let three = quote(&product, 3, &promotions, None, Utc::now()).unwrap();
+        assert_eq!(three.promotion_id, Some(3));
+        assert_eq!(three.total(), Ok(Money::new(2000, Currency::Jpy)));
+    }
+
+    /// 定率割引の端数は通貨の丸め方に従うこと
+    #[test]
+    fn percent_off_rounds_per_currency() {
+        let rule = || DiscountRule::PercentOff { percent: 15 };
+        let yen = quote(&product(999, Currency::Jpy), 1, &[promotion(1, rule(), None)], None, Utc::now()).unwrap();
+        assert_eq!(yen.discount, Money::new(149, Currency::Jpy));
+
+        let dollar = quote(&product(999, Currency::Usd), 1, &[promotion(1, rule(), None)], None, Utc::now()).unwrap();
+        assert_eq!(dollar.discount, Money::new(150, Currency::Usd));
+    }
+
+    /// クーポンは指定された場合のみ適用され、使えないクーポンはエラーになること
+    #[test]
+    fn coupon_requires_matching_code() {
+        let product = product(1000, Currency::Jpy);
+        let promotions = vec![promotion(1, DiscountRule::PercentOff { percent: 20 }, Some("summer"))];
+
+        let without = quote(&product, 1, &promotions, None, Utc::now()).unwrap();
+        assert_eq!(without.promotion_id, None);
+
+        let with = quote(&product, 1, &promotions, Some(" Summer "), Utc::now()).unwrap();
+        assert_eq!(with.promotion_id, Some(1));
+        assert_eq!(with.total(), Ok(Money::new(800, Currency::Jpy)));
+
+        assert_eq!(
+            quote(&product, 1, &promotions, Some("winter"), Utc::now()),
+            Err(DomainError::CouponNotApplicable("WINTER".to_string()))
+        );
+    }
+
+    /// 期間外・上限到達・通貨違いのプロモーションは適用されないこと
+    #[test]
+    fn skips_unavailable_promotions() {
+        let product = product(1000, Currency::Jpy);
+        let now = Utc::now();
+
+        let mut expired = promotion(1, DiscountRule::PercentOff { percent: 50 }, None);
+        expired.ends_at = Some(now - Duration::hours(1));
+        let mut upcoming = promotion(2, DiscountRule::PercentOff { percent: 50 }, None);
+        upcoming.starts_at = Some(now + Duration::hours(1));
+        let mut exhausted = promotion(3, DiscountRule::PercentOff { percent: 50 }, Some("ONCE"));
+        exhausted.usage_limit = Some(1);
+        exhausted.usage_count = 1;
+        let dollars_off = promotion(4, DiscountRule::AmountOff { amount: Money::new(500, Currency::Usd) }, None);
+
+        let promotions = vec![expired, upcoming, exhausted, dollars_off];
+        let quote_now = quote(&product, 1, &promotions, None, now).unwrap();
+        assert_eq!(quote_now.promotion_id, None);
+        assert_eq!(quote_now.discount, Money::zero(Currency::Jpy));
+        assert!(matches!(
+            quote(&product, 1, &promotions, Some("once"), now),
+            Err(DomainError::CouponNotApplicable(_))
+        ));
+    }
+}
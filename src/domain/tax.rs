@@ -0,0 +1,252 @@
+use std::collections::HashMap;
+use std::fmt;
+
+use crate::domain::error::DomainError;
+use crate::domain::models::{Money, RoundingMode};
+
+/// 商品の課税区分
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum TaxCategory {
+    /// 標準税率
+    #[default]
+    Standard,
+    /// 軽減税率（未設定の地域では標準税率）
+    Reduced,
+    /// 非課税（常に0%）
+    Exempt,
+}
+
+impl TaxCategory {
+    /// すべての課税区分
+    pub const ALL: [TaxCategory; 3] = [TaxCategory::Standard, TaxCategory::Reduced, TaxCategory::Exempt];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxCategory::Standard => "standard",
+            TaxCategory::Reduced => "reduced",
+            TaxCategory::Exempt => "exempt",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|category| category.as_str() == value)
+    }
+}
+
+/// 価格と税額の関係
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TaxMode {
+    /// 価格は税抜で、税額を上乗せする
+    Exclusive,
+    /// 価格は税込で、税額は価格に含まれる
+    Inclusive,
+}
+
+impl TaxMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxMode::Exclusive => "exclusive",
+            TaxMode::Inclusive => "inclusive",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "exclusive" => Some(TaxMode::Exclusive),
+            "inclusive" => Some(TaxMode::Inclusive),
+            _ => None,
+        }
+    }
+}
+
+/// 税率（100万分率で保持する。10% = 100,000）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct TaxRate(u32);
+
+impl TaxRate {
+    pub const ZERO: TaxRate = TaxRate(0);
+    const SCALE: u32 = 1_000_000;
+
+    /// 100万分率から作成します（100%を超える税率は扱わない）
+    pub fn from_millionths(millionths: u32) -> Option<Self> {
+        (millionths <= Self::SCALE).then_some(Self(millionths))
+    }
+
+    /// パーセントから作成します（小数点以下4桁まで）
+    pub fn from_percent(percent: f64) -> Option<Self> {
+        if !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+        Self::from_millionths((percent * 10_000.0).round() as u32)
+    }
+
+    pub fn millionths(&self) -> u32 {
+        self.0
+    }
+
+    pub fn percent(&self) -> f64 {
+        self.0 as f64 / 10_000.0
+    }
+}
+
+/// パーセント表記（例: `10%` / `8.875%`）
+impl fmt::Display for TaxRate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let whole = self.0 / 10_000;
+        let fraction = self.0 % 10_000;
+        if fraction == 0 {
+            return write!(f, "{}%", whole);
+        }
+        let fraction = format!("{:04}", fraction);
+        write!(f, "{}.{}%", whole, fraction.trim_end_matches('0'))
+    }
+}
+
+/// 地域ごとの税制
+#[derive(Debug, Clone)]
+pub struct TaxRegion {
+    /// 地域コード（大文字に正規化して保持する。例: `JP` / `US-NY`）
+    pub code: String,
+    pub mode: TaxMode,
+    /// 明細ごとの税額の端数の丸め方
+    pub rounding: RoundingMode,
+    rates: HashMap<TaxCategory, TaxRate>,
+}
+
+impl TaxRegion {
+    pub fn new(code: &str, mode: TaxMode, rounding: RoundingMode, rates: HashMap<TaxCategory, TaxRate>) -> Self {
+        Self {
+            code: TaxTable::normalize_code(code),
+            mode,
+            rounding,
+            rates,
+        }
+    }
+
+    /// 課税区分の税率
+    /// 非課税は常に0%、税率が設定されていない区分は標準税率で課税する
+    pub fn rate(&self, category: TaxCategory) -> TaxRate {
+        if category == TaxCategory::Exempt {
+            return TaxRate::ZERO;
+        }
+        self.rates
+            .get(&category)
+            .or_else(|| self.rates.get(&TaxCategory::Standard))
+            .copied()
+            .unwrap_or(TaxRate::ZERO)
+    }
+
+    /// 金額に対する税額
+    /// 税込方式では金額に含まれている税額を返す
+    pub fn tax_on(&self, category: TaxCategory, amount: Money) -> Result<Money, DomainError> {
+        let rate = self.rate(category).millionths() as u64;
+        let denominator = match self.mode {
+            TaxMode::Exclusive => TaxRate::SCALE as u64,
+            TaxMode::Inclusive => TaxRate::SCALE as u64 + rate,
+        };
+        amount.mul_ratio_with(rate, denominator, self.rounding)
+    }
+}
+
+/// 利用できる税制の一覧
+/// 地域が指定されない購入には既定の地域の税制を適用する
+#[derive(Debug, Clone)]
+pub struct TaxTable {
+    default_region: String,
+    regions: HashMap<String, TaxRegion>,
+}
+
+impl TaxTable {
+    pub fn new(default_region: &str, regions: Vec<TaxRegion>) -> Result<Self, DomainError> {
+        let regions: HashMap<String, TaxRegion> =
+            regions.into_iter().map(|region| (region.code.clone(), region)).collect();
+        let default_region = Self::normalize_code(default_region);
+        if !regions.contains_key(&default_region) {
+            return Err(DomainError::UnknownTaxRegion(default_region));
+        }
+        Ok(Self { default_region, regions })
+    }
+
+    /// 地域コードは大文字・小文字を区別しない
+    pub fn normalize_code(code: &str) -> String {
+        code.trim().to_ascii_uppercase()
+    }
+
+    /// 指定された地域（省略時は既定の地域）の税制
+    pub fn region(&self, code: Option<&str>) -> Result<&TaxRegion, DomainError> {
+        let code = code.map(Self::normalize_code).unwrap_or_else(|| self.default_region.clone());
+        self.regions.get(&code).ok_or(DomainError::UnknownTaxRegion(code))
+    }
+}
+
+#[cfg(test)]
+impl TaxRegion {
+    /// どの区分も0%の地域（税以外を検証するテスト用）
+    pub fn untaxed() -> Self {
+        Self::new("test", TaxMode::Exclusive, RoundingMode::Down, HashMap::new())
+    }
+}
+
+#[cfg(test)]
+impl TaxTable {
+    /// 0%の地域だけを持つ税制（税以外を検証するテスト用）
+    pub fn untaxed() -> Self {
+        Self::new("test", vec![TaxRegion::untaxed()]).expect("default region is registered")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::Currency;
+
+    fn region(mode: TaxMode, rounding: RoundingMode, standard: f64) -> TaxRegion {
+        let rates = HashMap::from([(TaxCategory::Standard, TaxRate::from_percent(standard).unwrap())]);
+        TaxRegion::new("test", mode, rounding, rates)
+    }
+
+    /// 税抜方式では税率分を上乗せし、端数は地域の丸め方に従うこと
+    #[test]
+    fn exclusive_tax_is_rounded_per_region() {
+        let amount = Money::new(1999, Currency::Usd);
+        let half_up = region(TaxMode::Exclusive, RoundingMode::HalfUp, 8.875);
+        let down = region(TaxMode::Exclusive, RoundingMode::Down, 8.875);
+
+        // 1999 * 8.875% = 177.41...
+        assert_eq!(half_up.tax_on(TaxCategory::Standard, amount), Ok(Money::new(177, Currency::Usd)));
+        assert_eq!(down.tax_on(TaxCategory::Standard, Money::new(1001, Currency::Usd)), Ok(Money::new(88, Currency::Usd)));
+        assert_eq!(half_up.tax_on(TaxCategory::Standard, Money::new(1001, Currency::Usd)), Ok(Money::new(89, Currency::Usd)));
+    }
+
+    /// 税込方式では価格に含まれる税額を求めること
+    #[test]
+    fn inclusive_tax_is_extracted_from_price() {
+        let jp = region(TaxMode::Inclusive, RoundingMode::Down, 10.0);
+
+        assert_eq!(jp.tax_on(TaxCategory::Standard, Money::new(1100, Currency::Jpy)), Ok(Money::new(100, Currency::Jpy)));
+        // 999 * 10 / 110 = 90.8...
+        assert_eq!(jp.tax_on(TaxCategory::Standard, Money::new(999, Currency::Jpy)), Ok(Money::new(90, Currency::Jpy)));
+    }
+
+    /// 非課税は0%、未設定の区分は標準税率になること
+    #[test]
+    fn rate_falls_back_to_standard() {
+        let jp = region(TaxMode::Inclusive, RoundingMode::Down, 10.0);
+
+        assert_eq!(jp.rate(TaxCategory::Reduced), TaxRate::from_percent(10.0).unwrap());
+        assert_eq!(jp.rate(TaxCategory::Exempt), TaxRate::ZERO);
+        assert_eq!(TaxRate::from_percent(8.875).unwrap().to_string(), "8.875%");
+        assert_eq!(TaxRate::from_percent(10.0).unwrap().to_string(), "10%");
+    }
+
+    /// 地域コードは大文字・小文字を区別せず、未知の地域はエラーになること
+    #[test]
+    fn table_looks_up_regions_case_insensitively() {
+        let table = TaxTable::new("test", vec![region(TaxMode::Exclusive, RoundingMode::HalfUp, 5.0)]).unwrap();
+
+        assert_eq!(table.region(None).unwrap().code, "TEST");
+        assert_eq!(table.region(Some("Test")).unwrap().code, "TEST");
+        assert!(matches!(table.region(Some("XX")), Err(DomainError::UnknownTaxRegion(code)) if code == "XX"));
+        assert!(TaxTable::new("XX", vec![]).is_err());
+    }
+}
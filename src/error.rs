@@ -1,38 +1,407 @@
-use axum::{http::StatusCode, response::IntoResponse};
+use axum::body::Body;
+use axum::extract::rejection::{JsonRejection, QueryRejection};
+use axum::http::{header, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use utoipa::ToSchema;
+
+use crate::application::{ApplicationError, RepositoryError};
+use crate::domain::DomainError;
+use crate::interface_adapters::validation::ValidationErrors;
 
 pub type Result<T> = core::result::Result<T, Error>;
 
+/// problem+jsonレスポンスのContent-Type
+pub const PROBLEM_JSON_CONTENT_TYPE: &str = "application/problem+json";
+
 #[derive(Debug)]
 pub enum Error {
-    BuyProductFailed,
+    /// ユースケースから返されたエラー
+    Application(ApplicationError),
     NotFound,
+    Conflict,
+    /// リクエストDTOのバリデーションエラー
+    InvalidFields(ValidationErrors),
+    /// JSONボディの読み取り失敗（構文エラー・Content-Type不正など）
+    InvalidJson(JsonRejection),
+    /// クエリパラメータの読み取り失敗
+    InvalidQuery(QueryRejection),
+    /// If-Matchヘッダーを解釈できない（弱いETag・複数のETagを含む）
+    InvalidIfMatch(String),
+    /// リクエストボディが上限を超えている
+    PayloadTooLarge,
     InternalServerError,
     ServerError(Option<String>),
 }
 
-impl IntoResponse for Error {
-    fn into_response(self) -> axum::response::Response {
-        println!("->> Error: {:?}", self);
+/// RFC 7807 Problem Details
+/// `code` はクライアントが分岐に使う安定したエラーコード
+#[derive(Debug, Clone, Serialize, ToSchema)]
+#[schema(example = json!({
+    "type": "urn:problem:insufficient-quantity",
+    "title": "Insufficient quantity",
+    "status": 400,
+    "code": "INSUFFICIENT_QUANTITY",
+    "detail": "Requested 20 but only 10 available",
+    "instance": "62623bb0-8d29-4946-bb1f-7665256fa8ba",
+    "product_id": 1,
+    "requested": 20,
+    "available": 10
+}))]
+pub struct ProblemDetails {
+    #[serde(rename = "type")]
+    pub problem_type: String,
+    pub title: String,
+    pub status: u16,
+    pub code: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub detail: Option<String>,
+    /// エラーが発生したリクエストのID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub instance: Option<String>,
+    /// エラー固有の追加情報（例: requested / available）
+    #[serde(flatten)]
+    #[schema(ignore)]
+    pub extensions: Map<String, Value>,
+}
+
+impl ProblemDetails {
+    pub fn new(status: StatusCode, code: &'static str, title: &str) -> Self {
+        Self {
+            problem_type: format!("urn:problem:{}", code.to_ascii_lowercase().replace('_', "-")),
+            title: title.to_string(),
+            status: status.as_u16(),
+            code,
+            detail: None,
+            instance: None,
+            extensions: Map::new(),
+        }
+    }
+
+    pub fn with_detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    pub fn with_extension(mut self, key: &str, value: Value) -> Self {
+        self.extensions.insert(key.to_string(), value);
+        self
+    }
+
+    pub fn with_instance(mut self, instance: Option<&str>) -> Self {
+        self.instance = instance.map(|s| s.to_string());
+        self
+    }
+
+    pub fn status_code(&self) -> StatusCode {
+        StatusCode::from_u16(self.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR)
+    }
+}
+
+impl IntoResponse for ProblemDetails {
+    fn into_response(self) -> Response {
+        let status = self.status_code();
+        let body = serde_json::to_vec(&self).unwrap_or_default();
+
+        let mut response = (status, body).into_response();
+        response.headers_mut().insert(
+            header::CONTENT_TYPE,
+            HeaderValue::from_static(PROBLEM_JSON_CONTENT_TYPE),
+        );
+        // レスポンスマッパーでinstance（リクエストID）を補完できるように保持する
+        response.extensions_mut().insert(self);
+        response
+    }
+}
+
+/// レスポンスがProblem Detailsの場合、instanceにリクエストIDを埋め込みます
+/// ボディだけを書き換え、ハンドラーが付けたヘッダー（ETag、Location、Retry-Afterなど）は保持します
+pub fn with_problem_instance(res: Response, request_id: Option<&str>) -> Response {
+    let Some(problem) = res.extensions().get::<ProblemDetails>().cloned() else {
+        return res;
+    };
+    let problem = problem.with_instance(request_id);
+    let body = serde_json::to_vec(&problem).unwrap_or_default();
 
+    let (mut parts, _) = res.into_parts();
+    parts.headers.remove(header::CONTENT_LENGTH);
+    parts.extensions.insert(problem);
+    Response::from_parts(parts, Body::from(body))
+}
+
+impl Error {
+    /// エラーをProblem Detailsに変換します
+    pub fn to_problem(&self) -> ProblemDetails {
         match self {
-            Error::BuyProductFailed => (
-                StatusCode::BAD_REQUEST, 
-                "Failed to buy product".to_string()
-            ),
-            Error::NotFound => (
-                StatusCode::NOT_FOUND, 
-                "Resource not found".to_string()
-            ),
-            Error::InternalServerError => (
-                StatusCode::INTERNAL_SERVER_ERROR, 
-                "Internal server error".to_string()
-            ),
-            Error::ServerError(msg) => (
-                StatusCode::INTERNAL_SERVER_ERROR, 
-                msg.unwrap_or_else(|| "Internal server error".to_string())
-            ),
-        }
-        .into_response()
+            Error::Application(err) => application_problem(err),
+            Error::NotFound => {
+                ProblemDetails::new(StatusCode::NOT_FOUND, "NOT_FOUND", "Resource not found")
+            }
+            Error::Conflict => version_conflict_problem(),
+            Error::InvalidFields(errors) => validation_problem(&errors.to_string())
+                .with_extension("errors", json!(errors)),
+            Error::InvalidJson(rejection) => json_rejection_problem(rejection),
+            Error::InvalidQuery(rejection) => {
+                ProblemDetails::new(StatusCode::BAD_REQUEST, "INVALID_QUERY", "Invalid query string")
+                    .with_detail(rejection.body_text())
+            }
+            Error::InvalidIfMatch(message) => {
+                ProblemDetails::new(StatusCode::BAD_REQUEST, "INVALID_IF_MATCH", "Invalid If-Match header")
+                    .with_detail(message.clone())
+            }
+            Error::PayloadTooLarge => {
+                ProblemDetails::new(StatusCode::PAYLOAD_TOO_LARGE, "PAYLOAD_TOO_LARGE", "Payload too large")
+            }
+            // 内部のエラーメッセージはレスポンスに含めず、IntoResponseでログにだけ出力する
+            Error::InternalServerError | Error::ServerError(_) => internal_problem(),
+        }
+    }
+}
+
+fn application_problem(err: &ApplicationError) -> ProblemDetails {
+    match err {
+        ApplicationError::ProductNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND", "Product not found")
+                .with_detail(format!("Product {} does not exist", id))
+                .with_extension("product_id", json!(id))
+        }
+        ApplicationError::OrderNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "ORDER_NOT_FOUND", "Order not found")
+                .with_detail(format!("Order {} does not exist", id))
+                .with_extension("order_id", json!(id))
+        }
+        ApplicationError::CartNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "CART_NOT_FOUND", "Cart not found")
+                .with_detail(format!("Cart {} does not exist", id))
+                .with_extension("cart_id", json!(id))
+        }
+        ApplicationError::ReservationNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "RESERVATION_NOT_FOUND", "Reservation not found")
+                .with_detail(format!("Reservation {} does not exist", id))
+                .with_extension("reservation_id", json!(id))
+        }
+        ApplicationError::PromotionNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "PROMOTION_NOT_FOUND", "Promotion not found")
+                .with_detail(format!("Promotion {} does not exist", id))
+                .with_extension("promotion_id", json!(id))
+        }
+        ApplicationError::CouponCodeTaken(code) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "COUPON_CODE_TAKEN", "Coupon code already in use")
+                .with_detail(format!("Coupon code {} is used by another promotion", code))
+                .with_extension("coupon_code", json!(code))
+        }
+        ApplicationError::CategoryNotFound(id) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND", "Category not found")
+                .with_detail(format!("Category {} does not exist", id))
+                .with_extension("category_id", json!(id))
+        }
+        ApplicationError::CategoryHasChildren(id) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "CATEGORY_HAS_CHILDREN", "Category has subcategories")
+                .with_detail(format!("Category {} still has subcategories; delete or move them first", id))
+                .with_extension("category_id", json!(id))
+        }
+        ApplicationError::SkuTaken(sku) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "SKU_TAKEN", "SKU already in use")
+                .with_detail(format!("SKU {} is used by another variant", sku))
+                .with_extension("sku", json!(sku))
+        }
+        ApplicationError::IdempotencyKeyInProgress(key) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "IDEMPOTENCY_KEY_IN_PROGRESS", "Request is still in progress")
+                .with_detail("A request with this Idempotency-Key is still being processed; retry later")
+                .with_extension("idempotency_key", json!(key))
+        }
+        ApplicationError::IdempotencyKeyMismatch(key) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "IDEMPOTENCY_KEY_MISMATCH", "Idempotency key reused")
+                .with_detail("This Idempotency-Key was already used for a different request")
+                .with_extension("idempotency_key", json!(key))
+        }
+        // カートにはETagがないため、If-Matchではなくカートを読み直してのやり直しを促す
+        ApplicationError::CartChanged(cart_id) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "CART_CHANGED", "Cart changed during the request")
+                .with_detail(format!("Cart {} or the stock of its products was changed by another request; fetch the cart and retry", cart_id))
+                .with_extension("cart_id", json!(cart_id))
+        }
+        ApplicationError::ReservationConflict(Some(reservation_id)) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "RESERVATION_CONFLICT", "Reservation changed during the request")
+                .with_detail(format!("Reservation {} or the stock it holds was changed by another request; fetch the reservation and retry", reservation_id))
+                .with_extension("reservation_id", json!(reservation_id))
+        }
+        ApplicationError::ReservationConflict(None) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "RESERVATION_CONFLICT", "Stock changed during the reservation")
+                .with_detail("The stock of the requested products was changed by another request; retry the reservation")
+        }
+        ApplicationError::StockChanged(product_id) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "STOCK_CHANGED", "Stock changed during the purchase")
+                .with_detail(format!("Other purchases kept changing the stock of product {}; retry the request", product_id))
+                .with_extension("product_id", json!(product_id))
+        }
+        ApplicationError::Domain(DomainError::InsufficientQuantity { product_id, requested, available }) => {
+            ProblemDetails::new(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY", "Insufficient quantity")
+                .with_detail(format!("Requested {} but only {} available", requested, available))
+                .with_extension("product_id", json!(product_id))
+                .with_extension("requested", json!(requested))
+                .with_extension("available", json!(available))
+        }
+        ApplicationError::Domain(DomainError::InvalidProductData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_PRODUCT_DATA", "Invalid product data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::InvalidOrderData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_ORDER_DATA", "Invalid order data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::InvalidCartData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_CART_DATA", "Invalid cart data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::CartItemNotFound(product_id)) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "CART_ITEM_NOT_FOUND", "Cart item not found")
+                .with_detail(format!("Product {} is not in the cart", product_id))
+                .with_extension("product_id", json!(product_id))
+        }
+        ApplicationError::Domain(DomainError::CartAlreadyCheckedOut(cart_id)) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "CART_CHECKED_OUT", "Cart is already checked out")
+                .with_detail(format!("Cart {} can no longer be modified", cart_id))
+                .with_extension("cart_id", json!(cart_id))
+        }
+        ApplicationError::Domain(DomainError::InvalidReservationData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_RESERVATION_DATA", "Invalid reservation data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::ReservationNotActive(reservation_id)) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "RESERVATION_NOT_ACTIVE", "Reservation is no longer active")
+                .with_detail(format!("Reservation {} has already been confirmed, released or expired", reservation_id))
+                .with_extension("reservation_id", json!(reservation_id))
+        }
+        ApplicationError::Domain(DomainError::ReservationExpired(reservation_id)) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "RESERVATION_EXPIRED", "Reservation has expired")
+                .with_detail(format!("Reservation {} can no longer be confirmed", reservation_id))
+                .with_extension("reservation_id", json!(reservation_id))
+        }
+        ApplicationError::Domain(DomainError::CurrencyMismatch { expected, actual }) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "CURRENCY_MISMATCH", "Currency mismatch")
+                .with_detail(format!("Amounts in {} and {} cannot be combined", expected.code(), actual.code()))
+                .with_extension("expected", json!(expected.code()))
+                .with_extension("actual", json!(actual.code()))
+        }
+        ApplicationError::Domain(DomainError::AmountOutOfRange) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "AMOUNT_OUT_OF_RANGE", "Amount out of range")
+                .with_detail("The resulting amount cannot be represented")
+        }
+        ApplicationError::Domain(DomainError::InvalidPromotionData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_PROMOTION_DATA", "Invalid promotion data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::CouponNotApplicable(code)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "COUPON_NOT_APPLICABLE", "Coupon cannot be applied")
+                .with_detail(format!("Coupon {} does not exist, is outside its validity window, has reached its usage limit or does not cover this product", code))
+                .with_extension("coupon_code", json!(code))
+        }
+        ApplicationError::Domain(DomainError::PromotionUnavailable(promotion_id)) => {
+            ProblemDetails::new(StatusCode::CONFLICT, "PROMOTION_UNAVAILABLE", "Promotion is no longer available")
+                .with_detail(format!("Promotion {} ran out or was removed while the order was being placed; retry to get the current price", promotion_id))
+                .with_extension("promotion_id", json!(promotion_id))
+        }
+        ApplicationError::Domain(DomainError::UnknownTaxRegion(code)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "UNKNOWN_TAX_REGION", "Unknown tax region")
+                .with_detail(format!("No tax rules are configured for region {}", code))
+                .with_extension("region", json!(code))
+        }
+        ApplicationError::Domain(DomainError::InvalidCategoryData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_CATEGORY_DATA", "Invalid category data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::InvalidVariantData(msg)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_VARIANT_DATA", "Invalid variant data")
+                .with_detail(msg.clone())
+        }
+        ApplicationError::Domain(DomainError::VariantNotFound { product_id, sku }) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "VARIANT_NOT_FOUND", "Variant not found")
+                .with_detail(format!("Product {} has no variant {}", product_id, sku))
+                .with_extension("product_id", json!(product_id))
+                .with_extension("sku", json!(sku))
+        }
+        ApplicationError::Domain(DomainError::VariantRequired(product_id)) => {
+            ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "VARIANT_REQUIRED", "Variant required")
+                .with_detail(format!("Product {} has variants; buy it by SKU", product_id))
+                .with_extension("product_id", json!(product_id))
+        }
+        ApplicationError::Validation(msg) => validation_problem(msg),
+        ApplicationError::Repository(RepositoryError::Conflict) => version_conflict_problem(),
+        ApplicationError::Repository(RepositoryError::NotFound) => {
+            ProblemDetails::new(StatusCode::NOT_FOUND, "NOT_FOUND", "Resource not found")
+        }
+        // インフラ起因のエラーは詳細をクライアントに返さない
+        ApplicationError::Repository(_) => internal_problem(),
+    }
+}
+
+fn json_rejection_problem(rejection: &JsonRejection) -> ProblemDetails {
+    match rejection {
+        JsonRejection::JsonDataError(_) => validation_problem(&rejection.body_text()),
+        JsonRejection::JsonSyntaxError(_) => {
+            ProblemDetails::new(StatusCode::BAD_REQUEST, "MALFORMED_JSON", "Malformed JSON body")
+                .with_detail(rejection.body_text())
+        }
+        JsonRejection::MissingJsonContentType(_) => {
+            ProblemDetails::new(StatusCode::UNSUPPORTED_MEDIA_TYPE, "UNSUPPORTED_MEDIA_TYPE", "Unsupported media type")
+                .with_detail("Expected request with `Content-Type: application/json`")
+        }
+        _ => ProblemDetails::new(rejection.status(), "INVALID_REQUEST_BODY", "Invalid request body")
+            .with_detail(rejection.body_text()),
+    }
+}
+
+fn validation_problem(msg: &str) -> ProblemDetails {
+    ProblemDetails::new(StatusCode::UNPROCESSABLE_ENTITY, "VALIDATION_FAILED", "Validation failed")
+        .with_detail(msg)
+}
+
+fn version_conflict_problem() -> ProblemDetails {
+    ProblemDetails::new(StatusCode::CONFLICT, "VERSION_CONFLICT", "Resource was modified by another request")
+        .with_detail("Fetch the latest version and retry with its ETag in If-Match")
+}
+
+fn internal_problem() -> ProblemDetails {
+    ProblemDetails::new(StatusCode::INTERNAL_SERVER_ERROR, "INTERNAL_ERROR", "Internal server error")
+}
+
+impl IntoResponse for Error {
+    fn into_response(self) -> Response {
+        let problem = self.to_problem();
+
+        if problem.status_code().is_server_error() {
+            tracing::error!(error = ?self, code = problem.code, "request failed");
+        } else {
+            tracing::warn!(error = ?self, code = problem.code, "request rejected");
+        }
+
+        problem.into_response()
+    }
+}
+
+impl From<ApplicationError> for Error {
+    fn from(error: ApplicationError) -> Self {
+        Error::Application(error)
+    }
+}
+
+impl From<ValidationErrors> for Error {
+    fn from(errors: ValidationErrors) -> Self {
+        Error::InvalidFields(errors)
+    }
+}
+
+impl From<JsonRejection> for Error {
+    fn from(rejection: JsonRejection) -> Self {
+        Error::InvalidJson(rejection)
+    }
+}
+
+impl From<QueryRejection> for Error {
+    fn from(rejection: QueryRejection) -> Self {
+        Error::InvalidQuery(rejection)
     }
 }
 
@@ -41,3 +410,64 @@ impl From<anyhow::Error> for Error {
         Error::ServerError(Some(error.to_string()))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use axum::body::to_bytes;
+
+    use super::*;
+
+    async fn problem_body(response: Response) -> Value {
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        serde_json::from_slice(&bytes).unwrap()
+    }
+
+    #[tokio::test]
+    async fn server_errors_do_not_expose_internal_messages() {
+        let error = Error::from(anyhow::anyhow!("connection refused: postgres://admin:secret@db"));
+
+        let body = problem_body(error.into_response()).await;
+
+        assert_eq!(body["code"], "INTERNAL_ERROR");
+        assert!(body.get("detail").is_none(), "unexpected detail: {body}");
+    }
+
+    #[tokio::test]
+    async fn problem_instance_keeps_handler_headers() {
+        let mut response = Error::Conflict.into_response();
+        response.headers_mut().insert(header::RETRY_AFTER, HeaderValue::from_static("1"));
+
+        let response = with_problem_instance(response, Some("req-1"));
+
+        assert_eq!(response.headers()[header::RETRY_AFTER], "1");
+        assert_eq!(response.headers()[header::CONTENT_TYPE], PROBLEM_JSON_CONTENT_TYPE);
+        assert_eq!(problem_body(response).await["instance"], "req-1");
+    }
+
+    #[tokio::test]
+    async fn cart_conflicts_do_not_ask_for_an_etag() {
+        let response = Error::from(ApplicationError::CartChanged(3)).into_response();
+
+        assert_eq!(response.status(), StatusCode::CONFLICT);
+        let body = problem_body(response).await;
+        assert_eq!(body["code"], "CART_CHANGED");
+        assert_eq!(body["cart_id"], 3);
+        assert!(!body["detail"].as_str().unwrap().contains("If-Match"), "unexpected detail: {body}");
+    }
+
+    #[tokio::test]
+    async fn reservation_conflicts_do_not_ask_for_an_etag() {
+        for (error, reservation_id) in [
+            (ApplicationError::ReservationConflict(Some(5)), json!(5)),
+            (ApplicationError::ReservationConflict(None), Value::Null),
+        ] {
+            let response = Error::from(error).into_response();
+
+            assert_eq!(response.status(), StatusCode::CONFLICT);
+            let body = problem_body(response).await;
+            assert_eq!(body["code"], "RESERVATION_CONFLICT");
+            assert_eq!(body["reservation_id"], reservation_id);
+            assert!(!body["detail"].as_str().unwrap().contains("If-Match"), "unexpected detail: {body}");
+        }
+    }
+}
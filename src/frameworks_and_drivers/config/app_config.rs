@@ -0,0 +1,569 @@
+use std::collections::HashMap;
+use std::net::SocketAddr;
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::domain::models::RoundingMode;
+use crate::domain::tax::{TaxCategory, TaxMode, TaxRate, TaxRegion, TaxTable};
+
+/// 設定ファイルを指定しない場合に読み込むファイル（存在しなければ無視）
+const DEFAULT_CONFIG_FILE: &str = "config.toml";
+/// 環境変数のプレフィックス（例: `APP_DATABASE__URL`）
+const ENV_PREFIX: &str = "APP";
+const LOG_LEVELS: [&str; 5] = ["trace", "debug", "info", "warn", "error"];
+
+/// アプリケーション設定
+/// 優先順位: CLIフラグ > 環境変数 > TOMLファイル > デフォルト値
+#[derive(Debug, Clone, Deserialize)]
+pub struct Config {
+    pub database: DatabaseConfig,
+    pub server: ServerConfig,
+    pub log: LogConfig,
+    pub features: FeatureToggles,
+    pub reservations: ReservationConfig,
+    pub idempotency: IdempotencyConfig,
+    pub tax: TaxConfig,
+}
+
+/// データベース接続設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct DatabaseConfig {
+    /// 接続URL（例: `sqlite:data/db.sqlite`、`postgres://localhost/shop`）
+    /// スキームでアプリ全体のデータを保存するデータベースを選ぶ
+    pub url: String,
+    /// コネクションプールの最大接続数
+    pub max_connections: u32,
+    /// コネクションプールが維持する最小接続数
+    pub min_connections: u32,
+    /// プールから接続を取得するまで待つ時間（秒）。過ぎるとエラーになる
+    pub acquire_timeout_seconds: u64,
+    /// 使われていない接続を閉じるまでの時間（秒）。0の場合は閉じない
+    pub idle_timeout_seconds: u64,
+    /// SQLiteの接続オプション
+    pub sqlite: SqliteConfig,
+    /// 商品リポジトリの実装（memoryの場合はプロモーション・注文・カート・予約もメモリ上に保持する）
+    pub product_repository: RepositoryBackend,
+}
+
+impl DatabaseConfig {
+    /// 接続するデータベースの種類
+    pub fn backend(&self) -> Option<DatabaseBackend> {
+        DatabaseBackend::from_url(&self.url)
+    }
+}
+
+/// SQLiteの接続オプション（接続ごとにPRAGMAとして設定する）
+#[derive(Debug, Clone, Deserialize)]
+pub struct SqliteConfig {
+    /// ジャーナルモード
+    pub journal_mode: SqliteJournalMode,
+    /// 書き込みの同期レベル
+    pub synchronous: SqliteSynchronous,
+    /// ロックの解放を待つ時間（秒）。過ぎると `database is locked` エラーになる
+    pub busy_timeout_seconds: u64,
+    /// 外部キー制約を有効にする
+    pub foreign_keys: bool,
+    /// データベースファイル（と親ディレクトリ）がなければ作成する
+    pub create_if_missing: bool,
+}
+
+/// SQLiteのジャーナルモード（`PRAGMA journal_mode`）
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SqliteJournalMode {
+    Delete,
+    Truncate,
+    Persist,
+    Memory,
+    /// 読み込みと書き込みを並行できる（推奨）
+    Wal,
+    Off,
+}
+
+/// SQLiteの同期レベル（`PRAGMA synchronous`）
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SqliteSynchronous {
+    Off,
+    /// WALモードではコミット済みのデータが電源断でも壊れない（推奨）
+    Normal,
+    Full,
+    Extra,
+}
+
+/// リポジトリの実装
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RepositoryBackend {
+    /// データベース（`database.url`）に保存する
+    #[serde(alias = "sqlite")]
+    Database,
+    /// プロセス内のメモリに保持する（再起動すると失われる。テスト・デモ用）
+    Memory,
+}
+
+/// データベースの種類（接続URLのスキームで判定する）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatabaseBackend {
+    /// `sqlite:`
+    Sqlite,
+    /// `postgres:` / `postgresql:`（`postgres` featureが必要）
+    Postgres,
+}
+
+impl DatabaseBackend {
+    pub fn from_url(url: &str) -> Option<Self> {
+        let (scheme, _) = url.split_once(':')?;
+        match scheme.to_ascii_lowercase().as_str() {
+            "sqlite" => Some(Self::Sqlite),
+            "postgres" | "postgresql" => Some(Self::Postgres),
+            _ => None,
+        }
+    }
+}
+
+/// HTTPサーバー設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct ServerConfig {
+    /// 待ち受けアドレス（例: `127.0.0.1:4000`）
+    pub bind_address: SocketAddr,
+}
+
+/// ログ設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct LogConfig {
+    /// ログレベル（trace / debug / info / warn / error）
+    pub level: String,
+    /// 出力形式
+    pub format: LogFormat,
+}
+
+/// ログの出力形式
+#[derive(Debug, Clone, Copy, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum LogFormat {
+    /// 開発向けの人が読みやすい形式
+    Pretty,
+    /// ログ基盤向けの1行1JSON形式
+    Json,
+}
+
+/// 機能フラグ
+#[derive(Debug, Clone, Deserialize)]
+pub struct FeatureToggles {
+    /// 商品の作成・更新・削除エンドポイントを有効にする
+    pub product_admin: bool,
+    /// 商品の全文検索エンドポイントを有効にする
+    pub product_search: bool,
+}
+
+/// 在庫予約の設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReservationConfig {
+    /// 予約の有効期間（秒）。過ぎると確定できず、在庫が戻される
+    pub ttl_seconds: u64,
+    /// 期限切れの予約を失効させるバックグラウンド処理の実行間隔（秒）
+    pub expiry_interval_seconds: u64,
+}
+
+/// Idempotency-Keyの設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct IdempotencyConfig {
+    /// 最初のレスポンスを保持する期間（秒）。過ぎると同じキーを新しいリクエストとして扱う
+    pub ttl_seconds: u64,
+}
+
+/// 税の設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaxConfig {
+    /// 購入時に地域が指定されない場合に適用する地域
+    pub default_region: String,
+    /// 地域コードごとの税制
+    /// 1つも設定されていない場合は日本の税制（税込、標準10%・軽減8%、切り捨て）を使う
+    #[serde(default)]
+    pub regions: HashMap<String, TaxRegionConfig>,
+}
+
+/// 地域ごとの税制の設定
+#[derive(Debug, Clone, Deserialize)]
+pub struct TaxRegionConfig {
+    /// 価格が税込（inclusive）か税抜（exclusive）か
+    pub mode: String,
+    /// 明細ごとの税額の丸め方（down / up / half_up / half_even）
+    pub rounding: String,
+    /// 課税区分（standard / reduced / exempt）ごとの税率（%）。standardは必須
+    pub rates: HashMap<String, f64>,
+}
+
+impl TaxConfig {
+    /// 設定から税制の一覧を組み立てます
+    pub fn table(&self) -> Result<TaxTable, ConfigError> {
+        let invalid = |message: String| ConfigError::Invalid { key: "tax.regions", message };
+        let configured = if self.regions.is_empty() { &Self::builtin_regions() } else { &self.regions };
+
+        let mut regions: Vec<TaxRegion> = Vec::with_capacity(configured.len());
+        for (code, region) in configured {
+            let mode = TaxMode::parse(&region.mode).ok_or_else(|| {
+                invalid(format!("region `{}`: mode `{}` is not one of inclusive, exclusive", code, region.mode))
+            })?;
+            let rounding = RoundingMode::parse(&region.rounding).ok_or_else(|| {
+                invalid(format!(
+                    "region `{}`: rounding `{}` is not one of down, up, half_up, half_even",
+                    code, region.rounding
+                ))
+            })?;
+            let mut rates = HashMap::new();
+            for (category, percent) in &region.rates {
+                let category = TaxCategory::parse(category).ok_or_else(|| {
+                    invalid(format!("region `{}`: unknown tax category `{}`", code, category))
+                })?;
+                let rate = TaxRate::from_percent(*percent).ok_or_else(|| {
+                    invalid(format!("region `{}`: rate {} must be between 0 and 100", code, percent))
+                })?;
+                rates.insert(category, rate);
+            }
+            if !rates.contains_key(&TaxCategory::Standard) {
+                return Err(invalid(format!("region `{}` has no standard rate", code)));
+            }
+            let region = TaxRegion::new(code, mode, rounding, rates);
+            // 地域コードは大文字・小文字を区別しないため、同じ地域の重複定義は拒否する
+            if regions.iter().any(|other| other.code == region.code) {
+                return Err(invalid(format!("region `{}` is defined more than once", region.code)));
+            }
+            regions.push(region);
+        }
+
+        TaxTable::new(&self.default_region, regions).map_err(|_| ConfigError::Invalid {
+            key: "tax.default_region",
+            message: format!("`{}` is not one of the configured regions", self.default_region),
+        })
+    }
+
+    fn builtin_regions() -> HashMap<String, TaxRegionConfig> {
+        let jp = TaxRegionConfig {
+            mode: "inclusive".to_string(),
+            rounding: "down".to_string(),
+            rates: HashMap::from([("standard".to_string(), 10.0), ("reduced".to_string(), 8.0)]),
+        };
+        HashMap::from([("JP".to_string(), jp)])
+    }
+}
+
+/// 設定に関するCLIフラグ
+/// 指定されたフラグはファイルや環境変数の値より優先される
+#[derive(Debug, Default, clap::Args)]
+pub struct ConfigArgs {
+    /// Path to the TOML config file
+    #[arg(long, global = true, env = "APP_CONFIG")]
+    pub config: Option<PathBuf>,
+    /// Database URL (overrides `database.url`)
+    #[arg(long, global = true)]
+    pub database_url: Option<String>,
+    /// Maximum number of pooled database connections (overrides `database.max_connections`)
+    #[arg(long, global = true)]
+    pub max_connections: Option<u32>,
+    /// Address to bind the HTTP server to (overrides `server.bind_address`)
+    #[arg(long, global = true)]
+    pub bind_address: Option<String>,
+    /// Log level (overrides `log.level`)
+    #[arg(long, global = true)]
+    pub log_level: Option<String>,
+    /// Log output format: pretty or json (overrides `log.format`)
+    #[arg(long, global = true)]
+    pub log_format: Option<String>,
+}
+
+#[derive(Debug)]
+pub enum ConfigError {
+    /// 設定の読み込み・パースエラー
+    Load(String),
+    /// 設定値が不正
+    Invalid { key: &'static str, message: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Load(msg) => write!(f, "Failed to load configuration: {}", msg),
+            ConfigError::Invalid { key, message } => write!(f, "Invalid configuration `{}`: {}", key, message),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<::config::ConfigError> for ConfigError {
+    fn from(err: ::config::ConfigError) -> Self {
+        ConfigError::Load(err.to_string())
+    }
+}
+
+impl Config {
+    /// デフォルト値・TOMLファイル・環境変数・CLIフラグの順に設定を重ねて読み込み、検証します
+    pub fn load(args: &ConfigArgs) -> Result<Self, ConfigError> {
+        Self::load_with_env(args, None)
+    }
+
+    /// `env` を指定した場合は、プロセスの環境変数の代わりにその値を読み込みます（テスト用）
+    fn load_with_env(args: &ConfigArgs, env: Option<::config::Map<String, String>>) -> Result<Self, ConfigError> {
+        // 明示的に指定された設定ファイルは必須、デフォルトのファイルは任意
+        let (path, required) = match &args.config {
+            Some(path) => (path.clone(), true),
+            None => (PathBuf::from(DEFAULT_CONFIG_FILE), false),
+        };
+
+        let config: Config = ::config::Config::builder()
+            .set_default("database.url", "sqlite:data/db.sqlite")?
+            .set_default("database.max_connections", 10)?
+            .set_default("database.min_connections", 0)?
+            .set_default("database.acquire_timeout_seconds", 30)?
+            .set_default("database.idle_timeout_seconds", 600)?
+            .set_default("database.sqlite.journal_mode", "wal")?
+            .set_default("database.sqlite.synchronous", "normal")?
+            .set_default("database.sqlite.busy_timeout_seconds", 5)?
+            .set_default("database.sqlite.foreign_keys", true)?
+            .set_default("database.sqlite.create_if_missing", true)?
+            .set_default("database.product_repository", "database")?
+            .set_default("server.bind_address", "127.0.0.1:4000")?
+            .set_default("log.level", "info")?
+            .set_default("log.format", "pretty")?
+            .set_default("features.product_admin", true)?
+            .set_default("features.product_search", true)?
+            .set_default("reservations.ttl_seconds", 900)?
+            .set_default("reservations.expiry_interval_seconds", 30)?
+            .set_default("idempotency.ttl_seconds", 86400)?
+            .set_default("tax.default_region", "JP")?
+            .add_source(::config::File::from(path).required(required))
+            .add_source(
+                ::config::Environment::with_prefix(ENV_PREFIX)
+                    .prefix_separator("_")
+                    .separator("__")
+                    .try_parsing(true)
+                    .source(env),
+            )
+            .set_override_option("database.url", args.database_url.clone())?
+            .set_override_option("database.max_connections", args.max_connections)?
+            .set_override_option("server.bind_address", args.bind_address.clone())?
+            .set_override_option("log.level", args.log_level.clone())?
+            .set_override_option("log.format", args.log_format.clone())?
+            .build()?
+            .try_deserialize()?;
+
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 起動前に設定値の整合性を検証します
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        self.validate_database_url()?;
+        if self.database.max_connections == 0 {
+            return Err(ConfigError::Invalid {
+                key: "database.max_connections",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.database.min_connections > self.database.max_connections {
+            return Err(ConfigError::Invalid {
+                key: "database.min_connections",
+                message: format!("must not exceed database.max_connections ({})", self.database.max_connections),
+            });
+        }
+        if self.database.acquire_timeout_seconds == 0 {
+            return Err(ConfigError::Invalid {
+                key: "database.acquire_timeout_seconds",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.reservations.ttl_seconds == 0 {
+            return Err(ConfigError::Invalid {
+                key: "reservations.ttl_seconds",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.reservations.expiry_interval_seconds == 0 {
+            return Err(ConfigError::Invalid {
+                key: "reservations.expiry_interval_seconds",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.idempotency.ttl_seconds == 0 {
+            return Err(ConfigError::Invalid {
+                key: "idempotency.ttl_seconds",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        self.tax.table()?;
+        if !LOG_LEVELS.contains(&self.log.level.to_ascii_lowercase().as_str()) {
+            return Err(ConfigError::Invalid {
+                key: "log.level",
+                message: format!("`{}` is not one of {}", self.log.level, LOG_LEVELS.join(", ")),
+            });
+        }
+        Ok(())
+    }
+
+    /// 接続URLはSQLiteか、`postgres` featureを有効にしてビルドした場合のPostgreSQLのみ
+    fn validate_database_url(&self) -> Result<(), ConfigError> {
+        let url = &self.database.url;
+        let invalid = |message: String| ConfigError::Invalid { key: "database.url", message };
+        match DatabaseBackend::from_url(url) {
+            Some(DatabaseBackend::Sqlite) => Ok(()),
+            #[cfg(feature = "postgres")]
+            Some(DatabaseBackend::Postgres) => url
+                .parse::<sqlx::postgres::PgConnectOptions>()
+                .map(|_| ())
+                .map_err(|e| invalid(format!("invalid PostgreSQL URL: {}", e))),
+            #[cfg(not(feature = "postgres"))]
+            Some(DatabaseBackend::Postgres) => {
+                Err(invalid("PostgreSQL support is not built in (build with `--features postgres`)".to_string()))
+            }
+            None => Err(invalid(format!(
+                "unsupported database URL `{}` (expected `sqlite:...` or `postgres://...`)",
+                url
+            ))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // テストごとの一時的な設定ファイルを書き出す
+    fn write_config(name: &str, contents: &str) -> PathBuf {
+        let path = std::env::temp_dir()
+            .join(format!("axum-mini-template-config-{}-{}.toml", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+
+    fn load_file(name: &str, contents: &str) -> Result<Config, ConfigError> {
+        let path = write_config(name, contents);
+        // 実行環境の環境変数に左右されないよう、環境変数は空にする
+        let config = Config::load_with_env(&ConfigArgs { config: Some(path.clone()), ..Default::default() }, Some(::config::Map::new()));
+        let _ = std::fs::remove_file(path);
+        config
+    }
+
+    type ConfigChange = fn(&mut Config);
+
+    fn invalid_key(result: Result<(), ConfigError>) -> &'static str {
+        match result {
+            Err(ConfigError::Invalid { key, .. }) => key,
+            other => panic!("expected an invalid configuration, got {:?}", other),
+        }
+    }
+
+    /// TOMLファイルの値を環境変数が、環境変数の値をCLIフラグが上書きし、指定のない項目はデフォルト値になること
+    #[test]
+    fn later_sources_override_earlier_ones() {
+        let path = write_config(
+            "precedence",
+            r#"
+            [log]
+            level = "warn"
+
+            [reservations]
+            ttl_seconds = 60
+
+            [server]
+            bind_address = "127.0.0.1:4100"
+            "#,
+        );
+        let env = ::config::Map::from([
+            ("APP_LOG__LEVEL".to_string(), "debug".to_string()),
+            ("APP_RESERVATIONS__TTL_SECONDS".to_string(), "120".to_string()),
+        ]);
+        let args = ConfigArgs { config: Some(path.clone()), log_level: Some("error".to_string()), ..Default::default() };
+        let config = Config::load_with_env(&args, Some(env));
+        let _ = std::fs::remove_file(path);
+        let config = config.unwrap();
+
+        assert_eq!(config.log.level, "error");
+        assert_eq!(config.reservations.ttl_seconds, 120);
+        assert_eq!(config.server.bind_address, "127.0.0.1:4100".parse().unwrap());
+        assert_eq!(config.reservations.expiry_interval_seconds, 30);
+        assert_eq!(config.database.product_repository, RepositoryBackend::Database);
+    }
+
+    /// 指定した設定ファイルがない場合や、値の型が合わない場合は読み込みエラーになること
+    #[test]
+    fn rejects_missing_files_and_malformed_values() {
+        let missing = std::env::temp_dir().join("axum-mini-template-config-missing.toml");
+        let result = Config::load_with_env(&ConfigArgs { config: Some(missing), ..Default::default() }, Some(::config::Map::new()));
+        assert!(matches!(result, Err(ConfigError::Load(_))), "{:?}", result);
+
+        let result = load_file("malformed", "[database]\nmax_connections = \"many\"\n");
+        assert!(matches!(result, Err(ConfigError::Load(_))), "{:?}", result);
+    }
+
+    /// 整合しない設定値は項目名とともに拒否されること
+    #[test]
+    fn rejects_invalid_values_by_key() {
+        let valid = load_file("valid", "").unwrap();
+        valid.validate().unwrap();
+
+        let cases: [(&str, ConfigChange); 9] = [
+            ("database.url", |c| c.database.url = "mysql://localhost/shop".to_string()),
+            ("database.max_connections", |c| c.database.max_connections = 0),
+            ("database.min_connections", |c| c.database.min_connections = c.database.max_connections + 1),
+            ("database.acquire_timeout_seconds", |c| c.database.acquire_timeout_seconds = 0),
+            ("reservations.ttl_seconds", |c| c.reservations.ttl_seconds = 0),
+            ("reservations.expiry_interval_seconds", |c| c.reservations.expiry_interval_seconds = 0),
+            ("idempotency.ttl_seconds", |c| c.idempotency.ttl_seconds = 0),
+            ("tax.default_region", |c| c.tax.default_region = "XX".to_string()),
+            ("log.level", |c| c.log.level = "verbose".to_string()),
+        ];
+        for (key, change) in cases {
+            let mut config = valid.clone();
+            change(&mut config);
+            assert_eq!(invalid_key(config.validate()), key);
+        }
+    }
+
+    /// 地域ごとの税制に不正な値があれば `tax.regions` として拒否されること
+    #[test]
+    fn rejects_invalid_tax_regions() {
+        let region = |mode: &str, rounding: &str, rates: &[(&str, f64)]| TaxRegionConfig {
+            mode: mode.to_string(),
+            rounding: rounding.to_string(),
+            rates: rates.iter().map(|(category, rate)| (category.to_string(), *rate)).collect(),
+        };
+        let valid = load_file("tax", "").unwrap();
+
+        for invalid in [
+            region("gross", "down", &[("standard", 10.0)]),
+            region("inclusive", "nearest", &[("standard", 10.0)]),
+            region("inclusive", "down", &[("standard", 10.0), ("luxury", 20.0)]),
+            region("inclusive", "down", &[("standard", 120.0)]),
+            region("inclusive", "down", &[("reduced", 8.0)]),
+        ] {
+            let mut config = valid.clone();
+            config.tax.regions = HashMap::from([("JP".to_string(), invalid)]);
+            assert_eq!(invalid_key(config.validate()), "tax.regions");
+        }
+    }
+
+    /// PostgreSQLのURLは `postgres` featureを有効にしてビルドした場合のみ使えること
+    #[cfg(not(feature = "postgres"))]
+    #[test]
+    fn rejects_postgres_urls_without_the_feature() {
+        let mut config = load_file("postgres", "").unwrap();
+        config.database.url = "postgres://localhost/shop".to_string();
+
+        assert_eq!(invalid_key(config.validate()), "database.url");
+    }
+
+    /// `postgres` featureを有効にした場合は、PostgreSQLのURLとして解釈できない値だけを拒否すること
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn accepts_valid_postgres_urls_with_the_feature() {
+        let mut config = load_file("postgres", "").unwrap();
+        config.database.url = "postgres://localhost/shop".to_string();
+        config.validate().unwrap();
+
+        config.database.url = "postgres://localhost:port/shop".to_string();
+        assert_eq!(invalid_key(config.validate()), "database.url");
+    }
+}
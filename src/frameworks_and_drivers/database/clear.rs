@@ -1,15 +1,47 @@
 use anyhow::Result;
 
-use crate::frameworks_and_drivers::database::db::get_db;
-
-pub async fn clear_database() -> Result<()> {
-    let db = get_db().await?;
-    let pool = db.get_pool();
+use crate::frameworks_and_drivers::database::db::Database;
 
+/// 外部キーで参照される側のテーブルが後になる順序で全削除する（SQLite・PostgreSQL共通）
+const STATEMENTS: [&str; 15] = [
+    // Idempotency-Keyの記録を全削除
+    "DELETE FROM idempotency_keys",
+    // 在庫予約テーブルを全削除
+    "DELETE FROM reservation_items",
+    "DELETE FROM reservations",
+    // カートテーブルを全削除
+    "DELETE FROM cart_items",
+    "DELETE FROM carts",
+    // 注文テーブルを全削除
+    "DELETE FROM order_lines",
+    "DELETE FROM orders",
+    // プロモーションテーブルを全削除
+    "DELETE FROM promotions",
+    // カテゴリ・タグと商品との関連を全削除
+    "DELETE FROM product_tags",
+    "DELETE FROM tags",
+    "DELETE FROM product_categories",
+    // 子カテゴリから削除しないと外部キー制約に違反するため、親子関係を外してから削除する
+    "UPDATE categories SET parent_id = NULL",
+    "DELETE FROM categories",
+    // 商品のバリエーションを全削除
+    "DELETE FROM product_variants",
     // products テーブルを全削除
-    sqlx::query("DELETE FROM products")
-        .execute(pool)
-        .await?;
+    "DELETE FROM products",
+];
+
+pub async fn clear_database(db: &Database) -> Result<()> {
+    for statement in STATEMENTS {
+        match db {
+            Database::Sqlite(db) => {
+                sqlx::query(statement).execute(db.get_pool()).await?;
+            }
+            #[cfg(feature = "postgres")]
+            Database::Postgres(pool) => {
+                sqlx::query(statement).execute(pool).await?;
+            }
+        }
+    }
 
     println!("Database cleared successfully!");
     Ok(())
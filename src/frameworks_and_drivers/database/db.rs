@@ -1,38 +1,296 @@
-use anyhow::Result;
-use sqlx::{Pool, Sqlite, SqlitePool};
+use anyhow::{bail, Result};
+use sqlx::pool::PoolOptions;
+use sqlx::sqlite::{SqliteConnectOptions, SqliteJournalMode, SqliteSynchronous};
+use sqlx::{Pool, Row, Sqlite};
+use std::path::Path;
+use std::str::FromStr;
 use std::sync::Arc;
+use std::time::Duration;
+#[cfg(test)]
 use tokio::sync::OnceCell;
 
-pub struct Database {
-    pool: Pool<Sqlite>,
+use crate::frameworks_and_drivers::config::{self, DatabaseBackend, DatabaseConfig};
+#[cfg(test)]
+use crate::frameworks_and_drivers::config::RepositoryBackend;
+
+/// アプリ全体のデータを保存するデータベース（`database.url` のスキームで選ぶ）
+pub enum Database {
+    Sqlite(Arc<SqliteDatabase>),
+    #[cfg(feature = "postgres")]
+    Postgres(sqlx::PgPool),
 }
 
 impl Database {
-    pub async fn new(database_url: &str) -> Result<Self> {
-        let pool = SqlitePool::connect(database_url).await?;
-        Ok(Self { pool })
+    /// 接続URLのスキームに応じたデータベースに接続します
+    pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+        match config.backend() {
+            Some(DatabaseBackend::Sqlite) => Ok(Self::Sqlite(Arc::new(SqliteDatabase::new(config).await?))),
+            #[cfg(feature = "postgres")]
+            Some(DatabaseBackend::Postgres) => Ok(Self::Postgres(connect_postgres(config).await?)),
+            _ => bail!("unsupported database URL `{}`", config.url),
+        }
+    }
+}
+
+pub struct SqliteDatabase {
+    pool: Pool<Sqlite>,
+}
+
+/// 接続に実際に適用されたSQLiteの設定
+/// メモリ上のデータベースではWALを使えないなど、設定した値と異なる場合がある
+#[derive(Debug)]
+pub struct SqliteSettings {
+    pub journal_mode: String,
+    pub synchronous: String,
+    pub busy_timeout_ms: i64,
+    pub foreign_keys: bool,
+}
+
+impl SqliteDatabase {
+    /// 設定のプールサイズ・タイムアウト・PRAGMAでSQLiteに接続し、適用された設定をログに出力します
+    pub async fn new(config: &DatabaseConfig) -> Result<Self> {
+        let options = sqlite_connect_options(config)?;
+        let pool = pool_options(config).connect_with(options).await?;
+        let database = Self { pool };
+
+        let settings = database.sqlite_settings().await?;
+        tracing::info!(
+            url = %config.url,
+            max_connections = config.max_connections,
+            min_connections = config.min_connections,
+            acquire_timeout_seconds = config.acquire_timeout_seconds,
+            idle_timeout_seconds = config.idle_timeout_seconds,
+            journal_mode = %settings.journal_mode,
+            synchronous = %settings.synchronous,
+            busy_timeout_ms = settings.busy_timeout_ms,
+            foreign_keys = settings.foreign_keys,
+            "database connected"
+        );
+
+        Ok(database)
     }
 
     pub fn get_pool(&self) -> &Pool<Sqlite> {
         &self.pool
     }
+
+    /// プールの接続に適用されているPRAGMAの値を読み出します
+    pub async fn sqlite_settings(&self) -> Result<SqliteSettings> {
+        let mut conn = self.pool.acquire().await?;
+        let journal_mode: String = sqlx::query("PRAGMA journal_mode").fetch_one(&mut *conn).await?.get(0);
+        let synchronous: i64 = sqlx::query("PRAGMA synchronous").fetch_one(&mut *conn).await?.get(0);
+        let busy_timeout_ms: i64 = sqlx::query("PRAGMA busy_timeout").fetch_one(&mut *conn).await?.get(0);
+        let foreign_keys: i64 = sqlx::query("PRAGMA foreign_keys").fetch_one(&mut *conn).await?.get(0);
+
+        let synchronous = match synchronous {
+            0 => "off".to_string(),
+            1 => "normal".to_string(),
+            2 => "full".to_string(),
+            3 => "extra".to_string(),
+            other => other.to_string(),
+        };
+
+        Ok(SqliteSettings {
+            journal_mode: journal_mode.to_ascii_lowercase(),
+            synchronous,
+            busy_timeout_ms,
+            foreign_keys: foreign_keys == 1,
+        })
+    }
 }
 
-// スレッドセーフなシングルトンインスタンス
-static DB_INSTANCE: OnceCell<Arc<Database>> = OnceCell::const_new();
+/// 設定のプールサイズとタイムアウトを持つプールのオプション（SQLite・PostgreSQL共通）
+fn pool_options<DB: sqlx::Database>(config: &DatabaseConfig) -> PoolOptions<DB> {
+    let idle_timeout = match config.idle_timeout_seconds {
+        0 => None,
+        seconds => Some(Duration::from_secs(seconds)),
+    };
+
+    PoolOptions::new()
+        .max_connections(config.max_connections)
+        .min_connections(config.min_connections)
+        .acquire_timeout(Duration::from_secs(config.acquire_timeout_seconds))
+        .idle_timeout(idle_timeout)
+}
+
+/// 接続URLに設定のPRAGMAを加えたSQLiteの接続オプション
+/// `create_if_missing` の場合はデータベースファイルの親ディレクトリも作成する
+fn sqlite_connect_options(config: &DatabaseConfig) -> Result<SqliteConnectOptions> {
+    let sqlite = &config.sqlite;
+    let journal_mode = match sqlite.journal_mode {
+        config::SqliteJournalMode::Delete => SqliteJournalMode::Delete,
+        config::SqliteJournalMode::Truncate => SqliteJournalMode::Truncate,
+        config::SqliteJournalMode::Persist => SqliteJournalMode::Persist,
+        config::SqliteJournalMode::Memory => SqliteJournalMode::Memory,
+        config::SqliteJournalMode::Wal => SqliteJournalMode::Wal,
+        config::SqliteJournalMode::Off => SqliteJournalMode::Off,
+    };
+    let synchronous = match sqlite.synchronous {
+        config::SqliteSynchronous::Off => SqliteSynchronous::Off,
+        config::SqliteSynchronous::Normal => SqliteSynchronous::Normal,
+        config::SqliteSynchronous::Full => SqliteSynchronous::Full,
+        config::SqliteSynchronous::Extra => SqliteSynchronous::Extra,
+    };
+
+    let options = SqliteConnectOptions::from_str(&config.url)?
+        .journal_mode(journal_mode)
+        .synchronous(synchronous)
+        .busy_timeout(Duration::from_secs(sqlite.busy_timeout_seconds))
+        .foreign_keys(sqlite.foreign_keys)
+        .create_if_missing(sqlite.create_if_missing);
 
-pub async fn init_db(database_url: &str) -> Result<()> {
-    let db = Database::new(database_url).await?;
-    // すでに初期化されている場合はエラーを返す
-    match DB_INSTANCE.set(Arc::new(db)) {
-        Ok(_) => Ok(()),
-        Err(_) => Err(anyhow::anyhow!("Database already initialized"))
+    if sqlite.create_if_missing
+        && let Some(parent) = Path::new(options.get_filename()).parent()
+        && !parent.as_os_str().is_empty()
+    {
+        std::fs::create_dir_all(parent)?;
     }
+
+    Ok(options)
+}
+
+/// 設定のプールサイズとタイムアウトでPostgreSQLに接続し、接続先をログに出力します
+/// URLにはパスワードが含まれうるため、ログにはホストとデータベース名だけを出す
+#[cfg(feature = "postgres")]
+async fn connect_postgres(config: &DatabaseConfig) -> Result<sqlx::PgPool> {
+    let options = config.url.parse::<sqlx::postgres::PgConnectOptions>()?;
+    let pool = pool_options(config).connect_with(options.clone()).await?;
+
+    tracing::info!(
+        host = %options.get_host(),
+        database = options.get_database().unwrap_or_default(),
+        max_connections = config.max_connections,
+        min_connections = config.min_connections,
+        acquire_timeout_seconds = config.acquire_timeout_seconds,
+        idle_timeout_seconds = config.idle_timeout_seconds,
+        "database connected"
+    );
+
+    Ok(pool)
 }
 
-pub async fn get_db() -> Result<Arc<Database>> {
-    match DB_INSTANCE.get() {
-        Some(db) => Ok(db.clone()),
-        None => Err(anyhow::anyhow!("Database not initialized"))
+/// テスト用のデータベースを返します
+/// プロセスごとに一時ファイルのSQLiteを1つだけ作成し、マイグレーションを適用します
+#[cfg(test)]
+pub async fn test_db() -> Arc<SqliteDatabase> {
+    static TEST_DB: OnceCell<Arc<SqliteDatabase>> = OnceCell::const_new();
+
+    TEST_DB
+        .get_or_try_init(|| async {
+            let path = std::env::temp_dir()
+                .join(format!("axum-mini-template-test-{}.sqlite", std::process::id()));
+            let _ = std::fs::remove_file(&path);
+
+            let config = test_config(format!("sqlite://{}", path.display()));
+            crate::frameworks_and_drivers::database::migrations::run_migrations(&config).await?;
+            Ok::<_, anyhow::Error>(Arc::new(SqliteDatabase::new(&config).await?))
+        })
+        .await
+        .expect("test database can be created")
+        .clone()
+}
+
+/// テスト用のPostgreSQLに接続します
+/// 環境変数 `TEST_POSTGRES_URL` が設定されていない場合は `None` を返します
+/// プロセスごとに最初の1回だけテスト用のスキーマを作り直してマイグレーションを適用するため、使い捨てのデータベースを指定してください
+/// コネクションはテストごとのランタイムに紐づくため、プールは呼び出しごとに作成します
+#[cfg(all(test, feature = "postgres"))]
+pub async fn init_test_postgres() -> Result<Option<sqlx::PgPool>> {
+    use sqlx::postgres::{PgConnectOptions, PgPoolOptions};
+
+    const TEST_SCHEMA: &str = "axum_mini_template_test";
+    static TEST_SCHEMA_READY: OnceCell<()> = OnceCell::const_new();
+
+    let Ok(url) = std::env::var("TEST_POSTGRES_URL") else {
+        return Ok(None);
+    };
+    let options = url
+        .parse::<PgConnectOptions>()?
+        .options([("search_path", TEST_SCHEMA)]);
+    let pool = PgPoolOptions::new().max_connections(2).connect_with(options).await?;
+
+    TEST_SCHEMA_READY
+        .get_or_try_init(|| async {
+            sqlx::query(&format!("DROP SCHEMA IF EXISTS {TEST_SCHEMA} CASCADE"))
+                .execute(&pool)
+                .await?;
+            sqlx::query(&format!("CREATE SCHEMA {TEST_SCHEMA}"))
+                .execute(&pool)
+                .await?;
+            crate::frameworks_and_drivers::database::migrations::run_postgres_migrations(&pool).await
+        })
+        .await?;
+
+    Ok(Some(pool))
+}
+
+/// テスト用のデータベース設定（`config.toml` の既定値と同じ）
+#[cfg(test)]
+pub fn test_config(url: String) -> DatabaseConfig {
+    DatabaseConfig {
+        url,
+        max_connections: 10,
+        min_connections: 0,
+        acquire_timeout_seconds: 30,
+        idle_timeout_seconds: 600,
+        sqlite: config::SqliteConfig {
+            journal_mode: config::SqliteJournalMode::Wal,
+            synchronous: config::SqliteSynchronous::Normal,
+            busy_timeout_seconds: 5,
+            foreign_keys: true,
+            create_if_missing: true,
+        },
+        product_repository: RepositoryBackend::Database,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("axum-mini-template-db-{}-{}", std::process::id(), name))
     }
-}
\ No newline at end of file
+
+    /// 設定したPRAGMAが接続に適用されること
+    #[tokio::test]
+    async fn applies_configured_pragmas() {
+        let path = temp_path("pragmas.sqlite");
+        let _ = std::fs::remove_file(&path);
+        let mut config = test_config(format!("sqlite://{}", path.display()));
+        config.sqlite.synchronous = config::SqliteSynchronous::Full;
+        config.sqlite.busy_timeout_seconds = 2;
+
+        let settings = SqliteDatabase::new(&config).await.unwrap().sqlite_settings().await.unwrap();
+
+        assert_eq!(settings.journal_mode, "wal");
+        assert_eq!(settings.synchronous, "full");
+        assert_eq!(settings.busy_timeout_ms, 2000);
+        assert!(settings.foreign_keys);
+    }
+
+    /// データベースファイルと親ディレクトリがなければ作成すること
+    #[tokio::test]
+    async fn creates_missing_database_file_and_directories() {
+        let dir = temp_path("nested");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("data").join("db.sqlite");
+
+        SqliteDatabase::new(&test_config(format!("sqlite:{}", path.display()))).await.unwrap();
+
+        assert!(path.exists());
+    }
+
+    /// `create_if_missing` が無効な場合は存在しないファイルに接続できないこと
+    #[tokio::test]
+    async fn refuses_missing_database_file_unless_configured() {
+        let dir = temp_path("missing");
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("db.sqlite");
+        let mut config = test_config(format!("sqlite:{}", path.display()));
+        config.sqlite.create_if_missing = false;
+
+        assert!(SqliteDatabase::new(&config).await.is_err());
+        assert!(!dir.exists());
+    }
+}
@@ -1,29 +1,161 @@
-use anyhow::Result;
-use sqlx::Sqlite;
-
-use crate::frameworks_and_drivers::database::db::get_db;
-
-pub async fn run_migrations(database_url: &str) -> Result<()> {
-    let db = get_db().await?;
-    let pool = db.get_pool();
-    
-    // テーブル作成（簡単な例）
-    sqlx::query(
-        r#"
-        CREATE TABLE IF NOT EXISTS products (
-            id INTEGER PRIMARY KEY AUTOINCREMENT,
-            name TEXT NOT NULL,
-            price INTEGER NOT NULL,
-            description TEXT NOT NULL,
-            quantity INTEGER NOT NULL,
-            created_at TEXT NOT NULL,
-            updated_at TEXT NOT NULL
-        )
-        "#
-    )
-    .execute(pool)
-    .await?;
+use std::collections::HashSet;
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use chrono::Utc;
+use sqlx::migrate::{Migrate, Migrator};
+use sqlx::Pool;
+
+use crate::frameworks_and_drivers::config::{DatabaseBackend, DatabaseConfig};
+use crate::frameworks_and_drivers::database::db::Database;
+
+/// migrationsディレクトリのマイグレーションをコンパイル時に埋め込む
+/// ファイル名は `{バージョン}_{名前}.up.sql` / `{バージョン}_{名前}.down.sql`
+static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+/// PostgreSQLに接続する場合のマイグレーション（サブディレクトリは上のMIGRATORには含まれない）
+#[cfg(feature = "postgres")]
+static POSTGRES_MIGRATOR: Migrator = sqlx::migrate!("./migrations/postgres");
+
+/// 新しいマイグレーションファイルを作成するディレクトリ
+const MIGRATIONS_DIR: &str = "migrations";
+
+/// マイグレーションの適用状況
+pub struct MigrationStatus {
+    /// マイグレーションを適用するデータベース
+    pub backend: DatabaseBackend,
+    pub version: i64,
+    pub description: String,
+    pub applied: bool,
+}
+
+/// 未適用のマイグレーションをすべて適用します
+/// `database.url` のデータベースに合わせたマイグレーションを適用します
+pub async fn run_migrations(config: &DatabaseConfig) -> Result<()> {
+    match Database::new(config).await? {
+        Database::Sqlite(db) => MIGRATOR.run(db.get_pool()).await?,
+        #[cfg(feature = "postgres")]
+        Database::Postgres(pool) => POSTGRES_MIGRATOR.run(&pool).await?,
+    }
 
     println!("Migrations completed successfully!");
     Ok(())
-} 
\ No newline at end of file
+}
+
+/// 適用済みのマイグレーションを新しいものから `steps` 件取り消します
+pub async fn revert_migrations(config: &DatabaseConfig, steps: usize) -> Result<()> {
+    let reverted = match Database::new(config).await? {
+        Database::Sqlite(db) => revert(&MIGRATOR, db.get_pool(), steps).await?,
+        #[cfg(feature = "postgres")]
+        Database::Postgres(pool) => revert(&POSTGRES_MIGRATOR, &pool, steps).await?,
+    };
+
+    match reverted {
+        0 => println!("No migrations to revert"),
+        reverted => println!("Reverted {} migration(s)", reverted),
+    }
+    Ok(())
+}
+
+/// 埋め込まれたマイグレーションごとの適用状況を返します
+pub async fn migration_status(config: &DatabaseConfig) -> Result<Vec<MigrationStatus>> {
+    match Database::new(config).await? {
+        Database::Sqlite(db) => statuses_of(&MIGRATOR, db.get_pool(), DatabaseBackend::Sqlite).await,
+        #[cfg(feature = "postgres")]
+        Database::Postgres(pool) => statuses_of(&POSTGRES_MIGRATOR, &pool, DatabaseBackend::Postgres).await,
+    }
+}
+
+/// PostgreSQL向けのマイグレーションを適用します（テスト用）
+#[cfg(all(test, feature = "postgres"))]
+pub async fn run_postgres_migrations(pool: &sqlx::PgPool) -> Result<()> {
+    POSTGRES_MIGRATOR.run(pool).await?;
+    Ok(())
+}
+
+// 適用済みのマイグレーションのバージョン
+async fn applied_versions<DB>(pool: &Pool<DB>) -> Result<Vec<i64>>
+where
+    DB: sqlx::Database,
+    DB::Connection: Migrate,
+{
+    let mut conn = pool.acquire().await?;
+    conn.ensure_migrations_table().await?;
+    let applied = conn
+        .list_applied_migrations()
+        .await?
+        .into_iter()
+        .map(|m| m.version)
+        .collect();
+
+    Ok(applied)
+}
+
+// 取り消したマイグレーションの件数を返す
+async fn revert<DB>(migrator: &Migrator, pool: &Pool<DB>, steps: usize) -> Result<usize>
+where
+    DB: sqlx::Database,
+    DB::Connection: Migrate,
+{
+    let mut applied = applied_versions(pool).await?;
+    applied.sort_unstable_by(|a, b| b.cmp(a));
+    if applied.is_empty() {
+        return Ok(0);
+    }
+
+    // 取り消し後に最新となるバージョン（すべて取り消す場合は0）
+    let target = applied.get(steps).copied().unwrap_or(0);
+    migrator.undo(pool, target).await?;
+
+    Ok(steps.min(applied.len()))
+}
+
+async fn statuses_of<DB>(migrator: &Migrator, pool: &Pool<DB>, backend: DatabaseBackend) -> Result<Vec<MigrationStatus>>
+where
+    DB: sqlx::Database,
+    DB::Connection: Migrate,
+{
+    let applied: HashSet<i64> = applied_versions(pool).await?.into_iter().collect();
+
+    let statuses = migrator
+        .iter()
+        .filter(|m| !m.migration_type.is_down_migration())
+        .map(|m| MigrationStatus {
+            backend,
+            version: m.version,
+            description: m.description.to_string(),
+            applied: applied.contains(&m.version),
+        })
+        .collect();
+
+    Ok(statuses)
+}
+
+/// 空のup/downマイグレーションファイルを作成し、作成したファイルのパスを返します
+/// 作成したマイグレーションは再ビルド後に埋め込まれます
+pub fn create_migration(name: &str) -> Result<Vec<String>> {
+    let name: String = name
+        .trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_lowercase() } else { '_' })
+        .collect();
+    let name = name.trim_matches('_');
+    if name.is_empty() {
+        bail!("Migration name must contain at least one alphanumeric character");
+    }
+
+    let version = Utc::now().format("%Y%m%d%H%M%S");
+    let dir = Path::new(MIGRATIONS_DIR);
+    std::fs::create_dir_all(dir)?;
+
+    let mut created = Vec::new();
+    for direction in ["up", "down"] {
+        let path = dir.join(format!("{}_{}.{}.sql", version, name, direction));
+        if path.exists() {
+            bail!("Migration file already exists: {}", path.display());
+        }
+        std::fs::write(&path, format!("-- {} migration: {}\n", direction, name))?;
+        created.push(path.display().to_string());
+    }
+
+    Ok(created)
+}
@@ -1,34 +1,135 @@
 use anyhow::Result;
 use chrono::Utc;
 
-use crate::frameworks_and_drivers::database::db::get_db;
+use crate::application::repositories::ProductRepository;
+use crate::domain::models::{Currency, Money, Product, ProductVariant};
+use crate::frameworks_and_drivers::database::db::{Database, SqliteDatabase};
 
-pub async fn seed_database() -> Result<()> {
-    let db = get_db().await?;
+// サンプルデータ（価格は通貨の最小単位）
+const PRODUCTS: [(&str, i64, &str, &str, i64); 4] = [
+    ("Laptop", 99999, "JPY", "High-performance laptop", 10),
+    ("Mouse", 2999, "JPY", "Wireless optical mouse", 50),
+    ("Keyboard", 7999, "JPY", "Mechanical keyboard", 25),
+    ("USB-C Hub", 4999, "USD", "7-in-1 USB-C hub", 30),
+];
+
+// キーボードは配列・色違いのバリエーションを持つ（商品の在庫はバリエーションの在庫の合計）
+const KEYBOARD_VARIANTS: [(&str, &str, i64, i64); 3] = [
+    ("KB-US-BLK", r#"{"colour":"black","layout":"US"}"#, 7999, 10),
+    ("KB-US-WHT", r#"{"colour":"white","layout":"US"}"#, 8499, 5),
+    ("KB-JIS-BLK", r#"{"colour":"black","layout":"JIS"}"#, 7999, 10),
+];
+
+pub async fn seed_database(db: &Database) -> Result<()> {
+    match db {
+        Database::Sqlite(db) => seed_sqlite(db).await?,
+        #[cfg(feature = "postgres")]
+        Database::Postgres(pool) => seed_postgres(pool).await?,
+    }
+
+    println!("Database seeded successfully!");
+    Ok(())
+}
+
+/// サンプルの商品をリポジトリ経由で登録する
+/// 商品をメモリ上に保持する設定（`database.product_repository = "memory"`）ではデータベースへのseedが商品に反映されないため、こちらを使う
+pub async fn seed_products(repository: &dyn ProductRepository) -> Result<()> {
+    for (name, price, currency, description, quantity) in PRODUCTS {
+        let currency = Currency::parse(currency).expect("sample currencies are supported");
+        let mut product = Product::create(
+            name.to_string(),
+            Money::new(price as u64, currency),
+            description.to_string(),
+            quantity as u32,
+        )?;
+        if name == "Keyboard" {
+            for (sku, attributes, price, quantity) in KEYBOARD_VARIANTS {
+                let attributes = serde_json::from_str(attributes)?;
+                product.add_variant(ProductVariant::new(sku, attributes, Money::new(price as u64, currency), quantity as u32))?;
+            }
+        }
+        repository.save(product).await?;
+    }
+
+    Ok(())
+}
+
+async fn seed_sqlite(db: &SqliteDatabase) -> Result<()> {
     let pool = db.get_pool();
     let now = Utc::now().to_rfc3339();
 
-    // サンプルデータを挿入
-    let products = vec![
-        ("Laptop", 99999, "High-performance laptop", 10),
-        ("Mouse", 2999, "Wireless optical mouse", 50),
-        ("Keyboard", 7999, "Mechanical keyboard", 25),
-    ];
-
-    for (name, price, description, quantity) in products {
-        sqlx::query(
-            "INSERT INTO products (name, price, description, quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
+    let mut keyboard_id = 0;
+    for (name, price, currency, description, quantity) in PRODUCTS {
+        let result = sqlx::query(
+            "INSERT INTO products (name, price, currency, description, quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
         )
         .bind(name)
         .bind(price)
+        .bind(currency)
         .bind(description)
         .bind(quantity)
         .bind(&now)
         .bind(&now)
         .execute(pool)
         .await?;
+        if name == "Keyboard" {
+            keyboard_id = result.last_insert_rowid();
+        }
+    }
+
+    for (sku, attributes, price, quantity) in KEYBOARD_VARIANTS {
+        sqlx::query(
+            "INSERT INTO product_variants (product_id, sku, attributes, price, quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(keyboard_id)
+        .bind(sku)
+        .bind(attributes)
+        .bind(price)
+        .bind(quantity)
+        .bind(&now)
+        .bind(&now)
+        .execute(pool)
+        .await?;
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "postgres")]
+async fn seed_postgres(pool: &sqlx::PgPool) -> Result<()> {
+    let now = Utc::now();
+
+    let mut keyboard_id = 0;
+    for (name, price, currency, description, quantity) in PRODUCTS {
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO products (name, price, currency, description, quantity, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $6) RETURNING id"
+        )
+        .bind(name)
+        .bind(price)
+        .bind(currency)
+        .bind(description)
+        .bind(quantity)
+        .bind(now)
+        .fetch_one(pool)
+        .await?;
+        if name == "Keyboard" {
+            keyboard_id = id;
+        }
+    }
+
+    for (sku, attributes, price, quantity) in KEYBOARD_VARIANTS {
+        sqlx::query(
+            "INSERT INTO product_variants (product_id, sku, attributes, price, quantity, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $6)"
+        )
+        .bind(keyboard_id)
+        .bind(sku)
+        .bind(attributes)
+        .bind(price)
+        .bind(quantity)
+        .bind(now)
+        .execute(pool)
+        .await?;
     }
 
-    println!("Database seeded successfully!");
     Ok(())
 } 
\ No newline at end of file
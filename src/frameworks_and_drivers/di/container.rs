@@ -1,44 +1,334 @@
 use std::sync::Arc;
 
-use crate::frameworks_and_drivers::persistence::repositories_impl::SqliteProductRepository;
-use crate::application::repositories::ProductRepository;
+use chrono::Duration;
+
+use crate::domain::tax::TaxTable;
+use crate::frameworks_and_drivers::config::{Config, RepositoryBackend};
+use crate::frameworks_and_drivers::database::db::{Database, SqliteDatabase};
+use crate::frameworks_and_drivers::persistence::repositories_impl::{SqliteCartRepository, SqliteOrderRepository, SqliteProductRepository};
+use crate::frameworks_and_drivers::persistence::repositories_impl::{SqliteIdempotencyRepository, SqlitePromotionRepository, SqliteReservationRepository};
+use crate::frameworks_and_drivers::persistence::repositories_impl::{InMemoryStore, InMemoryProductRepository, SqliteCategoryRepository};
+use crate::frameworks_and_drivers::persistence::repositories_impl::{InMemoryCartRepository, InMemoryOrderRepository, InMemoryReservationRepository};
+use crate::frameworks_and_drivers::persistence::repositories_impl::InMemoryPromotionRepository;
+#[cfg(feature = "postgres")]
+use crate::frameworks_and_drivers::persistence::repositories_impl::{PostgresCartRepository, PostgresOrderRepository, PostgresProductRepository};
+#[cfg(feature = "postgres")]
+use crate::frameworks_and_drivers::persistence::repositories_impl::{PostgresIdempotencyRepository, PostgresPromotionRepository, PostgresReservationRepository};
+#[cfg(feature = "postgres")]
+use crate::frameworks_and_drivers::persistence::repositories_impl::PostgresCategoryRepository;
+use crate::application::repositories::{CartRepository, OrderRepository, ProductRepository, ReservationRepository};
+use crate::application::repositories::{CategoryRepository, IdempotencyRepository, PromotionRepository};
 use crate::application::use_cases::{GetProductUseCase, GetAllProductsUseCase, BuyProductUseCase};
+use crate::application::use_cases::{CreateProductUseCase, UpdateProductUseCase, DeleteProductUseCase};
+use crate::application::use_cases::{SearchProductsUseCase, GetOrderUseCase};
+use crate::application::use_cases::{CreateCartUseCase, GetCartUseCase, AddCartItemUseCase};
+use crate::application::use_cases::{UpdateCartItemUseCase, RemoveCartItemUseCase, CheckoutCartUseCase};
+use crate::application::use_cases::{CreateReservationUseCase, GetReservationUseCase, ConfirmReservationUseCase};
+use crate::application::use_cases::{ReleaseReservationUseCase, ExpireReservationsUseCase, IdempotencyUseCase};
+use crate::application::use_cases::{CreatePromotionUseCase, GetPromotionUseCase, GetAllPromotionsUseCase, DeletePromotionUseCase};
+use crate::application::use_cases::{CreateCategoryUseCase, GetCategoryUseCase, GetAllCategoriesUseCase};
+use crate::application::use_cases::{UpdateCategoryUseCase, DeleteCategoryUseCase};
+use crate::application::use_cases::{AddProductVariantUseCase, UpdateProductVariantUseCase, RemoveProductVariantUseCase};
 
 /// コンテナはアプリケーションの依存関係を管理します
 /// Uncle Bob's Clean Architecture: Frameworks & Drivers層でDI設定
 pub struct Container {
+    /// アプリケーション設定
+    pub config: Arc<Config>,
+    /// `database.url` のデータベース（リポジトリはこのコネクションプールを共有する）
+    pub database: Arc<Database>,
     /// ProductRepositoryの実装
     pub product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    /// OrderRepositoryの実装
+    pub order_repository: Arc<dyn OrderRepository + Send + Sync>,
+    /// CartRepositoryの実装
+    pub cart_repository: Arc<dyn CartRepository + Send + Sync>,
+    /// ReservationRepositoryの実装
+    pub reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+    /// IdempotencyRepositoryの実装
+    pub idempotency_repository: Arc<dyn IdempotencyRepository + Send + Sync>,
+    /// PromotionRepositoryの実装
+    pub promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    /// CategoryRepositoryの実装
+    pub category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+    /// 地域ごとの税制（設定 `tax` から組み立てる）
+    pub tax_table: Arc<TaxTable>,
 }
 
 impl Container {
     /// 新しいコンテナを作成します
-    pub fn new() -> Self {
-        // リポジトリの実装をインスタンス化
-        let product_repository = Arc::new(SqliteProductRepository::new());
+    pub fn new(config: Arc<Config>, database: Arc<Database>) -> Self {
+        // リポジトリの実装をインスタンス化（すべて `database.url` の同じデータベースに保存する）
+        let DatabaseRepositories {
+            mut product_repository,
+            mut order_repository,
+            mut cart_repository,
+            mut reservation_repository,
+            idempotency_repository,
+            mut promotion_repository,
+            category_repository,
+        } = match &*database {
+            Database::Sqlite(db) => DatabaseRepositories::sqlite(db),
+            #[cfg(feature = "postgres")]
+            Database::Postgres(pool) => DatabaseRepositories::postgres(pool),
+        };
+        // 商品と、在庫を変更する注文・カート・予約、注文で利用回数を数えるプロモーションは設定で切り替える
+        // メモリ上のリポジトリは1つのストアを共有し、カテゴリとIdempotency-Keyはデータベースに保存する
+        if config.database.product_repository == RepositoryBackend::Memory {
+            tracing::warn!("products, promotions, orders, carts and reservations are kept in memory and will be lost on restart");
+            let store = Arc::new(InMemoryStore::new());
+            product_repository = Arc::new(InMemoryProductRepository::new(store.clone(), category_repository.clone()));
+            order_repository = Arc::new(InMemoryOrderRepository::new(store.clone()));
+            cart_repository = Arc::new(InMemoryCartRepository::new(store.clone()));
+            reservation_repository = Arc::new(InMemoryReservationRepository::new(store.clone()));
+            promotion_repository = Arc::new(InMemoryPromotionRepository::new(store));
+        }
+        let tax_table = Arc::new(config.tax.table().expect("tax configuration is validated when it is loaded"));
         
         Self {
+            config,
+            database,
             product_repository,
+            order_repository,
+            cart_repository,
+            reservation_repository,
+            idempotency_repository,
+            promotion_repository,
+            category_repository,
+            tax_table,
         }
     }
-    
+
     /// GetProductUseCaseを作成します
     pub fn create_get_product_usecase(&self) -> GetProductUseCase {
-        GetProductUseCase::new(self.product_repository.clone())
+        GetProductUseCase::new(self.product_repository.clone(), self.promotion_repository.clone())
     }
     
     /// GetAllProductsUseCaseを作成します
     pub fn create_get_all_products_usecase(&self) -> GetAllProductsUseCase {
-        GetAllProductsUseCase::new(self.product_repository.clone())
+        GetAllProductsUseCase::new(self.product_repository.clone(), self.promotion_repository.clone())
     }
     
     /// BuyProductUseCaseを作成します
     pub fn create_buy_product_usecase(&self) -> BuyProductUseCase {
-        BuyProductUseCase::new(self.product_repository.clone())
+        BuyProductUseCase::new(
+            self.product_repository.clone(),
+            self.order_repository.clone(),
+            self.promotion_repository.clone(),
+            self.tax_table.clone(),
+        )
+    }
+    
+    /// CreateProductUseCaseを作成します
+    pub fn create_create_product_usecase(&self) -> CreateProductUseCase {
+        CreateProductUseCase::new(
+            self.product_repository.clone(),
+            self.promotion_repository.clone(),
+            self.category_repository.clone(),
+        )
+    }
+    
+    /// UpdateProductUseCaseを作成します
+    pub fn create_update_product_usecase(&self) -> UpdateProductUseCase {
+        UpdateProductUseCase::new(
+            self.product_repository.clone(),
+            self.promotion_repository.clone(),
+            self.category_repository.clone(),
+        )
+    }
+    
+    /// DeleteProductUseCaseを作成します
+    pub fn create_delete_product_usecase(&self) -> DeleteProductUseCase {
+        DeleteProductUseCase::new(self.product_repository.clone())
+    }
+    
+    /// AddProductVariantUseCaseを作成します
+    pub fn create_add_product_variant_usecase(&self) -> AddProductVariantUseCase {
+        AddProductVariantUseCase::new(self.product_repository.clone(), self.promotion_repository.clone())
+    }
+    
+    /// UpdateProductVariantUseCaseを作成します
+    pub fn create_update_product_variant_usecase(&self) -> UpdateProductVariantUseCase {
+        UpdateProductVariantUseCase::new(self.product_repository.clone(), self.promotion_repository.clone())
+    }
+    
+    /// RemoveProductVariantUseCaseを作成します
+    pub fn create_remove_product_variant_usecase(&self) -> RemoveProductVariantUseCase {
+        RemoveProductVariantUseCase::new(self.product_repository.clone())
+    }
+    
+    /// SearchProductsUseCaseを作成します
+    pub fn create_search_products_usecase(&self) -> SearchProductsUseCase {
+        SearchProductsUseCase::new(self.product_repository.clone(), self.promotion_repository.clone())
+    }
+    
+    /// GetOrderUseCaseを作成します
+    pub fn create_get_order_usecase(&self) -> GetOrderUseCase {
+        GetOrderUseCase::new(self.order_repository.clone())
+    }
+    
+    /// CreateCartUseCaseを作成します
+    pub fn create_create_cart_usecase(&self) -> CreateCartUseCase {
+        CreateCartUseCase::new(self.cart_repository.clone())
+    }
+    
+    /// GetCartUseCaseを作成します
+    pub fn create_get_cart_usecase(&self) -> GetCartUseCase {
+        GetCartUseCase::new(self.cart_repository.clone())
+    }
+    
+    /// AddCartItemUseCaseを作成します
+    pub fn create_add_cart_item_usecase(&self) -> AddCartItemUseCase {
+        AddCartItemUseCase::new(self.cart_repository.clone(), self.product_repository.clone())
+    }
+    
+    /// UpdateCartItemUseCaseを作成します
+    pub fn create_update_cart_item_usecase(&self) -> UpdateCartItemUseCase {
+        UpdateCartItemUseCase::new(self.cart_repository.clone())
+    }
+    
+    /// RemoveCartItemUseCaseを作成します
+    pub fn create_remove_cart_item_usecase(&self) -> RemoveCartItemUseCase {
+        RemoveCartItemUseCase::new(self.cart_repository.clone())
+    }
+    
+    /// CheckoutCartUseCaseを作成します
+    pub fn create_checkout_cart_usecase(&self) -> CheckoutCartUseCase {
+        CheckoutCartUseCase::new(
+            self.cart_repository.clone(),
+            self.product_repository.clone(),
+            self.promotion_repository.clone(),
+            self.tax_table.clone(),
+        )
+    }
+    
+    /// CreateReservationUseCaseを作成します
+    /// 予約の有効期間は設定（`reservations.ttl_seconds`）から決まります
+    pub fn create_create_reservation_usecase(&self) -> CreateReservationUseCase {
+        let ttl = Duration::seconds(self.config.reservations.ttl_seconds as i64);
+        CreateReservationUseCase::new(self.reservation_repository.clone(), self.product_repository.clone(), ttl)
+    }
+    
+    /// GetReservationUseCaseを作成します
+    pub fn create_get_reservation_usecase(&self) -> GetReservationUseCase {
+        GetReservationUseCase::new(self.reservation_repository.clone())
+    }
+    
+    /// ConfirmReservationUseCaseを作成します
+    pub fn create_confirm_reservation_usecase(&self) -> ConfirmReservationUseCase {
+        ConfirmReservationUseCase::new(
+            self.reservation_repository.clone(),
+            self.product_repository.clone(),
+            self.promotion_repository.clone(),
+            self.tax_table.clone(),
+        )
+    }
+    
+    /// ReleaseReservationUseCaseを作成します
+    pub fn create_release_reservation_usecase(&self) -> ReleaseReservationUseCase {
+        ReleaseReservationUseCase::new(self.reservation_repository.clone())
+    }
+    
+    /// ExpireReservationsUseCaseを作成します
+    pub fn create_expire_reservations_usecase(&self) -> ExpireReservationsUseCase {
+        ExpireReservationsUseCase::new(self.reservation_repository.clone())
+    }
+    
+    /// IdempotencyUseCaseを作成します
+    /// レスポンスの保持期間は設定（`idempotency.ttl_seconds`）から決まります
+    pub fn create_idempotency_usecase(&self) -> IdempotencyUseCase {
+        let ttl = Duration::seconds(self.config.idempotency.ttl_seconds as i64);
+        IdempotencyUseCase::new(self.idempotency_repository.clone(), ttl)
+    }
+    
+    /// CreatePromotionUseCaseを作成します
+    pub fn create_create_promotion_usecase(&self) -> CreatePromotionUseCase {
+        CreatePromotionUseCase::new(self.promotion_repository.clone(), self.product_repository.clone())
+    }
+    
+    /// GetPromotionUseCaseを作成します
+    pub fn create_get_promotion_usecase(&self) -> GetPromotionUseCase {
+        GetPromotionUseCase::new(self.promotion_repository.clone())
+    }
+    
+    /// GetAllPromotionsUseCaseを作成します
+    pub fn create_get_all_promotions_usecase(&self) -> GetAllPromotionsUseCase {
+        GetAllPromotionsUseCase::new(self.promotion_repository.clone())
+    }
+    
+    /// DeletePromotionUseCaseを作成します
+    pub fn create_delete_promotion_usecase(&self) -> DeletePromotionUseCase {
+        DeletePromotionUseCase::new(self.promotion_repository.clone())
+    }
+    
+    /// CreateCategoryUseCaseを作成します
+    pub fn create_create_category_usecase(&self) -> CreateCategoryUseCase {
+        CreateCategoryUseCase::new(self.category_repository.clone())
+    }
+    
+    /// GetCategoryUseCaseを作成します
+    pub fn create_get_category_usecase(&self) -> GetCategoryUseCase {
+        GetCategoryUseCase::new(self.category_repository.clone())
+    }
+    
+    /// GetAllCategoriesUseCaseを作成します
+    pub fn create_get_all_categories_usecase(&self) -> GetAllCategoriesUseCase {
+        GetAllCategoriesUseCase::new(self.category_repository.clone())
+    }
+    
+    /// UpdateCategoryUseCaseを作成します
+    pub fn create_update_category_usecase(&self) -> UpdateCategoryUseCase {
+        UpdateCategoryUseCase::new(self.category_repository.clone())
+    }
+    
+    /// DeleteCategoryUseCaseを作成します
+    pub fn create_delete_category_usecase(&self) -> DeleteCategoryUseCase {
+        DeleteCategoryUseCase::new(self.category_repository.clone())
+    }
+}
+
+/// 1つのデータベースに保存するリポジトリの実装
+/// 在庫を変更するリポジトリが商品と同じトランザクションで更新できるよう、すべて同じデータベースに揃える
+struct DatabaseRepositories {
+    product_repository: Arc<dyn ProductRepository + Send + Sync>,
+    order_repository: Arc<dyn OrderRepository + Send + Sync>,
+    cart_repository: Arc<dyn CartRepository + Send + Sync>,
+    reservation_repository: Arc<dyn ReservationRepository + Send + Sync>,
+    idempotency_repository: Arc<dyn IdempotencyRepository + Send + Sync>,
+    promotion_repository: Arc<dyn PromotionRepository + Send + Sync>,
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl DatabaseRepositories {
+    fn sqlite(db: &Arc<SqliteDatabase>) -> Self {
+        Self {
+            product_repository: Arc::new(SqliteProductRepository::new(db.clone())),
+            order_repository: Arc::new(SqliteOrderRepository::new(db.clone())),
+            cart_repository: Arc::new(SqliteCartRepository::new(db.clone())),
+            reservation_repository: Arc::new(SqliteReservationRepository::new(db.clone())),
+            idempotency_repository: Arc::new(SqliteIdempotencyRepository::new(db.clone())),
+            promotion_repository: Arc::new(SqlitePromotionRepository::new(db.clone())),
+            category_repository: Arc::new(SqliteCategoryRepository::new(db.clone())),
+        }
+    }
+
+    #[cfg(feature = "postgres")]
+    fn postgres(pool: &sqlx::PgPool) -> Self {
+        let category_repository: Arc<dyn CategoryRepository + Send + Sync> =
+            Arc::new(PostgresCategoryRepository::new(pool.clone()));
+        Self {
+            product_repository: Arc::new(PostgresProductRepository::new(pool.clone(), category_repository.clone())),
+            order_repository: Arc::new(PostgresOrderRepository::new(pool.clone())),
+            cart_repository: Arc::new(PostgresCartRepository::new(pool.clone())),
+            reservation_repository: Arc::new(PostgresReservationRepository::new(pool.clone())),
+            idempotency_repository: Arc::new(PostgresIdempotencyRepository::new(pool.clone())),
+            promotion_repository: Arc::new(PostgresPromotionRepository::new(pool.clone())),
+            category_repository,
+        }
     }
 }
 
-/// グローバルなコンテナインスタンスを取得します
-pub fn get_container() -> Container {
-    Container::new()
+/// 設定とデータベースからコンテナを作成します
+pub fn get_container(config: Arc<Config>, database: Arc<Database>) -> Container {
+    Container::new(config, database)
 }
\ No newline at end of file
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::MissedTickBehavior;
+
+use crate::frameworks_and_drivers::Container;
+
+/// 期限切れのIdempotency-Keyの記録を削除する間隔
+/// 期限切れのキーは再利用時に上書きされるため、ここでは容量の回収だけを目的に低頻度で実行する
+const CLEANUP_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// 期限切れのIdempotency-Keyの記録を定期的に削除するバックグラウンドタスクを起動します
+pub fn spawn_idempotency_cleanup(container: Arc<Container>) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(CLEANUP_INTERVAL);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match container.create_idempotency_usecase().purge_expired().await {
+                Ok(0) => {}
+                Ok(deleted) => tracing::info!(deleted, "purged expired idempotency keys"),
+                Err(err) => tracing::error!(error = %err, "failed to purge expired idempotency keys"),
+            }
+        }
+    });
+}
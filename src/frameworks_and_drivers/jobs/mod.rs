@@ -0,0 +1,5 @@
+mod reservation_expiry;
+mod idempotency_cleanup;
+
+pub use reservation_expiry::*;
+pub use idempotency_cleanup::*;
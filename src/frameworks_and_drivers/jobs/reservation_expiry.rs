@@ -0,0 +1,24 @@
+use std::sync::Arc;
+use std::time::Duration;
+
+use tokio::time::MissedTickBehavior;
+
+use crate::frameworks_and_drivers::Container;
+
+/// 期限切れの予約を一定間隔で失効させ、在庫を戻すバックグラウンドタスクを起動します
+/// 起動直後にも1回実行するため、停止中に期限切れとなった予約も再起動時に戻されます
+pub fn spawn_reservation_expiry(container: Arc<Container>, interval: Duration) {
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Delay);
+
+        loop {
+            ticker.tick().await;
+            match container.create_expire_reservations_usecase().expire_stale().await {
+                Ok(0) => {}
+                Ok(expired) => tracing::info!(expired, "expired stale reservations"),
+                Err(err) => tracing::error!(error = %err, "failed to expire stale reservations"),
+            }
+        }
+    });
+}
@@ -1,9 +1,12 @@
 /// Frameworks & Drivers Layer
 /// Uncle Bob's Clean Architecture 最外層
 /// Web frameworks, databases, external APIs, dependency injection など
+pub mod config;
 pub mod database;
 pub mod persistence;
 pub mod di;
+pub mod telemetry;
+pub mod jobs;
 
 // メインモジュールからのexport
 pub use di::{Container, get_container}; 
\ No newline at end of file
@@ -0,0 +1,15 @@
+#[allow(dead_code)]
+pub struct CartEntity {
+    pub id: u32,
+    pub status: String,
+    pub order_id: Option<u32>,
+    pub version: u32,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub struct CartItemEntity {
+    pub product_id: u32,
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
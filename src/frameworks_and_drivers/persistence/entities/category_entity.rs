@@ -0,0 +1,8 @@
+#[allow(dead_code)]
+pub struct CategoryEntity {
+    pub id: u32,
+    pub name: String,
+    pub parent_id: Option<u32>,
+    pub created_at: String,
+    pub updated_at: String,
+}
@@ -0,0 +1,12 @@
+#[allow(dead_code)]
+pub struct IdempotencyKeyEntity {
+    pub key: String,
+    pub fingerprint: String,
+    pub status: String,
+    pub response_status: Option<u16>,
+    pub response_headers: Option<String>,
+    pub response_body: Option<Vec<u8>>,
+    pub created_at: String,
+    pub updated_at: String,
+    pub expires_at: String,
+}
@@ -1,3 +1,15 @@
 mod product_entity;
+mod order_entity;
+mod cart_entity;
+mod reservation_entity;
+mod idempotency_key_entity;
+mod promotion_entity;
+mod category_entity;
 
-pub use self::product_entity::ProductEntity;
+pub use self::product_entity::{ProductEntity, ProductVariantEntity};
+pub use self::order_entity::{OrderEntity, OrderLineEntity};
+pub use self::cart_entity::{CartEntity, CartItemEntity};
+pub use self::reservation_entity::{ReservationEntity, ReservationItemEntity};
+pub use self::idempotency_key_entity::IdempotencyKeyEntity;
+pub use self::promotion_entity::PromotionEntity;
+pub use self::category_entity::CategoryEntity;
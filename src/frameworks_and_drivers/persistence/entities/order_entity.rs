@@ -0,0 +1,27 @@
+#[allow(dead_code)]
+pub struct OrderEntity {
+    pub id: u32,
+    pub status: String,
+    pub tax_region: Option<String>,
+    pub tax_mode: String,
+    pub subtotal: i64,
+    pub tax: i64,
+    pub total: i64,
+    pub currency: String,
+    pub created_at: String,
+}
+
+#[allow(dead_code)]
+pub struct OrderLineEntity {
+    pub order_id: u32,
+    pub product_id: u32,
+    pub product_name: String,
+    pub sku: Option<String>,
+    pub unit_price: i64,
+    pub quantity: u32,
+    pub discount: i64,
+    pub promotion_id: Option<u32>,
+    pub tax_category: String,
+    pub tax_rate: u32,
+    pub tax: i64,
+}
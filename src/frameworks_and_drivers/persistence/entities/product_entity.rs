@@ -2,9 +2,26 @@
 pub struct ProductEntity {
     pub id: u32,
     pub name: String,
-    pub price: u32,
+    /// 通貨の最小単位での価格
+    pub price: i64,
+    /// ISO 4217の通貨コード
+    pub currency: String,
+    /// 課税区分（standard / reduced / exempt）
+    pub tax_category: String,
     pub description: String,
     pub quantity: u32,
+    pub reserved: u32,
+    pub version: u32,
     pub created_at: String,
     pub updated_at: String,
-}
\ No newline at end of file
+}
+#[allow(dead_code)]
+pub struct ProductVariantEntity {
+    pub product_id: u32,
+    pub sku: String,
+    /// 属性名と値のJSONオブジェクト
+    pub attributes: String,
+    /// 商品と同じ通貨の最小単位での価格
+    pub price: i64,
+    pub quantity: u32,
+}
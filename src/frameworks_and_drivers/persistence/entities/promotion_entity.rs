@@ -0,0 +1,18 @@
+#[allow(dead_code)]
+pub struct PromotionEntity {
+    pub id: u32,
+    pub name: String,
+    pub kind: String,
+    pub percent: Option<u32>,
+    pub amount: Option<i64>,
+    pub currency: Option<String>,
+    pub buy_quantity: Option<u32>,
+    pub get_quantity: Option<u32>,
+    pub product_id: Option<u32>,
+    pub coupon_code: Option<String>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub usage_limit: Option<u32>,
+    pub usage_count: u32,
+    pub created_at: String,
+}
@@ -0,0 +1,15 @@
+#[allow(dead_code)]
+pub struct ReservationEntity {
+    pub id: u32,
+    pub status: String,
+    pub expires_at: String,
+    pub order_id: Option<u32>,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+pub struct ReservationItemEntity {
+    pub product_id: u32,
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
@@ -1,2 +1,5 @@
 pub mod entities;
 pub mod repositories_impl;
+mod timestamp;
+
+pub(crate) use timestamp::sortable_timestamp;
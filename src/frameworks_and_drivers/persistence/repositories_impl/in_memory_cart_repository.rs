@@ -0,0 +1,110 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::models::{Cart, CartStatus, Order};
+use crate::application::repositories::CartRepository;
+use crate::application::error::RepositoryError;
+use super::in_memory_store::InMemoryStore;
+
+/// プロセス内のメモリにカートを保持するリポジトリ（テスト・デモ用）
+/// チェックアウトは、メモリ上の商品と同じ `InMemoryStore` のロックの下で在庫の減算と注文の保存と一緒に行う
+pub struct InMemoryCartRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryCartRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    // 読み込み時のバージョンと一致し、注文確定前の場合のみ更新できる
+    fn is_current(stored: Option<&Cart>, cart: &Cart) -> bool {
+        stored.is_some_and(|stored| stored.version == cart.version && stored.status == CartStatus::Open)
+    }
+}
+
+#[async_trait::async_trait]
+impl CartRepository for InMemoryCartRepository {
+    #[tracing::instrument(name = "cart_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Cart>, RepositoryError> {
+        Ok(self.store.lock()?.carts.get(&id).cloned())
+    }
+
+    #[tracing::instrument(name = "cart_repository.save", skip(self, cart), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn save(&self, mut cart: Cart) -> Result<Cart, RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        if cart.id == 0 {
+            // 新規作成
+            state.last_cart_id += 1;
+            cart.id = state.last_cart_id;
+            cart.version = 0;
+        } else {
+            if !Self::is_current(state.carts.get(&cart.id), &cart) {
+                return Err(RepositoryError::Conflict);
+            }
+            cart.version += 1;
+        }
+        state.carts.insert(cart.id, cart.clone());
+
+        Ok(cart)
+    }
+
+    #[tracing::instrument(name = "cart_repository.check_out", skip(self, cart, order), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn check_out(&self, cart: &Cart, order: Order) -> Result<Order, RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        // カートの読み込み後に変更された場合や、同じカートが既にチェックアウトされた場合は競合
+        if !Self::is_current(state.carts.get(&cart.id), cart) {
+            return Err(RepositoryError::Conflict);
+        }
+        let order = state.place_order(order, &HashMap::new())?;
+
+        if let Some(stored) = state.carts.get_mut(&cart.id) {
+            stored.status = CartStatus::CheckedOut;
+            stored.order_id = Some(order.id);
+            stored.version += 1;
+        }
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repositories::{OrderRepository, ProductRepository};
+    use crate::domain::models::{Currency, Money, OrderLine, Product};
+    use crate::domain::tax::TaxRegion;
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{
+        InMemoryOrderRepository, InMemoryProductRepository, SqliteCategoryRepository,
+    };
+
+    /// チェックアウトで在庫が減ってカートが注文に紐づき、古いカートや2回目のチェックアウトは拒否されること
+    #[tokio::test]
+    async fn check_out_places_order_once() {
+        let store = Arc::new(InMemoryStore::new());
+        let products = InMemoryProductRepository::new(store.clone(), Arc::new(SqliteCategoryRepository::new(test_db().await)));
+        let orders = InMemoryOrderRepository::new(store.clone());
+        let repository = InMemoryCartRepository::new(store);
+        let product = Product::create("cart item".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 5).unwrap();
+        let product = products.save(product).await.unwrap();
+
+        let mut cart = repository.save(Cart::create()).await.unwrap();
+        cart.add_item(product.id, None, 2).unwrap();
+        let cart = repository.save(cart).await.unwrap();
+        let mut stale = repository.find_by_id(cart.id).await.unwrap().unwrap();
+        stale.version -= 1;
+        assert!(matches!(repository.save(stale).await, Err(RepositoryError::Conflict)));
+
+        let order = || Order::place(vec![OrderLine::new(product.id, product.name.clone(), product.price, 2)], &TaxRegion::untaxed()).unwrap();
+        let placed = repository.check_out(&cart, order()).await.unwrap();
+        assert!(matches!(repository.check_out(&cart, order()).await, Err(RepositoryError::Conflict)));
+
+        let checked_out = repository.find_by_id(cart.id).await.unwrap().unwrap();
+        assert_eq!(checked_out.status, CartStatus::CheckedOut);
+        assert_eq!(checked_out.order_id, Some(placed.id));
+        assert!(orders.find_by_id(placed.id).await.unwrap().is_some());
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 3);
+    }
+}
@@ -0,0 +1,115 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use crate::domain::models::Order;
+use crate::application::repositories::OrderRepository;
+use crate::application::error::RepositoryError;
+use super::in_memory_store::InMemoryStore;
+
+/// プロセス内のメモリに注文を保持するリポジトリ（テスト・デモ用）
+/// 在庫の減算と注文の保存は、メモリ上の商品と同じ `InMemoryStore` のロックの下で行う
+pub struct InMemoryOrderRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryOrderRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderRepository for InMemoryOrderRepository {
+    #[tracing::instrument(name = "order_repository.place", skip(self, order), fields(lines = order.lines.len()), err(level = "warn"))]
+    async fn place(&self, order: Order, expected_versions: &HashMap<u32, u32>) -> Result<Order, RepositoryError> {
+        self.store.lock()?.place_order(order, expected_versions)
+    }
+
+    #[tracing::instrument(name = "order_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Order>, RepositoryError> {
+        Ok(self.store.lock()?.orders.get(&id).cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::*;
+    use crate::application::repositories::{ProductRepository, PromotionRepository};
+    use crate::domain::models::{Currency, DiscountRule, Money, OrderLine, Product, ProductVariant, Promotion};
+    use crate::domain::tax::TaxRegion;
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{
+        InMemoryProductRepository, InMemoryPromotionRepository, SqliteCategoryRepository,
+    };
+
+    async fn repositories() -> (InMemoryProductRepository, InMemoryPromotionRepository, InMemoryOrderRepository) {
+        let store = Arc::new(InMemoryStore::new());
+        let categories = Arc::new(SqliteCategoryRepository::new(test_db().await));
+        (
+            InMemoryProductRepository::new(store.clone(), categories),
+            InMemoryPromotionRepository::new(store.clone()),
+            InMemoryOrderRepository::new(store),
+        )
+    }
+
+    fn line(product: &Product, quantity: u32) -> OrderLine {
+        OrderLine::new(product.id, product.name.clone(), product.price, quantity)
+    }
+
+    /// 注文が保存され、商品とバリエーションの在庫が減ること
+    #[tokio::test]
+    async fn place_decrements_product_and_variant_stock() {
+        let (products, _, repository) = repositories().await;
+        let mut keyboard = Product::create("keyboard".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 0).unwrap();
+        keyboard.add_variant(ProductVariant::new("kb-us", BTreeMap::from([("layout".to_string(), "US".to_string())]), Money::new(100, Currency::Jpy), 3)).unwrap();
+        let keyboard = products.save(keyboard).await.unwrap();
+
+        let order_line = line(&keyboard, 2).with_sku(Some("KB-US".to_string()));
+        let order = Order::place(vec![order_line], &TaxRegion::untaxed()).unwrap();
+        let placed = repository.place(order, &HashMap::from([(keyboard.id, keyboard.version)])).await.unwrap();
+
+        assert_eq!(repository.find_by_id(placed.id).await.unwrap().unwrap().lines, placed.lines);
+        let keyboard = products.find_by_id(keyboard.id).await.unwrap().unwrap();
+        assert_eq!((keyboard.quantity, keyboard.version), (1, 1));
+        assert_eq!(keyboard.variant("KB-US").unwrap().quantity, 1);
+    }
+
+    /// 1明細でも在庫が足りない・バージョンが一致しない場合は、どの在庫も減らず注文も保存されないこと
+    #[tokio::test]
+    async fn place_changes_nothing_on_conflict() {
+        let (products, _, repository) = repositories().await;
+        let enough = products.save(Product::create("enough".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 5).unwrap()).await.unwrap();
+        let short = products.save(Product::create("short".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 1).unwrap()).await.unwrap();
+
+        let order = Order::place(vec![line(&enough, 2), line(&short, 2)], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(order, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+        let order = Order::place(vec![line(&enough, 2)], &TaxRegion::untaxed()).unwrap();
+        let stale = HashMap::from([(enough.id, enough.version + 1)]);
+        assert!(matches!(repository.place(order, &stale).await, Err(RepositoryError::Conflict)));
+
+        assert_eq!(products.find_by_id(enough.id).await.unwrap().unwrap().quantity, 5);
+        assert_eq!(products.find_by_id(short.id).await.unwrap().unwrap().quantity, 1);
+        assert!(repository.find_by_id(1).await.unwrap().is_none());
+    }
+
+    /// 利用回数の上限に達したプロモーションを使う注文は保存されないこと
+    #[tokio::test]
+    async fn place_enforces_promotion_usage_limit() {
+        let (products, promotions, repository) = repositories().await;
+        let product = products.save(Product::create("limited".to_string(), Money::new(1000, Currency::Jpy), "test product".to_string(), 5).unwrap()).await.unwrap();
+        let rule = DiscountRule::AmountOff { amount: Money::new(100, Currency::Jpy) };
+        let promotion = Promotion::create("once".to_string(), rule, Some(product.id), None, None, None, Some(1)).unwrap();
+        let promotion = promotions.save(promotion).await.unwrap();
+
+        let discounted = || line(&product, 1).with_discount(Money::new(100, Currency::Jpy), Some(promotion.id));
+        let order = Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap();
+        repository.place(order, &HashMap::new()).await.unwrap();
+        let order = Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(order, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+
+        assert_eq!(promotions.find_by_id(promotion.id).await.unwrap().unwrap().usage_count, 1);
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 4);
+    }
+}
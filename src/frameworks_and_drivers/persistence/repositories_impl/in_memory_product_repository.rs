@@ -0,0 +1,428 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use chrono::Utc;
+
+use crate::domain::models::{Product, ProductVariant};
+use crate::application::repositories::{
+    CategoryRepository, Page, ProductFilter, ProductListCriteria, ProductRepository, ProductSearchHit,
+    ProductSortKey, SortOrder,
+};
+use crate::application::error::RepositoryError;
+use super::in_memory_store::{InMemoryStore, State, StoredProduct};
+use super::search_highlight::escape_html;
+
+/// 検索結果の抜粋に含める語数
+const SNIPPET_TOKENS: usize = 16;
+
+/// プロセス内のメモリに商品を保持するリポジトリ（テスト・デモ用）
+/// 商品は `InMemoryStore` に保持し、在庫は同じストアの注文・カート・予約のリポジトリが変更する
+/// カテゴリは保持せず、読み込み時に `CategoryRepository` から解決する（削除されたカテゴリとの関連は外れる）
+pub struct InMemoryProductRepository {
+    store: Arc<InMemoryStore>,
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl InMemoryProductRepository {
+    pub fn new(store: Arc<InMemoryStore>, category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self { store, category_repository }
+    }
+
+    // 保存された商品からドメインモデルへのマッピング
+    // カテゴリはまとめて読み込み、存在しないカテゴリとの関連は除く
+    async fn to_domain(&self, stored: Vec<StoredProduct>) -> Result<Vec<Product>, RepositoryError> {
+        let mut category_ids: Vec<u32> = stored.iter().flat_map(|product| product.category_ids.clone()).collect();
+        category_ids.sort_unstable();
+        category_ids.dedup();
+        let categories = self.category_repository.find_by_ids(&category_ids).await?;
+
+        Ok(stored
+            .into_iter()
+            .map(|stored| {
+                let mut product = Product::new(
+                    stored.id,
+                    stored.name,
+                    stored.price,
+                    stored.description,
+                    stored.quantity,
+                    stored.reserved,
+                    stored.version,
+                )
+                .with_tax_category(stored.tax_category);
+                product.categories = categories
+                    .iter()
+                    .filter(|category| stored.category_ids.contains(&category.id))
+                    .cloned()
+                    .collect();
+                product.tags = stored.tags;
+                product.variants = stored.variants.into_iter().map(|(_, variant)| variant).collect();
+                product
+            })
+            .collect())
+    }
+
+    // 指定したカテゴリとその子孫のカテゴリのID
+    async fn category_subtree(&self, category_id: u32) -> Result<HashSet<u32>, RepositoryError> {
+        let categories = self.category_repository.find_all().await?;
+        let mut subtree = HashSet::from([category_id]);
+        let mut pending = vec![category_id];
+        while let Some(parent_id) = pending.pop() {
+            for category in categories.iter().filter(|category| category.parent_id == Some(parent_id)) {
+                if subtree.insert(category.id) {
+                    pending.push(category.id);
+                }
+            }
+        }
+        Ok(subtree)
+    }
+
+    fn matches(product: &StoredProduct, filter: &ProductFilter, subtree: Option<&HashSet<u32>>) -> bool {
+        filter.currency.is_none_or(|currency| product.price.currency() == currency)
+            && filter.min_price.is_none_or(|min_price| product.price.amount() >= u64::from(min_price))
+            && filter.max_price.is_none_or(|max_price| product.price.amount() <= u64::from(max_price))
+            && (!filter.in_stock_only || product.quantity > 0)
+            && subtree.is_none_or(|subtree| product.category_ids.iter().any(|id| subtree.contains(id)))
+            && filter.tag.as_ref().is_none_or(|tag| product.tags.contains(tag))
+    }
+
+    // 並び替えキーが同じ値の場合はidを第2キーにする（SQLite実装と同じ順序）
+    // 価格順は通貨コードの昇順でまとめ、その中で価格を比較する
+    fn sort(products: &mut [StoredProduct], key: ProductSortKey, order: SortOrder) {
+        products.sort_by(|a, b| {
+            let currency = match key {
+                ProductSortKey::Price => a.price.currency().code().cmp(b.price.currency().code()),
+                _ => std::cmp::Ordering::Equal,
+            };
+            let ordering = match key {
+                ProductSortKey::Id => a.id.cmp(&b.id),
+                ProductSortKey::Name => a.name.cmp(&b.name).then(a.id.cmp(&b.id)),
+                ProductSortKey::Price => a.price.amount().cmp(&b.price.amount()).then(a.id.cmp(&b.id)),
+                ProductSortKey::CreatedAt => a.created_at.cmp(&b.created_at).then(a.id.cmp(&b.id)),
+            };
+            currency.then(match order {
+                SortOrder::Asc => ordering,
+                SortOrder::Desc => ordering.reverse(),
+            })
+        });
+    }
+}
+
+/// 全文検索の対象となる語（英数字の連続）とその位置
+/// SQLiteのFTS5（unicode61トークナイザ）と同じく大文字・小文字を区別しない
+struct Token {
+    start: usize,
+    end: usize,
+    text: String,
+}
+
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (index, c) in text.char_indices().chain(std::iter::once((text.len(), ' '))) {
+        match (start, c.is_alphanumeric()) {
+            (None, true) => start = Some(index),
+            (Some(begin), false) => {
+                tokens.push(Token { start: begin, end: index, text: text[begin..index].to_lowercase() });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// 一致した語の範囲（`tokens[start..end]`）
+type Matches = Vec<(usize, usize)>;
+
+/// キーワードの各語をフレーズとして前方一致させた場合に、商品名・説明で一致する語の範囲
+/// すべての語がどちらかの列に一致しない場合は `None`
+fn match_phrases(phrases: &[Vec<String>], name: &[Token], description: &[Token]) -> Option<(Matches, Matches)> {
+    let find = |tokens: &[Token], phrase: &[String]| -> Matches {
+        if tokens.len() < phrase.len() {
+            return Vec::new();
+        }
+        (0..=tokens.len() - phrase.len())
+            .filter(|&start| {
+                phrase.iter().enumerate().all(|(offset, term)| {
+                    let token = &tokens[start + offset].text;
+                    if offset + 1 == phrase.len() { token.starts_with(term.as_str()) } else { token == term }
+                })
+            })
+            .map(|start| (start, start + phrase.len()))
+            .collect()
+    };
+
+    let mut name_matches = Vec::new();
+    let mut description_matches = Vec::new();
+    for phrase in phrases {
+        let in_name = find(name, phrase);
+        let in_description = find(description, phrase);
+        if in_name.is_empty() && in_description.is_empty() {
+            return None;
+        }
+        name_matches.extend(in_name);
+        description_matches.extend(in_description);
+    }
+    Some((name_matches, description_matches))
+}
+
+/// `tokens[from..to]` の範囲の本文をHTMLエスケープし、一致した語を `<mark>` で囲んで返す
+fn highlight(text: &str, tokens: &[Token], matches: &[(usize, usize)], from: usize, to: usize) -> String {
+    let mut marked = vec![false; tokens.len()];
+    for &(start, end) in matches {
+        marked[start..end].iter_mut().for_each(|flag| *flag = true);
+    }
+
+    let begin = if from == 0 { 0 } else { tokens[from].start };
+    let finish = if to == tokens.len() { text.len() } else { tokens[to - 1].end };
+    let mut result = String::new();
+    let mut cursor = begin;
+    let mut index = from;
+    while index < to {
+        if !marked[index] {
+            index += 1;
+            continue;
+        }
+        let mut last = index;
+        while last + 1 < to && marked[last + 1] {
+            last += 1;
+        }
+        result.push_str(&escape_html(&text[cursor..tokens[index].start]));
+        result.push_str("<mark>");
+        result.push_str(&escape_html(&text[tokens[index].start..tokens[last].end]));
+        result.push_str("</mark>");
+        cursor = tokens[last].end;
+        index = last + 1;
+    }
+    result.push_str(&escape_html(&text[cursor..finish]));
+    result
+}
+
+/// 最初に一致した語から始まる抜粋（前後を省略した場合は `…` を付ける）
+fn snippet(text: &str, tokens: &[Token], matches: &[(usize, usize)]) -> String {
+    if tokens.len() <= SNIPPET_TOKENS {
+        return highlight(text, tokens, matches, 0, tokens.len());
+    }
+    let first = matches.iter().map(|&(start, _)| start).min().unwrap_or(0);
+    let from = first.min(tokens.len() - SNIPPET_TOKENS);
+    let to = from + SNIPPET_TOKENS;
+
+    let mut result = String::new();
+    if from > 0 {
+        result.push('…');
+    }
+    result.push_str(&highlight(text, tokens, matches, from, to));
+    if to < tokens.len() {
+        result.push('…');
+    }
+    result
+}
+
+#[async_trait::async_trait]
+impl ProductRepository for InMemoryProductRepository {
+    #[tracing::instrument(name = "product_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self, criteria: &ProductListCriteria) -> Result<Page<Product>, RepositoryError> {
+        let subtree = match criteria.filter.category_id {
+            Some(category_id) => Some(self.category_subtree(category_id).await?),
+            None => None,
+        };
+
+        let mut matched: Vec<StoredProduct> = self.store.lock()?
+            .products
+            .values()
+            .filter(|product| Self::matches(product, &criteria.filter, subtree.as_ref()))
+            .cloned()
+            .collect();
+        Self::sort(&mut matched, criteria.sort_key, criteria.sort_order);
+
+        let total = matched.len() as u64;
+        let page: Vec<StoredProduct> = matched
+            .into_iter()
+            .skip(usize::try_from(criteria.pagination.offset()).unwrap_or(usize::MAX))
+            .take(criteria.pagination.limit() as usize)
+            .collect();
+
+        Ok(Page {
+            items: self.to_domain(page).await?,
+            total,
+            pagination: criteria.pagination,
+        })
+    }
+
+    /// 関連度はbm25ではなく、一致した語の数（商品名は2倍に数える）で近似する
+    #[tracing::instrument(name = "product_repository.search", skip(self), err(level = "warn"))]
+    async fn search(&self, keyword: &str, limit: u32) -> Result<Vec<ProductSearchHit>, RepositoryError> {
+        let phrases: Vec<Vec<String>> = keyword
+            .split_whitespace()
+            .map(|term| tokenize(term).into_iter().map(|token| token.text).collect::<Vec<_>>())
+            .filter(|phrase| !phrase.is_empty())
+            .collect();
+        if phrases.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut hits: Vec<(StoredProduct, f64, String, String)> = self.store.lock()?
+            .products
+            .values()
+            .filter_map(|product| {
+                let name = tokenize(&product.name);
+                let description = tokenize(&product.description);
+                let (name_matches, description_matches) = match_phrases(&phrases, &name, &description)?;
+                let score = (name_matches.len() * 2 + description_matches.len()) as f64;
+
+                Some((
+                    product.clone(),
+                    score,
+                    highlight(&product.name, &name, &name_matches, 0, name.len()),
+                    snippet(&product.description, &description, &description_matches),
+                ))
+            })
+            .collect();
+        hits.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.id.cmp(&b.0.id)));
+        hits.truncate(limit as usize);
+
+        let (stored, details): (Vec<StoredProduct>, Vec<(f64, String, String)>) = hits
+            .into_iter()
+            .map(|(product, score, highlighted_name, snippet)| (product, (score, highlighted_name, snippet)))
+            .unzip();
+        let products = self.to_domain(stored).await?;
+
+        Ok(products
+            .into_iter()
+            .zip(details)
+            .map(|(product, (score, highlighted_name, snippet))| ProductSearchHit {
+                product,
+                score,
+                highlighted_name,
+                snippet,
+            })
+            .collect())
+    }
+
+    #[tracing::instrument(name = "product_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Product>, RepositoryError> {
+        let stored = self.store.lock()?.products.get(&id).cloned();
+
+        Ok(self.to_domain(stored.into_iter().collect()).await?.pop())
+    }
+
+    #[tracing::instrument(name = "product_repository.find_by_sku", skip(self), err(level = "warn"))]
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<Product>, RepositoryError> {
+        let sku = ProductVariant::normalize_sku(sku);
+        let stored = self.store.lock()?
+            .products
+            .values()
+            .find(|product| product.variants.iter().any(|(_, variant)| variant.sku == sku))
+            .cloned();
+
+        Ok(self.to_domain(stored.into_iter().collect()).await?.pop())
+    }
+
+    #[tracing::instrument(name = "product_repository.save", skip(self, product), fields(product_id = product.id), err(level = "warn"))]
+    async fn save(&self, mut product: Product) -> Result<Product, RepositoryError> {
+        let mut state = self.store.lock()?;
+        let State { last_product_id, last_variant_seq, products, .. } = &mut *state;
+
+        // IDが0の場合だけ新規作成し、更新は読み込み時のバージョンと一致する場合のみ行う
+        // （削除された商品を別のIDで作り直さない）
+        let existing = products.get(&product.id);
+        if product.id != 0 {
+            match existing {
+                None => return Err(RepositoryError::NotFound),
+                Some(stored) if stored.version != product.version => return Err(RepositoryError::Conflict),
+                Some(_) => {}
+            }
+        }
+
+        // 他の商品が同じSKUを使っている場合は何も変更しない
+        let sku_taken = product.variants.iter().any(|variant| {
+            products.values().any(|other| {
+                other.id != product.id && other.variants.iter().any(|(_, taken)| taken.sku == variant.sku)
+            })
+        });
+        if sku_taken {
+            return Err(RepositoryError::Conflict);
+        }
+
+        // 関連付けは読み込み時の並び順（カテゴリはID順、タグは名前順）で保持する
+        let mut category_ids: Vec<u32> = product.categories.iter().map(|category| category.id).collect();
+        category_ids.sort_unstable();
+        category_ids.dedup();
+        let mut tags = product.tags.clone();
+        tags.sort();
+        tags.dedup();
+
+        // 残すバリエーションは連番を引き継ぎ、追加した順に並ぶようにする
+        let existing_variants = existing.map(|stored| stored.variants.as_slice()).unwrap_or_default();
+        let mut variants: Vec<(u64, ProductVariant)> = product.variants
+            .iter()
+            .map(|variant| {
+                let seq = existing_variants
+                    .iter()
+                    .find(|(_, existing)| existing.sku == variant.sku)
+                    .map(|(seq, _)| *seq)
+                    .unwrap_or_else(|| {
+                        *last_variant_seq += 1;
+                        *last_variant_seq
+                    });
+                (seq, variant.clone())
+            })
+            .collect();
+        variants.sort_by_key(|(seq, _)| *seq);
+
+        match products.get_mut(&product.id) {
+            Some(stored) => {
+                stored.name = product.name.clone();
+                stored.price = product.price;
+                stored.tax_category = product.tax_category;
+                stored.description = product.description.clone();
+                stored.quantity = product.quantity;
+                stored.version += 1;
+                stored.category_ids = category_ids;
+                stored.tags = tags;
+                stored.variants = variants;
+                product.version += 1;
+            }
+            // 新規作成（予約済みの在庫は予約でのみ増える）
+            None => {
+                *last_product_id += 1;
+                product.id = *last_product_id;
+                product.version = 0;
+                products.insert(product.id, StoredProduct {
+                    id: product.id,
+                    name: product.name.clone(),
+                    price: product.price,
+                    tax_category: product.tax_category,
+                    description: product.description.clone(),
+                    quantity: product.quantity,
+                    reserved: 0,
+                    version: 0,
+                    category_ids,
+                    tags,
+                    variants,
+                    created_at: Utc::now(),
+                });
+            }
+        }
+
+        Ok(product)
+    }
+
+    #[tracing::instrument(name = "product_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32, expected_version: Option<u32>) -> Result<(), RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        match state.products.get(&id) {
+            None => Err(RepositoryError::NotFound),
+            Some(product) if expected_version.is_some_and(|version| version != product.version) => {
+                Err(RepositoryError::Conflict)
+            }
+            Some(_) => {
+                state.products.remove(&id);
+                // 商品を対象とするプロモーションも削除する
+                state.promotions.retain(|_, promotion| promotion.product_id != Some(id));
+                Ok(())
+            }
+        }
+    }
+}
@@ -0,0 +1,99 @@
+use std::sync::Arc;
+
+use crate::domain::models::Promotion;
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::RepositoryError;
+use super::in_memory_store::InMemoryStore;
+
+/// プロセス内のメモリにプロモーションを保持するリポジトリ（テスト・デモ用）
+/// 利用回数はメモリ上の注文と同じ `InMemoryStore` で数える
+pub struct InMemoryPromotionRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryPromotionRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+}
+
+#[async_trait::async_trait]
+impl PromotionRepository for InMemoryPromotionRepository {
+    #[tracing::instrument(name = "promotion_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self) -> Result<Vec<Promotion>, RepositoryError> {
+        Ok(self.store.lock()?.promotions.values().cloned().collect())
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Promotion>, RepositoryError> {
+        Ok(self.store.lock()?.promotions.get(&id).cloned())
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_candidates", skip(self), err(level = "warn"))]
+    async fn find_candidates(&self, product_ids: &[u32], coupon_code: Option<&str>) -> Result<Vec<Promotion>, RepositoryError> {
+        let coupon_code = coupon_code.map(Promotion::normalize_code);
+
+        Ok(self.store.lock()?
+            .promotions
+            .values()
+            .filter(|promotion| promotion.product_id.is_none_or(|product_id| product_ids.contains(&product_id)))
+            .filter(|promotion| promotion.coupon_code.is_none() || promotion.coupon_code == coupon_code)
+            .cloned()
+            .collect())
+    }
+
+    #[tracing::instrument(name = "promotion_repository.save", skip(self, promotion), err(level = "warn"))]
+    async fn save(&self, mut promotion: Promotion) -> Result<Promotion, RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        // クーポンコードは全プロモーションで一意
+        let code_taken = promotion.coupon_code.is_some()
+            && state.promotions.values().any(|other| other.coupon_code == promotion.coupon_code);
+        if code_taken {
+            return Err(RepositoryError::Conflict);
+        }
+
+        state.last_promotion_id += 1;
+        promotion.id = state.last_promotion_id;
+        state.promotions.insert(promotion.id, promotion.clone());
+        Ok(promotion)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError> {
+        match self.store.lock()?.promotions.remove(&id) {
+            Some(_) => Ok(()),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::domain::models::{Currency, DiscountRule, Money};
+
+    fn promotion(product_id: Option<u32>, coupon_code: Option<&str>) -> Promotion {
+        let rule = DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) };
+        Promotion::create("test".to_string(), rule, product_id, coupon_code.map(str::to_string), None, None, None)
+            .unwrap()
+    }
+
+    /// クーポン付きの候補はコードが一致した場合だけ返り、同じコードは保存できないこと
+    #[tokio::test]
+    async fn save_and_find_candidates() {
+        let repository = InMemoryPromotionRepository::new(Arc::new(InMemoryStore::new()));
+        let automatic = repository.save(promotion(None, None)).await.unwrap();
+        let coupon = repository.save(promotion(Some(1), Some("welcome"))).await.unwrap();
+        repository.save(promotion(Some(2), None)).await.unwrap();
+
+        let ids = |promotions: Vec<Promotion>| promotions.iter().map(|p| p.id).collect::<Vec<_>>();
+        assert_eq!(ids(repository.find_candidates(&[1], None).await.unwrap()), vec![automatic.id]);
+        assert_eq!(ids(repository.find_candidates(&[1], Some("Welcome")).await.unwrap()), vec![automatic.id, coupon.id]);
+
+        let duplicate = repository.save(promotion(None, Some("WELCOME"))).await;
+        assert!(matches!(duplicate, Err(RepositoryError::Conflict)));
+        repository.delete(coupon.id).await.unwrap();
+        assert!(matches!(repository.delete(coupon.id).await, Err(RepositoryError::NotFound)));
+    }
+}
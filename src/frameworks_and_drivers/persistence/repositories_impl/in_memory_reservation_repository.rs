@@ -0,0 +1,174 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{Order, Reservation, ReservationStatus};
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::RepositoryError;
+use super::in_memory_store::{InMemoryStore, State, StockItem};
+
+/// プロセス内のメモリに在庫予約を保持するリポジトリ（テスト・デモ用）
+/// 在庫の確保・確定・戻しは、メモリ上の商品と同じ `InMemoryStore` のロックの下で行う
+pub struct InMemoryReservationRepository {
+    store: Arc<InMemoryStore>,
+}
+
+impl InMemoryReservationRepository {
+    pub fn new(store: Arc<InMemoryStore>) -> Self {
+        Self { store }
+    }
+
+    // 保存済みの予約が有効でなければ競合とする
+    fn ensure_active(state: &State, reservation: &Reservation) -> Result<(), RepositoryError> {
+        match state.reservations.get(&reservation.id) {
+            Some(stored) if stored.status == ReservationStatus::Active => Ok(()),
+            _ => Err(RepositoryError::Conflict),
+        }
+    }
+
+    // 有効な予約の状態を変更する
+    fn close(state: &mut State, reservation: &Reservation, order_id: Option<u32>) {
+        if let Some(stored) = state.reservations.get_mut(&reservation.id) {
+            stored.status = reservation.status;
+            stored.order_id = order_id;
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationRepository for InMemoryReservationRepository {
+    #[tracing::instrument(name = "reservation_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Reservation>, RepositoryError> {
+        Ok(self.store.lock()?.reservations.get(&id).cloned())
+    }
+
+    #[tracing::instrument(name = "reservation_repository.find_expired", skip(self), err(level = "warn"))]
+    async fn find_expired(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<Reservation>, RepositoryError> {
+        let mut expired: Vec<Reservation> = self.store.lock()?
+            .reservations
+            .values()
+            .filter(|reservation| reservation.status == ReservationStatus::Active && reservation.expires_at <= now)
+            .cloned()
+            .collect();
+        expired.sort_by_key(|reservation| (reservation.expires_at, reservation.id));
+        expired.truncate(limit as usize);
+
+        Ok(expired)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.reserve", skip(self, reservation), fields(items = reservation.items.len()), err(level = "warn"))]
+    async fn reserve(&self, mut reservation: Reservation) -> Result<Reservation, RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        let items: Vec<StockItem> = reservation.items.iter().map(StockItem::from).collect();
+        state.check_stock(&items, &HashMap::new())?;
+        state.take_stock(&items, true);
+
+        state.last_reservation_id += 1;
+        reservation.id = state.last_reservation_id;
+        state.reservations.insert(reservation.id, reservation.clone());
+
+        Ok(reservation)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.confirm", skip(self, reservation, order), fields(reservation_id = reservation.id), err(level = "warn"))]
+    async fn confirm(&self, reservation: &Reservation, order: Order) -> Result<Order, RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        Self::ensure_active(&state, reservation)?;
+        let items: Vec<StockItem> = reservation.items.iter().map(StockItem::from).collect();
+        state.check_reserved(&items)?;
+        // 在庫は予約時に販売可能な在庫から除いてあるため、注文の保存では減算しない
+        let order = state.insert_order(order)?;
+        state.settle_reserved(&items);
+        Self::close(&mut state, reservation, Some(order.id));
+
+        Ok(order)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.release", skip(self, reservation), fields(reservation_id = reservation.id, status = reservation.status.as_str()), err(level = "warn"))]
+    async fn release(&self, reservation: &Reservation) -> Result<(), RepositoryError> {
+        let mut state = self.store.lock()?;
+
+        Self::ensure_active(&state, reservation)?;
+        let items: Vec<StockItem> = reservation.items.iter().map(StockItem::from).collect();
+        state.restock(&items);
+        Self::close(&mut state, reservation, None);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::application::repositories::ProductRepository;
+    use crate::application::use_cases::ExpireReservationsUseCase;
+    use crate::domain::models::{Currency, Money, OrderLine, Product, ProductVariant, ReservationItem};
+    use crate::domain::tax::TaxRegion;
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{InMemoryProductRepository, SqliteCategoryRepository};
+
+    async fn repositories() -> (InMemoryProductRepository, Arc<InMemoryReservationRepository>) {
+        let store = Arc::new(InMemoryStore::new());
+        let categories = Arc::new(SqliteCategoryRepository::new(test_db().await));
+        (InMemoryProductRepository::new(store.clone(), categories), Arc::new(InMemoryReservationRepository::new(store)))
+    }
+
+    async fn stock_of(products: &InMemoryProductRepository, product: &Product) -> (u32, u32) {
+        let product = products.find_by_id(product.id).await.unwrap().unwrap();
+        (product.quantity, product.reserved)
+    }
+
+    fn reservation(product: &Product, sku: Option<&str>, quantity: u32, expires_at: DateTime<Utc>) -> Reservation {
+        let items = vec![ReservationItem { product_id: product.id, sku: sku.map(str::to_string), quantity }];
+        Reservation::create(items, expires_at).unwrap()
+    }
+
+    /// 予約で在庫が確保され、足りない場合は何も確保されず、確定すると予約済みの在庫が注文になること
+    #[tokio::test]
+    async fn reserve_and_confirm_move_stock() {
+        let (products, repository) = repositories().await;
+        let product = Product::create("reserved".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 5).unwrap();
+        let product = products.save(product).await.unwrap();
+        let later = Utc::now() + Duration::minutes(5);
+
+        let short = repository.reserve(reservation(&product, None, 6, later)).await;
+        assert!(matches!(short, Err(RepositoryError::Conflict)));
+        let reserved = repository.reserve(reservation(&product, None, 2, later)).await.unwrap();
+        assert_eq!(stock_of(&products, &product).await, (3, 2));
+
+        let mut confirmed = repository.find_by_id(reserved.id).await.unwrap().unwrap();
+        confirmed.status = ReservationStatus::Confirmed;
+        let order = Order::place(vec![OrderLine::new(product.id, product.name.clone(), product.price, 2)], &TaxRegion::untaxed()).unwrap();
+        let order = repository.confirm(&confirmed, order).await.unwrap();
+        assert_eq!(stock_of(&products, &product).await, (3, 0));
+        assert_eq!(repository.find_by_id(reserved.id).await.unwrap().unwrap().order_id, Some(order.id));
+
+        let order = Order::place(vec![OrderLine::new(product.id, product.name.clone(), product.price, 2)], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.confirm(&confirmed, order).await, Err(RepositoryError::Conflict)));
+    }
+
+    /// 期限切れの予約が失効し、バリエーションの在庫が戻ること
+    #[tokio::test]
+    async fn expired_reservations_return_variant_stock() {
+        let (products, repository) = repositories().await;
+        let mut product = Product::create("expiring".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 0).unwrap();
+        product.add_variant(ProductVariant::new("exp-1", BTreeMap::from([("colour".to_string(), "red".to_string())]), Money::new(100, Currency::Jpy), 4)).unwrap();
+        let product = products.save(product).await.unwrap();
+
+        repository.reserve(reservation(&product, Some("EXP-1"), 3, Utc::now() - Duration::seconds(1))).await.unwrap();
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().variant("EXP-1").unwrap().quantity, 1);
+
+        let expired = ExpireReservationsUseCase::new(repository.clone()).expire_stale().await.unwrap();
+        assert_eq!(expired, 1);
+        assert_eq!(stock_of(&products, &product).await, (4, 0));
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().variant("EXP-1").unwrap().quantity, 4);
+        assert!(repository.find_expired(Utc::now(), 10).await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,221 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use chrono::{DateTime, Utc};
+
+use crate::domain::models::{Cart, Money, Order, OrderLine, ProductVariant, Promotion, Reservation, ReservationItem};
+use crate::domain::tax::TaxCategory;
+use crate::application::error::RepositoryError;
+
+/// プロセス内のメモリに保持する商品・プロモーション・注文・カート・予約（テスト・デモ用）
+/// 再起動すると内容は失われる
+/// メモリ上の各リポジトリはこのストアを共有し、在庫を変更する操作は1つのロックの下で商品と一緒に更新する
+#[derive(Default)]
+pub struct InMemoryStore {
+    state: Mutex<State>,
+}
+
+#[derive(Default)]
+pub(super) struct State {
+    /// 最後に採番した商品ID（削除されたIDは再利用しない）
+    pub(super) last_product_id: u32,
+    /// 最後に採番したバリエーションの連番（読み込み時の並び順に使う）
+    pub(super) last_variant_seq: u64,
+    pub(super) products: BTreeMap<u32, StoredProduct>,
+    pub(super) last_promotion_id: u32,
+    pub(super) promotions: BTreeMap<u32, Promotion>,
+    pub(super) last_order_id: u32,
+    pub(super) orders: BTreeMap<u32, Order>,
+    pub(super) last_cart_id: u32,
+    pub(super) carts: BTreeMap<u32, Cart>,
+    pub(super) last_reservation_id: u32,
+    pub(super) reservations: BTreeMap<u32, Reservation>,
+}
+
+/// 保存された商品（productsテーブルの行と関連に相当）
+#[derive(Clone)]
+pub(super) struct StoredProduct {
+    pub(super) id: u32,
+    pub(super) name: String,
+    pub(super) price: Money,
+    pub(super) tax_category: TaxCategory,
+    pub(super) description: String,
+    pub(super) quantity: u32,
+    pub(super) reserved: u32,
+    pub(super) version: u32,
+    pub(super) category_ids: Vec<u32>,
+    pub(super) tags: Vec<String>,
+    pub(super) variants: Vec<(u64, ProductVariant)>,
+    pub(super) created_at: DateTime<Utc>,
+}
+
+/// 在庫を変更する品目（注文明細・予約した商品）
+pub(super) struct StockItem<'a> {
+    product_id: u32,
+    sku: Option<&'a str>,
+    quantity: u32,
+}
+
+impl<'a> From<&'a OrderLine> for StockItem<'a> {
+    fn from(line: &'a OrderLine) -> Self {
+        Self { product_id: line.product_id, sku: line.sku.as_deref(), quantity: line.quantity }
+    }
+}
+
+impl<'a> From<&'a ReservationItem> for StockItem<'a> {
+    fn from(item: &'a ReservationItem) -> Self {
+        Self { product_id: item.product_id, sku: item.sku.as_deref(), quantity: item.quantity }
+    }
+}
+
+impl InMemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(super) fn lock(&self) -> Result<MutexGuard<'_, State>, RepositoryError> {
+        self.state
+            .lock()
+            .map_err(|_| RepositoryError::Unknown("in-memory store is poisoned".to_string()))
+    }
+}
+
+impl State {
+    /// 在庫を減らして注文を保存する
+    /// いずれかの明細で在庫不足・バージョン不一致・商品が存在しない場合は何も変更せず競合とする
+    pub(super) fn place_order(&mut self, order: Order, expected_versions: &HashMap<u32, u32>) -> Result<Order, RepositoryError> {
+        let items: Vec<StockItem> = order.lines.iter().map(StockItem::from).collect();
+        self.check_stock(&items, expected_versions)?;
+        let order = self.insert_order(order)?;
+
+        let items: Vec<StockItem> = order.lines.iter().map(StockItem::from).collect();
+        self.take_stock(&items, false);
+        Ok(order)
+    }
+
+    /// 在庫を変更せずに注文を保存する
+    /// 適用されたプロモーションの利用回数も数え、上限に達していれば何も変更せず競合とする
+    pub(super) fn insert_order(&mut self, mut order: Order) -> Result<Order, RepositoryError> {
+        let mut promotion_ids: Vec<u32> = order.lines.iter().filter_map(|line| line.promotion_id).collect();
+        promotion_ids.sort_unstable();
+        promotion_ids.dedup();
+
+        let exhausted = promotion_ids.iter().any(|id| {
+            self.promotions
+                .get(id)
+                .is_none_or(|promotion| promotion.usage_limit.is_some_and(|limit| promotion.usage_count >= limit))
+        });
+        if exhausted {
+            return Err(RepositoryError::Conflict);
+        }
+        for id in promotion_ids {
+            if let Some(promotion) = self.promotions.get_mut(&id) {
+                promotion.usage_count += 1;
+            }
+        }
+
+        self.last_order_id += 1;
+        order.id = self.last_order_id;
+        self.orders.insert(order.id, order.clone());
+        Ok(order)
+    }
+
+    /// 販売可能な在庫から品目を減らせるか確認する（同じ商品・SKUの品目は合計して比べる）
+    pub(super) fn check_stock(&self, items: &[StockItem], expected_versions: &HashMap<u32, u32>) -> Result<(), RepositoryError> {
+        let mut by_product: HashMap<u32, u32> = HashMap::new();
+        let mut by_variant: HashMap<(u32, &str), u32> = HashMap::new();
+        for item in items {
+            *by_product.entry(item.product_id).or_default() += item.quantity;
+            if let Some(sku) = item.sku {
+                *by_variant.entry((item.product_id, sku)).or_default() += item.quantity;
+            }
+        }
+
+        for (product_id, quantity) in by_product {
+            let product = self.products.get(&product_id).ok_or(RepositoryError::Conflict)?;
+            let stale = expected_versions.get(&product_id).is_some_and(|version| *version != product.version);
+            if stale || product.quantity < quantity {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        for ((product_id, sku), quantity) in by_variant {
+            let variant = self.products[&product_id]
+                .variants
+                .iter()
+                .find(|(_, variant)| variant.sku == sku)
+                .ok_or(RepositoryError::Conflict)?;
+            if variant.1.quantity < quantity {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    /// 販売可能な在庫から品目を減らす（`check_stock` で確認済みであること）
+    /// `reserve` の場合は減らした分を予約済みの在庫に移す
+    pub(super) fn take_stock(&mut self, items: &[StockItem], reserve: bool) {
+        for item in items {
+            let Some(product) = self.products.get_mut(&item.product_id) else { continue };
+            if let Some((_, variant)) = product.variants.iter_mut().find(|(_, variant)| Some(variant.sku.as_str()) == item.sku) {
+                variant.quantity -= item.quantity;
+            }
+            product.quantity -= item.quantity;
+            if reserve {
+                product.reserved += item.quantity;
+            }
+            product.version += 1;
+        }
+    }
+
+    /// 予約済みの在庫から品目を除けるか確認する
+    pub(super) fn check_reserved(&self, items: &[StockItem]) -> Result<(), RepositoryError> {
+        let mut by_product: HashMap<u32, u32> = HashMap::new();
+        for item in items {
+            *by_product.entry(item.product_id).or_default() += item.quantity;
+        }
+
+        let short = by_product.into_iter().any(|(product_id, quantity)| {
+            self.products.get(&product_id).is_none_or(|product| product.reserved < quantity)
+        });
+        if short {
+            return Err(RepositoryError::Conflict);
+        }
+        Ok(())
+    }
+
+    /// 予約済みの在庫から品目を除く（注文として確定した分。`check_reserved` で確認済みであること）
+    pub(super) fn settle_reserved(&mut self, items: &[StockItem]) {
+        for item in items {
+            if let Some(product) = self.products.get_mut(&item.product_id) {
+                product.reserved -= item.quantity;
+            }
+        }
+    }
+
+    /// 予約済みの在庫を販売可能な在庫に戻す
+    /// 予約後に商品が削除されている場合は戻す先がないため無視する
+    /// バリエーションが削除されている場合は、その在庫ごと商品から取り除かれているため予約数だけ減らす
+    pub(super) fn restock(&mut self, items: &[StockItem]) {
+        for item in items {
+            let Some(product) = self.products.get_mut(&item.product_id) else { continue };
+            if product.reserved < item.quantity {
+                continue;
+            }
+            let restock = match item.sku {
+                Some(sku) => match product.variants.iter_mut().find(|(_, variant)| variant.sku == sku) {
+                    Some((_, variant)) => {
+                        variant.quantity += item.quantity;
+                        true
+                    }
+                    None => false,
+                },
+                None => true,
+            };
+            if restock {
+                product.quantity += item.quantity;
+            }
+            product.reserved -= item.quantity;
+            product.version += 1;
+        }
+    }
+}
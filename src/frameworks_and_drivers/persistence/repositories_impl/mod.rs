@@ -1,3 +1,58 @@
 mod sqlite_product_repository;
+mod sqlite_order_repository;
+mod sqlite_cart_repository;
+mod sqlite_reservation_repository;
+mod sqlite_idempotency_repository;
+mod sqlite_promotion_repository;
+mod sqlite_category_repository;
+mod in_memory_store;
+mod in_memory_product_repository;
+mod in_memory_order_repository;
+mod in_memory_cart_repository;
+mod in_memory_reservation_repository;
+mod in_memory_promotion_repository;
+#[cfg(feature = "postgres")]
+mod postgres_product_repository;
+#[cfg(feature = "postgres")]
+mod postgres_order_repository;
+#[cfg(feature = "postgres")]
+mod postgres_cart_repository;
+#[cfg(feature = "postgres")]
+mod postgres_reservation_repository;
+#[cfg(feature = "postgres")]
+mod postgres_idempotency_repository;
+#[cfg(feature = "postgres")]
+mod postgres_promotion_repository;
+#[cfg(feature = "postgres")]
+mod postgres_category_repository;
+mod search_highlight;
+#[cfg(test)]
+mod product_repository_conformance;
 
 pub use self::sqlite_product_repository::*;
+pub use self::sqlite_order_repository::*;
+pub use self::sqlite_cart_repository::*;
+pub use self::sqlite_reservation_repository::*;
+pub use self::sqlite_idempotency_repository::*;
+pub use self::sqlite_promotion_repository::*;
+pub use self::sqlite_category_repository::*;
+pub use self::in_memory_store::InMemoryStore;
+pub use self::in_memory_product_repository::*;
+pub use self::in_memory_order_repository::*;
+pub use self::in_memory_cart_repository::*;
+pub use self::in_memory_reservation_repository::*;
+pub use self::in_memory_promotion_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_product_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_order_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_cart_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_reservation_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_idempotency_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_promotion_repository::*;
+#[cfg(feature = "postgres")]
+pub use self::postgres_category_repository::*;
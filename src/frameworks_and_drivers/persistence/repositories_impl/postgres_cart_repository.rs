@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, Utc};
+use sqlx::postgres::{PgConnection, PgRow};
+use sqlx::{PgPool, Row};
+
+use crate::domain::models::{Cart, CartItem, CartStatus, Order};
+use crate::frameworks_and_drivers::persistence::entities::{CartEntity, CartItemEntity};
+use crate::frameworks_and_drivers::persistence::repositories_impl::PostgresOrderRepository;
+use crate::application::repositories::CartRepository;
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLにカートを保存するリポジトリ（`postgres` feature）
+pub struct PostgresCartRepository {
+    pool: PgPool,
+}
+
+impl PostgresCartRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: CartEntity, items: Vec<CartItemEntity>) -> Result<Cart, RepositoryError> {
+        let status = CartStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown cart status: {}", entity.status)))?;
+        let items = items
+            .into_iter()
+            .map(|item| CartItem { product_id: item.product_id, sku: item.sku, quantity: item.quantity })
+            .collect();
+
+        Ok(Cart::new(entity.id, items, status, entity.order_id, entity.version))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> CartEntity {
+        CartEntity {
+            id: row.get::<i64, _>("id") as u32,
+            status: row.get("status"),
+            order_id: row.get::<Option<i64>, _>("order_id").map(|id| id as u32),
+            version: row.get::<i64, _>("version") as u32,
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at").to_rfc3339(),
+        }
+    }
+
+    fn row_to_item_entity(row: &PgRow) -> CartItemEntity {
+        CartItemEntity {
+            product_id: row.get::<i64, _>("product_id") as u32,
+            sku: row.get("sku"),
+            quantity: row.get::<i64, _>("quantity") as u32,
+        }
+    }
+
+    // カート内の商品を追加された順序のまま置き換える
+    async fn replace_items(conn: &mut PgConnection, cart: &Cart) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM cart_items WHERE cart_id = $1")
+            .bind(i64::from(cart.id))
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        for (position, item) in cart.items.iter().enumerate() {
+            sqlx::query("INSERT INTO cart_items (cart_id, product_id, sku, quantity, position) VALUES ($1, $2, $3, $4, $5)")
+                .bind(i64::from(cart.id))
+                .bind(i64::from(item.product_id))
+                .bind(&item.sku)
+                .bind(i64::from(item.quantity))
+                .bind(position as i64)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CartRepository for PostgresCartRepository {
+    #[tracing::instrument(name = "cart_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Cart>, RepositoryError> {
+        let Some(row) = sqlx::query("SELECT * FROM carts WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let items = sqlx::query("SELECT * FROM cart_items WHERE cart_id = $1 ORDER BY position")
+            .bind(i64::from(id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_item_entity)
+            .collect();
+
+        Self::entity_to_domain(Self::row_to_entity(&row), items).map(Some)
+    }
+
+    #[tracing::instrument(name = "cart_repository.save", skip(self, cart), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn save(&self, mut cart: Cart) -> Result<Cart, RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now();
+
+        if cart.id == 0 {
+            // 新規作成
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO carts (status, version, created_at, updated_at) VALUES ($1, 0, $2, $2) RETURNING id"
+            )
+            .bind(cart.status.as_str())
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            cart.id = id as u32;
+            cart.version = 0;
+        } else {
+            // 更新（読み込み時のバージョンと一致し、注文確定前の場合のみ）
+            let result = sqlx::query(
+                "UPDATE carts SET version = version + 1, updated_at = $1 WHERE id = $2 AND version = $3 AND status = $4"
+            )
+            .bind(now)
+            .bind(i64::from(cart.id))
+            .bind(i64::from(cart.version))
+            .bind(CartStatus::Open.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+            cart.version += 1;
+        }
+
+        Self::replace_items(&mut tx, &cart).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(cart)
+    }
+
+    #[tracing::instrument(name = "cart_repository.check_out", skip(self, cart, order), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn check_out(&self, cart: &Cart, order: Order) -> Result<Order, RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let order = PostgresOrderRepository::place_in(&mut tx, order, &HashMap::new()).await?;
+
+        // カートの読み込み後に商品が変更された場合や、同じカートが既にチェックアウトされた場合は競合
+        let result = sqlx::query(
+            "UPDATE carts SET status = $1, order_id = $2, version = version + 1, updated_at = $3 \
+             WHERE id = $4 AND version = $5 AND status = $6"
+        )
+        .bind(CartStatus::CheckedOut.as_str())
+        .bind(i64::from(order.id))
+        .bind(Utc::now())
+        .bind(i64::from(cart.id))
+        .bind(i64::from(cart.version))
+        .bind(CartStatus::Open.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::Conflict);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::error::ApplicationError;
+    use crate::application::repositories::ProductRepository;
+    use crate::application::use_cases::CheckoutCartUseCase;
+    use crate::domain::models::{Currency, Money, OrderLine, Product};
+    use crate::domain::DomainError;
+    use crate::domain::tax::{TaxRegion, TaxTable};
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{
+        PostgresCategoryRepository, PostgresProductRepository, PostgresPromotionRepository,
+    };
+
+    // TEST_POSTGRES_URL が設定されていない場合は `None`（テストは何もせずに成功する）
+    async fn repositories() -> Option<(Arc<PostgresProductRepository>, Arc<PostgresCartRepository>, PgPool)> {
+        let pool = init_test_postgres().await.unwrap()?;
+        let categories = Arc::new(PostgresCategoryRepository::new(pool.clone()));
+        Some((
+            Arc::new(PostgresProductRepository::new(pool.clone(), categories)),
+            Arc::new(PostgresCartRepository::new(pool.clone())),
+            pool,
+        ))
+    }
+
+    async fn insert_product(products: &PostgresProductRepository, name: &str, quantity: u32) -> Product {
+        let product = Product::create(name.to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), quantity).unwrap();
+        products.save(product).await.unwrap()
+    }
+
+    async fn insert_cart(carts: &PostgresCartRepository, items: &[(&Product, u32)]) -> Cart {
+        let mut cart = Cart::create();
+        for (product, quantity) in items {
+            cart.add_item(product.id, None, *quantity).unwrap();
+        }
+        carts.save(cart).await.unwrap()
+    }
+
+    /// 全商品の在庫が減り、カートが注文に紐づくこと。在庫が足りなければどの在庫も減らないこと
+    #[tokio::test]
+    async fn checkout_decrements_stock_in_the_same_database() {
+        let Some((products, carts, pool)) = repositories().await else { return };
+        let laptop = insert_product(&products, "pg cart laptop", 5).await;
+        let mouse = insert_product(&products, "pg cart mouse", 1).await;
+        let use_case = CheckoutCartUseCase::new(
+            carts.clone(),
+            products.clone(),
+            Arc::new(PostgresPromotionRepository::new(pool)),
+            Arc::new(TaxTable::untaxed()),
+        );
+
+        let short = insert_cart(&carts, &[(&laptop, 2), (&mouse, 2)]).await;
+        assert!(matches!(
+            use_case.checkout(short.id, None).await,
+            Err(ApplicationError::Domain(DomainError::InsufficientQuantity { requested: 2, available: 1, .. }))
+        ));
+        assert_eq!(products.find_by_id(laptop.id).await.unwrap().unwrap().quantity, 5);
+
+        let cart = insert_cart(&carts, &[(&laptop, 2), (&mouse, 1)]).await;
+        let order = use_case.checkout(cart.id, None).await.unwrap();
+        assert_eq!(order.total, Money::new(300, Currency::Jpy));
+        assert_eq!(products.find_by_id(laptop.id).await.unwrap().unwrap().quantity, 3);
+        assert_eq!(products.find_by_id(mouse.id).await.unwrap().unwrap().quantity, 0);
+        let cart = carts.find_by_id(cart.id).await.unwrap().unwrap();
+        assert_eq!(cart.status, CartStatus::CheckedOut);
+        assert_eq!(cart.order_id, Some(order.id));
+    }
+
+    /// 古いバージョンのカートは保存されず、同じカートを2回チェックアウトしても注文は1件しか作られないこと
+    #[tokio::test]
+    async fn save_and_check_out_reject_stale_carts() {
+        let Some((products, carts, _)) = repositories().await else { return };
+        let product = insert_product(&products, "pg cart stale", 5).await;
+        let cart = insert_cart(&carts, &[(&product, 1)]).await;
+
+        let mut first = carts.find_by_id(cart.id).await.unwrap().unwrap();
+        first.update_quantity(product.id, None, 2).unwrap();
+        let first = carts.save(first).await.unwrap();
+        let mut stale = cart;
+        stale.update_quantity(product.id, None, 3).unwrap();
+        assert!(matches!(carts.save(stale).await, Err(RepositoryError::Conflict)));
+
+        let line = || OrderLine::new(product.id, product.name.clone(), product.price, 2);
+        carts.check_out(&first, Order::place(vec![line()], &TaxRegion::untaxed()).unwrap()).await.unwrap();
+        let second = carts.check_out(&first, Order::place(vec![line()], &TaxRegion::untaxed()).unwrap()).await;
+        assert!(matches!(second, Err(RepositoryError::Conflict)));
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 3);
+    }
+}
@@ -0,0 +1,198 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Row};
+
+use crate::domain::models::Category;
+use crate::frameworks_and_drivers::persistence::entities::CategoryEntity;
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLに商品カテゴリを保存するリポジトリ（`postgres` feature）
+pub struct PostgresCategoryRepository {
+    pool: PgPool,
+}
+
+impl PostgresCategoryRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: CategoryEntity) -> Category {
+        Category::new(entity.id, entity.name, entity.parent_id)
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> CategoryEntity {
+        CategoryEntity {
+            id: row.get::<i64, _>("id") as u32,
+            name: row.get("name"),
+            parent_id: row.get::<Option<i64>, _>("parent_id").map(|id| id as u32),
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at").to_rfc3339(),
+        }
+    }
+
+    fn rows_to_domain(rows: &[PgRow]) -> Vec<Category> {
+        rows.iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl CategoryRepository for PostgresCategoryRepository {
+    #[tracing::instrument(name = "category_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self) -> Result<Vec<Category>, RepositoryError> {
+        let rows = sqlx::query("SELECT * FROM categories ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(Self::rows_to_domain(&rows))
+    }
+
+    #[tracing::instrument(name = "category_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Category>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM categories WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(row.map(|row| Self::entity_to_domain(Self::row_to_entity(&row))))
+    }
+
+    #[tracing::instrument(name = "category_repository.find_by_ids", skip(self), err(level = "warn"))]
+    async fn find_by_ids(&self, ids: &[u32]) -> Result<Vec<Category>, RepositoryError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let ids: Vec<i64> = ids.iter().copied().map(i64::from).collect();
+
+        let rows = sqlx::query("SELECT * FROM categories WHERE id = ANY($1) ORDER BY id")
+            .bind(ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(Self::rows_to_domain(&rows))
+    }
+
+    #[tracing::instrument(name = "category_repository.save", skip(self, category), fields(category_id = category.id), err(level = "warn"))]
+    async fn save(&self, mut category: Category) -> Result<Category, RepositoryError> {
+        let now = Utc::now();
+
+        // 新規作成
+        if category.id == 0 {
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO categories (name, parent_id, created_at, updated_at) VALUES ($1, $2, $3, $3) RETURNING id"
+            )
+            .bind(&category.name)
+            .bind(category.parent_id.map(i64::from))
+            .bind(now)
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            category.id = id as u32;
+            return Ok(category);
+        }
+
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        // 同時に実行された更新は互いの変更を見ないまま祖先をたどるため、カテゴリの更新を直列化する
+        // 読み込みは妨げない
+        sqlx::query("LOCK TABLE categories IN SHARE ROW EXCLUSIVE MODE")
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        // 更新
+        // 移動先の祖先に自分自身が含まれる場合は更新しない
+        let result = sqlx::query(
+            r#"
+            UPDATE categories SET name = $1, parent_id = $2, updated_at = $3
+            WHERE id = $4 AND NOT EXISTS (
+                WITH RECURSIVE ancestors(id) AS (
+                    SELECT $2::BIGINT
+                    UNION
+                    SELECT c.parent_id FROM categories c JOIN ancestors a ON c.id = a.id
+                    WHERE c.parent_id IS NOT NULL
+                )
+                SELECT 1 FROM ancestors WHERE id = $4
+            )
+            "#
+        )
+        .bind(&category.name)
+        .bind(category.parent_id.map(i64::from))
+        .bind(now)
+        .bind(i64::from(category.id))
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return match self.find_by_id(category.id).await? {
+                Some(_) => Err(RepositoryError::Conflict),
+                None => Err(RepositoryError::NotFound),
+            };
+        }
+        Ok(category)
+    }
+
+    #[tracing::instrument(name = "category_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError> {
+        // 子カテゴリの parent_id が参照しているため、外部キー制約違反になる
+        let result = sqlx::query("DELETE FROM categories WHERE id = $1")
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db_error) if db_error.is_foreign_key_violation() => RepositoryError::Conflict,
+                _ => RepositoryError::QueryExecution(e.to_string()),
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+
+    /// 循環する移動は保存されず、子カテゴリがあるカテゴリは削除できないこと
+    #[tokio::test]
+    async fn save_rejects_cycles_and_delete_rejects_parents() {
+        let Some(pool) = init_test_postgres().await.unwrap() else { return };
+        let repository = PostgresCategoryRepository::new(pool);
+        let mut first = repository.save(Category::create("pg cycle first".to_string(), None).unwrap()).await.unwrap();
+        let mut second = repository.save(Category::create("pg cycle second".to_string(), None).unwrap()).await.unwrap();
+
+        first.parent_id = Some(second.id);
+        repository.save(first.clone()).await.unwrap();
+        assert_eq!(repository.find_by_id(first.id).await.unwrap(), Some(first.clone()));
+        second.parent_id = Some(first.id);
+        assert!(matches!(repository.save(second.clone()).await, Err(RepositoryError::Conflict)));
+        second.parent_id = Some(second.id);
+        assert!(matches!(repository.save(second.clone()).await, Err(RepositoryError::Conflict)));
+        let missing = Category::new(u32::MAX, "missing".to_string(), None);
+        assert!(matches!(repository.save(missing).await, Err(RepositoryError::NotFound)));
+
+        assert!(matches!(repository.delete(second.id).await, Err(RepositoryError::Conflict)));
+        repository.delete(first.id).await.unwrap();
+        repository.delete(second.id).await.unwrap();
+        assert!(matches!(repository.delete(second.id).await, Err(RepositoryError::NotFound)));
+    }
+}
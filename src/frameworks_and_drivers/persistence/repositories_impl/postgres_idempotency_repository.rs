@@ -0,0 +1,201 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Row};
+
+use crate::frameworks_and_drivers::persistence::entities::IdempotencyKeyEntity;
+use crate::application::repositories::{IdempotencyRecord, IdempotencyRepository, IdempotencyStatus, StoredResponse};
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLにIdempotency-Keyの記録を保存するリポジトリ（`postgres` feature）
+pub struct PostgresIdempotencyRepository {
+    pool: PgPool,
+}
+
+impl PostgresIdempotencyRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // エンティティからレコードへのマッピング
+    fn entity_to_record(entity: IdempotencyKeyEntity) -> Result<IdempotencyRecord, RepositoryError> {
+        let status = IdempotencyStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown idempotency status: {}", entity.status)))?;
+        let response = match (entity.response_status, entity.response_headers, entity.response_body) {
+            (Some(status), Some(headers), Some(body)) => Some(StoredResponse {
+                status,
+                headers: serde_json::from_str(&headers).map_err(|e| RepositoryError::Unknown(e.to_string()))?,
+                body,
+            }),
+            _ => None,
+        };
+
+        Ok(IdempotencyRecord {
+            fingerprint: entity.fingerprint,
+            status,
+            response,
+        })
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> IdempotencyKeyEntity {
+        IdempotencyKeyEntity {
+            key: row.get("key"),
+            fingerprint: row.get("fingerprint"),
+            status: row.get("status"),
+            response_status: row.get::<Option<i64>, _>("response_status").map(|status| status as u16),
+            response_headers: row.get("response_headers"),
+            response_body: row.get("response_body"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at").to_rfc3339(),
+            expires_at: row.get::<DateTime<Utc>, _>("expires_at").to_rfc3339(),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for PostgresIdempotencyRepository {
+    #[tracing::instrument(name = "idempotency_repository.claim", skip(self, fingerprint), err(level = "warn"))]
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, RepositoryError> {
+        // 新規のキーは挿入し、期限切れ・放棄されたキーは最初のリクエストとして上書きする
+        // 挿入と判定を1文で行い、同じキーの同時リクエストのうち1つだけが確保できるようにする
+        let result = sqlx::query(
+            r#"
+            INSERT INTO idempotency_keys (key, fingerprint, status, created_at, updated_at, expires_at)
+            VALUES ($1, $2, $3, $4, $4, $5)
+            ON CONFLICT (key) DO UPDATE SET
+                fingerprint = excluded.fingerprint,
+                status = excluded.status,
+                response_status = NULL,
+                response_headers = NULL,
+                response_body = NULL,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at,
+                expires_at = excluded.expires_at
+            WHERE idempotency_keys.expires_at <= $4
+               OR (idempotency_keys.status = $3 AND idempotency_keys.updated_at <= $6)
+            "#
+        )
+        .bind(key)
+        .bind(fingerprint)
+        .bind(IdempotencyStatus::InProgress.as_str())
+        .bind(now)
+        .bind(expires_at)
+        .bind(stale_before)
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(None);
+        }
+
+        let row = sqlx::query("SELECT * FROM idempotency_keys WHERE key = $1")
+            .bind(key)
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match row {
+            Some(row) => Self::entity_to_record(Self::row_to_entity(&row)).map(Some),
+            // 確保に失敗した直後に削除された場合
+            None => Err(RepositoryError::Conflict),
+        }
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.complete", skip(self, response), err(level = "warn"))]
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), RepositoryError> {
+        let headers = serde_json::to_string(&response.headers)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+
+        let result = sqlx::query(
+            "UPDATE idempotency_keys SET status = $1, response_status = $2, response_headers = $3, response_body = $4, updated_at = $5 \
+             WHERE key = $6 AND status = $7"
+        )
+        .bind(IdempotencyStatus::Completed.as_str())
+        .bind(i64::from(response.status))
+        .bind(headers)
+        .bind(&response.body)
+        .bind(Utc::now())
+        .bind(key)
+        .bind(IdempotencyStatus::InProgress.as_str())
+        .execute(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.release", skip(self), err(level = "warn"))]
+    async fn release(&self, key: &str) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM idempotency_keys WHERE key = $1 AND status = $2")
+            .bind(key)
+            .bind(IdempotencyStatus::InProgress.as_str())
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.delete_expired", skip(self), err(level = "warn"))]
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= $1")
+            .bind(now)
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::Duration;
+
+    use super::*;
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status: 201,
+            headers: vec![("location".to_string(), "/orders/1".to_string())],
+            body: br#"{"id":1}"#.to_vec(),
+        }
+    }
+
+    /// 完了したレスポンスが読み込め、期限切れのキーと放棄された処理中のキーは新しいリクエストとして確保できること
+    #[tokio::test]
+    async fn claim_replays_and_reuses_keys() {
+        let Some(pool) = init_test_postgres().await.unwrap() else { return };
+        let repository = PostgresIdempotencyRepository::new(pool);
+        let now = Utc::now();
+
+        assert!(repository.claim("pg expired", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().is_none());
+        repository.complete("pg expired", &response()).await.unwrap();
+        let record = repository.claim("pg expired", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().unwrap();
+        assert_eq!(record.status, IdempotencyStatus::Completed);
+        assert_eq!(record.response, Some(response()));
+        let later = now + Duration::hours(2);
+        assert!(repository.claim("pg expired", "new", later, later + Duration::hours(1), later - Duration::minutes(1)).await.unwrap().is_none());
+
+        assert!(repository.claim("pg stale", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().is_none());
+        let record = repository.claim("pg stale", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().unwrap();
+        assert_eq!(record.status, IdempotencyStatus::InProgress);
+        let later = now + Duration::minutes(5);
+        assert!(repository.claim("pg stale", "fp", later, later + Duration::hours(1), later - Duration::minutes(1)).await.unwrap().is_none());
+
+        repository.release("pg stale").await.unwrap();
+        assert!(repository.claim("pg stale", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().is_none());
+    }
+}
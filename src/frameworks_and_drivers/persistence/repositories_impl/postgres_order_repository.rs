@@ -0,0 +1,382 @@
+use std::collections::HashMap;
+
+use chrono::{DateTime, SubsecRound, Utc};
+use sqlx::postgres::{PgConnection, PgRow};
+use sqlx::{PgPool, Row};
+
+use crate::domain::models::{Currency, Money, Order, OrderLine, OrderStatus};
+use crate::domain::tax::{TaxCategory, TaxMode, TaxRate};
+use crate::frameworks_and_drivers::persistence::entities::{OrderEntity, OrderLineEntity};
+use crate::application::repositories::OrderRepository;
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLに注文を保存するリポジトリ（`postgres` feature）
+/// 在庫の減算は商品と同じデータベースで、注文の保存と同じトランザクション内で行う
+pub struct PostgresOrderRepository {
+    pool: PgPool,
+}
+
+impl PostgresOrderRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// 在庫の減算と注文の保存を、呼び出し元のトランザクション内で行う
+    /// 他のリポジトリが注文確定を自身の更新と同じトランザクションで行うために使う
+    pub(crate) async fn place_in(
+        conn: &mut PgConnection,
+        order: Order,
+        expected_versions: &HashMap<u32, u32>,
+    ) -> Result<Order, RepositoryError> {
+        let now = Utc::now();
+
+        // 明細ごとに在庫チェックと減算を1つのUPDATEで行う
+        // 1件でも条件を満たさなければトランザクションごと破棄する
+        for line in &order.lines {
+            // バリエーションの在庫を減らし、商品の在庫（バリエーションの合計）も同じだけ減らす
+            if let Some(sku) = &line.sku {
+                let result = sqlx::query(
+                    "UPDATE product_variants SET quantity = quantity - $1, updated_at = $2 \
+                     WHERE product_id = $3 AND sku = $4 AND quantity >= $1"
+                )
+                .bind(i64::from(line.quantity))
+                .bind(now)
+                .bind(i64::from(line.product_id))
+                .bind(sku)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                if result.rows_affected() == 0 {
+                    return Err(RepositoryError::Conflict);
+                }
+            }
+
+            let result = sqlx::query(
+                "UPDATE products SET quantity = quantity - $1, version = version + 1, updated_at = $2 \
+                 WHERE id = $3 AND quantity >= $1 AND ($4::BIGINT IS NULL OR version = $4)"
+            )
+            .bind(i64::from(line.quantity))
+            .bind(now)
+            .bind(i64::from(line.product_id))
+            .bind(expected_versions.get(&line.product_id).copied().map(i64::from))
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        Self::insert_in(conn, order).await
+    }
+
+    /// 在庫を変更せずに注文と明細を保存する
+    /// 予約済みの在庫を注文として確定する場合など、在庫を別途調整済みの場合に使う
+    /// 適用されたプロモーションの利用回数も数え、上限に達していれば競合とする
+    pub(crate) async fn insert_in(conn: &mut PgConnection, mut order: Order) -> Result<Order, RepositoryError> {
+        Self::use_promotions_in(conn, &order).await?;
+
+        // 保存する精度に揃え、返す注文と読み込んだ注文で日時が一致するようにする
+        order.placed_at = order.placed_at.trunc_subsecs(6);
+
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO orders (status, tax_region, tax_mode, subtotal, tax, total, currency, created_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id"
+        )
+        .bind(order.status.as_str())
+        .bind(&order.tax_region)
+        .bind(order.tax_mode.as_str())
+        .bind(order.subtotal.amount() as i64)
+        .bind(order.tax.amount() as i64)
+        .bind(order.total.amount() as i64)
+        .bind(order.total.currency().code())
+        .bind(order.placed_at)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        order.id = id as u32;
+
+        for line in &order.lines {
+            sqlx::query(
+                "INSERT INTO order_lines \
+                 (order_id, product_id, product_name, sku, unit_price, quantity, discount, promotion_id, tax_category, tax_rate, tax) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)"
+            )
+            .bind(id)
+            .bind(i64::from(line.product_id))
+            .bind(&line.product_name)
+            .bind(&line.sku)
+            .bind(line.unit_price.amount() as i64)
+            .bind(i64::from(line.quantity))
+            .bind(line.discount.amount() as i64)
+            .bind(line.promotion_id.map(i64::from))
+            .bind(line.tax_category.as_str())
+            .bind(i64::from(line.tax_rate.millionths()))
+            .bind(line.tax.amount() as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        Ok(order)
+    }
+
+    // プロモーションの利用回数を注文1件につき1回数える
+    async fn use_promotions_in(conn: &mut PgConnection, order: &Order) -> Result<(), RepositoryError> {
+        let mut promotion_ids: Vec<u32> = order.lines.iter().filter_map(|line| line.promotion_id).collect();
+        promotion_ids.sort_unstable();
+        promotion_ids.dedup();
+
+        for promotion_id in promotion_ids {
+            let result = sqlx::query(
+                "UPDATE promotions SET usage_count = usage_count + 1 \
+                 WHERE id = $1 AND (usage_limit IS NULL OR usage_count < usage_limit)"
+            )
+            .bind(i64::from(promotion_id))
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: OrderEntity, lines: Vec<OrderLineEntity>) -> Result<Order, RepositoryError> {
+        let status = OrderStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown order status: {}", entity.status)))?;
+        let placed_at = DateTime::parse_from_rfc3339(&entity.created_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?
+            .with_timezone(&Utc);
+        // 明細の単価は注文と同じ通貨で保存している
+        let currency = Currency::parse(&entity.currency)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", entity.currency)))?;
+        let tax_mode = TaxMode::parse(&entity.tax_mode)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax mode: {}", entity.tax_mode)))?;
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                let unit_price = Money::new(line.unit_price as u64, currency);
+                let discount = Money::new(line.discount as u64, currency);
+                let tax_category = TaxCategory::parse(&line.tax_category)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax category: {}", line.tax_category)))?;
+                let tax_rate = TaxRate::from_millionths(line.tax_rate)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("invalid tax rate: {}", line.tax_rate)))?;
+                Ok(OrderLine::new(line.product_id, line.product_name, unit_price, line.quantity)
+                    .with_sku(line.sku)
+                    .with_discount(discount, line.promotion_id)
+                    .with_tax(tax_category, tax_rate, Money::new(line.tax as u64, currency)))
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        // 小計・税額・総額は明細から集計し直す
+        Order::new(entity.id, lines, entity.tax_region, tax_mode, status, placed_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> OrderEntity {
+        OrderEntity {
+            id: row.get::<i64, _>("id") as u32,
+            status: row.get("status"),
+            tax_region: row.get("tax_region"),
+            tax_mode: row.get("tax_mode"),
+            subtotal: row.get("subtotal"),
+            tax: row.get("tax"),
+            total: row.get("total"),
+            currency: row.get("currency"),
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+        }
+    }
+
+    fn row_to_line_entity(row: &PgRow) -> OrderLineEntity {
+        OrderLineEntity {
+            order_id: row.get::<i64, _>("order_id") as u32,
+            product_id: row.get::<i64, _>("product_id") as u32,
+            product_name: row.get("product_name"),
+            sku: row.get("sku"),
+            unit_price: row.get("unit_price"),
+            quantity: row.get::<i64, _>("quantity") as u32,
+            discount: row.get("discount"),
+            promotion_id: row.get::<Option<i64>, _>("promotion_id").map(|id| id as u32),
+            tax_category: row.get("tax_category"),
+            tax_rate: row.get::<i64, _>("tax_rate") as u32,
+            tax: row.get("tax"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderRepository for PostgresOrderRepository {
+    #[tracing::instrument(name = "order_repository.place", skip(self, order), fields(lines = order.lines.len()), err(level = "warn"))]
+    async fn place(&self, order: Order, expected_versions: &HashMap<u32, u32>) -> Result<Order, RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let order = Self::place_in(&mut tx, order, expected_versions).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+
+    #[tracing::instrument(name = "order_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Order>, RepositoryError> {
+        let Some(row) = sqlx::query("SELECT * FROM orders WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let lines = sqlx::query("SELECT * FROM order_lines WHERE order_id = $1 ORDER BY id")
+            .bind(i64::from(id))
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_line_entity)
+            .collect();
+
+        Self::entity_to_domain(Self::row_to_entity(&row), lines).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::commands::BuyProductCommand;
+    use crate::application::error::ApplicationError;
+    use crate::application::repositories::{ProductRepository, PromotionRepository};
+    use crate::application::use_cases::BuyProductUseCase;
+    use crate::domain::models::{DiscountRule, Product, Promotion};
+    use crate::domain::DomainError;
+    use crate::domain::tax::{TaxRegion, TaxTable};
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{
+        PostgresCategoryRepository, PostgresProductRepository, PostgresPromotionRepository,
+    };
+
+    // TEST_POSTGRES_URL が設定されていない場合は `None`（テストは何もせずに成功する）
+    async fn repositories() -> Option<(PostgresProductRepository, PostgresOrderRepository, PgPool)> {
+        let pool = init_test_postgres().await.unwrap()?;
+        let categories = Arc::new(PostgresCategoryRepository::new(pool.clone()));
+        Some((PostgresProductRepository::new(pool.clone(), categories), PostgresOrderRepository::new(pool.clone()), pool))
+    }
+
+    async fn insert_product(products: &PostgresProductRepository, name: &str, price: Money, quantity: u32) -> Product {
+        let product = Product::create(name.to_string(), price, "test product".to_string(), quantity).unwrap();
+        products.save(product).await.unwrap()
+    }
+
+    fn line(product: &Product, quantity: u32) -> OrderLine {
+        OrderLine::new(product.id, product.name.clone(), product.price, quantity)
+    }
+
+    /// 注文と明細が保存され、在庫が同じデータベースの商品から減ること
+    #[tokio::test]
+    async fn place_persists_order_and_decrements_stock() {
+        let Some((products, repository, _)) = repositories().await else { return };
+        let product = insert_product(&products, "pg order snapshot", Money::new(1200, Currency::Jpy), 5).await;
+
+        let order = Order::place(vec![line(&product, 2)], &TaxRegion::untaxed()).unwrap();
+        let placed = repository.place(order, &HashMap::new()).await.unwrap();
+
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 3);
+        let order = repository.find_by_id(placed.id).await.unwrap().unwrap();
+        assert_eq!(order.status, OrderStatus::Placed);
+        assert_eq!(order.placed_at, placed.placed_at);
+        assert_eq!(order.total, Money::new(2400, Currency::Jpy));
+        assert_eq!(order.lines, placed.lines);
+    }
+
+    /// 1明細でも在庫が足りない場合や期待するバージョンと異なる場合は、どの在庫も減らないこと
+    #[tokio::test]
+    async fn place_rolls_back_on_conflict() {
+        let Some((products, repository, _)) = repositories().await else { return };
+        let enough = insert_product(&products, "pg order enough", Money::new(100, Currency::Jpy), 5).await;
+        let short = insert_product(&products, "pg order short", Money::new(100, Currency::Jpy), 1).await;
+
+        let order = Order::place(vec![line(&enough, 2), line(&short, 2)], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(order, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+
+        let order = Order::place(vec![line(&enough, 1)], &TaxRegion::untaxed()).unwrap();
+        let stale = HashMap::from([(enough.id, enough.version + 1)]);
+        assert!(matches!(repository.place(order, &stale).await, Err(RepositoryError::Conflict)));
+
+        assert_eq!(products.find_by_id(enough.id).await.unwrap().unwrap().quantity, 5);
+        assert_eq!(products.find_by_id(short.id).await.unwrap().unwrap().quantity, 1);
+    }
+
+    /// 利用上限に達したプロモーションを使う注文は作られず、在庫も減らないこと
+    #[tokio::test]
+    async fn place_enforces_promotion_usage_limit() {
+        let Some((products, repository, pool)) = repositories().await else { return };
+        let promotions = PostgresPromotionRepository::new(pool);
+        let product = insert_product(&products, "pg order promoted", Money::new(1000, Currency::Jpy), 5).await;
+        let rule = DiscountRule::PercentOff { percent: 10 };
+        let promotion = Promotion::create("pg once".to_string(), rule, Some(product.id), None, None, None, Some(1)).unwrap();
+        let promotion = promotions.save(promotion).await.unwrap();
+        let discounted = || line(&product, 1).with_discount(Money::new(100, Currency::Jpy), Some(promotion.id));
+
+        let placed = repository.place(Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap(), &HashMap::new()).await.unwrap();
+        assert_eq!(repository.find_by_id(placed.id).await.unwrap().unwrap().lines[0].promotion_id, Some(promotion.id));
+
+        let second = Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(second, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+        assert_eq!(promotions.find_by_id(promotion.id).await.unwrap().unwrap().usage_count, 1);
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 4);
+    }
+
+    /// 同時購入でも在庫を超えて販売されないこと
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn concurrent_buys_never_oversell() {
+        const STOCK: u32 = 10;
+        const BUYERS: usize = 40;
+
+        let Some((products, orders, pool)) = repositories().await else { return };
+        let product = insert_product(&products, "pg concurrent", Money::new(100, Currency::Jpy), STOCK).await;
+        let products = Arc::new(products);
+        let use_case = Arc::new(BuyProductUseCase::new(
+            products.clone(),
+            Arc::new(orders),
+            Arc::new(PostgresPromotionRepository::new(pool)),
+            Arc::new(TaxTable::untaxed()),
+        ));
+
+        let handles: Vec<_> = (0..BUYERS)
+            .map(|_| {
+                let use_case = use_case.clone();
+                tokio::spawn(async move {
+                    let command = BuyProductCommand { quantity: 1, sku: None, expected_version: None, coupon_code: None, region: None };
+                    use_case.buy(product.id, command).await
+                })
+            })
+            .collect();
+
+        let mut sold = 0;
+        for handle in handles {
+            match handle.await.unwrap() {
+                Ok(_) => sold += 1,
+                Err(ApplicationError::Domain(DomainError::InsufficientQuantity { .. })) => {}
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+
+        assert_eq!(sold, STOCK);
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 0);
+    }
+}
@@ -0,0 +1,546 @@
+use std::collections::{BTreeMap, HashSet};
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::postgres::{PgConnection, PgRow};
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+
+use crate::domain::models::{Currency, Money, Product, ProductVariant};
+use crate::domain::tax::TaxCategory;
+use crate::frameworks_and_drivers::persistence::entities::{ProductEntity, ProductVariantEntity};
+use crate::application::repositories::{
+    CategoryRepository, Page, ProductFilter, ProductListCriteria, ProductRepository, ProductSearchHit,
+    ProductSortKey, SortOrder,
+};
+use crate::application::error::RepositoryError;
+use super::search_highlight::{marked_to_html, MARK_END, MARK_START};
+
+/// 検索結果の抜粋に含める語数
+const SNIPPET_WORDS: u32 = 16;
+
+/// PostgreSQLに商品を保存するリポジトリ（`postgres` feature）
+/// カテゴリは関連付けのIDだけを保持し、読み込み時に `CategoryRepository` から解決する
+/// 整数の列はすべてBIGINTで、`u32` の値をそのまま保存する
+pub struct PostgresProductRepository {
+    pool: PgPool,
+    category_repository: Arc<dyn CategoryRepository + Send + Sync>,
+}
+
+impl PostgresProductRepository {
+    pub fn new(pool: PgPool, category_repository: Arc<dyn CategoryRepository + Send + Sync>) -> Self {
+        Self {
+            pool,
+            category_repository,
+        }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: ProductEntity) -> Result<Product, RepositoryError> {
+        let currency = Currency::parse(&entity.currency)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", entity.currency)))?;
+        let tax_category = TaxCategory::parse(&entity.tax_category)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax category: {}", entity.tax_category)))?;
+
+        Ok(Product::new(
+            entity.id,
+            entity.name,
+            Money::new(entity.price as u64, currency),
+            entity.description,
+            entity.quantity,
+            entity.reserved,
+            entity.version,
+        )
+        .with_tax_category(tax_category))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> ProductEntity {
+        ProductEntity {
+            id: row.get::<i64, _>("id") as u32,
+            name: row.get("name"),
+            price: row.get("price"),
+            currency: row.get("currency"),
+            tax_category: row.get("tax_category"),
+            description: row.get("description"),
+            quantity: row.get::<i64, _>("quantity") as u32,
+            reserved: row.get::<i64, _>("reserved") as u32,
+            version: row.get::<i64, _>("version") as u32,
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at").to_rfc3339(),
+        }
+    }
+
+    // バリエーションのエンティティからドメインモデルへのマッピング
+    // 価格の通貨は商品と同じ
+    fn variant_to_domain(entity: ProductVariantEntity, currency: Currency) -> Result<ProductVariant, RepositoryError> {
+        let attributes: BTreeMap<String, String> = serde_json::from_str(&entity.attributes)
+            .map_err(|e| RepositoryError::Unknown(format!("invalid attributes of variant {}: {}", entity.sku, e)))?;
+
+        Ok(ProductVariant::new(&entity.sku, attributes, Money::new(entity.price as u64, currency), entity.quantity))
+    }
+
+    fn row_to_variant_entity(row: &PgRow) -> ProductVariantEntity {
+        ProductVariantEntity {
+            product_id: row.get::<i64, _>("product_id") as u32,
+            sku: row.get("sku"),
+            attributes: row.get("attributes"),
+            price: row.get("price"),
+            quantity: row.get::<i64, _>("quantity") as u32,
+        }
+    }
+
+    // 指定したカテゴリとその子孫のカテゴリのID
+    async fn category_subtree(&self, category_id: u32) -> Result<Vec<i64>, RepositoryError> {
+        let categories = self.category_repository.find_all().await?;
+        let mut subtree = HashSet::from([category_id]);
+        let mut pending = vec![category_id];
+        while let Some(parent_id) = pending.pop() {
+            for category in categories.iter().filter(|category| category.parent_id == Some(parent_id)) {
+                if subtree.insert(category.id) {
+                    pending.push(category.id);
+                }
+            }
+        }
+        Ok(subtree.into_iter().map(i64::from).collect())
+    }
+
+    // 絞り込み条件をWHERE句として追加
+    // カテゴリで絞り込む場合は、子孫のカテゴリを含めたIDを `subtree` に渡す
+    fn push_filter(query: &mut QueryBuilder<'_, Postgres>, filter: &ProductFilter, subtree: Option<&[i64]>) {
+        query.push(" WHERE 1 = 1");
+        if let Some(currency) = filter.currency {
+            query.push(" AND currency = ").push_bind(currency.code());
+        }
+        if let Some(min_price) = filter.min_price {
+            query.push(" AND price >= ").push_bind(i64::from(min_price));
+        }
+        if let Some(max_price) = filter.max_price {
+            query.push(" AND price <= ").push_bind(i64::from(max_price));
+        }
+        if filter.in_stock_only {
+            query.push(" AND quantity > 0");
+        }
+        if let Some(subtree) = subtree {
+            query
+                .push(" AND id IN (SELECT product_id FROM product_categories WHERE category_id = ANY(")
+                .push_bind(subtree.to_vec())
+                .push("))");
+        }
+        if let Some(tag) = &filter.tag {
+            query
+                .push(" AND id IN (SELECT pt.product_id FROM product_tags pt JOIN tags t ON t.id = pt.tag_id WHERE t.name = ")
+                .push_bind(tag.clone())
+                .push(")");
+        }
+    }
+
+    // 商品のカテゴリ・タグ・バリエーションをまとめて読み込む
+    // 削除されたカテゴリとの関連は除く
+    async fn load_relations(&self, mut products: Vec<&mut Product>) -> Result<(), RepositoryError> {
+        if products.is_empty() {
+            return Ok(());
+        }
+        let ids: Vec<i64> = products.iter().map(|product| i64::from(product.id)).collect();
+
+        let category_rows = sqlx::query(
+            "SELECT product_id, category_id FROM product_categories WHERE product_id = ANY($1) ORDER BY category_id"
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        let mut category_ids: Vec<u32> = category_rows
+            .iter()
+            .map(|row| row.get::<i64, _>("category_id") as u32)
+            .collect();
+        category_ids.sort_unstable();
+        category_ids.dedup();
+        let categories = self.category_repository.find_by_ids(&category_ids).await?;
+
+        let tag_rows = sqlx::query(
+            "SELECT pt.product_id, t.name FROM product_tags pt \
+             JOIN tags t ON t.id = pt.tag_id WHERE pt.product_id = ANY($1) ORDER BY t.name"
+        )
+        .bind(&ids)
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let variant_rows = sqlx::query("SELECT * FROM product_variants WHERE product_id = ANY($1) ORDER BY id")
+            .bind(&ids)
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        for product in products.iter_mut() {
+            let product_id = i64::from(product.id);
+            let linked: Vec<u32> = category_rows
+                .iter()
+                .filter(|row| row.get::<i64, _>("product_id") == product_id)
+                .map(|row| row.get::<i64, _>("category_id") as u32)
+                .collect();
+            product.categories = categories
+                .iter()
+                .filter(|category| linked.contains(&category.id))
+                .cloned()
+                .collect();
+            product.tags = tag_rows
+                .iter()
+                .filter(|row| row.get::<i64, _>("product_id") == product_id)
+                .map(|row| row.get("name"))
+                .collect();
+            product.variants = variant_rows
+                .iter()
+                .filter(|row| row.get::<i64, _>("product_id") == product_id)
+                .map(|row| Self::variant_to_domain(Self::row_to_variant_entity(row), product.price.currency()))
+                .collect::<Result<Vec<_>, RepositoryError>>()?;
+        }
+        Ok(())
+    }
+
+    // 商品のカテゴリとタグの関連付け、バリエーションを置き換える
+    async fn replace_relations(conn: &mut PgConnection, product: &Product) -> Result<(), RepositoryError> {
+        let product_id = i64::from(product.id);
+
+        sqlx::query("DELETE FROM product_categories WHERE product_id = $1")
+            .bind(product_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        for category in &product.categories {
+            sqlx::query("INSERT INTO product_categories (product_id, category_id) VALUES ($1, $2) ON CONFLICT DO NOTHING")
+                .bind(product_id)
+                .bind(i64::from(category.id))
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        sqlx::query("DELETE FROM product_tags WHERE product_id = $1")
+            .bind(product_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        for tag in &product.tags {
+            sqlx::query("INSERT INTO tags (name) VALUES ($1) ON CONFLICT (name) DO NOTHING")
+                .bind(tag)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+            sqlx::query(
+                "INSERT INTO product_tags (product_id, tag_id) SELECT $1, id FROM tags WHERE name = $2 ON CONFLICT DO NOTHING"
+            )
+            .bind(product_id)
+            .bind(tag)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        // 残すバリエーションはSKUで更新し、在庫の変化をidで追えるようにする
+        let skus: Vec<String> = product.variants.iter().map(|variant| variant.sku.clone()).collect();
+        sqlx::query("DELETE FROM product_variants WHERE product_id = $1 AND sku <> ALL($2)")
+            .bind(product_id)
+            .bind(&skus)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let now = Utc::now();
+        for variant in &product.variants {
+            let attributes = serde_json::to_string(&variant.attributes)
+                .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+            // 他の商品が同じSKUを使っている場合は更新されない
+            let result = sqlx::query(
+                "INSERT INTO product_variants (product_id, sku, attributes, price, quantity, created_at, updated_at) \
+                 VALUES ($1, $2, $3, $4, $5, $6, $7) \
+                 ON CONFLICT (sku) DO UPDATE SET attributes = excluded.attributes, price = excluded.price, \
+                 quantity = excluded.quantity, updated_at = excluded.updated_at \
+                 WHERE product_variants.product_id = excluded.product_id"
+            )
+            .bind(product_id)
+            .bind(&variant.sku)
+            .bind(attributes)
+            .bind(variant.price.amount() as i64)
+            .bind(i64::from(variant.quantity))
+            .bind(now)
+            .bind(now)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    // 入力されたキーワードをtsqueryの式に変換
+    // 各語を英数字の語に分けてフレーズとして連結し、最後の語を前方一致させる
+    // 英数字以外は取り除くため、演算子として解釈されることはない
+    fn to_tsquery(keyword: &str) -> Option<String> {
+        let phrases: Vec<String> = keyword
+            .split_whitespace()
+            .filter_map(|term| {
+                let words: Vec<&str> = term.split(|c: char| !c.is_alphanumeric()).filter(|word| !word.is_empty()).collect();
+                (!words.is_empty()).then(|| format!("({}:*)", words.join(" <-> ")))
+            })
+            .collect();
+
+        (!phrases.is_empty()).then(|| phrases.join(" & "))
+    }
+
+    fn sort_column(key: ProductSortKey) -> &'static str {
+        match key {
+            ProductSortKey::Id => "id",
+            ProductSortKey::Name => "name",
+            ProductSortKey::Price => "price",
+            ProductSortKey::CreatedAt => "created_at",
+        }
+    }
+
+    fn sort_direction(order: SortOrder) -> &'static str {
+        match order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl ProductRepository for PostgresProductRepository {
+    #[tracing::instrument(name = "product_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self, criteria: &ProductListCriteria) -> Result<Page<Product>, RepositoryError> {
+        let subtree = match criteria.filter.category_id {
+            Some(category_id) => Some(self.category_subtree(category_id).await?),
+            None => None,
+        };
+
+        let mut count_query = QueryBuilder::<Postgres>::new("SELECT COUNT(*) FROM products");
+        Self::push_filter(&mut count_query, &criteria.filter, subtree.as_deref());
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM products");
+        Self::push_filter(&mut query, &criteria.filter, subtree.as_deref());
+        // 並び替えキーが同じ値の場合もページ間で順序が揺れないようにidを第2キーにする
+        // 照合順序による差が出ないよう、名前はバイト順で比較する（SQLite実装と同じ順序）
+        let sort_column = match criteria.sort_key {
+            ProductSortKey::Name => "name COLLATE \"C\"",
+            key => Self::sort_column(key),
+        };
+        // 価格は通貨ごとの最小単位のため、通貨をまたいで比較せず通貨ごとにまとめる
+        let currency_group = if criteria.sort_key == ProductSortKey::Price { "currency COLLATE \"C\" ASC, " } else { "" };
+        query.push(format!(
+            " ORDER BY {}{} {}, id {}",
+            currency_group,
+            sort_column,
+            Self::sort_direction(criteria.sort_order),
+            Self::sort_direction(criteria.sort_order),
+        ));
+        query.push(" LIMIT ").push_bind(i64::from(criteria.pagination.limit()));
+        query.push(" OFFSET ").push_bind(i64::try_from(criteria.pagination.offset()).unwrap_or(i64::MAX));
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut products = rows
+            .iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect::<Result<Vec<Product>, RepositoryError>>()?;
+        self.load_relations(products.iter_mut().collect()).await?;
+
+        Ok(Page {
+            items: products,
+            total: total as u64,
+            pagination: criteria.pagination,
+        })
+    }
+
+    /// 関連度はts_rank（商品名の一致を説明より重く評価する）
+    #[tracing::instrument(name = "product_repository.search", skip(self), err(level = "warn"))]
+    async fn search(&self, keyword: &str, limit: u32) -> Result<Vec<ProductSearchHit>, RepositoryError> {
+        let Some(tsquery) = Self::to_tsquery(keyword) else {
+            return Ok(Vec::new());
+        };
+
+        let rows = sqlx::query(
+            r#"
+            SELECT p.*,
+                   ts_rank(p.search_vector, q)::float8 AS rank,
+                   ts_headline('simple', p.name, q, $2) AS highlighted_name,
+                   ts_headline('simple', p.description, q, $3) AS snippet
+            FROM products p, to_tsquery('simple', $1) q
+            WHERE p.search_vector @@ q
+            ORDER BY rank DESC, p.id
+            LIMIT $4
+            "#
+        )
+        .bind(tsquery)
+        .bind(format!("StartSel={MARK_START}, StopSel={MARK_END}, HighlightAll=true"))
+        .bind(format!(
+            "StartSel={MARK_START}, StopSel={MARK_END}, MaxWords={}, MinWords={}, ShortWord=0",
+            SNIPPET_WORDS,
+            SNIPPET_WORDS / 2
+        ))
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut hits = rows
+            .iter()
+            .map(|row| {
+                Ok(ProductSearchHit {
+                    product: Self::entity_to_domain(Self::row_to_entity(row))?,
+                    score: row.get("rank"),
+                    highlighted_name: marked_to_html(row.get("highlighted_name")),
+                    snippet: marked_to_html(row.get("snippet")),
+                })
+            })
+            .collect::<Result<Vec<ProductSearchHit>, RepositoryError>>()?;
+        self.load_relations(hits.iter_mut().map(|hit| &mut hit.product).collect()).await?;
+
+        Ok(hits)
+    }
+
+    #[tracing::instrument(name = "product_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Product>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM products WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut product = Self::entity_to_domain(Self::row_to_entity(&row))?;
+        self.load_relations(vec![&mut product]).await?;
+
+        Ok(Some(product))
+    }
+
+    #[tracing::instrument(name = "product_repository.find_by_sku", skip(self), err(level = "warn"))]
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<Product>, RepositoryError> {
+        let row = sqlx::query(
+            "SELECT p.* FROM products p JOIN product_variants v ON v.product_id = p.id WHERE v.sku = $1"
+        )
+        .bind(ProductVariant::normalize_sku(sku))
+        .fetch_optional(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut product = Self::entity_to_domain(Self::row_to_entity(&row))?;
+        self.load_relations(vec![&mut product]).await?;
+
+        Ok(Some(product))
+    }
+
+    #[tracing::instrument(name = "product_repository.save", skip(self, product), fields(product_id = product.id), err(level = "warn"))]
+    async fn save(&self, mut product: Product) -> Result<Product, RepositoryError> {
+        // 商品と関連付けを同時に更新する
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now();
+
+        if product.id == 0 {
+            // 新規作成
+            let id: i64 = sqlx::query_scalar(
+                "INSERT INTO products (name, price, currency, tax_category, description, quantity, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8) RETURNING id"
+            )
+            .bind(&product.name)
+            .bind(product.price.amount() as i64)
+            .bind(product.price.currency().code())
+            .bind(product.tax_category.as_str())
+            .bind(&product.description)
+            .bind(i64::from(product.quantity))
+            .bind(now)
+            .bind(now)
+            .fetch_one(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            product.id = id as u32;
+            product.version = 0;
+        } else {
+            // 更新（読み込み時のバージョンと一致する場合のみ）
+            let result = sqlx::query(
+                "UPDATE products SET name = $1, price = $2, currency = $3, tax_category = $4, description = $5, quantity = $6, version = version + 1, updated_at = $7 WHERE id = $8 AND version = $9"
+            )
+            .bind(&product.name)
+            .bind(product.price.amount() as i64)
+            .bind(product.price.currency().code())
+            .bind(product.tax_category.as_str())
+            .bind(&product.description)
+            .bind(i64::from(product.quantity))
+            .bind(now)
+            .bind(i64::from(product.id))
+            .bind(i64::from(product.version))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                // 更新されなかった理由が削除済みなのかバージョン不一致なのかを判定
+                let exists = sqlx::query("SELECT id FROM products WHERE id = $1")
+                    .bind(i64::from(product.id))
+                    .fetch_optional(&mut *tx)
+                    .await
+                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                return match exists {
+                    Some(_) => Err(RepositoryError::Conflict),
+                    None => Err(RepositoryError::NotFound),
+                };
+            }
+            product.version += 1;
+        }
+        Self::replace_relations(&mut tx, &product).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(product)
+    }
+
+    #[tracing::instrument(name = "product_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32, expected_version: Option<u32>) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM products WHERE id = $1 AND ($2::BIGINT IS NULL OR version = $2)")
+            .bind(i64::from(id))
+            .bind(expected_version.map(i64::from))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(());
+        }
+
+        // 削除されなかった理由が存在しないのかバージョン不一致なのかを判定
+        let exists = sqlx::query("SELECT id FROM products WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match exists {
+            Some(_) => Err(RepositoryError::Conflict),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+}
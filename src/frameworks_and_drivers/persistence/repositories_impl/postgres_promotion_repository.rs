@@ -0,0 +1,230 @@
+use chrono::{DateTime, Utc};
+use sqlx::postgres::PgRow;
+use sqlx::{PgPool, Postgres, QueryBuilder, Row};
+
+use crate::domain::models::{Currency, DiscountRule, Money, Promotion};
+use crate::frameworks_and_drivers::persistence::entities::PromotionEntity;
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLにプロモーションを保存するリポジトリ（`postgres` feature）
+pub struct PostgresPromotionRepository {
+    pool: PgPool,
+}
+
+impl PostgresPromotionRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: PromotionEntity) -> Result<Promotion, RepositoryError> {
+        let missing = |column: &str| RepositoryError::Unknown(format!("promotion {} has no {}", entity.id, column));
+        let rule = match entity.kind.as_str() {
+            "percent_off" => DiscountRule::PercentOff {
+                percent: entity.percent.ok_or_else(|| missing("percent"))?,
+            },
+            "amount_off" => {
+                let amount = entity.amount.ok_or_else(|| missing("amount"))?;
+                let code = entity.currency.as_deref().ok_or_else(|| missing("currency"))?;
+                let currency = Currency::parse(code)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", code)))?;
+                DiscountRule::AmountOff { amount: Money::new(amount as u64, currency) }
+            }
+            "buy_n_get_m" => DiscountRule::BuyNGetM {
+                buy: entity.buy_quantity.ok_or_else(|| missing("buy_quantity"))?,
+                get: entity.get_quantity.ok_or_else(|| missing("get_quantity"))?,
+            },
+            kind => return Err(RepositoryError::Unknown(format!("unknown promotion kind: {}", kind))),
+        };
+
+        Ok(Promotion::new(
+            entity.id,
+            entity.name,
+            rule,
+            entity.product_id,
+            entity.coupon_code,
+            Self::parse_timestamp(entity.starts_at)?,
+            Self::parse_timestamp(entity.ends_at)?,
+            entity.usage_limit,
+            entity.usage_count,
+        ))
+    }
+
+    fn parse_timestamp(value: Option<String>) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        value
+            .map(|value| {
+                DateTime::parse_from_rfc3339(&value)
+                    .map(|at| at.with_timezone(&Utc))
+                    .map_err(|e| RepositoryError::Unknown(e.to_string()))
+            })
+            .transpose()
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> PromotionEntity {
+        let optional = |column: &str| row.get::<Option<i64>, _>(column).map(|value| value as u32);
+        let timestamp = |column: &str| row.get::<Option<DateTime<Utc>>, _>(column).map(|at| at.to_rfc3339());
+        PromotionEntity {
+            id: row.get::<i64, _>("id") as u32,
+            name: row.get("name"),
+            kind: row.get("kind"),
+            percent: optional("percent"),
+            amount: row.get("amount"),
+            currency: row.get("currency"),
+            buy_quantity: optional("buy_quantity"),
+            get_quantity: optional("get_quantity"),
+            product_id: optional("product_id"),
+            coupon_code: row.get("coupon_code"),
+            starts_at: timestamp("starts_at"),
+            ends_at: timestamp("ends_at"),
+            usage_limit: optional("usage_limit"),
+            usage_count: row.get::<i64, _>("usage_count") as u32,
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+        }
+    }
+
+    fn rows_to_domain(rows: &[PgRow]) -> Result<Vec<Promotion>, RepositoryError> {
+        rows.iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl PromotionRepository for PostgresPromotionRepository {
+    #[tracing::instrument(name = "promotion_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self) -> Result<Vec<Promotion>, RepositoryError> {
+        let rows = sqlx::query("SELECT * FROM promotions ORDER BY id")
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Self::rows_to_domain(&rows)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Promotion>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM promotions WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        row.map(|row| Self::entity_to_domain(Self::row_to_entity(&row))).transpose()
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_candidates", skip(self), err(level = "warn"))]
+    async fn find_candidates(&self, product_ids: &[u32], coupon_code: Option<&str>) -> Result<Vec<Promotion>, RepositoryError> {
+        let product_ids: Vec<i64> = product_ids.iter().copied().map(i64::from).collect();
+        let mut query = QueryBuilder::<Postgres>::new("SELECT * FROM promotions WHERE (product_id IS NULL OR product_id = ANY(");
+        query.push_bind(product_ids);
+        query.push(")) AND (coupon_code IS NULL");
+        if let Some(coupon_code) = coupon_code {
+            query.push(" OR coupon_code = ").push_bind(Promotion::normalize_code(coupon_code));
+        }
+        query.push(") ORDER BY id");
+
+        let rows = query
+            .build()
+            .fetch_all(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Self::rows_to_domain(&rows)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.save", skip(self, promotion), err(level = "warn"))]
+    async fn save(&self, mut promotion: Promotion) -> Result<Promotion, RepositoryError> {
+        let (percent, amount, buy_quantity, get_quantity) = match promotion.rule {
+            DiscountRule::PercentOff { percent } => (Some(percent), None, None, None),
+            DiscountRule::AmountOff { amount } => (None, Some(amount), None, None),
+            DiscountRule::BuyNGetM { buy, get } => (None, None, Some(buy), Some(get)),
+        };
+
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO promotions \
+             (name, kind, percent, amount, currency, buy_quantity, get_quantity, product_id, coupon_code, starts_at, ends_at, usage_limit, usage_count, created_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) RETURNING id"
+        )
+        .bind(&promotion.name)
+        .bind(promotion.rule.kind())
+        .bind(percent.map(i64::from))
+        .bind(amount.map(|amount| amount.amount() as i64))
+        .bind(amount.map(|amount| amount.currency().code()))
+        .bind(buy_quantity.map(i64::from))
+        .bind(get_quantity.map(i64::from))
+        .bind(promotion.product_id.map(i64::from))
+        .bind(&promotion.coupon_code)
+        .bind(promotion.starts_at)
+        .bind(promotion.ends_at)
+        .bind(promotion.usage_limit.map(i64::from))
+        .bind(i64::from(promotion.usage_count))
+        .bind(Utc::now())
+        .fetch_one(&self.pool)
+        .await
+        .map_err(|e| match e.as_database_error() {
+            Some(db_error) if db_error.is_unique_violation() => RepositoryError::Conflict,
+            _ => RepositoryError::QueryExecution(e.to_string()),
+        })?;
+
+        promotion.id = id as u32;
+        Ok(promotion)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM promotions WHERE id = $1")
+            .bind(i64::from(id))
+            .execute(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::repositories::ProductRepository;
+    use crate::domain::models::Product;
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{PostgresCategoryRepository, PostgresProductRepository};
+
+    fn promotion(product_id: u32, coupon_code: Option<&str>) -> Promotion {
+        let rule = DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) };
+        Promotion::create("test".to_string(), rule, Some(product_id), coupon_code.map(str::to_string), None, None, None)
+            .unwrap()
+    }
+
+    /// 保存したルールが読み込まれ、クーポン付きの候補はコードが一致した場合だけ返り、同じコードは保存できないこと
+    #[tokio::test]
+    async fn save_and_find_candidates() {
+        let Some(pool) = init_test_postgres().await.unwrap() else { return };
+        let products = PostgresProductRepository::new(pool.clone(), Arc::new(PostgresCategoryRepository::new(pool.clone())));
+        let repository = PostgresPromotionRepository::new(pool);
+        let product = Product::create("pg promotion".to_string(), Money::new(1000, Currency::Jpy), "test product".to_string(), 5).unwrap();
+        let product = products.save(product).await.unwrap();
+
+        let automatic = repository.save(promotion(product.id, None)).await.unwrap();
+        let coupon = repository.save(promotion(product.id, Some("pg-candidate"))).await.unwrap();
+        let found = repository.find_by_id(coupon.id).await.unwrap().unwrap();
+        assert_eq!(found.rule, DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) });
+        assert_eq!(found.coupon_code.as_deref(), Some("PG-CANDIDATE"));
+
+        let ids = |promotions: Vec<Promotion>| promotions.iter().map(|p| p.id).collect::<Vec<_>>();
+        let without_code = repository.find_candidates(&[product.id], None).await.unwrap();
+        assert_eq!(ids(without_code), vec![automatic.id]);
+        let with_code = repository.find_candidates(&[product.id], Some("pg-candidate")).await.unwrap();
+        assert_eq!(ids(with_code), vec![automatic.id, coupon.id]);
+
+        let duplicate = repository.save(promotion(product.id, Some("PG-CANDIDATE"))).await;
+        assert!(matches!(duplicate, Err(RepositoryError::Conflict)));
+    }
+}
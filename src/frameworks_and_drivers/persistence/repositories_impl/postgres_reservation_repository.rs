@@ -0,0 +1,384 @@
+use chrono::{DateTime, SubsecRound, Utc};
+use sqlx::postgres::{PgConnection, PgRow};
+use sqlx::{PgPool, Row};
+
+use crate::domain::models::{Order, Reservation, ReservationItem, ReservationStatus};
+use crate::frameworks_and_drivers::persistence::entities::{ReservationEntity, ReservationItemEntity};
+use crate::frameworks_and_drivers::persistence::repositories_impl::PostgresOrderRepository;
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::RepositoryError;
+
+/// PostgreSQLに在庫予約を保存するリポジトリ（`postgres` feature）
+/// 在庫の確保・戻しは商品と同じデータベースで、予約の保存と同じトランザクション内で行う
+pub struct PostgresReservationRepository {
+    pool: PgPool,
+}
+
+impl PostgresReservationRepository {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: ReservationEntity, items: Vec<ReservationItemEntity>) -> Result<Reservation, RepositoryError> {
+        let status = ReservationStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown reservation status: {}", entity.status)))?;
+        let expires_at = DateTime::parse_from_rfc3339(&entity.expires_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?
+            .with_timezone(&Utc);
+        let items = items
+            .into_iter()
+            .map(|item| ReservationItem { product_id: item.product_id, sku: item.sku, quantity: item.quantity })
+            .collect();
+
+        Ok(Reservation::new(entity.id, items, status, expires_at, entity.order_id))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &PgRow) -> ReservationEntity {
+        ReservationEntity {
+            id: row.get::<i64, _>("id") as u32,
+            status: row.get("status"),
+            expires_at: row.get::<DateTime<Utc>, _>("expires_at").to_rfc3339(),
+            order_id: row.get::<Option<i64>, _>("order_id").map(|id| id as u32),
+            created_at: row.get::<DateTime<Utc>, _>("created_at").to_rfc3339(),
+            updated_at: row.get::<DateTime<Utc>, _>("updated_at").to_rfc3339(),
+        }
+    }
+
+    fn row_to_item_entity(row: &PgRow) -> ReservationItemEntity {
+        ReservationItemEntity {
+            product_id: row.get::<i64, _>("product_id") as u32,
+            sku: row.get("sku"),
+            quantity: row.get::<i64, _>("quantity") as u32,
+        }
+    }
+
+    // 行と予約された商品を読み込んでドメインモデルにする
+    async fn load(pool: &PgPool, row: &PgRow) -> Result<Reservation, RepositoryError> {
+        let entity = Self::row_to_entity(row);
+        let items = sqlx::query("SELECT * FROM reservation_items WHERE reservation_id = $1 ORDER BY position")
+            .bind(i64::from(entity.id))
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_item_entity)
+            .collect();
+
+        Self::entity_to_domain(entity, items)
+    }
+
+    // 有効な予約の状態を変更する。既に有効でなければ競合とする
+    async fn close_in(
+        conn: &mut PgConnection,
+        reservation: &Reservation,
+        order_id: Option<u32>,
+        now: DateTime<Utc>,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE reservations SET status = $1, order_id = $2, updated_at = $3 WHERE id = $4 AND status = $5"
+        )
+        .bind(reservation.status.as_str())
+        .bind(order_id.map(i64::from))
+        .bind(now)
+        .bind(i64::from(reservation.id))
+        .bind(ReservationStatus::Active.as_str())
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::Conflict);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationRepository for PostgresReservationRepository {
+    #[tracing::instrument(name = "reservation_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Reservation>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM reservations WHERE id = $1")
+            .bind(i64::from(id))
+            .fetch_optional(&self.pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match row {
+            Some(row) => Self::load(&self.pool, &row).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "reservation_repository.find_expired", skip(self), err(level = "warn"))]
+    async fn find_expired(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<Reservation>, RepositoryError> {
+        let rows = sqlx::query(
+            "SELECT * FROM reservations WHERE status = $1 AND expires_at <= $2 ORDER BY expires_at, id LIMIT $3"
+        )
+        .bind(ReservationStatus::Active.as_str())
+        .bind(now)
+        .bind(i64::from(limit))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut reservations = Vec::with_capacity(rows.len());
+        for row in &rows {
+            reservations.push(Self::load(&self.pool, row).await?);
+        }
+        Ok(reservations)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.reserve", skip(self, reservation), fields(items = reservation.items.len()), err(level = "warn"))]
+    async fn reserve(&self, mut reservation: Reservation) -> Result<Reservation, RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now();
+        // 保存する精度に揃え、返す予約と読み込んだ予約で期限が一致するようにする
+        reservation.expires_at = reservation.expires_at.trunc_subsecs(6);
+
+        // 商品ごとに在庫チェックと確保を1つのUPDATEで行う
+        // 1件でも条件を満たさなければトランザクションごと破棄する
+        for item in &reservation.items {
+            // バリエーションの在庫を減らし、商品の在庫（バリエーションの合計）からも同じだけ確保する
+            if let Some(sku) = &item.sku {
+                let result = sqlx::query(
+                    "UPDATE product_variants SET quantity = quantity - $1, updated_at = $2 \
+                     WHERE product_id = $3 AND sku = $4 AND quantity >= $1"
+                )
+                .bind(i64::from(item.quantity))
+                .bind(now)
+                .bind(i64::from(item.product_id))
+                .bind(sku)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                if result.rows_affected() == 0 {
+                    return Err(RepositoryError::Conflict);
+                }
+            }
+
+            let result = sqlx::query(
+                "UPDATE products SET quantity = quantity - $1, reserved = reserved + $1, version = version + 1, updated_at = $2 \
+                 WHERE id = $3 AND quantity >= $1"
+            )
+            .bind(i64::from(item.quantity))
+            .bind(now)
+            .bind(i64::from(item.product_id))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        let id: i64 = sqlx::query_scalar(
+            "INSERT INTO reservations (status, expires_at, created_at, updated_at) VALUES ($1, $2, $3, $3) RETURNING id"
+        )
+        .bind(reservation.status.as_str())
+        .bind(reservation.expires_at)
+        .bind(now)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        reservation.id = id as u32;
+
+        for (position, item) in reservation.items.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO reservation_items (reservation_id, product_id, sku, quantity, position) VALUES ($1, $2, $3, $4, $5)"
+            )
+            .bind(id)
+            .bind(i64::from(item.product_id))
+            .bind(&item.sku)
+            .bind(i64::from(item.quantity))
+            .bind(position as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(reservation)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.confirm", skip(self, reservation, order), fields(reservation_id = reservation.id), err(level = "warn"))]
+    async fn confirm(&self, reservation: &Reservation, order: Order) -> Result<Order, RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now();
+
+        // 在庫は予約時に販売可能な在庫から除いてあるため、注文の保存では減算しない
+        let order = PostgresOrderRepository::insert_in(&mut tx, order).await?;
+        Self::close_in(&mut tx, reservation, Some(order.id), now).await?;
+
+        for item in &reservation.items {
+            let result = sqlx::query(
+                "UPDATE products SET reserved = reserved - $1, updated_at = $2 WHERE id = $3 AND reserved >= $1"
+            )
+            .bind(i64::from(item.quantity))
+            .bind(now)
+            .bind(i64::from(item.product_id))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.release", skip(self, reservation), fields(reservation_id = reservation.id, status = reservation.status.as_str()), err(level = "warn"))]
+    async fn release(&self, reservation: &Reservation) -> Result<(), RepositoryError> {
+        let mut tx = self.pool
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now();
+
+        Self::close_in(&mut tx, reservation, None, now).await?;
+
+        // 予約後に商品が削除されている場合は戻す先がないため、更新されなくても無視する
+        for item in &reservation.items {
+            // バリエーションが削除されている場合は、その在庫ごと商品から取り除かれているため予約数だけ減らす
+            let restock = match &item.sku {
+                Some(sku) => {
+                    sqlx::query(
+                        "UPDATE product_variants SET quantity = quantity + $1, updated_at = $2 WHERE product_id = $3 AND sku = $4"
+                    )
+                    .bind(i64::from(item.quantity))
+                    .bind(now)
+                    .bind(i64::from(item.product_id))
+                    .bind(sku)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+                    .rows_affected()
+                        > 0
+                }
+                None => true,
+            };
+
+            sqlx::query(
+                "UPDATE products SET quantity = quantity + $1, reserved = reserved - $2, version = version + 1, updated_at = $3 \
+                 WHERE id = $4 AND reserved >= $2"
+            )
+            .bind(if restock { i64::from(item.quantity) } else { 0 })
+            .bind(i64::from(item.quantity))
+            .bind(now)
+            .bind(i64::from(item.product_id))
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::application::repositories::ProductRepository;
+    use crate::domain::models::{Currency, Money, OrderLine, Product, ProductVariant};
+    use crate::domain::tax::TaxRegion;
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{PostgresCategoryRepository, PostgresProductRepository};
+
+    // TEST_POSTGRES_URL が設定されていない場合は `None`（テストは何もせずに成功する）
+    async fn repositories() -> Option<(PostgresProductRepository, PostgresReservationRepository)> {
+        let pool = init_test_postgres().await.unwrap()?;
+        let categories = Arc::new(PostgresCategoryRepository::new(pool.clone()));
+        Some((PostgresProductRepository::new(pool.clone(), categories), PostgresReservationRepository::new(pool)))
+    }
+
+    async fn stock_of(products: &PostgresProductRepository, product: &Product) -> (u32, u32) {
+        let product = products.find_by_id(product.id).await.unwrap().unwrap();
+        (product.quantity, product.reserved)
+    }
+
+    fn reservation(items: &[(&Product, Option<&str>, u32)]) -> Reservation {
+        let items = items
+            .iter()
+            .map(|(product, sku, quantity)| ReservationItem { product_id: product.id, sku: sku.map(str::to_string), quantity: *quantity })
+            .collect();
+        Reservation::create(items, Utc::now() + Duration::minutes(5)).unwrap()
+    }
+
+    /// 予約した数量が確保され、1商品でも在庫が足りなければどの在庫も確保されないこと
+    #[tokio::test]
+    async fn reserve_moves_quantity_to_reserved() {
+        let Some((products, repository)) = repositories().await else { return };
+        let product = Product::create("pg reserve".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 5).unwrap();
+        let product = products.save(product).await.unwrap();
+        let short = Product::create("pg reserve short".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 1).unwrap();
+        let short = products.save(short).await.unwrap();
+
+        let result = repository.reserve(reservation(&[(&product, None, 2), (&short, None, 2)])).await;
+        assert!(matches!(result, Err(RepositoryError::Conflict)));
+        assert_eq!(stock_of(&products, &product).await, (5, 0));
+
+        let reserved = repository.reserve(reservation(&[(&product, None, 2)])).await.unwrap();
+        assert_eq!(stock_of(&products, &product).await, (3, 2));
+        let loaded = repository.find_by_id(reserved.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, ReservationStatus::Active);
+        assert_eq!(loaded.expires_at, reserved.expires_at);
+        assert_eq!(loaded.items, reserved.items);
+    }
+
+    /// バリエーションの在庫が確保され、確定すると注文になり、取り消すと戻ること
+    #[tokio::test]
+    async fn confirm_and_release_variant_stock() {
+        let Some((products, repository)) = repositories().await else { return };
+        let mut product = Product::create("pg reserve variants".to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), 0).unwrap();
+        let attributes = BTreeMap::from([("layout".to_string(), "US".to_string())]);
+        product.add_variant(ProductVariant::new("PG-RESERVE-US", attributes, Money::new(100, Currency::Jpy), 4)).unwrap();
+        let product = products.save(product).await.unwrap();
+
+        let mut confirmed = repository.reserve(reservation(&[(&product, Some("PG-RESERVE-US"), 1)])).await.unwrap();
+        let mut released = repository.reserve(reservation(&[(&product, Some("PG-RESERVE-US"), 2)])).await.unwrap();
+        assert_eq!(stock_of(&products, &product).await, (1, 3));
+
+        confirmed.confirm(Utc::now()).unwrap();
+        let line = OrderLine::new(product.id, product.name.clone(), product.price, 1).with_sku(Some("PG-RESERVE-US".to_string()));
+        let order = repository.confirm(&confirmed, Order::place(vec![line], &TaxRegion::untaxed()).unwrap()).await.unwrap();
+        assert_eq!(repository.find_by_id(confirmed.id).await.unwrap().unwrap().order_id, Some(order.id));
+
+        released.release().unwrap();
+        repository.release(&released).await.unwrap();
+        assert!(matches!(repository.release(&released).await, Err(RepositoryError::Conflict)));
+
+        let product = products.find_by_id(product.id).await.unwrap().unwrap();
+        assert_eq!((product.quantity, product.reserved), (3, 0));
+        assert_eq!(product.variant("PG-RESERVE-US").unwrap().quantity, 3);
+    }
+}
@@ -0,0 +1,323 @@
+//! `ProductRepository` の実装が同じ振る舞いをすることを確認する共通テスト
+//! 各シナリオをSQLite実装とメモリ実装の両方に対して実行する
+//! `postgres` featureを有効にし、環境変数 `TEST_POSTGRES_URL` を設定した場合はPostgreSQL実装に対しても実行する
+//! テスト用データベースはプロセス内で共有されるため、シナリオごとに固有の名前・タグを使う
+//! 各シナリオには、実装と同じデータベースのカテゴリリポジトリも渡す
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use crate::application::error::RepositoryError;
+use crate::application::repositories::{
+    CategoryRepository, Pagination, ProductFilter, ProductListCriteria, ProductRepository, ProductSortKey, SortOrder,
+};
+use crate::domain::models::{Category, Currency, Money, Product, ProductVariant};
+use crate::domain::tax::TaxCategory;
+use crate::frameworks_and_drivers::database::db::test_db;
+use crate::frameworks_and_drivers::persistence::repositories_impl::{
+    InMemoryProductRepository, InMemoryStore, SqliteCategoryRepository, SqliteProductRepository,
+};
+#[cfg(feature = "postgres")]
+use crate::frameworks_and_drivers::persistence::repositories_impl::{PostgresCategoryRepository, PostgresProductRepository};
+
+/// シナリオごとに両方の実装に対するテストを生成する
+macro_rules! conformance_tests {
+    ($($scenario:ident),* $(,)?) => {
+        mod sqlite {
+            $(
+                #[tokio::test]
+                async fn $scenario() {
+                    let (repository, categories) = super::sqlite_repository().await;
+                    super::$scenario(&repository, &categories).await;
+                }
+            )*
+        }
+
+        mod in_memory {
+            $(
+                #[tokio::test]
+                async fn $scenario() {
+                    let (repository, categories) = super::in_memory_repository().await;
+                    super::$scenario(&repository, &categories).await;
+                }
+            )*
+        }
+
+        #[cfg(feature = "postgres")]
+        mod postgres {
+            $(
+                #[tokio::test]
+                async fn $scenario() {
+                    // TEST_POSTGRES_URL が設定されていない場合は実行しない
+                    let Some((repository, categories)) = super::postgres_repository().await else {
+                        return;
+                    };
+                    super::$scenario(&repository, &categories).await;
+                }
+            )*
+        }
+    };
+}
+
+conformance_tests!(
+    save_assigns_ids_and_round_trips,
+    save_rejects_stale_version,
+    delete_checks_existence_and_version,
+    save_after_delete_is_not_found,
+    find_all_filters_sorts_and_paginates,
+    find_all_compares_prices_within_a_currency,
+    find_all_filters_by_category_subtree_and_tag,
+    search_reflects_product_changes,
+    save_syncs_variants,
+);
+
+async fn sqlite_repository() -> (SqliteProductRepository, SqliteCategoryRepository) {
+    (SqliteProductRepository::new(test_db().await), SqliteCategoryRepository::new(test_db().await))
+}
+
+// カテゴリはどちらの実装でもデータベースから解決する
+async fn in_memory_repository() -> (InMemoryProductRepository, SqliteCategoryRepository) {
+    let categories = Arc::new(SqliteCategoryRepository::new(test_db().await));
+    let repository = InMemoryProductRepository::new(Arc::new(InMemoryStore::new()), categories);
+    (repository, SqliteCategoryRepository::new(test_db().await))
+}
+
+// カテゴリも同じPostgreSQLのテスト用スキーマに保存する
+#[cfg(feature = "postgres")]
+async fn postgres_repository() -> Option<(PostgresProductRepository, PostgresCategoryRepository)> {
+    use crate::frameworks_and_drivers::database::db::init_test_postgres;
+
+    let pool = init_test_postgres().await.unwrap()?;
+    let repository = PostgresProductRepository::new(pool.clone(), Arc::new(PostgresCategoryRepository::new(pool.clone())));
+    Some((repository, PostgresCategoryRepository::new(pool)))
+}
+
+async fn insert_product(repository: &dyn ProductRepository, name: &str, price: u64, quantity: u32) -> u32 {
+    let product = Product::create(name.to_string(), Money::new(price, Currency::Jpy), "test product".to_string(), quantity).unwrap();
+    repository.save(product).await.unwrap().id
+}
+
+async fn find_ids(repository: &dyn ProductRepository, filter: ProductFilter) -> Vec<u32> {
+    let criteria = ProductListCriteria {
+        filter,
+        sort_key: ProductSortKey::Id,
+        sort_order: SortOrder::Asc,
+        pagination: Pagination::new(1, 100),
+    };
+    let page = repository.find_all(&criteria).await.unwrap();
+    page.items.iter().map(|p| p.id).collect()
+}
+
+/// 新規作成でIDが採番されてバージョンが0になり、保存した内容がそのまま読み込めること
+async fn save_assigns_ids_and_round_trips(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product = Product::create(
+        "round trip".to_string(),
+        Money::new(1999, Currency::Usd),
+        "kept as is".to_string(),
+        4,
+    )
+    .unwrap()
+    .with_tax_category(TaxCategory::Reduced);
+
+    let saved = repository.save(product).await.unwrap();
+    assert_ne!(saved.id, 0);
+    assert_eq!(saved.version, 0);
+    let next_id = insert_product(repository, "round trip next", 100, 1).await;
+    assert!(next_id > saved.id);
+
+    let product = repository.find_by_id(saved.id).await.unwrap().unwrap();
+    assert_eq!(product.name, "round trip");
+    assert_eq!(product.price, Money::new(1999, Currency::Usd));
+    assert_eq!(product.description, "kept as is");
+    assert_eq!(product.tax_category, TaxCategory::Reduced);
+    assert_eq!((product.quantity, product.reserved, product.version), (4, 0, 0));
+    assert!(product.categories.is_empty() && product.tags.is_empty() && product.variants.is_empty());
+    assert!(repository.find_by_id(u32::MAX).await.unwrap().is_none());
+}
+
+/// 古いバージョンでの保存は競合として拒否されること
+async fn save_rejects_stale_version(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product_id = insert_product(repository, "versioned", 1000, 5).await;
+
+    let mut first = repository.find_by_id(product_id).await.unwrap().unwrap();
+    let mut second = repository.find_by_id(product_id).await.unwrap().unwrap();
+
+    first.name = "first writer".to_string();
+    repository.save(first).await.unwrap();
+
+    second.name = "second writer".to_string();
+    assert!(matches!(repository.save(second).await, Err(RepositoryError::Conflict)));
+
+    let product = repository.find_by_id(product_id).await.unwrap().unwrap();
+    assert_eq!(product.name, "first writer");
+    assert_eq!(product.version, 1);
+}
+
+/// バージョンが一致しない削除は競合、存在しない商品の削除はNotFoundになること
+async fn delete_checks_existence_and_version(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product_id = insert_product(repository, "deleted", 1000, 1).await;
+
+    assert!(matches!(repository.delete(product_id, Some(1)).await, Err(RepositoryError::Conflict)));
+    repository.delete(product_id, Some(0)).await.unwrap();
+    assert!(repository.find_by_id(product_id).await.unwrap().is_none());
+    assert!(matches!(repository.delete(product_id, None).await, Err(RepositoryError::NotFound)));
+}
+
+/// 削除された商品の保存はNotFoundになり、別のIDで作り直されないこと
+async fn save_after_delete_is_not_found(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product_id = insert_product(repository, "saved after delete", 1000, 1).await;
+    let mut product = repository.find_by_id(product_id).await.unwrap().unwrap();
+    repository.delete(product_id, None).await.unwrap();
+
+    product.set_tags(vec!["saved-after-delete".to_string()]);
+    assert!(matches!(repository.save(product).await, Err(RepositoryError::NotFound)));
+    assert!(repository.find_by_id(product_id).await.unwrap().is_none());
+    let by_tag = ProductFilter { tag: Some("saved-after-delete".to_string()), ..Default::default() };
+    assert!(find_ids(repository, by_tag).await.is_empty());
+}
+
+/// 価格・在庫で絞り込め、並び替えキーが同じ場合はID順になり、ページごとに取得できること
+async fn find_all_filters_sorts_and_paginates(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let mut ids = Vec::new();
+    for (name, price, quantity) in [("page b", 300, 0), ("page a", 100, 1), ("page c", 200, 1), ("page a", 200, 1)] {
+        let mut product = repository.find_by_id(insert_product(repository, name, price, quantity).await).await.unwrap().unwrap();
+        product.set_tags(vec!["conformance-page".to_string()]);
+        ids.push(repository.save(product).await.unwrap().id);
+    }
+    let filter = ProductFilter { tag: Some("conformance-page".to_string()), ..Default::default() };
+    let list = |filter: ProductFilter, sort_key, sort_order, page| async move {
+        let criteria = ProductListCriteria { filter, sort_key, sort_order, pagination: Pagination::new(page, 2) };
+        repository.find_all(&criteria).await.unwrap()
+    };
+
+    let page = list(filter.clone(), ProductSortKey::Price, SortOrder::Desc, 1).await;
+    assert_eq!(page.items.iter().map(|p| p.id).collect::<Vec<_>>(), vec![ids[0], ids[3]]);
+    assert_eq!((page.total, page.next_page()), (4, Some(2)));
+    let page = list(filter.clone(), ProductSortKey::Price, SortOrder::Desc, 2).await;
+    assert_eq!(page.items.iter().map(|p| p.id).collect::<Vec<_>>(), vec![ids[2], ids[1]]);
+    assert_eq!(page.next_page(), None);
+
+    let page = list(filter.clone(), ProductSortKey::Name, SortOrder::Asc, 1).await;
+    assert_eq!(page.items.iter().map(|p| p.id).collect::<Vec<_>>(), vec![ids[1], ids[3]]);
+
+    let in_range = ProductFilter { min_price: Some(150), max_price: Some(300), in_stock_only: true, ..filter };
+    assert_eq!(find_ids(repository, in_range).await, vec![ids[2], ids[3]]);
+}
+
+/// 価格の絞り込みは指定した通貨の商品だけを対象にし、価格順は通貨ごとにまとめて並べること
+async fn find_all_compares_prices_within_a_currency(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let mut ids = Vec::new();
+    for (price, currency) in [(5000, Currency::Usd), (3000, Currency::Jpy), (1000, Currency::Usd), (9000, Currency::Jpy)] {
+        let mut product = Product::create("priced".to_string(), Money::new(price, currency), String::new(), 1).unwrap();
+        product.set_tags(vec!["conformance-currency".to_string()]);
+        ids.push(repository.save(product).await.unwrap().id);
+    }
+    let filter = ProductFilter { tag: Some("conformance-currency".to_string()), ..Default::default() };
+
+    let usd_over_2000 = ProductFilter { currency: Some(Currency::Usd), min_price: Some(2000), ..filter.clone() };
+    assert_eq!(find_ids(repository, usd_over_2000).await, vec![ids[0]]);
+
+    let criteria = ProductListCriteria {
+        filter,
+        sort_key: ProductSortKey::Price,
+        sort_order: SortOrder::Desc,
+        pagination: Pagination::new(1, 10),
+    };
+    let page = repository.find_all(&criteria).await.unwrap();
+    assert_eq!(page.items.iter().map(|p| p.id).collect::<Vec<_>>(), vec![ids[3], ids[1], ids[0], ids[2]]);
+}
+
+/// カテゴリ（子孫のカテゴリを含む）とタグで絞り込め、保存時に関連付けが置き換わること
+async fn find_all_filters_by_category_subtree_and_tag(repository: &dyn ProductRepository, categories: &dyn CategoryRepository) {
+    let child_product_id = insert_product(repository, "in child category", 1000, 1).await;
+    let other_product_id = insert_product(repository, "in other category", 1000, 1).await;
+    let root = categories.save(Category::create("filter root".to_string(), None).unwrap()).await.unwrap();
+    let child = categories.save(Category::create("filter child".to_string(), Some(root.id)).unwrap()).await.unwrap();
+    let other = categories.save(Category::create("filter other".to_string(), None).unwrap()).await.unwrap();
+
+    let mut product = repository.find_by_id(child_product_id).await.unwrap().unwrap();
+    product.categories = vec![child.clone()];
+    product.set_tags(vec!["Filter-Sale ".to_string(), "filter-sale".to_string()]);
+    repository.save(product).await.unwrap();
+    let mut product = repository.find_by_id(other_product_id).await.unwrap().unwrap();
+    product.categories = vec![other.clone()];
+    product.set_tags(vec!["filter-new".to_string()]);
+    repository.save(product).await.unwrap();
+
+    let by_category = |id| ProductFilter { category_id: Some(id), ..Default::default() };
+    let by_tag = |tag: &str| ProductFilter { tag: Some(tag.to_string()), ..Default::default() };
+
+    assert_eq!(find_ids(repository, by_category(root.id)).await, vec![child_product_id]);
+    assert_eq!(find_ids(repository, by_category(other.id)).await, vec![other_product_id]);
+    assert_eq!(find_ids(repository, by_tag("filter-sale")).await, vec![child_product_id]);
+
+    let mut product = repository.find_by_id(child_product_id).await.unwrap().unwrap();
+    assert_eq!(product.categories, vec![child]);
+    assert_eq!(product.tags, vec!["filter-sale".to_string()]);
+
+    product.set_tags(Vec::new());
+    repository.save(product).await.unwrap();
+    assert!(find_ids(repository, by_tag("filter-sale")).await.is_empty());
+
+    // 削除されたカテゴリとの関連は外れる
+    categories.delete(other.id).await.unwrap();
+    assert!(repository.find_by_id(other_product_id).await.unwrap().unwrap().categories.is_empty());
+}
+
+/// 商品の作成・更新が検索結果に反映されること
+async fn search_reflects_product_changes(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product_id = insert_product(repository, "Quixotic Lantern", 1000, 1).await;
+
+    let hits = repository.search("quixot", 10).await.unwrap();
+    assert!(hits.iter().any(|hit| hit.product.id == product_id));
+    let hit = hits.iter().find(|hit| hit.product.id == product_id).unwrap();
+    assert!(hit.highlighted_name.contains("<mark>Quixotic</mark>"));
+
+    let mut product = repository.find_by_id(product_id).await.unwrap().unwrap();
+    product.name = "Plain Lamp".to_string();
+    repository.save(product).await.unwrap();
+
+    let hits = repository.search("quixot", 10).await.unwrap();
+    assert!(hits.iter().all(|hit| hit.product.id != product_id));
+
+    // FTS5の演算子や引用符を含む入力でもエラーにならないこと
+    assert!(repository.search("\"lamp OR (", 10).await.is_ok());
+
+    // 本文中のタグはエスケープされ、ハイライトの `<mark>` だけがHTMLとして残ること
+    let product_id = insert_product(repository, "<b>Zanzibar</b> & co", 1000, 1).await;
+    let hits = repository.search("zanzibar", 10).await.unwrap();
+    let hit = hits.iter().find(|hit| hit.product.id == product_id).unwrap();
+    assert_eq!(hit.highlighted_name, "&lt;b&gt;<mark>Zanzibar</mark>&lt;/b&gt; &amp; co");
+}
+
+/// バリエーションが保存・読み込みでき、SKUで検索でき、他の商品のSKUは上書きされないこと
+async fn save_syncs_variants(repository: &dyn ProductRepository, _categories: &dyn CategoryRepository) {
+    let product_id = insert_product(repository, "with variants", 1200, 0).await;
+    let other_id = insert_product(repository, "sku owner", 1200, 0).await;
+    let variant = |sku: &str, layout: &str, quantity| {
+        let attributes = BTreeMap::from([("layout".to_string(), layout.to_string())]);
+        ProductVariant::new(sku, attributes, Money::new(1200, Currency::Jpy), quantity)
+    };
+
+    let mut product = repository.find_by_id(product_id).await.unwrap().unwrap();
+    product.add_variant(variant("repo-us", "US", 3)).unwrap();
+    product.add_variant(variant("repo-jis", "JIS", 2)).unwrap();
+    repository.save(product).await.unwrap();
+
+    let mut product = repository.find_by_sku("REPO-JIS").await.unwrap().unwrap();
+    assert_eq!(product.id, product_id);
+    assert_eq!(product.quantity, 5);
+    assert_eq!(product.variants, vec![variant("repo-us", "US", 3), variant("repo-jis", "JIS", 2)]);
+
+    product.update_variant("repo-us", None, None, Some(7)).unwrap();
+    product.remove_variant("repo-jis").unwrap();
+    repository.save(product).await.unwrap();
+    let product = repository.find_by_id(product_id).await.unwrap().unwrap();
+    assert_eq!(product.variants, vec![variant("repo-us", "US", 7)]);
+    assert!(repository.find_by_sku("repo-jis").await.unwrap().is_none());
+
+    let mut other = repository.find_by_id(other_id).await.unwrap().unwrap();
+    other.add_variant(variant("repo-us", "US", 1)).unwrap();
+    assert!(matches!(repository.save(other).await, Err(RepositoryError::Conflict)));
+    assert_eq!(repository.find_by_sku("repo-us").await.unwrap().unwrap().id, product_id);
+}
@@ -0,0 +1,47 @@
+//! 全文検索のハイライトをHTMLとして安全に組み立てるためのヘルパー
+//!
+//! 商品名・説明は利用者が登録した生のテキストのため、データベースのハイライト関数に
+//! `<mark>` を直接渡すと本文中のタグがそのままレスポンスに混ざってしまう。
+//! ハイライト関数には本文に現れない私用領域の文字を区切りとして渡し、
+//! 本文をエスケープしてから区切りを `<mark>` に置き換える。
+
+/// ハイライト開始の区切り文字（Unicode私用領域）
+pub(super) const MARK_START: &str = "\u{E000}";
+/// ハイライト終了の区切り文字（Unicode私用領域）
+pub(super) const MARK_END: &str = "\u{E001}";
+
+/// HTMLの特殊文字をエスケープする
+pub(super) fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// 区切り文字でハイライトされたテキストをエスケープし、区切りを `<mark>` タグに置き換える
+pub(super) fn marked_to_html(marked: &str) -> String {
+    escape_html(marked).replace(MARK_START, "<mark>").replace(MARK_END, "</mark>")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_text_and_keeps_only_the_highlight_tags() {
+        let marked = format!("<script>alert('x')</script> & {MARK_START}Mouse{MARK_END}");
+
+        assert_eq!(
+            marked_to_html(&marked),
+            "&lt;script&gt;alert(&#39;x&#39;)&lt;/script&gt; &amp; <mark>Mouse</mark>"
+        );
+    }
+}
@@ -0,0 +1,315 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::Utc;
+use sqlx::sqlite::{SqliteConnection, SqliteRow};
+use sqlx::Row;
+
+use crate::domain::models::{Cart, CartItem, CartStatus, Order};
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::{CartEntity, CartItemEntity};
+use crate::frameworks_and_drivers::persistence::repositories_impl::SqliteOrderRepository;
+use crate::application::repositories::CartRepository;
+use crate::application::error::RepositoryError;
+
+pub struct SqliteCartRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqliteCartRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: CartEntity, items: Vec<CartItemEntity>) -> Result<Cart, RepositoryError> {
+        let status = CartStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown cart status: {}", entity.status)))?;
+        let items = items
+            .into_iter()
+            .map(|item| CartItem { product_id: item.product_id, sku: item.sku, quantity: item.quantity })
+            .collect();
+
+        Ok(Cart::new(entity.id, items, status, entity.order_id, entity.version))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> CartEntity {
+        CartEntity {
+            id: row.get("id"),
+            status: row.get("status"),
+            order_id: row.get("order_id"),
+            version: row.get("version"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+
+    fn row_to_item_entity(row: &SqliteRow) -> CartItemEntity {
+        CartItemEntity {
+            product_id: row.get("product_id"),
+            sku: row.get("sku"),
+            quantity: row.get("quantity"),
+        }
+    }
+
+    // カート内の商品を追加された順序のまま置き換える
+    async fn replace_items(conn: &mut SqliteConnection, cart: &Cart) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM cart_items WHERE cart_id = ?")
+            .bind(cart.id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        for (position, item) in cart.items.iter().enumerate() {
+            sqlx::query("INSERT INTO cart_items (cart_id, product_id, sku, quantity, position) VALUES (?, ?, ?, ?, ?)")
+                .bind(cart.id)
+                .bind(item.product_id)
+                .bind(&item.sku)
+                .bind(item.quantity)
+                .bind(position as i64)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CartRepository for SqliteCartRepository {
+    #[tracing::instrument(name = "cart_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Cart>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let Some(row) = sqlx::query("SELECT * FROM carts WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let items = sqlx::query("SELECT * FROM cart_items WHERE cart_id = ? ORDER BY position")
+            .bind(id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_item_entity)
+            .collect();
+
+        Self::entity_to_domain(Self::row_to_entity(&row), items).map(Some)
+    }
+
+    #[tracing::instrument(name = "cart_repository.save", skip(self, cart), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn save(&self, mut cart: Cart) -> Result<Cart, RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now().to_rfc3339();
+
+        if cart.id == 0 {
+            // 新規作成
+            let result = sqlx::query("INSERT INTO carts (status, version, created_at, updated_at) VALUES (?, 0, ?, ?)")
+                .bind(cart.status.as_str())
+                .bind(&now)
+                .bind(&now)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            cart.id = result.last_insert_rowid() as u32;
+            cart.version = 0;
+        } else {
+            // 更新（読み込み時のバージョンと一致し、注文確定前の場合のみ）
+            let result = sqlx::query(
+                "UPDATE carts SET version = version + 1, updated_at = ? WHERE id = ? AND version = ? AND status = ?"
+            )
+            .bind(&now)
+            .bind(cart.id)
+            .bind(cart.version)
+            .bind(CartStatus::Open.as_str())
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+            cart.version += 1;
+        }
+
+        Self::replace_items(&mut tx, &cart).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(cart)
+    }
+
+    #[tracing::instrument(name = "cart_repository.check_out", skip(self, cart, order), fields(cart_id = cart.id), err(level = "warn"))]
+    async fn check_out(&self, cart: &Cart, order: Order) -> Result<Order, RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let order = SqliteOrderRepository::place_in(&mut tx, order, &HashMap::new()).await?;
+
+        // カートの読み込み後に商品が変更された場合や、同じカートが既にチェックアウトされた場合は競合
+        let result = sqlx::query(
+            "UPDATE carts SET status = ?, order_id = ?, version = version + 1, updated_at = ? \
+             WHERE id = ? AND version = ? AND status = ?"
+        )
+        .bind(CartStatus::CheckedOut.as_str())
+        .bind(order.id)
+        .bind(Utc::now().to_rfc3339())
+        .bind(cart.id)
+        .bind(cart.version)
+        .bind(CartStatus::Open.as_str())
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::Conflict);
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::error::ApplicationError;
+    use crate::application::repositories::ProductRepository;
+    use crate::application::use_cases::CheckoutCartUseCase;
+    use crate::domain::models::{Currency, Money, OrderLine, Product};
+    use crate::domain::DomainError;
+    use crate::domain::tax::{TaxRegion, TaxTable};
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{SqliteProductRepository, SqlitePromotionRepository};
+
+    async fn insert_product(name: &str, price: Money, quantity: u32) -> Product {
+        let product = Product::create(name.to_string(), price, "test product".to_string(), quantity).unwrap();
+        SqliteProductRepository::new(test_db().await).save(product).await.unwrap()
+    }
+
+    async fn insert_cart(items: &[(&Product, u32)]) -> Cart {
+        let mut cart = Cart::create();
+        for (product, quantity) in items {
+            cart.add_item(product.id, None, *quantity).unwrap();
+        }
+        SqliteCartRepository::new(test_db().await).save(cart).await.unwrap()
+    }
+
+    async fn checkout_usecase() -> CheckoutCartUseCase {
+        let db = test_db().await;
+        CheckoutCartUseCase::new(
+            Arc::new(SqliteCartRepository::new(db.clone())),
+            Arc::new(SqliteProductRepository::new(db.clone())),
+            Arc::new(SqlitePromotionRepository::new(db)),
+            Arc::new(TaxTable::untaxed()),
+        )
+    }
+
+    /// 古いバージョンのカートは保存されないこと
+    #[tokio::test]
+    async fn save_rejects_stale_version() {
+        let product = insert_product("cart stale", Money::new(100, Currency::Jpy), 5).await;
+        let repository = SqliteCartRepository::new(test_db().await);
+        let cart = insert_cart(&[(&product, 1)]).await;
+
+        let mut first = repository.find_by_id(cart.id).await.unwrap().unwrap();
+        first.update_quantity(product.id, None, 2).unwrap();
+        repository.save(first).await.unwrap();
+
+        let mut stale = cart;
+        stale.update_quantity(product.id, None, 3).unwrap();
+        assert!(matches!(repository.save(stale).await, Err(RepositoryError::Conflict)));
+    }
+
+    /// 全商品の在庫が減り、カートが注文に紐づくこと
+    #[tokio::test]
+    async fn checkout_places_order_for_every_item() {
+        let laptop = insert_product("cart laptop", Money::new(1000, Currency::Jpy), 5).await;
+        let mouse = insert_product("cart mouse", Money::new(50, Currency::Jpy), 10).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let cart = insert_cart(&[(&laptop, 2), (&mouse, 3)]).await;
+
+        let order = checkout_usecase().await.checkout(cart.id, None).await.unwrap();
+        assert_eq!(order.total, Money::new(2150, Currency::Jpy));
+        assert_eq!(order.lines.len(), 2);
+
+        assert_eq!(products.find_by_id(laptop.id).await.unwrap().unwrap().quantity, 3);
+        assert_eq!(products.find_by_id(mouse.id).await.unwrap().unwrap().quantity, 7);
+
+        let cart = SqliteCartRepository::new(test_db().await).find_by_id(cart.id).await.unwrap().unwrap();
+        assert_eq!(cart.status, CartStatus::CheckedOut);
+        assert_eq!(cart.order_id, Some(order.id));
+    }
+
+    /// 1商品でも在庫が足りなければ、どの在庫も減らずカートも開いたままであること
+    #[tokio::test]
+    async fn checkout_rolls_back_when_any_item_lacks_stock() {
+        let enough = insert_product("cart enough", Money::new(100, Currency::Jpy), 5).await;
+        let short = insert_product("cart short", Money::new(100, Currency::Jpy), 1).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let cart = insert_cart(&[(&enough, 2), (&short, 2)]).await;
+
+        let result = checkout_usecase().await.checkout(cart.id, None).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::InsufficientQuantity { requested: 2, available: 1, .. }))
+        ));
+
+        assert_eq!(products.find_by_id(enough.id).await.unwrap().unwrap().quantity, 5);
+        assert_eq!(products.find_by_id(short.id).await.unwrap().unwrap().quantity, 1);
+        let cart = SqliteCartRepository::new(test_db().await).find_by_id(cart.id).await.unwrap().unwrap();
+        assert_eq!(cart.status, CartStatus::Open);
+    }
+
+    /// 通貨の異なる商品は1つの注文にできず、在庫も減らないこと
+    #[tokio::test]
+    async fn checkout_rejects_mixed_currencies() {
+        let yen = insert_product("cart yen", Money::new(100, Currency::Jpy), 5).await;
+        let dollar = insert_product("cart dollar", Money::new(100, Currency::Usd), 5).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let cart = insert_cart(&[(&yen, 1), (&dollar, 1)]).await;
+
+        let result = checkout_usecase().await.checkout(cart.id, None).await;
+        assert!(matches!(
+            result,
+            Err(ApplicationError::Domain(DomainError::CurrencyMismatch { expected: Currency::Jpy, actual: Currency::Usd }))
+        ));
+        assert_eq!(products.find_by_id(dollar.id).await.unwrap().unwrap().quantity, 5);
+    }
+
+    /// 同じカートを2回チェックアウトしても注文は1件しか作られないこと
+    #[tokio::test]
+    async fn check_out_rejects_second_checkout() {
+        let product = insert_product("cart twice", Money::new(100, Currency::Jpy), 5).await;
+        let repository = SqliteCartRepository::new(test_db().await);
+        let cart = insert_cart(&[(&product, 1)]).await;
+        let line = || OrderLine::new(product.id, product.name.clone(), product.price, 1);
+
+        repository.check_out(&cart, Order::place(vec![line()], &TaxRegion::untaxed()).unwrap()).await.unwrap();
+        let second = repository.check_out(&cart, Order::place(vec![line()], &TaxRegion::untaxed()).unwrap()).await;
+        assert!(matches!(second, Err(RepositoryError::Conflict)));
+
+        let products = SqliteProductRepository::new(test_db().await);
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 4);
+    }
+}
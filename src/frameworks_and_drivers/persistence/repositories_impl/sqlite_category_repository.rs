@@ -0,0 +1,219 @@
+use std::sync::Arc;
+
+use chrono::Utc;
+use sqlx::sqlite::SqliteRow;
+use sqlx::{QueryBuilder, Row, Sqlite};
+
+use crate::domain::models::Category;
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::CategoryEntity;
+use crate::application::repositories::CategoryRepository;
+use crate::application::error::RepositoryError;
+
+pub struct SqliteCategoryRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqliteCategoryRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: CategoryEntity) -> Category {
+        Category::new(entity.id, entity.name, entity.parent_id)
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> CategoryEntity {
+        CategoryEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            parent_id: row.get("parent_id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+
+    fn rows_to_domain(rows: &[SqliteRow]) -> Vec<Category> {
+        rows.iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl CategoryRepository for SqliteCategoryRepository {
+    #[tracing::instrument(name = "category_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self) -> Result<Vec<Category>, RepositoryError> {
+        let rows = sqlx::query("SELECT * FROM categories ORDER BY id")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(Self::rows_to_domain(&rows))
+    }
+
+    #[tracing::instrument(name = "category_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Category>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM categories WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(row.map(|row| Self::entity_to_domain(Self::row_to_entity(&row))))
+    }
+
+    #[tracing::instrument(name = "category_repository.find_by_ids", skip(self), err(level = "warn"))]
+    async fn find_by_ids(&self, ids: &[u32]) -> Result<Vec<Category>, RepositoryError> {
+        if ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM categories WHERE id IN (");
+        let mut separated = query.separated(", ");
+        for id in ids {
+            separated.push_bind(*id);
+        }
+        query.push(") ORDER BY id");
+
+        let rows = query
+            .build()
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(Self::rows_to_domain(&rows))
+    }
+
+    #[tracing::instrument(name = "category_repository.save", skip(self, category), fields(category_id = category.id), err(level = "warn"))]
+    async fn save(&self, mut category: Category) -> Result<Category, RepositoryError> {
+        let now = Utc::now().to_rfc3339();
+
+        // 新規作成
+        if category.id == 0 {
+            let result = sqlx::query(
+                "INSERT INTO categories (name, parent_id, created_at, updated_at) VALUES (?, ?, ?, ?)"
+            )
+            .bind(&category.name)
+            .bind(category.parent_id)
+            .bind(&now)
+            .bind(&now)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            category.id = result.last_insert_rowid() as u32;
+            return Ok(category);
+        }
+
+        // 更新
+        // 移動先の祖先に自分自身が含まれる場合は更新しない
+        // 判定と更新を1つの文で行い、同時に別のカテゴリが移動されても循環を保存しないようにする
+        let result = sqlx::query(
+            r#"
+            UPDATE categories SET name = ?1, parent_id = ?2, updated_at = ?3
+            WHERE id = ?4 AND NOT EXISTS (
+                WITH RECURSIVE ancestors(id) AS (
+                    SELECT ?2
+                    UNION
+                    SELECT c.parent_id FROM categories c JOIN ancestors a ON c.id = a.id
+                    WHERE c.parent_id IS NOT NULL
+                )
+                SELECT 1 FROM ancestors WHERE id = ?4
+            )
+            "#
+        )
+        .bind(&category.name)
+        .bind(category.parent_id)
+        .bind(&now)
+        .bind(category.id)
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return match self.find_by_id(category.id).await? {
+                Some(_) => Err(RepositoryError::Conflict),
+                None => Err(RepositoryError::NotFound),
+            };
+        }
+        Ok(category)
+    }
+
+    #[tracing::instrument(name = "category_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError> {
+        // 子カテゴリの parent_id が参照しているため、外部キー制約違反になる
+        let result = sqlx::query("DELETE FROM categories WHERE id = ?")
+            .bind(id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| match e.as_database_error() {
+                Some(db_error) if db_error.is_foreign_key_violation() => RepositoryError::Conflict,
+                _ => RepositoryError::QueryExecution(e.to_string()),
+            })?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frameworks_and_drivers::database::db::test_db;
+
+    async fn insert_category(name: &str, parent_id: Option<u32>) -> Category {
+        let category = Category::create(name.to_string(), parent_id).unwrap();
+        SqliteCategoryRepository::new(test_db().await).save(category).await.unwrap()
+    }
+
+    /// 更新した名前と親カテゴリが読み込まれ、存在しないカテゴリの更新はNotFoundになること
+    #[tokio::test]
+    async fn save_updates_name_and_parent() {
+        let root = insert_category("category root", None).await;
+        let mut child = insert_category("category child", None).await;
+        let repository = SqliteCategoryRepository::new(test_db().await);
+
+        child.name = "renamed child".to_string();
+        child.parent_id = Some(root.id);
+        repository.save(child.clone()).await.unwrap();
+
+        assert_eq!(repository.find_by_id(child.id).await.unwrap(), Some(child));
+        let missing = Category::new(u32::MAX, "missing".to_string(), None);
+        assert!(matches!(repository.save(missing).await, Err(RepositoryError::NotFound)));
+    }
+
+    /// 保存時点の木構造で循環する移動は、読み込んだ時点では循環しなくても保存されないこと
+    #[tokio::test]
+    async fn save_rejects_moves_that_would_create_a_cycle() {
+        let mut first = insert_category("cycle first", None).await;
+        let mut second = insert_category("cycle second", None).await;
+        let repository = SqliteCategoryRepository::new(test_db().await);
+
+        // 同じ時点の木構造を読んだ2つの更新が、互いを親にしようとする
+        first.parent_id = Some(second.id);
+        second.parent_id = Some(first.id);
+        repository.save(first.clone()).await.unwrap();
+
+        assert!(matches!(repository.save(second.clone()).await, Err(RepositoryError::Conflict)));
+        assert_eq!(repository.find_by_id(second.id).await.unwrap().unwrap().parent_id, None);
+        second.parent_id = Some(second.id);
+        assert!(matches!(repository.save(second).await, Err(RepositoryError::Conflict)));
+    }
+
+    /// 子カテゴリがあるカテゴリは削除できないこと
+    #[tokio::test]
+    async fn delete_rejects_category_with_children() {
+        let parent = insert_category("category parent", None).await;
+        let child = insert_category("category leaf", Some(parent.id)).await;
+        let repository = SqliteCategoryRepository::new(test_db().await);
+
+        assert!(matches!(repository.delete(parent.id).await, Err(RepositoryError::Conflict)));
+        repository.delete(child.id).await.unwrap();
+        repository.delete(parent.id).await.unwrap();
+        assert!(matches!(repository.delete(parent.id).await, Err(RepositoryError::NotFound)));
+    }
+}
@@ -0,0 +1,267 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::Row;
+
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::IdempotencyKeyEntity;
+use crate::frameworks_and_drivers::persistence::sortable_timestamp;
+use crate::application::repositories::{IdempotencyRecord, IdempotencyRepository, IdempotencyStatus, StoredResponse};
+use crate::application::error::RepositoryError;
+
+pub struct SqliteIdempotencyRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqliteIdempotencyRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    // エンティティからレコードへのマッピング
+    fn entity_to_record(entity: IdempotencyKeyEntity) -> Result<IdempotencyRecord, RepositoryError> {
+        let status = IdempotencyStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown idempotency status: {}", entity.status)))?;
+        let response = match (entity.response_status, entity.response_headers, entity.response_body) {
+            (Some(status), Some(headers), Some(body)) => Some(StoredResponse {
+                status,
+                headers: serde_json::from_str(&headers).map_err(|e| RepositoryError::Unknown(e.to_string()))?,
+                body,
+            }),
+            _ => None,
+        };
+
+        Ok(IdempotencyRecord {
+            fingerprint: entity.fingerprint,
+            status,
+            response,
+        })
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> IdempotencyKeyEntity {
+        IdempotencyKeyEntity {
+            key: row.get("key"),
+            fingerprint: row.get("fingerprint"),
+            status: row.get("status"),
+            response_status: row.get("response_status"),
+            response_headers: row.get("response_headers"),
+            response_body: row.get("response_body"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+            expires_at: row.get("expires_at"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl IdempotencyRepository for SqliteIdempotencyRepository {
+    #[tracing::instrument(name = "idempotency_repository.claim", skip(self, fingerprint), err(level = "warn"))]
+    async fn claim(
+        &self,
+        key: &str,
+        fingerprint: &str,
+        now: DateTime<Utc>,
+        expires_at: DateTime<Utc>,
+        stale_before: DateTime<Utc>,
+    ) -> Result<Option<IdempotencyRecord>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let now = sortable_timestamp(now);
+
+        // 新規のキーは挿入し、期限切れ・放棄されたキーは最初のリクエストとして上書きする
+        // 挿入と判定を1文で行い、同じキーの同時リクエストのうち1つだけが確保できるようにする
+        let result = sqlx::query(
+            r#"
+            INSERT INTO idempotency_keys (key, fingerprint, status, created_at, updated_at, expires_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT(key) DO UPDATE SET
+                fingerprint = excluded.fingerprint,
+                status = excluded.status,
+                response_status = NULL,
+                response_headers = NULL,
+                response_body = NULL,
+                created_at = excluded.created_at,
+                updated_at = excluded.updated_at,
+                expires_at = excluded.expires_at
+            WHERE idempotency_keys.expires_at <= ?
+               OR (idempotency_keys.status = ? AND idempotency_keys.updated_at <= ?)
+            "#
+        )
+        .bind(key)
+        .bind(fingerprint)
+        .bind(IdempotencyStatus::InProgress.as_str())
+        .bind(&now)
+        .bind(&now)
+        .bind(sortable_timestamp(expires_at))
+        .bind(&now)
+        .bind(IdempotencyStatus::InProgress.as_str())
+        .bind(sortable_timestamp(stale_before))
+        .execute(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(None);
+        }
+
+        let row = sqlx::query("SELECT * FROM idempotency_keys WHERE key = ?")
+            .bind(key)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match row {
+            Some(row) => Self::entity_to_record(Self::row_to_entity(&row)).map(Some),
+            // 確保に失敗した直後に削除された場合
+            None => Err(RepositoryError::Conflict),
+        }
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.complete", skip(self, response), err(level = "warn"))]
+    async fn complete(&self, key: &str, response: &StoredResponse) -> Result<(), RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let headers = serde_json::to_string(&response.headers)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+
+        let result = sqlx::query(
+            "UPDATE idempotency_keys SET status = ?, response_status = ?, response_headers = ?, response_body = ?, updated_at = ? \
+             WHERE key = ? AND status = ?"
+        )
+        .bind(IdempotencyStatus::Completed.as_str())
+        .bind(response.status)
+        .bind(headers)
+        .bind(&response.body)
+        .bind(sortable_timestamp(Utc::now()))
+        .bind(key)
+        .bind(IdempotencyStatus::InProgress.as_str())
+        .execute(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.release", skip(self), err(level = "warn"))]
+    async fn release(&self, key: &str) -> Result<(), RepositoryError> {
+        let pool = self.db.get_pool();
+
+        sqlx::query("DELETE FROM idempotency_keys WHERE key = ? AND status = ?")
+            .bind(key)
+            .bind(IdempotencyStatus::InProgress.as_str())
+            .execute(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(())
+    }
+
+    #[tracing::instrument(name = "idempotency_repository.delete_expired", skip(self), err(level = "warn"))]
+    async fn delete_expired(&self, now: DateTime<Utc>) -> Result<u64, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let result = sqlx::query("DELETE FROM idempotency_keys WHERE expires_at <= ?")
+            .bind(sortable_timestamp(now))
+            .execute(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(result.rows_affected())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::application::error::ApplicationError;
+    use crate::application::use_cases::{IdempotencyOutcome, IdempotencyUseCase};
+    use crate::frameworks_and_drivers::database::db::test_db;
+
+    async fn usecase() -> IdempotencyUseCase {
+        IdempotencyUseCase::new(Arc::new(SqliteIdempotencyRepository::new(test_db().await)), Duration::hours(1))
+    }
+
+    fn response() -> StoredResponse {
+        StoredResponse {
+            status: 201,
+            headers: vec![
+                ("content-type".to_string(), "application/json".to_string()),
+                ("location".to_string(), "/orders/1".to_string()),
+            ],
+            body: br#"{"id":1}"#.to_vec(),
+        }
+    }
+
+    /// 最初のリクエストのレスポンスが保存され、同じ内容の再送で返されること
+    #[tokio::test]
+    async fn begin_replays_completed_response() {
+        let usecase = usecase().await;
+
+        assert!(matches!(usecase.begin("replay", "fp").await, Ok(IdempotencyOutcome::Proceed)));
+        usecase.complete("replay", &response()).await.unwrap();
+
+        match usecase.begin("replay", "fp").await {
+            Ok(IdempotencyOutcome::Replay(stored)) => assert_eq!(stored, response()),
+            other => panic!("expected replay, got {:?}", other),
+        }
+    }
+
+    /// 処理中のキーへの再送は処理中エラー、異なる内容での再利用は不一致エラーになること
+    #[tokio::test]
+    async fn begin_rejects_in_progress_and_mismatched_requests() {
+        let usecase = usecase().await;
+
+        assert!(matches!(usecase.begin("busy", "fp").await, Ok(IdempotencyOutcome::Proceed)));
+        assert!(matches!(
+            usecase.begin("busy", "fp").await,
+            Err(ApplicationError::IdempotencyKeyInProgress(_))
+        ));
+        assert!(matches!(
+            usecase.begin("busy", "other").await,
+            Err(ApplicationError::IdempotencyKeyMismatch(_))
+        ));
+
+        usecase.complete("busy", &response()).await.unwrap();
+        assert!(matches!(
+            usecase.begin("busy", "other").await,
+            Err(ApplicationError::IdempotencyKeyMismatch(_))
+        ));
+    }
+
+    /// 解放したキーは同じキーで再実行できること
+    #[tokio::test]
+    async fn abandon_allows_retry() {
+        let usecase = usecase().await;
+
+        assert!(matches!(usecase.begin("abandon", "fp").await, Ok(IdempotencyOutcome::Proceed)));
+        usecase.abandon("abandon").await.unwrap();
+        assert!(matches!(usecase.begin("abandon", "fp").await, Ok(IdempotencyOutcome::Proceed)));
+    }
+
+    /// 期限切れのキーと、放棄された処理中のキーは新しいリクエストとして確保できること
+    #[tokio::test]
+    async fn claim_reuses_expired_and_stale_keys() {
+        let repository = SqliteIdempotencyRepository::new(test_db().await);
+        let now = Utc::now();
+
+        assert!(repository.claim("expired", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().is_none());
+        repository.complete("expired", &response()).await.unwrap();
+        let later = now + Duration::hours(2);
+        assert!(repository.claim("expired", "new", later, later + Duration::hours(1), later - Duration::minutes(1)).await.unwrap().is_none());
+
+        assert!(repository.claim("stale", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().is_none());
+        let record = repository.claim("stale", "fp", now, now + Duration::hours(1), now - Duration::minutes(1)).await.unwrap().unwrap();
+        assert_eq!(record.status, IdempotencyStatus::InProgress);
+        let later = now + Duration::minutes(5);
+        assert!(repository.claim("stale", "fp", later, later + Duration::hours(1), later - Duration::minutes(1)).await.unwrap().is_none());
+    }
+}
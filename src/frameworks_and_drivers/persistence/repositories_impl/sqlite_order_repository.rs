@@ -0,0 +1,488 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::{SqliteConnection, SqliteRow};
+use sqlx::Row;
+
+use crate::domain::models::{Currency, Money, Order, OrderLine, OrderStatus};
+use crate::domain::tax::{TaxCategory, TaxMode, TaxRate};
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::{OrderEntity, OrderLineEntity};
+use crate::application::repositories::OrderRepository;
+use crate::application::error::RepositoryError;
+
+pub struct SqliteOrderRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqliteOrderRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    /// 在庫の減算と注文の保存を、呼び出し元のトランザクション内で行う
+    /// 他のリポジトリが注文確定を自身の更新と同じトランザクションで行うために使う
+    pub(crate) async fn place_in(
+        conn: &mut SqliteConnection,
+        order: Order,
+        expected_versions: &HashMap<u32, u32>,
+    ) -> Result<Order, RepositoryError> {
+        let now = Utc::now().to_rfc3339();
+
+        // 明細ごとに在庫チェックと減算を1つのUPDATEで行う
+        // 1件でも条件を満たさなければトランザクションごと破棄する
+        for line in &order.lines {
+            // バリエーションの在庫を減らし、商品の在庫（バリエーションの合計）も同じだけ減らす
+            if let Some(sku) = &line.sku {
+                let result = sqlx::query(
+                    "UPDATE product_variants SET quantity = quantity - ?, updated_at = ? \
+                     WHERE product_id = ? AND sku = ? AND quantity >= ?"
+                )
+                .bind(line.quantity)
+                .bind(&now)
+                .bind(line.product_id)
+                .bind(sku)
+                .bind(line.quantity)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                if result.rows_affected() == 0 {
+                    return Err(RepositoryError::Conflict);
+                }
+            }
+
+            let expected_version = expected_versions.get(&line.product_id);
+            let result = sqlx::query(
+                "UPDATE products SET quantity = quantity - ?, version = version + 1, updated_at = ? \
+                 WHERE id = ? AND quantity >= ? AND (? IS NULL OR version = ?)"
+            )
+            .bind(line.quantity)
+            .bind(&now)
+            .bind(line.product_id)
+            .bind(line.quantity)
+            .bind(expected_version)
+            .bind(expected_version)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        Self::insert_in(conn, order).await
+    }
+
+    /// 在庫を変更せずに注文と明細を保存する
+    /// 予約済みの在庫を注文として確定する場合など、在庫を別途調整済みの場合に使う
+    /// 適用されたプロモーションの利用回数も数え、上限に達していれば競合とする
+    pub(crate) async fn insert_in(conn: &mut SqliteConnection, mut order: Order) -> Result<Order, RepositoryError> {
+        Self::use_promotions_in(conn, &order).await?;
+
+        let result = sqlx::query(
+            "INSERT INTO orders (status, tax_region, tax_mode, subtotal, tax, total, currency, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+            .bind(order.status.as_str())
+            .bind(&order.tax_region)
+            .bind(order.tax_mode.as_str())
+            .bind(order.subtotal.amount() as i64)
+            .bind(order.tax.amount() as i64)
+            .bind(order.total.amount() as i64)
+            .bind(order.total.currency().code())
+            .bind(order.placed_at.to_rfc3339())
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        order.id = result.last_insert_rowid() as u32;
+
+        for line in &order.lines {
+            sqlx::query(
+                "INSERT INTO order_lines \
+                 (order_id, product_id, product_name, sku, unit_price, quantity, discount, promotion_id, tax_category, tax_rate, tax) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(order.id)
+            .bind(line.product_id)
+            .bind(&line.product_name)
+            .bind(&line.sku)
+            .bind(line.unit_price.amount() as i64)
+            .bind(line.quantity)
+            .bind(line.discount.amount() as i64)
+            .bind(line.promotion_id)
+            .bind(line.tax_category.as_str())
+            .bind(line.tax_rate.millionths())
+            .bind(line.tax.amount() as i64)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        Ok(order)
+    }
+
+    // プロモーションの利用回数を注文1件につき1回数える
+    async fn use_promotions_in(conn: &mut SqliteConnection, order: &Order) -> Result<(), RepositoryError> {
+        let mut promotion_ids: Vec<u32> = order.lines.iter().filter_map(|line| line.promotion_id).collect();
+        promotion_ids.sort_unstable();
+        promotion_ids.dedup();
+
+        for promotion_id in promotion_ids {
+            let result = sqlx::query(
+                "UPDATE promotions SET usage_count = usage_count + 1 \
+                 WHERE id = ? AND (usage_limit IS NULL OR usage_count < usage_limit)"
+            )
+            .bind(promotion_id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: OrderEntity, lines: Vec<OrderLineEntity>) -> Result<Order, RepositoryError> {
+        let status = OrderStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown order status: {}", entity.status)))?;
+        let placed_at = DateTime::parse_from_rfc3339(&entity.created_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?
+            .with_timezone(&Utc);
+        // 明細の単価は注文と同じ通貨で保存している
+        let currency = Currency::parse(&entity.currency)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", entity.currency)))?;
+        let tax_mode = TaxMode::parse(&entity.tax_mode)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax mode: {}", entity.tax_mode)))?;
+        let lines = lines
+            .into_iter()
+            .map(|line| {
+                let unit_price = Money::new(line.unit_price as u64, currency);
+                let discount = Money::new(line.discount as u64, currency);
+                let tax_category = TaxCategory::parse(&line.tax_category)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax category: {}", line.tax_category)))?;
+                let tax_rate = TaxRate::from_millionths(line.tax_rate)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("invalid tax rate: {}", line.tax_rate)))?;
+                Ok(OrderLine::new(line.product_id, line.product_name, unit_price, line.quantity)
+                    .with_sku(line.sku)
+                    .with_discount(discount, line.promotion_id)
+                    .with_tax(tax_category, tax_rate, Money::new(line.tax as u64, currency)))
+            })
+            .collect::<Result<Vec<_>, RepositoryError>>()?;
+
+        // 小計・税額・総額は明細から集計し直す
+        Order::new(entity.id, lines, entity.tax_region, tax_mode, status, placed_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> OrderEntity {
+        OrderEntity {
+            id: row.get("id"),
+            status: row.get("status"),
+            tax_region: row.get("tax_region"),
+            tax_mode: row.get("tax_mode"),
+            subtotal: row.get("subtotal"),
+            tax: row.get("tax"),
+            total: row.get("total"),
+            currency: row.get("currency"),
+            created_at: row.get("created_at"),
+        }
+    }
+
+    fn row_to_line_entity(row: &SqliteRow) -> OrderLineEntity {
+        OrderLineEntity {
+            order_id: row.get("order_id"),
+            product_id: row.get("product_id"),
+            product_name: row.get("product_name"),
+            sku: row.get("sku"),
+            unit_price: row.get("unit_price"),
+            quantity: row.get("quantity"),
+            discount: row.get("discount"),
+            promotion_id: row.get("promotion_id"),
+            tax_category: row.get("tax_category"),
+            tax_rate: row.get("tax_rate"),
+            tax: row.get("tax"),
+        }
+    }
+}
+
+#[async_trait::async_trait]
+impl OrderRepository for SqliteOrderRepository {
+    #[tracing::instrument(name = "order_repository.place", skip(self, order), fields(lines = order.lines.len()), err(level = "warn"))]
+    async fn place(&self, order: Order, expected_versions: &HashMap<u32, u32>) -> Result<Order, RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let order = Self::place_in(&mut tx, order, expected_versions).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+
+    #[tracing::instrument(name = "order_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Order>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let Some(row) = sqlx::query("SELECT * FROM orders WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+        else {
+            return Ok(None);
+        };
+
+        let lines = sqlx::query("SELECT * FROM order_lines WHERE order_id = ? ORDER BY id")
+            .bind(id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_line_entity)
+            .collect();
+
+        Self::entity_to_domain(Self::row_to_entity(&row), lines).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::sync::Arc;
+
+    use super::*;
+    use crate::application::commands::BuyProductCommand;
+    use crate::application::error::ApplicationError;
+    use crate::application::repositories::{ProductRepository, PromotionRepository};
+    use crate::application::use_cases::BuyProductUseCase;
+    use crate::domain::models::{DiscountRule, Product, ProductVariant, Promotion};
+    use crate::domain::DomainError;
+    use crate::domain::models::RoundingMode;
+    use crate::domain::tax::{TaxRegion, TaxTable};
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::{SqliteProductRepository, SqlitePromotionRepository};
+
+    async fn insert_product(name: &str, price: Money, quantity: u32) -> Product {
+        let product = Product::create(name.to_string(), price, "test product".to_string(), quantity).unwrap();
+        SqliteProductRepository::new(test_db().await).save(product).await.unwrap()
+    }
+
+    fn line(product: &Product, quantity: u32) -> OrderLine {
+        OrderLine::new(product.id, product.name.clone(), product.price, quantity)
+    }
+
+    /// 注文と明細が保存され、単価のスナップショットが残ること
+    #[tokio::test]
+    async fn place_persists_order_with_price_snapshot() {
+        let product = insert_product("order snapshot", Money::new(1200, Currency::Jpy), 5).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let repository = SqliteOrderRepository::new(test_db().await);
+
+        let order = Order::place(vec![line(&product, 2)], &TaxRegion::untaxed()).unwrap();
+        let placed = repository.place(order, &HashMap::new()).await.unwrap();
+
+        let mut changed = products.find_by_id(product.id).await.unwrap().unwrap();
+        assert_eq!(changed.quantity, 3);
+        changed.price = Money::new(9999, Currency::Jpy);
+        products.save(changed).await.unwrap();
+
+        let order = repository.find_by_id(placed.id).await.unwrap().unwrap();
+        assert_eq!(order.status, OrderStatus::Placed);
+        assert_eq!(order.total, Money::new(2400, Currency::Jpy));
+        assert_eq!(
+            order.lines,
+            vec![OrderLine::new(product.id, "order snapshot".to_string(), Money::new(1200, Currency::Jpy), 2)]
+        );
+    }
+
+    /// 注文の通貨が保存され、明細の単価も同じ通貨で読み込まれること
+    #[tokio::test]
+    async fn place_persists_order_currency() {
+        let product = insert_product("order in dollars", Money::new(1999, Currency::Usd), 5).await;
+        let repository = SqliteOrderRepository::new(test_db().await);
+
+        let order = Order::place(vec![line(&product, 3)], &TaxRegion::untaxed()).unwrap();
+        let placed = repository.place(order, &HashMap::new()).await.unwrap();
+
+        let order = repository.find_by_id(placed.id).await.unwrap().unwrap();
+        assert_eq!(order.total, Money::new(5997, Currency::Usd));
+        assert_eq!(order.lines[0].unit_price, Money::new(1999, Currency::Usd));
+    }
+
+    /// 税額が明細ごとに課税区分の税率で丸められて保存され、小計・税額・総額が読み込めること
+    #[tokio::test]
+    async fn place_persists_tax_per_line() {
+        let standard = insert_product("order taxed", Money::new(1001, Currency::Usd), 5).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let food = Product::create("order food".to_string(), Money::new(500, Currency::Usd), "test product".to_string(), 5)
+            .unwrap()
+            .with_tax_category(TaxCategory::Reduced);
+        let food = products.save(food).await.unwrap();
+        let food = products.find_by_id(food.id).await.unwrap().unwrap();
+        assert_eq!(food.tax_category, TaxCategory::Reduced);
+
+        let rates = HashMap::from([
+            (TaxCategory::Standard, TaxRate::from_percent(8.875).unwrap()),
+            (TaxCategory::Reduced, TaxRate::from_percent(4.0).unwrap()),
+        ]);
+        let region = TaxRegion::new("us-ny", TaxMode::Exclusive, RoundingMode::HalfUp, rates);
+        let food_line = line(&food, 2).with_tax(food.tax_category, TaxRate::ZERO, Money::zero(Currency::Usd));
+        let order = Order::place(vec![line(&standard, 1), food_line], &region).unwrap();
+        let placed = SqliteOrderRepository::new(test_db().await).place(order, &HashMap::new()).await.unwrap();
+
+        let order = SqliteOrderRepository::new(test_db().await).find_by_id(placed.id).await.unwrap().unwrap();
+        assert_eq!(order.tax_region.as_deref(), Some("US-NY"));
+        assert_eq!(order.tax_mode, TaxMode::Exclusive);
+        // 1001 * 8.875% = 88.8... → 89、1000 * 4% = 40
+        assert_eq!(order.lines[0].tax, Money::new(89, Currency::Usd));
+        assert_eq!(order.lines[1].tax, Money::new(40, Currency::Usd));
+        assert_eq!(order.lines[1].tax_rate, TaxRate::from_percent(4.0).unwrap());
+        assert_eq!(order.subtotal, Money::new(2001, Currency::Usd));
+        assert_eq!(order.tax, Money::new(129, Currency::Usd));
+        assert_eq!(order.total, Money::new(2130, Currency::Usd));
+    }
+
+    /// 利用上限に達したプロモーションを使う注文は作られず、在庫も減らないこと
+    #[tokio::test]
+    async fn place_enforces_promotion_usage_limit() {
+        let product = insert_product("order promoted", Money::new(1000, Currency::Jpy), 5).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let promotions = SqlitePromotionRepository::new(test_db().await);
+        let repository = SqliteOrderRepository::new(test_db().await);
+        let rule = DiscountRule::PercentOff { percent: 10 };
+        let promotion = Promotion::create("once".to_string(), rule, Some(product.id), None, None, None, Some(1)).unwrap();
+        let promotion = promotions.save(promotion).await.unwrap();
+        let discounted = || line(&product, 1).with_discount(Money::new(100, Currency::Jpy), Some(promotion.id));
+
+        let placed = repository.place(Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap(), &HashMap::new()).await.unwrap();
+        let order = repository.find_by_id(placed.id).await.unwrap().unwrap();
+        assert_eq!(order.total, Money::new(900, Currency::Jpy));
+        assert_eq!(order.lines[0].promotion_id, Some(promotion.id));
+
+        let second = Order::place(vec![discounted()], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(second, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+        assert_eq!(promotions.find_by_id(promotion.id).await.unwrap().unwrap().usage_count, 1);
+        assert_eq!(products.find_by_id(product.id).await.unwrap().unwrap().quantity, 4);
+    }
+
+    /// 1明細でも在庫が足りなければ、他の明細の在庫も注文も反映されないこと
+    #[tokio::test]
+    async fn place_rolls_back_when_any_line_lacks_stock() {
+        let enough = insert_product("order enough", Money::new(100, Currency::Jpy), 5).await;
+        let short = insert_product("order short", Money::new(100, Currency::Jpy), 1).await;
+        let products = SqliteProductRepository::new(test_db().await);
+        let repository = SqliteOrderRepository::new(test_db().await);
+
+        let order = Order::place(vec![line(&enough, 2), line(&short, 2)], &TaxRegion::untaxed()).unwrap();
+        assert!(matches!(repository.place(order, &HashMap::new()).await, Err(RepositoryError::Conflict)));
+
+        assert_eq!(products.find_by_id(enough.id).await.unwrap().unwrap().quantity, 5);
+        assert_eq!(products.find_by_id(short.id).await.unwrap().unwrap().quantity, 1);
+    }
+
+    /// 期待するバージョンと一致しない場合は注文されないこと
+    #[tokio::test]
+    async fn place_rejects_stale_version() {
+        let product = insert_product("order versioned", Money::new(100, Currency::Jpy), 5).await;
+        let repository = SqliteOrderRepository::new(test_db().await);
+
+        let order = Order::place(vec![line(&product, 1)], &TaxRegion::untaxed()).unwrap();
+        let stale = HashMap::from([(product.id, product.version + 1)]);
+        assert!(matches!(repository.place(order, &stale).await, Err(RepositoryError::Conflict)));
+    }
+
+    /// 大量の同時購入でも在庫を超えて販売されず、販売数と同じ数の注文が作られること
+    #[tokio::test(flavor = "multi_thread", worker_threads = 8)]
+    async fn concurrent_buys_never_oversell() {
+        const STOCK: u32 = 50;
+        const BUYERS: usize = 300;
+
+        let product = insert_product("concurrent", Money::new(100, Currency::Jpy), STOCK).await;
+        let products = Arc::new(SqliteProductRepository::new(test_db().await));
+        let orders = Arc::new(SqliteOrderRepository::new(test_db().await));
+        let use_case = Arc::new(BuyProductUseCase::new(
+            products.clone(),
+            orders.clone(),
+            Arc::new(SqlitePromotionRepository::new(test_db().await)),
+            Arc::new(TaxTable::untaxed()),
+        ));
+
+        let handles: Vec<_> = (0..BUYERS)
+            .map(|_| {
+                let use_case = use_case.clone();
+                tokio::spawn(async move {
+                    let command = BuyProductCommand { quantity: 1, sku: None, expected_version: None, coupon_code: None, region: None };
+                    use_case.buy(product.id, command).await
+                })
+            })
+            .collect();
+
+        let mut order_ids = Vec::new();
+        for handle in handles {
+            match handle.await.unwrap() {
+                Ok(order) => order_ids.push(order.id),
+                Err(ApplicationError::Domain(DomainError::InsufficientQuantity { .. })) => {}
+                Err(e) => panic!("unexpected error: {}", e),
+            }
+        }
+
+        let product = products.find_by_id(product.id).await.unwrap().unwrap();
+        assert_eq!(order_ids.len(), STOCK as usize);
+        assert_eq!(product.quantity, 0);
+        for id in order_ids {
+            assert!(orders.find_by_id(id).await.unwrap().is_some());
+        }
+    }
+
+    /// SKUを指定した購入はバリエーションの価格で注文され、バリエーションと商品の両方の在庫が減ること
+    #[tokio::test]
+    async fn buy_by_sku_decrements_variant_stock() {
+        let mut product = Product::create("order variants".to_string(), Money::new(1000, Currency::Jpy), "test product".to_string(), 0).unwrap();
+        for (sku, layout, price) in [("ORDER-US", "US", 1000), ("ORDER-JIS", "JIS", 1500)] {
+            let attributes = BTreeMap::from([("layout".to_string(), layout.to_string())]);
+            product.add_variant(ProductVariant::new(sku, attributes, Money::new(price, Currency::Jpy), 2)).unwrap();
+        }
+        let products = Arc::new(SqliteProductRepository::new(test_db().await));
+        let product = products.save(product).await.unwrap();
+        let use_case = BuyProductUseCase::new(
+            products.clone(),
+            Arc::new(SqliteOrderRepository::new(test_db().await)),
+            Arc::new(SqlitePromotionRepository::new(test_db().await)),
+            Arc::new(TaxTable::untaxed()),
+        );
+        let command = |sku: Option<&str>, quantity| BuyProductCommand {
+            quantity,
+            sku: sku.map(str::to_string),
+            expected_version: None,
+            coupon_code: None,
+            region: None,
+        };
+
+        let order = use_case.buy(product.id, command(Some("order-jis"), 2)).await.unwrap();
+        assert_eq!(order.lines[0].sku.as_deref(), Some("ORDER-JIS"));
+        assert_eq!(order.total, Money::new(3000, Currency::Jpy));
+
+        let product = products.find_by_id(product.id).await.unwrap().unwrap();
+        assert_eq!(product.variant("order-jis").unwrap().quantity, 0);
+        assert_eq!(product.quantity, 2);
+        assert!(matches!(
+            use_case.buy(product.id, command(Some("order-jis"), 1)).await,
+            Err(ApplicationError::Domain(DomainError::InsufficientQuantity { available: 0, .. }))
+        ));
+        assert!(matches!(
+            use_case.buy(product.id, command(None, 1)).await,
+            Err(ApplicationError::Domain(DomainError::VariantRequired(_)))
+        ));
+    }
+}
@@ -1,67 +1,395 @@
-use sqlx::Row;
+use sqlx::sqlite::{SqliteConnection, SqliteRow};
+use sqlx::{Pool, QueryBuilder, Row, Sqlite};
 use chrono::Utc;
+use std::collections::BTreeMap;
+use std::sync::Arc;
 
-use crate::domain::models::Product;
-use crate::frameworks_and_drivers::database::db::get_db;
-use crate::frameworks_and_drivers::persistence::entities::ProductEntity;
-use crate::application::repositories::ProductRepository;
+use crate::domain::models::{Category, Currency, Money, Product, ProductVariant};
+use crate::domain::tax::TaxCategory;
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::{ProductEntity, ProductVariantEntity};
+use crate::application::repositories::{
+    Page, ProductFilter, ProductListCriteria, ProductRepository, ProductSearchHit, ProductSortKey,
+    SortOrder,
+};
 use crate::application::error::RepositoryError;
+use super::search_highlight::{marked_to_html, MARK_END, MARK_START};
 
-pub struct SqliteProductRepository;
+pub struct SqliteProductRepository {
+    db: Arc<SqliteDatabase>,
+}
 
 impl SqliteProductRepository {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
     }
     
     // エンティティからドメインモデルへのマッピング
-    fn entity_to_domain(entity: ProductEntity) -> Product {
-        Product::new(
+    fn entity_to_domain(entity: ProductEntity) -> Result<Product, RepositoryError> {
+        let currency = Currency::parse(&entity.currency)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", entity.currency)))?;
+        let tax_category = TaxCategory::parse(&entity.tax_category)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown tax category: {}", entity.tax_category)))?;
+
+        Ok(Product::new(
             entity.id,
             entity.name,
-            entity.price,
+            Money::new(entity.price as u64, currency),
             entity.description,
             entity.quantity,
+            entity.reserved,
+            entity.version,
         )
+        .with_tax_category(tax_category))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> ProductEntity {
+        ProductEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            price: row.get("price"),
+            currency: row.get("currency"),
+            tax_category: row.get("tax_category"),
+            description: row.get("description"),
+            quantity: row.get("quantity"),
+            reserved: row.get("reserved"),
+            version: row.get("version"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+
+    // バリエーションのエンティティからドメインモデルへのマッピング
+    // 価格の通貨は商品と同じ
+    fn variant_to_domain(entity: ProductVariantEntity, currency: Currency) -> Result<ProductVariant, RepositoryError> {
+        let attributes: BTreeMap<String, String> = serde_json::from_str(&entity.attributes)
+            .map_err(|e| RepositoryError::Unknown(format!("invalid attributes of variant {}: {}", entity.sku, e)))?;
+
+        Ok(ProductVariant::new(&entity.sku, attributes, Money::new(entity.price as u64, currency), entity.quantity))
+    }
+
+    fn row_to_variant_entity(row: &SqliteRow) -> ProductVariantEntity {
+        ProductVariantEntity {
+            product_id: row.get("product_id"),
+            sku: row.get("sku"),
+            attributes: row.get("attributes"),
+            price: row.get("price"),
+            quantity: row.get("quantity"),
+        }
+    }
+
+    // 絞り込み条件をWHERE句として追加
+    fn push_filter(query: &mut QueryBuilder<'_, Sqlite>, filter: &ProductFilter) {
+        query.push(" WHERE 1 = 1");
+        if let Some(currency) = filter.currency {
+            query.push(" AND currency = ").push_bind(currency.code());
+        }
+        if let Some(min_price) = filter.min_price {
+            query.push(" AND price >= ").push_bind(min_price);
+        }
+        if let Some(max_price) = filter.max_price {
+            query.push(" AND price <= ").push_bind(max_price);
+        }
+        if filter.in_stock_only {
+            query.push(" AND quantity > 0");
+        }
+        // 子孫のカテゴリに属する商品も対象にする
+        if let Some(category_id) = filter.category_id {
+            query
+                .push(
+                    " AND id IN (SELECT product_id FROM product_categories WHERE category_id IN (\
+                     WITH RECURSIVE subtree(id) AS (SELECT ",
+                )
+                .push_bind(category_id)
+                .push(
+                    " UNION SELECT c.id FROM categories c JOIN subtree s ON c.parent_id = s.id) \
+                     SELECT id FROM subtree))",
+                );
+        }
+        if let Some(tag) = &filter.tag {
+            query
+                .push(" AND id IN (SELECT pt.product_id FROM product_tags pt JOIN tags t ON t.id = pt.tag_id WHERE t.name = ")
+                .push_bind(tag.clone())
+                .push(")");
+        }
+    }
+
+    // 商品のカテゴリ・タグ・バリエーションをまとめて読み込む
+    async fn load_relations(pool: &Pool<Sqlite>, mut products: Vec<&mut Product>) -> Result<(), RepositoryError> {
+        if products.is_empty() {
+            return Ok(());
+        }
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT pc.product_id, c.id, c.name, c.parent_id FROM product_categories pc \
+             JOIN categories c ON c.id = pc.category_id WHERE pc.product_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for product in &products {
+            separated.push_bind(product.id);
+        }
+        query.push(") ORDER BY c.id");
+        let category_rows = query
+            .build()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut query = QueryBuilder::<Sqlite>::new(
+            "SELECT pt.product_id, t.name FROM product_tags pt \
+             JOIN tags t ON t.id = pt.tag_id WHERE pt.product_id IN (",
+        );
+        let mut separated = query.separated(", ");
+        for product in &products {
+            separated.push_bind(product.id);
+        }
+        query.push(") ORDER BY t.name");
+        let tag_rows = query
+            .build()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM product_variants WHERE product_id IN (");
+        let mut separated = query.separated(", ");
+        for product in &products {
+            separated.push_bind(product.id);
+        }
+        query.push(") ORDER BY id");
+        let variant_rows = query
+            .build()
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        for product in products.iter_mut() {
+            product.categories = category_rows
+                .iter()
+                .filter(|row| row.get::<u32, _>("product_id") == product.id)
+                .map(|row| Category::new(row.get("id"), row.get("name"), row.get("parent_id")))
+                .collect();
+            product.tags = tag_rows
+                .iter()
+                .filter(|row| row.get::<u32, _>("product_id") == product.id)
+                .map(|row| row.get("name"))
+                .collect();
+            product.variants = variant_rows
+                .iter()
+                .filter(|row| row.get::<u32, _>("product_id") == product.id)
+                .map(|row| Self::variant_to_domain(Self::row_to_variant_entity(row), product.price.currency()))
+                .collect::<Result<Vec<_>, RepositoryError>>()?;
+        }
+        Ok(())
+    }
+
+    // 商品のカテゴリとタグの関連付け、バリエーションを置き換える
+    async fn replace_relations(conn: &mut SqliteConnection, product: &Product) -> Result<(), RepositoryError> {
+        sqlx::query("DELETE FROM product_categories WHERE product_id = ?")
+            .bind(product.id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        for category in &product.categories {
+            sqlx::query("INSERT OR IGNORE INTO product_categories (product_id, category_id) VALUES (?, ?)")
+                .bind(product.id)
+                .bind(category.id)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        sqlx::query("DELETE FROM product_tags WHERE product_id = ?")
+            .bind(product.id)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        for tag in &product.tags {
+            sqlx::query("INSERT INTO tags (name) VALUES (?) ON CONFLICT (name) DO NOTHING")
+                .bind(tag)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+            sqlx::query("INSERT OR IGNORE INTO product_tags (product_id, tag_id) SELECT ?, id FROM tags WHERE name = ?")
+                .bind(product.id)
+                .bind(tag)
+                .execute(&mut *conn)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        // 残すバリエーションはSKUで更新し、在庫の変化をidで追えるようにする
+        let mut query = QueryBuilder::<Sqlite>::new("DELETE FROM product_variants WHERE product_id = ");
+        query.push_bind(product.id);
+        if product.has_variants() {
+            query.push(" AND sku NOT IN (");
+            let mut separated = query.separated(", ");
+            for variant in &product.variants {
+                separated.push_bind(variant.sku.clone());
+            }
+            query.push(")");
+        }
+        query
+            .build()
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let now = Utc::now().to_rfc3339();
+        for variant in &product.variants {
+            let attributes = serde_json::to_string(&variant.attributes)
+                .map_err(|e| RepositoryError::Unknown(e.to_string()))?;
+            // 他の商品が同じSKUを使っている場合は更新されない
+            let result = sqlx::query(
+                "INSERT INTO product_variants (product_id, sku, attributes, price, quantity, created_at, updated_at) \
+                 VALUES (?, ?, ?, ?, ?, ?, ?) \
+                 ON CONFLICT (sku) DO UPDATE SET attributes = excluded.attributes, price = excluded.price, \
+                 quantity = excluded.quantity, updated_at = excluded.updated_at \
+                 WHERE product_variants.product_id = excluded.product_id"
+            )
+            .bind(product.id)
+            .bind(&variant.sku)
+            .bind(attributes)
+            .bind(variant.price.amount() as i64)
+            .bind(variant.quantity)
+            .bind(&now)
+            .bind(&now)
+            .execute(&mut *conn)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+        Ok(())
+    }
+
+    // 入力されたキーワードをFTS5のMATCH式に変換
+    // 各語をフレーズとしてクォートし、演算子として解釈されないようにした上で前方一致させる
+    fn to_match_expression(keyword: &str) -> Option<String> {
+        let terms: Vec<String> = keyword
+            .split_whitespace()
+            .map(|term| format!("\"{}\"*", term.replace('"', "\"\"")))
+            .collect();
+
+        (!terms.is_empty()).then(|| terms.join(" "))
+    }
+
+    fn sort_column(key: ProductSortKey) -> &'static str {
+        match key {
+            ProductSortKey::Id => "id",
+            ProductSortKey::Name => "name",
+            ProductSortKey::Price => "price",
+            ProductSortKey::CreatedAt => "created_at",
+        }
+    }
+
+    fn sort_direction(order: SortOrder) -> &'static str {
+        match order {
+            SortOrder::Asc => "ASC",
+            SortOrder::Desc => "DESC",
+        }
     }
 }
 
 #[async_trait::async_trait]
 impl ProductRepository for SqliteProductRepository {
-    async fn find_all(&self) -> Result<Vec<Product>, RepositoryError> {
-        let db = get_db().await
-            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
-        let pool = db.get_pool();
-        
-        let rows = sqlx::query("SELECT * FROM products")
+    #[tracing::instrument(name = "product_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self, criteria: &ProductListCriteria) -> Result<Page<Product>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let mut count_query = QueryBuilder::<Sqlite>::new("SELECT COUNT(*) FROM products");
+        Self::push_filter(&mut count_query, &criteria.filter);
+        let total: i64 = count_query
+            .build_query_scalar()
+            .fetch_one(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM products");
+        Self::push_filter(&mut query, &criteria.filter);
+        // 並び替えキーが同じ値の場合もページ間で順序が揺れないようにidを第2キーにする
+        // 価格は通貨ごとの最小単位のため、通貨をまたいで比較せず通貨ごとにまとめる
+        let currency_group = if criteria.sort_key == ProductSortKey::Price { "currency ASC, " } else { "" };
+        query.push(format!(
+            " ORDER BY {}{} {}, id {}",
+            currency_group,
+            Self::sort_column(criteria.sort_key),
+            Self::sort_direction(criteria.sort_order),
+            Self::sort_direction(criteria.sort_order),
+        ));
+        query.push(" LIMIT ").push_bind(criteria.pagination.limit());
+        query.push(" OFFSET ").push_bind(i64::try_from(criteria.pagination.offset()).unwrap_or(i64::MAX));
+
+        let rows = query
+            .build()
             .fetch_all(pool)
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
         
-        let products = rows
+        let mut products = rows
+            .iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect::<Result<Vec<Product>, RepositoryError>>()?;
+        Self::load_relations(pool, products.iter_mut().collect()).await?;
+
+        Ok(Page {
+            items: products,
+            total: total as u64,
+            pagination: criteria.pagination,
+        })
+    }
+
+    #[tracing::instrument(name = "product_repository.search", skip(self), err(level = "warn"))]
+    async fn search(&self, keyword: &str, limit: u32) -> Result<Vec<ProductSearchHit>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let Some(match_expression) = Self::to_match_expression(keyword) else {
+            return Ok(Vec::new());
+        };
+
+        // bm25は関連度が高いほど小さい値を返すため、昇順に並べて符号を反転したものをスコアとする
+        let rows = sqlx::query(
+            r#"
+            SELECT p.*,
+                   bm25(products_fts) AS rank,
+                   highlight(products_fts, 0, ?1, ?2) AS highlighted_name,
+                   snippet(products_fts, 1, ?1, ?2, '…', 16) AS snippet
+            FROM products_fts
+            JOIN products p ON p.id = products_fts.rowid
+            WHERE products_fts MATCH ?3
+            ORDER BY rank
+            LIMIT ?4
+            "#
+        )
+        .bind(MARK_START)
+        .bind(MARK_END)
+        .bind(match_expression)
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut hits = rows
             .iter()
             .map(|row| {
-                let entity = ProductEntity {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    price: row.get("price"),
-                    description: row.get("description"),
-                    quantity: row.get("quantity"),
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                };
-                
-                Self::entity_to_domain(entity)
+                Ok(ProductSearchHit {
+                    product: Self::entity_to_domain(Self::row_to_entity(row))?,
+                    score: -row.get::<f64, _>("rank"),
+                    highlighted_name: marked_to_html(row.get("highlighted_name")),
+                    snippet: marked_to_html(row.get("snippet")),
+                })
             })
-            .collect::<Vec<Product>>();
-        
-        Ok(products)
+            .collect::<Result<Vec<ProductSearchHit>, RepositoryError>>()?;
+        Self::load_relations(pool, hits.iter_mut().map(|hit| &mut hit.product).collect()).await?;
+
+        Ok(hits)
     }
 
+    #[tracing::instrument(name = "product_repository.find_by_id", skip(self), err(level = "warn"))]
     async fn find_by_id(&self, id: u32) -> Result<Option<Product>, RepositoryError> {
-        let db = get_db().await
-            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
-        let pool = db.get_pool();
+        let pool = self.db.get_pool();
         
         let row = sqlx::query("SELECT * FROM products WHERE id = ?")
             .bind(id)
@@ -69,71 +397,134 @@ impl ProductRepository for SqliteProductRepository {
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
         
-        match row {
-            Some(row) => {
-                let entity = ProductEntity {
-                    id: row.get("id"),
-                    name: row.get("name"),
-                    price: row.get("price"),
-                    description: row.get("description"),
-                    quantity: row.get("quantity"),
-                    created_at: row.get("created_at"),
-                    updated_at: row.get("updated_at"),
-                };
-                
-                Ok(Some(Self::entity_to_domain(entity)))
-            },
-            None => Ok(None),
-        }
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut product = Self::entity_to_domain(Self::row_to_entity(&row))?;
+        Self::load_relations(pool, vec![&mut product]).await?;
+
+        Ok(Some(product))
     }
 
-    async fn save(&self, product: Product) -> Result<(), RepositoryError> {
-        let db = get_db().await
+    #[tracing::instrument(name = "product_repository.find_by_sku", skip(self), err(level = "warn"))]
+    async fn find_by_sku(&self, sku: &str) -> Result<Option<Product>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let row = sqlx::query(
+            "SELECT p.* FROM products p JOIN product_variants v ON v.product_id = p.id WHERE v.sku = ?"
+        )
+        .bind(ProductVariant::normalize_sku(sku))
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+        let mut product = Self::entity_to_domain(Self::row_to_entity(&row))?;
+        Self::load_relations(pool, vec![&mut product]).await?;
+
+        Ok(Some(product))
+    }
+
+    #[tracing::instrument(name = "product_repository.save", skip(self, product), fields(product_id = product.id), err(level = "warn"))]
+    async fn save(&self, mut product: Product) -> Result<Product, RepositoryError> {
+        // 商品と関連付けを同時に更新する
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
             .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
-        let pool = db.get_pool();
         
         let now = Utc::now().to_rfc3339();
         
-        // 既存のプロダクトを検索
-        let existing = sqlx::query("SELECT * FROM products WHERE id = ?")
+        if product.id == 0 {
+            // 新規作成
+            let result = sqlx::query(
+                "INSERT INTO products (name, price, currency, tax_category, description, quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?, ?, ?)"
+            )
+            .bind(&product.name)
+            .bind(product.price.amount() as i64)
+            .bind(product.price.currency().code())
+            .bind(product.tax_category.as_str())
+            .bind(&product.description)
+            .bind(product.quantity)
+            .bind(&now)
+            .bind(&now)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            product.id = result.last_insert_rowid() as u32;
+            product.version = 0;
+        } else {
+            // 更新（読み込み時のバージョンと一致する場合のみ）
+            // 最初に書き込むことで、読み込み後に書き込みロックへ昇格できずに失敗するのを避ける
+            let result = sqlx::query(
+                "UPDATE products SET name = ?, price = ?, currency = ?, tax_category = ?, description = ?, quantity = ?, version = version + 1, updated_at = ? WHERE id = ? AND version = ?"
+            )
+            .bind(&product.name)
+            .bind(product.price.amount() as i64)
+            .bind(product.price.currency().code())
+            .bind(product.tax_category.as_str())
+            .bind(&product.description)
+            .bind(product.quantity)
+            .bind(&now)
             .bind(product.id)
-            .fetch_optional(pool)
+            .bind(product.version)
+            .execute(&mut *tx)
             .await
             .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
-        
-        match existing {
-            // 更新
-            Some(_) => {
-                sqlx::query(
-                    "UPDATE products SET name = ?, price = ?, description = ?, quantity = ?, updated_at = ? WHERE id = ?"
-                )
-                .bind(&product.name)
-                .bind(product.price)
-                .bind(&product.description)
-                .bind(product.quantity)
-                .bind(&now)
-                .bind(product.id)
-                .execute(pool)
-                .await
-                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
-            },
-            // 新規作成
-            None => {
-                sqlx::query(
-                    "INSERT INTO products (name, price, description, quantity, created_at, updated_at) VALUES (?, ?, ?, ?, ?, ?)"
-                )
-                .bind(&product.name)
-                .bind(product.price)
-                .bind(&product.description)
-                .bind(product.quantity)
-                .bind(&now)
-                .bind(&now)
-                .execute(pool)
-                .await
-                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                // 更新されなかった理由が削除済みなのかバージョン不一致なのかを判定
+                let exists = sqlx::query("SELECT id FROM products WHERE id = ?")
+                    .bind(product.id)
+                    .fetch_optional(&mut *tx)
+                    .await
+                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                return match exists {
+                    Some(_) => Err(RepositoryError::Conflict),
+                    None => Err(RepositoryError::NotFound),
+                };
             }
+            product.version += 1;
+        }
+        Self::replace_relations(&mut tx, &product).await?;
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(product)
+    }
+
+    #[tracing::instrument(name = "product_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32, expected_version: Option<u32>) -> Result<(), RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let result = sqlx::query("DELETE FROM products WHERE id = ? AND (? IS NULL OR version = ?)")
+            .bind(id)
+            .bind(expected_version)
+            .bind(expected_version)
+            .execute(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 1 {
+            return Ok(());
+        }
+
+        // 削除されなかった理由が存在しないのかバージョン不一致なのかを判定
+        let exists = sqlx::query("SELECT id FROM products WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match exists {
+            Some(_) => Err(RepositoryError::Conflict),
+            None => Err(RepositoryError::NotFound),
         }
-        
-        Ok(())
     }
 }
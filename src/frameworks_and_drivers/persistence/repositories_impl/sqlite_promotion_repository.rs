@@ -0,0 +1,260 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, Utc};
+use sqlx::sqlite::SqliteRow;
+use sqlx::{QueryBuilder, Row, Sqlite};
+
+use crate::domain::models::{Currency, DiscountRule, Money, Promotion};
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::PromotionEntity;
+use crate::frameworks_and_drivers::persistence::sortable_timestamp;
+use crate::application::repositories::PromotionRepository;
+use crate::application::error::RepositoryError;
+
+pub struct SqlitePromotionRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqlitePromotionRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: PromotionEntity) -> Result<Promotion, RepositoryError> {
+        let missing = |column: &str| RepositoryError::Unknown(format!("promotion {} has no {}", entity.id, column));
+        let rule = match entity.kind.as_str() {
+            "percent_off" => DiscountRule::PercentOff {
+                percent: entity.percent.ok_or_else(|| missing("percent"))?,
+            },
+            "amount_off" => {
+                let amount = entity.amount.ok_or_else(|| missing("amount"))?;
+                let code = entity.currency.as_deref().ok_or_else(|| missing("currency"))?;
+                let currency = Currency::parse(code)
+                    .ok_or_else(|| RepositoryError::Unknown(format!("unknown currency: {}", code)))?;
+                DiscountRule::AmountOff { amount: Money::new(amount as u64, currency) }
+            }
+            "buy_n_get_m" => DiscountRule::BuyNGetM {
+                buy: entity.buy_quantity.ok_or_else(|| missing("buy_quantity"))?,
+                get: entity.get_quantity.ok_or_else(|| missing("get_quantity"))?,
+            },
+            kind => return Err(RepositoryError::Unknown(format!("unknown promotion kind: {}", kind))),
+        };
+
+        Ok(Promotion::new(
+            entity.id,
+            entity.name,
+            rule,
+            entity.product_id,
+            entity.coupon_code,
+            Self::parse_timestamp(entity.starts_at)?,
+            Self::parse_timestamp(entity.ends_at)?,
+            entity.usage_limit,
+            entity.usage_count,
+        ))
+    }
+
+    fn parse_timestamp(value: Option<String>) -> Result<Option<DateTime<Utc>>, RepositoryError> {
+        value
+            .map(|value| {
+                DateTime::parse_from_rfc3339(&value)
+                    .map(|at| at.with_timezone(&Utc))
+                    .map_err(|e| RepositoryError::Unknown(e.to_string()))
+            })
+            .transpose()
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> PromotionEntity {
+        PromotionEntity {
+            id: row.get("id"),
+            name: row.get("name"),
+            kind: row.get("kind"),
+            percent: row.get("percent"),
+            amount: row.get("amount"),
+            currency: row.get("currency"),
+            buy_quantity: row.get("buy_quantity"),
+            get_quantity: row.get("get_quantity"),
+            product_id: row.get("product_id"),
+            coupon_code: row.get("coupon_code"),
+            starts_at: row.get("starts_at"),
+            ends_at: row.get("ends_at"),
+            usage_limit: row.get("usage_limit"),
+            usage_count: row.get("usage_count"),
+            created_at: row.get("created_at"),
+        }
+    }
+
+    fn rows_to_domain(rows: &[SqliteRow]) -> Result<Vec<Promotion>, RepositoryError> {
+        rows.iter()
+            .map(|row| Self::entity_to_domain(Self::row_to_entity(row)))
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl PromotionRepository for SqlitePromotionRepository {
+    #[tracing::instrument(name = "promotion_repository.find_all", skip(self), err(level = "warn"))]
+    async fn find_all(&self) -> Result<Vec<Promotion>, RepositoryError> {
+        let rows = sqlx::query("SELECT * FROM promotions ORDER BY id")
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Self::rows_to_domain(&rows)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Promotion>, RepositoryError> {
+        let row = sqlx::query("SELECT * FROM promotions WHERE id = ?")
+            .bind(id)
+            .fetch_optional(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        row.map(|row| Self::entity_to_domain(Self::row_to_entity(&row))).transpose()
+    }
+
+    #[tracing::instrument(name = "promotion_repository.find_candidates", skip(self), err(level = "warn"))]
+    async fn find_candidates(&self, product_ids: &[u32], coupon_code: Option<&str>) -> Result<Vec<Promotion>, RepositoryError> {
+        let mut query = QueryBuilder::<Sqlite>::new("SELECT * FROM promotions WHERE (product_id IS NULL");
+        if !product_ids.is_empty() {
+            query.push(" OR product_id IN (");
+            let mut separated = query.separated(", ");
+            for product_id in product_ids {
+                separated.push_bind(*product_id);
+            }
+            query.push(")");
+        }
+        query.push(") AND (coupon_code IS NULL");
+        if let Some(coupon_code) = coupon_code {
+            query.push(" OR coupon_code = ").push_bind(Promotion::normalize_code(coupon_code));
+        }
+        query.push(") ORDER BY id");
+
+        let rows = query
+            .build()
+            .fetch_all(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Self::rows_to_domain(&rows)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.save", skip(self, promotion), err(level = "warn"))]
+    async fn save(&self, mut promotion: Promotion) -> Result<Promotion, RepositoryError> {
+        let (percent, amount, buy_quantity, get_quantity) = match promotion.rule {
+            DiscountRule::PercentOff { percent } => (Some(percent), None, None, None),
+            DiscountRule::AmountOff { amount } => (None, Some(amount), None, None),
+            DiscountRule::BuyNGetM { buy, get } => (None, None, Some(buy), Some(get)),
+        };
+
+        let result = sqlx::query(
+            "INSERT INTO promotions \
+             (name, kind, percent, amount, currency, buy_quantity, get_quantity, product_id, coupon_code, starts_at, ends_at, usage_limit, usage_count, created_at) \
+             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)"
+        )
+        .bind(&promotion.name)
+        .bind(promotion.rule.kind())
+        .bind(percent)
+        .bind(amount.map(|amount| amount.amount() as i64))
+        .bind(amount.map(|amount| amount.currency().code()))
+        .bind(buy_quantity)
+        .bind(get_quantity)
+        .bind(promotion.product_id)
+        .bind(&promotion.coupon_code)
+        .bind(promotion.starts_at.map(sortable_timestamp))
+        .bind(promotion.ends_at.map(sortable_timestamp))
+        .bind(promotion.usage_limit)
+        .bind(promotion.usage_count)
+        .bind(Utc::now().to_rfc3339())
+        .execute(self.db.get_pool())
+        .await
+        .map_err(|e| match e.as_database_error() {
+            Some(db_error) if db_error.is_unique_violation() => RepositoryError::Conflict,
+            _ => RepositoryError::QueryExecution(e.to_string()),
+        })?;
+
+        promotion.id = result.last_insert_rowid() as u32;
+        Ok(promotion)
+    }
+
+    #[tracing::instrument(name = "promotion_repository.delete", skip(self), err(level = "warn"))]
+    async fn delete(&self, id: u32) -> Result<(), RepositoryError> {
+        let result = sqlx::query("DELETE FROM promotions WHERE id = ?")
+            .bind(id)
+            .execute(self.db.get_pool())
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::application::repositories::ProductRepository;
+    use crate::domain::models::Product;
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::SqliteProductRepository;
+
+    async fn insert_product(name: &str) -> Product {
+        let product = Product::create(name.to_string(), Money::new(1000, Currency::Jpy), "test product".to_string(), 5).unwrap();
+        SqliteProductRepository::new(test_db().await).save(product).await.unwrap()
+    }
+
+    fn promotion(product_id: u32, coupon_code: Option<&str>) -> Promotion {
+        let rule = DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) };
+        Promotion::create("test".to_string(), rule, Some(product_id), coupon_code.map(str::to_string), None, None, None)
+            .unwrap()
+    }
+
+    /// 保存したルールと期間がそのまま読み込まれること
+    #[tokio::test]
+    async fn save_round_trips_rule_and_period() {
+        let product = insert_product("promotion round trip").await;
+        let repository = SqlitePromotionRepository::new(test_db().await);
+        let starts_at = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+        let mut saved = promotion(product.id, Some("round-trip"));
+        saved.starts_at = Some(starts_at);
+        let saved = repository.save(saved).await.unwrap();
+
+        let found = repository.find_by_id(saved.id).await.unwrap().unwrap();
+        assert_eq!(found.rule, DiscountRule::AmountOff { amount: Money::new(150, Currency::Jpy) });
+        assert_eq!(found.coupon_code.as_deref(), Some("ROUND-TRIP"));
+        assert_eq!(found.starts_at, Some(starts_at));
+        assert_eq!(found.ends_at, None);
+    }
+
+    /// クーポン付きの候補はコードが一致した場合だけ返ること（大文字・小文字は区別しない）
+    #[tokio::test]
+    async fn find_candidates_requires_matching_coupon() {
+        let product = insert_product("promotion candidates").await;
+        let other = insert_product("promotion other").await;
+        let repository = SqlitePromotionRepository::new(test_db().await);
+        let automatic = repository.save(promotion(product.id, None)).await.unwrap();
+        let coupon = repository.save(promotion(product.id, Some("CANDIDATE"))).await.unwrap();
+        repository.save(promotion(other.id, None)).await.unwrap();
+
+        let ids = |promotions: Vec<Promotion>| promotions.iter().map(|p| p.id).collect::<Vec<_>>();
+        let without_code = repository.find_candidates(&[product.id], None).await.unwrap();
+        assert_eq!(ids(without_code), vec![automatic.id]);
+        let with_code = repository.find_candidates(&[product.id], Some("candidate")).await.unwrap();
+        assert_eq!(ids(with_code), vec![automatic.id, coupon.id]);
+    }
+
+    /// 同じクーポンコードは保存できないこと
+    #[tokio::test]
+    async fn save_rejects_duplicate_coupon_code() {
+        let product = insert_product("promotion duplicate").await;
+        let repository = SqlitePromotionRepository::new(test_db().await);
+        repository.save(promotion(product.id, Some("TWICE"))).await.unwrap();
+
+        let duplicate = repository.save(promotion(product.id, Some("twice"))).await;
+        assert!(matches!(duplicate, Err(RepositoryError::Conflict)));
+    }
+}
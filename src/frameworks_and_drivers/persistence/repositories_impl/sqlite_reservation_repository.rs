@@ -0,0 +1,448 @@
+use std::sync::Arc;
+
+use chrono::{DateTime, SubsecRound, Utc};
+use sqlx::sqlite::{SqliteConnection, SqlitePool, SqliteRow};
+use sqlx::Row;
+
+use crate::domain::models::{Order, Reservation, ReservationItem, ReservationStatus};
+use crate::frameworks_and_drivers::database::db::SqliteDatabase;
+use crate::frameworks_and_drivers::persistence::entities::{ReservationEntity, ReservationItemEntity};
+use crate::frameworks_and_drivers::persistence::sortable_timestamp;
+use crate::frameworks_and_drivers::persistence::repositories_impl::SqliteOrderRepository;
+use crate::application::repositories::ReservationRepository;
+use crate::application::error::RepositoryError;
+
+pub struct SqliteReservationRepository {
+    db: Arc<SqliteDatabase>,
+}
+
+impl SqliteReservationRepository {
+    pub fn new(db: Arc<SqliteDatabase>) -> Self {
+        Self { db }
+    }
+
+    // エンティティからドメインモデルへのマッピング
+    fn entity_to_domain(entity: ReservationEntity, items: Vec<ReservationItemEntity>) -> Result<Reservation, RepositoryError> {
+        let status = ReservationStatus::parse(&entity.status)
+            .ok_or_else(|| RepositoryError::Unknown(format!("unknown reservation status: {}", entity.status)))?;
+        let expires_at = DateTime::parse_from_rfc3339(&entity.expires_at)
+            .map_err(|e| RepositoryError::Unknown(e.to_string()))?
+            .with_timezone(&Utc);
+        let items = items
+            .into_iter()
+            .map(|item| ReservationItem { product_id: item.product_id, sku: item.sku, quantity: item.quantity })
+            .collect();
+
+        Ok(Reservation::new(entity.id, items, status, expires_at, entity.order_id))
+    }
+
+    // 行からエンティティへのマッピング
+    fn row_to_entity(row: &SqliteRow) -> ReservationEntity {
+        ReservationEntity {
+            id: row.get("id"),
+            status: row.get("status"),
+            expires_at: row.get("expires_at"),
+            order_id: row.get("order_id"),
+            created_at: row.get("created_at"),
+            updated_at: row.get("updated_at"),
+        }
+    }
+
+    fn row_to_item_entity(row: &SqliteRow) -> ReservationItemEntity {
+        ReservationItemEntity {
+            product_id: row.get("product_id"),
+            sku: row.get("sku"),
+            quantity: row.get("quantity"),
+        }
+    }
+
+    // 行と予約された商品を読み込んでドメインモデルにする
+    async fn load(pool: &SqlitePool, row: &SqliteRow) -> Result<Reservation, RepositoryError> {
+        let entity = Self::row_to_entity(row);
+        let items = sqlx::query("SELECT * FROM reservation_items WHERE reservation_id = ? ORDER BY position")
+            .bind(entity.id)
+            .fetch_all(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+            .iter()
+            .map(Self::row_to_item_entity)
+            .collect();
+
+        Self::entity_to_domain(entity, items)
+    }
+
+    // 有効な予約の状態を変更する。既に有効でなければ競合とする
+    async fn close_in(
+        conn: &mut SqliteConnection,
+        reservation: &Reservation,
+        order_id: Option<u32>,
+        now: &str,
+    ) -> Result<(), RepositoryError> {
+        let result = sqlx::query(
+            "UPDATE reservations SET status = ?, order_id = ?, updated_at = ? WHERE id = ? AND status = ?"
+        )
+        .bind(reservation.status.as_str())
+        .bind(order_id)
+        .bind(now)
+        .bind(reservation.id)
+        .bind(ReservationStatus::Active.as_str())
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::Conflict);
+        }
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl ReservationRepository for SqliteReservationRepository {
+    #[tracing::instrument(name = "reservation_repository.find_by_id", skip(self), err(level = "warn"))]
+    async fn find_by_id(&self, id: u32) -> Result<Option<Reservation>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let row = sqlx::query("SELECT * FROM reservations WHERE id = ?")
+            .bind(id)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        match row {
+            Some(row) => Self::load(pool, &row).await.map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[tracing::instrument(name = "reservation_repository.find_expired", skip(self), err(level = "warn"))]
+    async fn find_expired(&self, now: DateTime<Utc>, limit: u32) -> Result<Vec<Reservation>, RepositoryError> {
+        let pool = self.db.get_pool();
+
+        let rows = sqlx::query(
+            "SELECT * FROM reservations WHERE status = ? AND expires_at <= ? ORDER BY expires_at, id LIMIT ?"
+        )
+        .bind(ReservationStatus::Active.as_str())
+        .bind(sortable_timestamp(now))
+        .bind(limit)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        let mut reservations = Vec::with_capacity(rows.len());
+        for row in &rows {
+            reservations.push(Self::load(pool, row).await?);
+        }
+        Ok(reservations)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.reserve", skip(self, reservation), fields(items = reservation.items.len()), err(level = "warn"))]
+    async fn reserve(&self, mut reservation: Reservation) -> Result<Reservation, RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now().to_rfc3339();
+        // 保存する精度に揃え、返す予約と読み込んだ予約で期限が一致するようにする
+        reservation.expires_at = reservation.expires_at.trunc_subsecs(6);
+
+        // 商品ごとに在庫チェックと確保を1つのUPDATEで行う
+        // 1件でも条件を満たさなければトランザクションごと破棄する
+        for item in &reservation.items {
+            // バリエーションの在庫を減らし、商品の在庫（バリエーションの合計）からも同じだけ確保する
+            if let Some(sku) = &item.sku {
+                let result = sqlx::query(
+                    "UPDATE product_variants SET quantity = quantity - ?, updated_at = ? \
+                     WHERE product_id = ? AND sku = ? AND quantity >= ?"
+                )
+                .bind(item.quantity)
+                .bind(&now)
+                .bind(item.product_id)
+                .bind(sku)
+                .bind(item.quantity)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+                if result.rows_affected() == 0 {
+                    return Err(RepositoryError::Conflict);
+                }
+            }
+
+            let result = sqlx::query(
+                "UPDATE products SET quantity = quantity - ?, reserved = reserved + ?, version = version + 1, updated_at = ? \
+                 WHERE id = ? AND quantity >= ?"
+            )
+            .bind(item.quantity)
+            .bind(item.quantity)
+            .bind(&now)
+            .bind(item.product_id)
+            .bind(item.quantity)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        let result = sqlx::query(
+            "INSERT INTO reservations (status, expires_at, created_at, updated_at) VALUES (?, ?, ?, ?)"
+        )
+        .bind(reservation.status.as_str())
+        .bind(sortable_timestamp(reservation.expires_at))
+        .bind(&now)
+        .bind(&now)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        reservation.id = result.last_insert_rowid() as u32;
+
+        for (position, item) in reservation.items.iter().enumerate() {
+            sqlx::query(
+                "INSERT INTO reservation_items (reservation_id, product_id, sku, quantity, position) VALUES (?, ?, ?, ?, ?)"
+            )
+            .bind(reservation.id)
+            .bind(item.product_id)
+            .bind(&item.sku)
+            .bind(item.quantity)
+            .bind(position as i64)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(reservation)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.confirm", skip(self, reservation, order), fields(reservation_id = reservation.id), err(level = "warn"))]
+    async fn confirm(&self, reservation: &Reservation, order: Order) -> Result<Order, RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now().to_rfc3339();
+
+        // 在庫は予約時に販売可能な在庫から除いてあるため、注文の保存では減算しない
+        let order = SqliteOrderRepository::insert_in(&mut tx, order).await?;
+        Self::close_in(&mut tx, reservation, Some(order.id), &now).await?;
+
+        for item in &reservation.items {
+            let result = sqlx::query(
+                "UPDATE products SET reserved = reserved - ?, updated_at = ? WHERE id = ? AND reserved >= ?"
+            )
+            .bind(item.quantity)
+            .bind(&now)
+            .bind(item.product_id)
+            .bind(item.quantity)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+            if result.rows_affected() == 0 {
+                return Err(RepositoryError::Conflict);
+            }
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(order)
+    }
+
+    #[tracing::instrument(name = "reservation_repository.release", skip(self, reservation), fields(reservation_id = reservation.id, status = reservation.status.as_str()), err(level = "warn"))]
+    async fn release(&self, reservation: &Reservation) -> Result<(), RepositoryError> {
+        let mut tx = self.db.get_pool()
+            .begin()
+            .await
+            .map_err(|e| RepositoryError::DatabaseConnection(e.to_string()))?;
+
+        let now = Utc::now().to_rfc3339();
+
+        Self::close_in(&mut tx, reservation, None, &now).await?;
+
+        // 予約後に商品が削除されている場合は戻す先がないため、更新されなくても無視する
+        for item in &reservation.items {
+            // バリエーションが削除されている場合は、その在庫ごと商品から取り除かれているため予約数だけ減らす
+            let restock = match &item.sku {
+                Some(sku) => {
+                    sqlx::query(
+                        "UPDATE product_variants SET quantity = quantity + ?, updated_at = ? WHERE product_id = ? AND sku = ?"
+                    )
+                    .bind(item.quantity)
+                    .bind(&now)
+                    .bind(item.product_id)
+                    .bind(sku)
+                    .execute(&mut *tx)
+                    .await
+                    .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?
+                    .rows_affected()
+                        > 0
+                }
+                None => true,
+            };
+
+            sqlx::query(
+                "UPDATE products SET quantity = quantity + ?, reserved = reserved - ?, version = version + 1, updated_at = ? \
+                 WHERE id = ? AND reserved >= ?"
+            )
+            .bind(if restock { item.quantity } else { 0 })
+            .bind(item.quantity)
+            .bind(&now)
+            .bind(item.product_id)
+            .bind(item.quantity)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+        }
+
+        tx.commit()
+            .await
+            .map_err(|e| RepositoryError::QueryExecution(e.to_string()))?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use chrono::Duration;
+
+    use super::*;
+    use crate::application::repositories::ProductRepository;
+    use crate::application::use_cases::ExpireReservationsUseCase;
+    use crate::domain::models::{Currency, Money, OrderLine, Product};
+    use crate::domain::tax::TaxRegion;
+    use crate::frameworks_and_drivers::database::db::test_db;
+    use crate::frameworks_and_drivers::persistence::repositories_impl::SqliteProductRepository;
+
+    async fn insert_product(name: &str, quantity: u32) -> Product {
+        let product = Product::create(name.to_string(), Money::new(100, Currency::Jpy), "test product".to_string(), quantity).unwrap();
+        SqliteProductRepository::new(test_db().await).save(product).await.unwrap()
+    }
+
+    async fn stock_of(product: &Product) -> (u32, u32) {
+        let product = SqliteProductRepository::new(test_db().await).find_by_id(product.id).await.unwrap().unwrap();
+        (product.quantity, product.reserved)
+    }
+
+    fn reservation(items: &[(&Product, u32)], expires_at: DateTime<Utc>) -> Reservation {
+        let items = items
+            .iter()
+            .map(|(product, quantity)| ReservationItem { product_id: product.id, sku: None, quantity: *quantity })
+            .collect();
+        Reservation::create(items, expires_at).unwrap()
+    }
+
+    /// 予約した数量が販売可能な在庫から確保され、予約が読み込めること
+    #[tokio::test]
+    async fn reserve_moves_quantity_to_reserved() {
+        let product = insert_product("reserve moves", 5).await;
+        let repository = SqliteReservationRepository::new(test_db().await);
+
+        let reserved = repository
+            .reserve(reservation(&[(&product, 2)], Utc::now() + Duration::minutes(5)))
+            .await
+            .unwrap();
+        assert_eq!(stock_of(&product).await, (3, 2));
+
+        let loaded = repository.find_by_id(reserved.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, ReservationStatus::Active);
+        assert_eq!(loaded.expires_at, reserved.expires_at);
+        assert_eq!(loaded.items, vec![ReservationItem { product_id: product.id, sku: None, quantity: 2 }]);
+    }
+
+    /// 1商品でも在庫が足りなければ、どの在庫も確保されないこと
+    #[tokio::test]
+    async fn reserve_rolls_back_when_any_item_lacks_stock() {
+        let enough = insert_product("reserve enough", 5).await;
+        let short = insert_product("reserve short", 1).await;
+        let repository = SqliteReservationRepository::new(test_db().await);
+
+        let result = repository
+            .reserve(reservation(&[(&enough, 2), (&short, 2)], Utc::now() + Duration::minutes(5)))
+            .await;
+        assert!(matches!(result, Err(RepositoryError::Conflict)));
+
+        assert_eq!(stock_of(&enough).await, (5, 0));
+        assert_eq!(stock_of(&short).await, (1, 0));
+    }
+
+    /// 確定すると確保していた在庫が注文になり、販売可能な在庫には戻らないこと
+    #[tokio::test]
+    async fn confirm_turns_reserved_stock_into_order() {
+        let product = insert_product("reserve confirm", 5).await;
+        let repository = SqliteReservationRepository::new(test_db().await);
+        let mut reservation = repository
+            .reserve(reservation(&[(&product, 2)], Utc::now() + Duration::minutes(5)))
+            .await
+            .unwrap();
+
+        reservation.confirm(Utc::now()).unwrap();
+        let order = Order::place(vec![OrderLine::new(product.id, product.name.clone(), product.price, 2)], &TaxRegion::untaxed()).unwrap();
+        let order = repository.confirm(&reservation, order).await.unwrap();
+
+        assert_eq!(stock_of(&product).await, (3, 0));
+        let loaded = repository.find_by_id(reservation.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, ReservationStatus::Confirmed);
+        assert_eq!(loaded.order_id, Some(order.id));
+
+        // 確定済みの予約は取り消せない
+        let mut released = loaded;
+        released.status = ReservationStatus::Released;
+        assert!(matches!(repository.release(&released).await, Err(RepositoryError::Conflict)));
+        assert_eq!(stock_of(&product).await, (3, 0));
+    }
+
+    /// 取り消すと確保していた在庫が販売可能な在庫に戻ること
+    #[tokio::test]
+    async fn release_returns_reserved_stock() {
+        let product = insert_product("reserve release", 5).await;
+        let repository = SqliteReservationRepository::new(test_db().await);
+        let mut reservation = repository
+            .reserve(reservation(&[(&product, 2)], Utc::now() + Duration::minutes(5)))
+            .await
+            .unwrap();
+
+        reservation.release().unwrap();
+        repository.release(&reservation).await.unwrap();
+
+        assert_eq!(stock_of(&product).await, (5, 0));
+        let loaded = repository.find_by_id(reservation.id).await.unwrap().unwrap();
+        assert_eq!(loaded.status, ReservationStatus::Released);
+    }
+
+    /// 期限切れの予約だけが失効し、在庫が戻ること
+    #[tokio::test]
+    async fn expire_stale_releases_only_expired_reservations() {
+        let product = insert_product("reserve expire", 5).await;
+        let repository = Arc::new(SqliteReservationRepository::new(test_db().await));
+        let stale = repository
+            .reserve(reservation(&[(&product, 2)], Utc::now() - Duration::seconds(1)))
+            .await
+            .unwrap();
+        let fresh = repository
+            .reserve(reservation(&[(&product, 1)], Utc::now() + Duration::minutes(5)))
+            .await
+            .unwrap();
+
+        let expired = ExpireReservationsUseCase::new(repository.clone()).expire_stale().await.unwrap();
+        assert!(expired >= 1);
+
+        assert_eq!(stock_of(&product).await, (4, 1));
+        let stale = repository.find_by_id(stale.id).await.unwrap().unwrap();
+        assert_eq!(stale.status, ReservationStatus::Expired);
+        let fresh = repository.find_by_id(fresh.id).await.unwrap().unwrap();
+        assert_eq!(fresh.status, ReservationStatus::Active);
+    }
+}
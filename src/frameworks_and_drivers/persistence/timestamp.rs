@@ -0,0 +1,6 @@
+use chrono::{DateTime, SecondsFormat, Utc};
+
+/// SQL上で文字列として大小比較する日時を、桁数を固定したUTCの形式にする
+pub(crate) fn sortable_timestamp(at: DateTime<Utc>) -> String {
+    at.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
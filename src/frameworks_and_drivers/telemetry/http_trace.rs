@@ -0,0 +1,64 @@
+use std::time::Duration;
+
+use axum::Router;
+use axum::body::Body;
+use axum::extract::MatchedPath;
+use axum::http::{HeaderName, Request, Response};
+use tower::ServiceBuilder;
+use tower_http::request_id::{MakeRequestUuid, PropagateRequestIdLayer, SetRequestIdLayer};
+use tower_http::trace::TraceLayer;
+use tracing::{Level, Span};
+
+/// リクエストIDを受け渡すヘッダー
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// ルーターにリクエストごとのトレーシングを追加します
+/// 1. リクエストIDを採番（クライアントが `x-request-id` を送った場合はそれを使う）
+/// 2. メソッド・パス・ルート・リクエストIDを持つspanを作成し、レスポンス時にステータスとレイテンシを記録
+/// 3. リクエストIDをレスポンスヘッダーに付与
+pub fn with_request_tracing<S>(router: Router<S>) -> Router<S>
+where
+    S: Clone + Send + Sync + 'static,
+{
+    let header = HeaderName::from_static(REQUEST_ID_HEADER);
+
+    router.layer(
+        ServiceBuilder::new()
+            .layer(SetRequestIdLayer::new(header.clone(), MakeRequestUuid))
+            .layer(
+                TraceLayer::new_for_http()
+                    .make_span_with(make_request_span)
+                    .on_response(on_response),
+            )
+            .layer(PropagateRequestIdLayer::new(header)),
+    )
+}
+
+fn make_request_span(request: &Request<Body>) -> Span {
+    let route = request
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string());
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .unwrap_or_default();
+
+    tracing::span!(
+        Level::INFO,
+        "http_request",
+        method = %request.method(),
+        path = %request.uri().path(),
+        route = route.as_deref().unwrap_or("<unmatched>"),
+        request_id = %request_id,
+        status = tracing::field::Empty,
+        latency_ms = tracing::field::Empty,
+    )
+}
+
+fn on_response(response: &Response<Body>, latency: Duration, span: &Span) {
+    span.record("status", response.status().as_u16());
+    span.record("latency_ms", latency.as_millis() as u64);
+    tracing::info!("request completed");
+}
@@ -0,0 +1,5 @@
+mod http_trace;
+mod subscriber;
+
+pub use http_trace::*;
+pub use subscriber::*;
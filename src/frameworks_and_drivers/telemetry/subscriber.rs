@@ -0,0 +1,20 @@
+use anyhow::Result;
+use tracing_subscriber::EnvFilter;
+
+use crate::frameworks_and_drivers::config::{LogConfig, LogFormat};
+
+/// 設定に従ってグローバルなtracingサブスクライバーを初期化します
+/// 出力形式は人が読みやすいpretty形式とログ基盤向けのJSON形式を切り替えられます
+pub fn init_tracing(config: &LogConfig) -> Result<()> {
+    let filter = EnvFilter::try_new(&config.level)?;
+    let builder = tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_target(true);
+
+    let result = match config.format {
+        LogFormat::Pretty => builder.pretty().try_init(),
+        LogFormat::Json => builder.json().flatten_event(true).with_current_span(true).try_init(),
+    };
+
+    result.map_err(|e| anyhow::anyhow!("Failed to initialize tracing: {}", e))
+}
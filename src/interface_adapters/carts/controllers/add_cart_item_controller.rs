@@ -0,0 +1,52 @@
+use axum::extract::{Path, State};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::carts::presenters::CartPresenter;
+use crate::interface_adapters::carts::requests::AddCartItemRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Add Cart Item Controller - カートへの商品追加の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct AddCartItemController;
+
+impl AddCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts/{id}/items", post(handle))
+    }
+}
+
+/// POST /carts/{id}/items - カートへの商品追加処理
+/// 既にカートにある商品の場合は数量を加算する
+#[utoipa::path(
+    post,
+    path = "/carts/{id}/items",
+    impl_for = AddCartItemController,
+    operation_id = "add_cart_item",
+    params(("id" = u32, Path, description = "カートID")),
+    request_body = AddCartItemRequest,
+    tag = "carts",
+    responses(
+        (status = 200, description = "更新後のカート", body = CartPresenter),
+        (status = 404, description = "カート・商品・バリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "注文確定済み、または他のリクエストと競合", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正な数量・SKU、またはバリエーションのある商品でSKUが指定されていない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    ValidatedJson(request): ValidatedJson<AddCartItemRequest>
+) -> Result<Json<CartPresenter>> {
+    let add_cart_item_usecase = container.create_add_cart_item_usecase();
+
+    let cart = add_cart_item_usecase
+        .add(id, request.into_command())
+        .await?;
+
+    Ok(Json(cart.into()))
+}
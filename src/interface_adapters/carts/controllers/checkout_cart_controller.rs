@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::orders::presenters::OrderPresenter;
+use crate::interface_adapters::orders::requests::TaxRegionQuery;
+use crate::interface_adapters::validation::ValidatedQuery;
+
+/// Checkout Cart Controller - カートのチェックアウトの単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CheckoutCartController;
+
+impl CheckoutCartController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts/{id}/checkout", post(handle))
+    }
+}
+
+/// POST /carts/{id}/checkout - カート内の全商品の注文確定処理
+/// 全商品の在庫を確保できた場合のみ注文が作成され、201と注文の内容を返す
+#[utoipa::path(
+    post,
+    path = "/carts/{id}/checkout",
+    impl_for = CheckoutCartController,
+    operation_id = "checkout_cart",
+    params(("id" = u32, Path, description = "カートID"), TaxRegionQuery),
+    tag = "carts",
+    responses(
+        (status = 201, description = "作成された注文", body = OrderPresenter, headers(("Location" = String, description = "作成された注文のURL"))),
+        (status = 400, description = "在庫不足", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "カート・商品・バリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "注文確定済み、プロモーションが利用できなくなった、または他のリクエストと競合", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "カートが空、または税制が設定されていない地域", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    ValidatedQuery(query): ValidatedQuery<TaxRegionQuery>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<OrderPresenter>)> {
+    let checkout_cart_usecase = container.create_checkout_cart_usecase();
+
+    let order = checkout_cart_usecase
+        .checkout(id, query.region.as_deref())
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/orders/{}", order.id))];
+    Ok((StatusCode::CREATED, headers, Json(order.into())))
+}
@@ -0,0 +1,44 @@
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::Result;
+use crate::interface_adapters::carts::presenters::CartPresenter;
+
+/// Create Cart Controller - カート作成の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CreateCartController;
+
+impl CreateCartController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts", post(handle))
+    }
+}
+
+/// POST /carts - 空のカートの作成処理
+#[utoipa::path(
+    post,
+    path = "/carts",
+    impl_for = CreateCartController,
+    operation_id = "create_cart",
+    tag = "carts",
+    responses(
+        (status = 201, description = "作成されたカート", body = CartPresenter, headers(("Location" = String, description = "作成されたカートのURL"))),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<CartPresenter>)> {
+    let create_cart_usecase = container.create_create_cart_usecase();
+
+    let cart = create_cart_usecase
+        .create()
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/carts/{}", cart.id))];
+    Ok((StatusCode::CREATED, headers, Json(cart.into())))
+}
@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::carts::presenters::CartPresenter;
+
+/// Get Cart Controller - カート取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetCartController;
+
+impl GetCartController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts/{id}", get(handle))
+    }
+}
+
+/// GET /carts/{id} - カート取得処理
+#[utoipa::path(
+    get,
+    path = "/carts/{id}",
+    impl_for = GetCartController,
+    operation_id = "get_cart",
+    params(("id" = u32, Path, description = "カートID")),
+    tag = "carts",
+    responses(
+        (status = 200, description = "カート", body = CartPresenter),
+        (status = 404, description = "カートが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<CartPresenter>> {
+    let get_cart_usecase = container.create_get_cart_usecase();
+
+    let cart = get_cart_usecase
+        .get_by_id(id)
+        .await?;
+
+    Ok(Json(cart.into()))
+}
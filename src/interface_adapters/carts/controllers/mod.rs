@@ -0,0 +1,13 @@
+mod create_cart_controller;
+mod get_cart_controller;
+mod add_cart_item_controller;
+mod update_cart_item_controller;
+mod remove_cart_item_controller;
+mod checkout_cart_controller;
+
+pub use create_cart_controller::CreateCartController;
+pub use get_cart_controller::GetCartController;
+pub use add_cart_item_controller::AddCartItemController;
+pub use update_cart_item_controller::UpdateCartItemController;
+pub use remove_cart_item_controller::RemoveCartItemController;
+pub use checkout_cart_controller::CheckoutCartController;
@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::{routing::delete, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::carts::presenters::CartPresenter;
+use crate::interface_adapters::carts::requests::CartItemSkuQuery;
+use crate::interface_adapters::validation::ValidatedQuery;
+
+/// Remove Cart Item Controller - カートからの商品削除の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct RemoveCartItemController;
+
+impl RemoveCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts/{id}/items/{product_id}", delete(handle))
+    }
+}
+
+/// DELETE /carts/{id}/items/{product_id} - カートからの商品削除処理
+#[utoipa::path(
+    delete,
+    path = "/carts/{id}/items/{product_id}",
+    impl_for = RemoveCartItemController,
+    operation_id = "remove_cart_item",
+    params(
+        ("id" = u32, Path, description = "カートID"),
+        ("product_id" = u32, Path, description = "商品ID"),
+        CartItemSkuQuery,
+    ),
+    tag = "carts",
+    responses(
+        (status = 200, description = "更新後のカート", body = CartPresenter),
+        (status = 404, description = "カートが存在しない、または商品がカートにない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "注文確定済み、または他のリクエストと競合", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なSKU", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, product_id)): Path<(u32, u32)>,
+    ValidatedQuery(query): ValidatedQuery<CartItemSkuQuery>
+) -> Result<Json<CartPresenter>> {
+    let remove_cart_item_usecase = container.create_remove_cart_item_usecase();
+
+    let cart = remove_cart_item_usecase
+        .remove(id, product_id, query.sku.as_deref())
+        .await?;
+
+    Ok(Json(cart.into()))
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::{routing::put, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::carts::presenters::CartPresenter;
+use crate::interface_adapters::carts::requests::{CartItemSkuQuery, UpdateCartItemRequest};
+use crate::interface_adapters::validation::{ValidatedJson, ValidatedQuery};
+
+/// Update Cart Item Controller - カート内商品の数量変更の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct UpdateCartItemController;
+
+impl UpdateCartItemController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/carts/{id}/items/{product_id}", put(handle))
+    }
+}
+
+/// PUT /carts/{id}/items/{product_id} - カート内商品の数量変更処理
+#[utoipa::path(
+    put,
+    path = "/carts/{id}/items/{product_id}",
+    impl_for = UpdateCartItemController,
+    operation_id = "update_cart_item",
+    params(
+        ("id" = u32, Path, description = "カートID"),
+        ("product_id" = u32, Path, description = "商品ID"),
+        CartItemSkuQuery,
+    ),
+    request_body = UpdateCartItemRequest,
+    tag = "carts",
+    responses(
+        (status = 200, description = "更新後のカート", body = CartPresenter),
+        (status = 404, description = "カートが存在しない、または商品がカートにない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "注文確定済み、または他のリクエストと競合", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正な数量、またはSKU", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, product_id)): Path<(u32, u32)>,
+    ValidatedQuery(query): ValidatedQuery<CartItemSkuQuery>,
+    ValidatedJson(request): ValidatedJson<UpdateCartItemRequest>
+) -> Result<Json<CartPresenter>> {
+    let update_cart_item_usecase = container.create_update_cart_item_usecase();
+
+    let cart = update_cart_item_usecase
+        .update(id, product_id, query.sku.as_deref(), request.into_command())
+        .await?;
+
+    Ok(Json(cart.into()))
+}
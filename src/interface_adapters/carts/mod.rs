@@ -0,0 +1,23 @@
+pub mod controllers;
+pub mod requests;
+pub mod presenters;
+
+use axum::Router;
+use std::sync::Arc;
+use crate::frameworks_and_drivers::Container;
+
+pub use controllers::{CreateCartController, GetCartController, AddCartItemController};
+pub use controllers::{UpdateCartItemController, RemoveCartItemController, CheckoutCartController};
+pub use requests::{AddCartItemRequest, UpdateCartItemRequest};
+pub use presenters::{CartPresenter, CartItemPresenter};
+
+/// Carts モジュールの全ルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(CreateCartController::routes())
+        .merge(GetCartController::routes())
+        .merge(AddCartItemController::routes())
+        .merge(UpdateCartItemController::routes())
+        .merge(RemoveCartItemController::routes())
+        .merge(CheckoutCartController::routes())
+}
@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::queries::{CartItemQuery, GetCartQuery};
+
+/// Cart Presenter - カートレスポンスの整形を担当
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CartPresenter {
+    pub id: u32,
+    /// カートステータス（`open` / `checked_out`）
+    pub status: String,
+    /// チェックアウトで作成された注文のID
+    pub order_id: Option<u32>,
+    pub version: u32,
+    pub items: Vec<CartItemPresenter>,
+}
+
+/// カート内商品1件分のレスポンス
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CartItemPresenter {
+    pub product_id: u32,
+    /// カートに追加したバリエーションのSKU
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+impl From<CartItemQuery> for CartItemPresenter {
+    fn from(query: CartItemQuery) -> Self {
+        CartItemPresenter {
+            product_id: query.product_id,
+            sku: query.sku,
+            quantity: query.quantity,
+        }
+    }
+}
+
+impl From<GetCartQuery> for CartPresenter {
+    fn from(query: GetCartQuery) -> Self {
+        CartPresenter {
+            id: query.id,
+            status: query.status,
+            order_id: query.order_id,
+            version: query.version,
+            items: query.items.into_iter().map(|item| item.into()).collect(),
+        }
+    }
+}
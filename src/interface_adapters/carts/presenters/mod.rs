@@ -0,0 +1,3 @@
+mod cart_presenter;
+
+pub use cart_presenter::{CartPresenter, CartItemPresenter};
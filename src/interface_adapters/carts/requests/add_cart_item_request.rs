@@ -0,0 +1,43 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::AddCartItemCommand;
+use crate::interface_adapters::products::requests::validate_sku;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Add Cart Item Request - カートへの商品追加リクエスト専用DTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct AddCartItemRequest {
+    /// 商品ID
+    pub product_id: u32,
+    /// 追加するバリエーションのSKU（バリエーションのある商品では必須。大文字・小文字は区別しない）
+    pub sku: Option<String>,
+    /// 追加する数量
+    pub quantity: u32,
+}
+
+impl Validate for AddCartItemRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.quantity == 0 {
+            errors.add("quantity", "Quantity must be greater than 0");
+        }
+        if self.quantity > 1000 {
+            errors.add("quantity", "Quantity cannot exceed 1000");
+        }
+        validate_sku("sku", self.sku.as_deref(), &mut errors);
+        errors.into_result()
+    }
+}
+
+impl AddCartItemRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self) -> AddCartItemCommand {
+        AddCartItemCommand {
+            product_id: self.product_id,
+            sku: self.sku,
+            quantity: self.quantity,
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use crate::interface_adapters::products::requests::validate_sku;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Cart Item Sku Query - カート内の商品を指定するクエリパラメータDTO
+/// 例: `/carts/1/items/3?sku=KB-US-BLK`
+#[derive(Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct CartItemSkuQuery {
+    /// カートに追加したバリエーションのSKU（バリエーションのある商品では必須。大文字・小文字は区別しない）
+    pub sku: Option<String>,
+}
+
+impl Validate for CartItemSkuQuery {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        validate_sku("sku", self.sku.as_deref(), &mut errors);
+        errors.into_result()
+    }
+}
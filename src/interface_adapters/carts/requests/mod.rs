@@ -0,0 +1,7 @@
+mod add_cart_item_request;
+mod cart_item_sku_query;
+mod update_cart_item_request;
+
+pub use add_cart_item_request::AddCartItemRequest;
+pub use cart_item_sku_query::CartItemSkuQuery;
+pub use update_cart_item_request::UpdateCartItemRequest;
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::UpdateCartItemCommand;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Update Cart Item Request - カート内商品の数量変更リクエスト専用DTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateCartItemRequest {
+    /// 変更後の数量
+    pub quantity: u32,
+}
+
+impl Validate for UpdateCartItemRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.quantity == 0 {
+            errors.add("quantity", "Quantity must be greater than 0");
+        }
+        if self.quantity > 1000 {
+            errors.add("quantity", "Quantity cannot exceed 1000");
+        }
+        errors.into_result()
+    }
+}
+
+impl UpdateCartItemRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self) -> UpdateCartItemCommand {
+        UpdateCartItemCommand {
+            quantity: self.quantity,
+        }
+    }
+}
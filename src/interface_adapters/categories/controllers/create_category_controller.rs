@@ -0,0 +1,50 @@
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::categories::presenters::CategoryPresenter;
+use crate::interface_adapters::categories::requests::CategoryRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Create Category Controller - カテゴリ作成の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CreateCategoryController;
+
+impl CreateCategoryController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/categories", post(handle))
+    }
+}
+
+/// POST /categories - カテゴリ作成処理
+#[utoipa::path(
+    post,
+    path = "/categories",
+    impl_for = CreateCategoryController,
+    operation_id = "create_category",
+    request_body = CategoryRequest,
+    tag = "categories",
+    responses(
+        (status = 201, description = "作成されたカテゴリ", body = CategoryPresenter, headers(("Location" = String, description = "作成されたカテゴリのURL"))),
+        (status = 404, description = "親カテゴリが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なカテゴリ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CategoryRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<CategoryPresenter>)> {
+    let create_category_usecase = container.create_create_category_usecase();
+
+    let category = create_category_usecase
+        .create(request.into_create_command())
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/categories/{}", category.id))];
+    Ok((StatusCode::CREATED, headers, Json(category.into())))
+}
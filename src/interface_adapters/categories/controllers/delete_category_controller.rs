@@ -0,0 +1,47 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{routing::delete, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+
+/// Delete Category Controller - カテゴリ削除の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct DeleteCategoryController;
+
+impl DeleteCategoryController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/categories/{id}", delete(handle))
+    }
+}
+
+/// DELETE /categories/{id} - カテゴリ削除処理
+/// 商品は削除されず、カテゴリとの関連付けだけが外れる
+#[utoipa::path(
+    delete,
+    path = "/categories/{id}",
+    impl_for = DeleteCategoryController,
+    operation_id = "delete_category",
+    params(("id" = u32, Path, description = "カテゴリID")),
+    tag = "categories",
+    responses(
+        (status = 204, description = "削除完了"),
+        (status = 404, description = "カテゴリが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "子カテゴリがある", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+) -> Result<StatusCode> {
+    let delete_category_usecase = container.create_delete_category_usecase();
+
+    delete_category_usecase
+        .delete(id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
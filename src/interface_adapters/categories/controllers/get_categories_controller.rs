@@ -0,0 +1,43 @@
+use axum::extract::State;
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::Result;
+use crate::interface_adapters::categories::presenters::CategoryListPresenter;
+
+/// Get Categories Controller - カテゴリ一覧取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetCategoriesController;
+
+impl GetCategoriesController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/categories", get(handle))
+    }
+}
+
+/// GET /categories - カテゴリ一覧取得処理
+/// すべての階層のカテゴリを作成順に返す
+#[utoipa::path(
+    get,
+    path = "/categories",
+    impl_for = GetCategoriesController,
+    operation_id = "list_categories",
+    tag = "categories",
+    responses(
+        (status = 200, description = "カテゴリ一覧", body = CategoryListPresenter),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+) -> Result<Json<CategoryListPresenter>> {
+    let get_all_categories_usecase = container.create_get_all_categories_usecase();
+
+    let categories = get_all_categories_usecase
+        .get_all()
+        .await?;
+
+    Ok(Json(categories.into()))
+}
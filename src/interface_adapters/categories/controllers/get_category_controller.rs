@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::categories::presenters::CategoryPresenter;
+
+/// Get Category Controller - カテゴリ取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetCategoryController;
+
+impl GetCategoryController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/categories/{id}", get(handle))
+    }
+}
+
+/// GET /categories/{id} - カテゴリ取得処理
+#[utoipa::path(
+    get,
+    path = "/categories/{id}",
+    impl_for = GetCategoryController,
+    operation_id = "get_category",
+    params(("id" = u32, Path, description = "カテゴリID")),
+    tag = "categories",
+    responses(
+        (status = 200, description = "カテゴリ", body = CategoryPresenter),
+        (status = 404, description = "カテゴリが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<CategoryPresenter>> {
+    let get_category_usecase = container.create_get_category_usecase();
+
+    let category = get_category_usecase
+        .get_by_id(id)
+        .await?;
+
+    Ok(Json(category.into()))
+}
@@ -0,0 +1,11 @@
+mod create_category_controller;
+mod get_categories_controller;
+mod get_category_controller;
+mod update_category_controller;
+mod delete_category_controller;
+
+pub use create_category_controller::CreateCategoryController;
+pub use get_categories_controller::GetCategoriesController;
+pub use get_category_controller::GetCategoryController;
+pub use update_category_controller::UpdateCategoryController;
+pub use delete_category_controller::DeleteCategoryController;
@@ -0,0 +1,51 @@
+use axum::extract::{Path, State};
+use axum::{routing::put, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::categories::presenters::CategoryPresenter;
+use crate::interface_adapters::categories::requests::CategoryRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Update Category Controller - カテゴリ更新の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct UpdateCategoryController;
+
+impl UpdateCategoryController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/categories/{id}", put(handle))
+    }
+}
+
+/// PUT /categories/{id} - カテゴリの名前と親カテゴリの更新処理
+/// 自分自身や子孫のカテゴリの下には移動できない
+#[utoipa::path(
+    put,
+    path = "/categories/{id}",
+    impl_for = UpdateCategoryController,
+    operation_id = "update_category",
+    params(("id" = u32, Path, description = "カテゴリID")),
+    request_body = CategoryRequest,
+    tag = "categories",
+    responses(
+        (status = 200, description = "更新後のカテゴリ", body = CategoryPresenter),
+        (status = 404, description = "カテゴリまたは親カテゴリが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なカテゴリ（親子関係の循環を含む）", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    ValidatedJson(request): ValidatedJson<CategoryRequest>
+) -> Result<Json<CategoryPresenter>> {
+    let update_category_usecase = container.create_update_category_usecase();
+
+    let category = update_category_usecase
+        .update(id, request.into_update_command())
+        .await?;
+
+    Ok(Json(category.into()))
+}
@@ -0,0 +1,22 @@
+pub mod controllers;
+pub mod requests;
+pub mod presenters;
+
+use axum::Router;
+use std::sync::Arc;
+use crate::frameworks_and_drivers::Container;
+
+pub use controllers::{CreateCategoryController, GetCategoriesController, GetCategoryController};
+pub use controllers::{UpdateCategoryController, DeleteCategoryController};
+pub use requests::CategoryRequest;
+pub use presenters::{CategoryPresenter, CategoryListPresenter};
+
+/// Categories モジュールの全ルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(CreateCategoryController::routes())
+        .merge(GetCategoriesController::routes())
+        .merge(GetCategoryController::routes())
+        .merge(UpdateCategoryController::routes())
+        .merge(DeleteCategoryController::routes())
+}
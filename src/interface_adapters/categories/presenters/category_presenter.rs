@@ -0,0 +1,37 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::queries::GetCategoryQuery;
+
+/// Category Presenter - カテゴリのレスポンス形式
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CategoryPresenter {
+    pub id: u32,
+    pub name: String,
+    /// 親カテゴリID（nullはルート）
+    pub parent_id: Option<u32>,
+}
+
+impl From<GetCategoryQuery> for CategoryPresenter {
+    fn from(query: GetCategoryQuery) -> Self {
+        CategoryPresenter {
+            id: query.id,
+            name: query.name,
+            parent_id: query.parent_id,
+        }
+    }
+}
+
+/// カテゴリ一覧のレスポンス形式
+/// 木構造は `parent_id` をたどって組み立てる
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CategoryListPresenter {
+    pub items: Vec<CategoryPresenter>,
+}
+
+impl From<Vec<GetCategoryQuery>> for CategoryListPresenter {
+    fn from(categories: Vec<GetCategoryQuery>) -> Self {
+        CategoryListPresenter {
+            items: categories.into_iter().map(CategoryPresenter::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,3 @@
+mod category_presenter;
+
+pub use category_presenter::{CategoryPresenter, CategoryListPresenter};
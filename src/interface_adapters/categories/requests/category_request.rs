@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::{CreateCategoryCommand, UpdateCategoryCommand};
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Category Request - カテゴリ作成・更新リクエスト専用DTO
+/// 更新（PUT）では名前と親カテゴリを置き換える
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CategoryRequest {
+    /// カテゴリ名
+    pub name: String,
+    /// 親カテゴリID（省略またはnullでルート）
+    pub parent_id: Option<u32>,
+}
+
+impl Validate for CategoryRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.name.trim().is_empty() {
+            errors.add("name", "Name must not be empty");
+        }
+        if self.name.chars().count() > 100 {
+            errors.add("name", "Name cannot exceed 100 characters");
+        }
+        errors.into_result()
+    }
+}
+
+impl CategoryRequest {
+    /// Requestから作成コマンドへの変換
+    pub fn into_create_command(self) -> CreateCategoryCommand {
+        CreateCategoryCommand {
+            name: self.name,
+            parent_id: self.parent_id,
+        }
+    }
+
+    /// Requestから更新コマンドへの変換
+    pub fn into_update_command(self) -> UpdateCategoryCommand {
+        UpdateCategoryCommand {
+            name: self.name,
+            parent_id: self.parent_id,
+        }
+    }
+}
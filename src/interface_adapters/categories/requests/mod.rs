@@ -0,0 +1,3 @@
+mod category_request;
+
+pub use category_request::CategoryRequest;
@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use axum::body::{to_bytes, Body, Bytes, HttpBody};
+use axum::extract::{Request, State};
+use axum::http::{header, HeaderMap, HeaderName, HeaderValue, Method, StatusCode, Uri};
+use axum::middleware::Next;
+use axum::response::Response;
+use sha2::{Digest, Sha256};
+
+use crate::application::repositories::StoredResponse;
+use crate::application::use_cases::IdempotencyOutcome;
+use crate::error::{with_problem_instance, Error, Result};
+use crate::frameworks_and_drivers::telemetry::REQUEST_ID_HEADER;
+use crate::frameworks_and_drivers::Container;
+use crate::interface_adapters::validation::ValidationErrors;
+
+/// 再送を識別するためにクライアントが付与するヘッダー
+pub const IDEMPOTENCY_KEY_HEADER: &str = "idempotency-key";
+/// 保存済みのレスポンスを返したことを示すヘッダー
+pub const IDEMPOTENT_REPLAYED_HEADER: &str = "idempotent-replayed";
+
+/// Idempotency-Keyの最大長
+const MAX_KEY_LENGTH: usize = 255;
+/// フィンガープリントのために読み込むボディの上限（axumのJSON Extractorの既定値と同じ）
+const MAX_BODY_BYTES: usize = 2 * 1024 * 1024;
+/// 保存するレスポンスボディの上限（これより大きい、または大きさが分からないレスポンスは保存しない）
+const MAX_STORED_RESPONSE_BYTES: usize = 1024 * 1024;
+
+/// POSTリクエストのIdempotency-Keyを処理するミドルウェア
+/// 1. 同じキーの最初のリクエストだけを実行し、そのレスポンス（ステータス・ヘッダー・ボディ）を保存する
+/// 2. 同じキーと同じ内容の再送には保存済みのレスポンスをそのまま返す
+/// 3. 同じキーが異なる内容で使われた場合は422、最初のリクエストが処理中の場合は409を返す
+///
+/// 5xxのレスポンスと保存の上限を超えるレスポンスは保存せず、同じキーで再実行できるようにする
+pub async fn idempotency(
+    State(container): State<Arc<Container>>,
+    request: Request,
+    next: Next,
+) -> Result<Response> {
+    if request.method() != Method::POST {
+        return Ok(next.run(request).await);
+    }
+    let Some(key) = request.headers().get(IDEMPOTENCY_KEY_HEADER) else {
+        return Ok(next.run(request).await);
+    };
+    let key = parse_key(key)?;
+    let request_id = request
+        .headers()
+        .get(REQUEST_ID_HEADER)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.to_string());
+
+    let (parts, body) = request.into_parts();
+    let body = to_bytes(body, MAX_BODY_BYTES).await.map_err(|_| Error::PayloadTooLarge)?;
+    let fingerprint = fingerprint(&parts.method, &parts.uri, &body);
+
+    let idempotency_usecase = container.create_idempotency_usecase();
+    if let IdempotencyOutcome::Replay(stored) = idempotency_usecase.begin(&key, &fingerprint).await? {
+        return Ok(replay(stored));
+    }
+
+    let response = next.run(Request::from_parts(parts, Body::from(body))).await;
+    // 保存したレスポンスを再送時にそのまま返せるよう、instanceを確定させてから保存する
+    let response = with_problem_instance(response, request_id.as_deref());
+
+    let storable = is_storable(response.body());
+    if !storable {
+        tracing::warn!("response is too large to store for idempotency key");
+    }
+    if response.status().is_server_error() || !storable {
+        if let Err(err) = idempotency_usecase.abandon(&key).await {
+            tracing::error!(error = %err, "failed to release idempotency key");
+        }
+        return Ok(response);
+    }
+
+    let (parts, body) = response.into_parts();
+    let body = match to_bytes(body, MAX_STORED_RESPONSE_BYTES).await {
+        Ok(body) => body,
+        Err(err) => {
+            tracing::error!(error = %err, "failed to buffer response for idempotency key");
+            if let Err(err) = idempotency_usecase.abandon(&key).await {
+                tracing::error!(error = %err, "failed to release idempotency key");
+            }
+            return Err(Error::InternalServerError);
+        }
+    };
+
+    // 保存に失敗してもリクエスト自体は成功しているためレスポンスは返す
+    // キーは処理中のまま残り、放棄されたとみなされるまで再送は409になる
+    let stored = to_stored(parts.status, &parts.headers, &body);
+    if let Err(err) = idempotency_usecase.complete(&key, &stored).await {
+        tracing::error!(error = %err, "failed to store response for idempotency key");
+    }
+
+    Ok(Response::from_parts(parts, Body::from(body)))
+}
+
+fn parse_key(value: &HeaderValue) -> Result<String> {
+    let mut errors = ValidationErrors::new();
+    match value.to_str() {
+        Ok("") => errors.add("Idempotency-Key", "Idempotency-Key must not be empty"),
+        Ok(key) if key.len() > MAX_KEY_LENGTH => {
+            errors.add("Idempotency-Key", format!("Idempotency-Key cannot exceed {} characters", MAX_KEY_LENGTH))
+        }
+        Ok(key) => return Ok(key.to_string()),
+        Err(_) => errors.add("Idempotency-Key", "Idempotency-Key must be visible ASCII"),
+    }
+    Err(Error::InvalidFields(errors))
+}
+
+/// メソッド・パス（クエリを含む）・ボディからリクエストを識別するハッシュを作る
+fn fingerprint(method: &Method, uri: &Uri, body: &Bytes) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(method.as_str());
+    hasher.update(b"\n");
+    hasher.update(uri.path_and_query().map(|path| path.as_str()).unwrap_or("/"));
+    hasher.update(b"\n");
+    hasher.update(body);
+    hex::encode(hasher.finalize())
+}
+
+/// ボディの大きさが事前に分かり、保存の上限以内かどうか
+/// 読み始める前に判定し、上限を超えるボディを途中まで読んで捨てることがないようにする
+fn is_storable(body: &Body) -> bool {
+    body.size_hint()
+        .upper()
+        .is_some_and(|upper| upper <= MAX_STORED_RESPONSE_BYTES as u64)
+}
+
+fn to_stored(status: StatusCode, headers: &HeaderMap, body: &Bytes) -> StoredResponse {
+    let headers = headers
+        .iter()
+        .filter(|(name, _)| *name != header::CONTENT_LENGTH)
+        .filter_map(|(name, value)| Some((name.to_string(), value.to_str().ok()?.to_string())))
+        .collect();
+
+    StoredResponse {
+        status: status.as_u16(),
+        headers,
+        body: body.to_vec(),
+    }
+}
+
+fn replay(stored: StoredResponse) -> Response {
+    let status = StatusCode::from_u16(stored.status).unwrap_or(StatusCode::INTERNAL_SERVER_ERROR);
+    // `(status, body).into_response()` だと既定のContent-Typeが付き、保存したContent-Typeと重複する
+    let mut response = Response::new(Body::from(stored.body));
+    *response.status_mut() = status;
+
+    let headers = response.headers_mut();
+    for (name, value) in stored.headers {
+        if let (Ok(name), Ok(value)) = (HeaderName::try_from(name), HeaderValue::try_from(value)) {
+            headers.append(name, value);
+        }
+    }
+    headers.insert(IDEMPOTENT_REPLAYED_HEADER, HeaderValue::from_static("true"));
+    response
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn only_bodies_of_known_size_within_the_limit_are_stored() {
+        assert!(is_storable(&Body::from("{}")));
+        assert!(is_storable(&Body::from(vec![0; MAX_STORED_RESPONSE_BYTES])));
+        assert!(!is_storable(&Body::from(vec![0; MAX_STORED_RESPONSE_BYTES + 1])));
+
+        // ストリーミングのボディは大きさが分からないため保存しない
+        let streamed = Body::from_stream(Body::from("{}").into_data_stream());
+        assert!(!is_storable(&streamed));
+    }
+
+    #[test]
+    fn replay_restores_the_stored_content_type_once() {
+        let stored = StoredResponse {
+            status: 400,
+            headers: vec![("content-type".to_string(), "application/problem+json".to_string())],
+            body: b"{}".to_vec(),
+        };
+
+        let response = replay(stored);
+
+        assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+        let content_types: Vec<_> = response.headers().get_all(header::CONTENT_TYPE).iter().collect();
+        assert_eq!(content_types, ["application/problem+json"]);
+        assert_eq!(response.headers()[IDEMPOTENT_REPLAYED_HEADER], "true");
+    }
+}
@@ -1,4 +1,14 @@
 pub mod products;
+pub mod orders;
+pub mod carts;
+pub mod reservations;
+pub mod promotions;
+pub mod categories;
+pub mod validation;
+pub mod idempotency;
+pub mod openapi;
 
 pub use products::{GetProductsController, GetProductController, BuyProductController};
-pub use products::{ProductPresenter, BuyProductRequest}; 
\ No newline at end of file
+pub use products::{CreateProductController, UpdateProductController, DeleteProductController};
+pub use products::SearchProductsController;
+pub use products::{ProductPresenter, BuyProductRequest, CreateProductRequest, UpdateProductRequest}; 
\ No newline at end of file
@@ -0,0 +1,226 @@
+use axum::Router;
+use std::sync::Arc;
+use utoipa::openapi::path::{Parameter, ParameterBuilder, ParameterIn, PathsBuilder};
+use utoipa::openapi::{ObjectBuilder, Required, Type};
+use utoipa::OpenApi;
+use utoipa_swagger_ui::SwaggerUi;
+
+use crate::error::ProblemDetails;
+use crate::frameworks_and_drivers::Container;
+use crate::interface_adapters::carts::{
+    AddCartItemController, AddCartItemRequest, CartItemPresenter, CartPresenter, CheckoutCartController,
+    CreateCartController, GetCartController, RemoveCartItemController, UpdateCartItemController,
+    UpdateCartItemRequest,
+};
+use crate::interface_adapters::reservations::{
+    ConfirmReservationController, CreateReservationController, CreateReservationRequest,
+    GetReservationController, ReleaseReservationController, ReservationItemPresenter,
+    ReservationItemRequest, ReservationPresenter,
+};
+use crate::interface_adapters::promotions::{
+    CreatePromotionController, CreatePromotionRequest, DeletePromotionController, GetPromotionController,
+    GetPromotionsController, PromotionKindParam, PromotionListPresenter, PromotionPresenter,
+};
+use crate::interface_adapters::categories::{
+    CategoryListPresenter, CategoryPresenter, CategoryRequest, CreateCategoryController, DeleteCategoryController,
+    GetCategoriesController, GetCategoryController, UpdateCategoryController,
+};
+use crate::interface_adapters::orders::{GetOrderController, OrderLinePresenter, OrderPresenter};
+use crate::interface_adapters::products::presenters::{
+    ProductListPresenter, ProductPresenter, ProductSearchHitPresenter, ProductSearchPresenter, ProductVariantPresenter,
+};
+use crate::interface_adapters::products::requests::{
+    BuyProductRequest, CreateProductRequest, CreateVariantRequest, ProductSortParam, SortOrderParam,
+    UpdateProductRequest, UpdateVariantRequest,
+};
+use crate::interface_adapters::products::{
+    AddProductVariantController, BuyProductController, CreateProductController, DeleteProductController,
+    GetProductController, GetProductsController, RemoveProductVariantController, SearchProductsController,
+    UpdateProductController, UpdateProductVariantController,
+};
+use crate::interface_adapters::validation::FieldError;
+
+/// OpenAPI ドキュメント定義
+/// パスは各Controllerの `#[utoipa::path(impl_for = ...)]` から `ApiDoc::build` で収集する
+#[derive(OpenApi)]
+#[openapi(
+    info(title = "axum-mini-template", description = "商品の参照・購入・管理API"),
+    components(schemas(
+        ProductPresenter,
+        ProductVariantPresenter,
+        ProductListPresenter,
+        ProductSearchPresenter,
+        ProductSearchHitPresenter,
+        BuyProductRequest,
+        CreateProductRequest,
+        UpdateProductRequest,
+        CreateVariantRequest,
+        UpdateVariantRequest,
+        ProductSortParam,
+        SortOrderParam,
+        OrderPresenter,
+        OrderLinePresenter,
+        CartPresenter,
+        CartItemPresenter,
+        AddCartItemRequest,
+        UpdateCartItemRequest,
+        ReservationPresenter,
+        ReservationItemPresenter,
+        CreateReservationRequest,
+        ReservationItemRequest,
+        PromotionPresenter,
+        PromotionListPresenter,
+        CreatePromotionRequest,
+        PromotionKindParam,
+        CategoryPresenter,
+        CategoryListPresenter,
+        CategoryRequest,
+        ProblemDetails,
+        FieldError,
+    )),
+    tags(
+        (name = "products", description = "商品"),
+        (name = "orders", description = "注文"),
+        (name = "carts", description = "カート"),
+        (name = "reservations", description = "在庫予約"),
+        (name = "promotions", description = "プロモーション"),
+        (name = "categories", description = "カテゴリ"),
+    )
+)]
+pub struct ApiDoc;
+
+impl ApiDoc {
+    /// 全Controllerのパスを含むOpenAPIドキュメントを生成します
+    pub fn build() -> utoipa::openapi::OpenApi {
+        let mut doc = Self::openapi();
+        doc.info.license = None;
+        doc.paths = PathsBuilder::new()
+            .path_from::<GetProductsController>()
+            .path_from::<GetProductController>()
+            .path_from::<BuyProductController>()
+            .path_from::<CreateProductController>()
+            .path_from::<UpdateProductController>()
+            .path_from::<DeleteProductController>()
+            .path_from::<AddProductVariantController>()
+            .path_from::<UpdateProductVariantController>()
+            .path_from::<RemoveProductVariantController>()
+            .path_from::<SearchProductsController>()
+            .path_from::<GetOrderController>()
+            .path_from::<CreateCartController>()
+            .path_from::<GetCartController>()
+            .path_from::<AddCartItemController>()
+            .path_from::<UpdateCartItemController>()
+            .path_from::<RemoveCartItemController>()
+            .path_from::<CheckoutCartController>()
+            .path_from::<CreateReservationController>()
+            .path_from::<GetReservationController>()
+            .path_from::<ConfirmReservationController>()
+            .path_from::<ReleaseReservationController>()
+            .path_from::<CreatePromotionController>()
+            .path_from::<GetPromotionsController>()
+            .path_from::<GetPromotionController>()
+            .path_from::<DeletePromotionController>()
+            .path_from::<CreateCategoryController>()
+            .path_from::<GetCategoriesController>()
+            .path_from::<GetCategoryController>()
+            .path_from::<UpdateCategoryController>()
+            .path_from::<DeleteCategoryController>()
+            .build();
+
+        // Idempotency-KeyミドルウェアはすべてのPOSTに適用されるため、各操作にヘッダーを追加する
+        for item in doc.paths.paths.values_mut() {
+            if let Some(post) = item.post.as_mut() {
+                post.parameters.get_or_insert_with(Vec::new).push(idempotency_key_parameter());
+            }
+        }
+        doc
+    }
+}
+
+fn idempotency_key_parameter() -> Parameter {
+    ParameterBuilder::new()
+        .name("Idempotency-Key")
+        .parameter_in(ParameterIn::Header)
+        .required(Required::False)
+        .description(Some(
+            "再送を識別するキー。同じキーと同じ内容の再送には最初のレスポンスを返す（`idempotent-replayed: true`）",
+        ))
+        .schema(Some(ObjectBuilder::new().schema_type(Type::String).max_length(Some(255))))
+        .build()
+}
+
+/// OpenAPI ドキュメントをJSON文字列として出力します
+pub fn openapi_json() -> Result<String, serde_json::Error> {
+    ApiDoc::build().to_pretty_json()
+}
+
+/// `/openapi.json` と Swagger UI (`/docs`) のルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    SwaggerUi::new("/docs")
+        .url("/openapi.json", ApiDoc::build())
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn documents_every_route() {
+        let doc = ApiDoc::build();
+        let operations: Vec<String> = doc.paths.paths
+            .values()
+            .flat_map(|item| {
+                [&item.get, &item.post, &item.put, &item.patch, &item.delete]
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|operation| operation.operation_id.clone())
+            })
+            .collect();
+
+        for operation_id in [
+            "list_products",
+            "get_product",
+            "buy_product",
+            "create_product",
+            "update_product",
+            "delete_product",
+            "add_product_variant",
+            "update_product_variant",
+            "remove_product_variant",
+            "search_products",
+            "get_order",
+            "create_cart",
+            "get_cart",
+            "add_cart_item",
+            "update_cart_item",
+            "remove_cart_item",
+            "checkout_cart",
+            "create_reservation",
+            "get_reservation",
+            "confirm_reservation",
+            "release_reservation",
+            "create_promotion",
+            "list_promotions",
+            "get_promotion",
+            "delete_promotion",
+            "create_category",
+            "list_categories",
+            "get_category",
+            "update_category",
+            "delete_category",
+        ] {
+            assert!(operations.iter().any(|id| id == operation_id), "{operation_id} is not documented");
+        }
+    }
+
+    #[test]
+    fn committed_spec_is_up_to_date() {
+        let committed = include_str!("../../openapi.json");
+        assert_eq!(
+            committed.trim_end(),
+            openapi_json().unwrap(),
+            "openapi.json is stale; run `cargo run -- openapi --output openapi.json`",
+        );
+    }
+}
@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::orders::presenters::OrderPresenter;
+
+/// Get Order Controller - 注文取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetOrderController;
+
+impl GetOrderController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/orders/{id}", get(handle))
+    }
+}
+
+/// GET /orders/{id} - 注文取得処理
+#[utoipa::path(
+    get,
+    path = "/orders/{id}",
+    impl_for = GetOrderController,
+    operation_id = "get_order",
+    params(("id" = u32, Path, description = "注文ID")),
+    tag = "orders",
+    responses(
+        (status = 200, description = "注文", body = OrderPresenter),
+        (status = 404, description = "注文が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<OrderPresenter>> {
+    let get_order_usecase = container.create_get_order_usecase();
+
+    let order = get_order_usecase
+        .get_by_id(id)
+        .await?;
+
+    Ok(Json(order.into()))
+}
@@ -0,0 +1,3 @@
+mod get_order_controller;
+
+pub use get_order_controller::GetOrderController;
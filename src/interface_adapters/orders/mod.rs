@@ -0,0 +1,16 @@
+pub mod controllers;
+pub mod presenters;
+pub mod requests;
+
+use axum::Router;
+use std::sync::Arc;
+use crate::frameworks_and_drivers::Container;
+
+pub use controllers::GetOrderController;
+pub use presenters::{OrderPresenter, OrderLinePresenter};
+
+/// Orders モジュールの全ルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(GetOrderController::routes())
+}
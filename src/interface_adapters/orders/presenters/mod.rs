@@ -0,0 +1,3 @@
+mod order_presenter;
+
+pub use order_presenter::{OrderPresenter, OrderLinePresenter};
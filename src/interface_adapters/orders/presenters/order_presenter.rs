@@ -0,0 +1,94 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::queries::{GetOrderQuery, OrderLineQuery};
+
+/// Order Presenter - 注文レスポンスの整形を担当
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct OrderPresenter {
+    pub id: u32,
+    /// 注文ステータス（`placed`）
+    pub status: String,
+    /// 税額の計算に使った地域（税計算の導入前の注文はnull）
+    pub tax_region: Option<String>,
+    /// `exclusive`（税抜価格に税額を加算）または `inclusive`（税込価格、税額は小計に含まれる）
+    pub tax_mode: String,
+    /// 明細の小計の合計（通貨の最小単位）
+    pub subtotal: u64,
+    /// 税額
+    pub tax: u64,
+    /// 支払総額
+    pub total: u64,
+    /// 通貨コード（ISO 4217、明細の金額も同じ通貨）
+    pub currency: String,
+    pub subtotal_display: String,
+    pub tax_display: String,
+    /// 通貨ごとの表示形式の支払総額
+    pub total_display: String,
+    /// 注文日時（RFC 3339）
+    pub placed_at: String,
+    pub lines: Vec<OrderLinePresenter>,
+}
+
+/// 注文明細1件分のレスポンス
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct OrderLinePresenter {
+    pub product_id: u32,
+    /// 注文時点の商品名
+    pub product_name: String,
+    /// 購入したバリエーションのSKU
+    pub sku: Option<String>,
+    /// 注文時点の単価（定価、通貨の最小単位）
+    pub unit_price: u64,
+    pub quantity: u32,
+    /// 明細全体の割引額
+    pub discount: u64,
+    /// 適用されたプロモーション
+    pub promotion_id: Option<u32>,
+    /// 割引後の小計
+    pub subtotal: u64,
+    /// 課税区分（`standard` / `reduced` / `exempt`）
+    pub tax_category: String,
+    /// 税率（%）
+    pub tax_rate: f64,
+    /// 明細の税額
+    pub tax: u64,
+}
+
+impl From<OrderLineQuery> for OrderLinePresenter {
+    fn from(query: OrderLineQuery) -> Self {
+        OrderLinePresenter {
+            product_id: query.product_id,
+            product_name: query.product_name,
+            sku: query.sku,
+            unit_price: query.unit_price.amount(),
+            quantity: query.quantity,
+            discount: query.discount.amount(),
+            promotion_id: query.promotion_id,
+            subtotal: query.subtotal.amount(),
+            tax_category: query.tax_category,
+            tax_rate: query.tax_rate.percent(),
+            tax: query.tax.amount(),
+        }
+    }
+}
+
+impl From<GetOrderQuery> for OrderPresenter {
+    fn from(query: GetOrderQuery) -> Self {
+        OrderPresenter {
+            id: query.id,
+            status: query.status,
+            tax_region: query.tax_region,
+            tax_mode: query.tax_mode,
+            subtotal: query.subtotal.amount(),
+            tax: query.tax.amount(),
+            total: query.total.amount(),
+            currency: query.total.currency().code().to_string(),
+            subtotal_display: query.subtotal.to_string(),
+            tax_display: query.tax.to_string(),
+            total_display: query.total.to_string(),
+            placed_at: query.placed_at.to_rfc3339(),
+            lines: query.lines.into_iter().map(|line| line.into()).collect(),
+        }
+    }
+}
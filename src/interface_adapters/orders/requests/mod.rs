@@ -0,0 +1,3 @@
+mod tax_region_query;
+
+pub use tax_region_query::{validate_region, TaxRegionQuery};
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Tax Region Query - 注文を確定するエンドポイントの税地域クエリパラメータDTO
+/// 例: `/carts/1/checkout?region=US-NY`
+#[derive(Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct TaxRegionQuery {
+    /// 税額の計算に使う地域（省略時は設定 `tax.default_region`）
+    pub region: Option<String>,
+}
+
+/// 地域コードの形式を検証します（設定されている地域かどうかはユースケースで判定する）
+pub fn validate_region(region: Option<&str>, errors: &mut ValidationErrors) {
+    if let Some(region) = region
+        && (region.is_empty()
+            || region.len() > 32
+            || !region.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+    {
+        errors.add("region", "Region must be 1-32 letters, digits, '-' or '_'");
+    }
+}
+
+impl Validate for TaxRegionQuery {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        validate_region(self.region.as_deref(), &mut errors);
+        errors.into_result()
+    }
+}
@@ -0,0 +1,56 @@
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::etag::{parse_if_match, to_etag};
+use crate::interface_adapters::products::presenters::ProductPresenter;
+use crate::interface_adapters::products::requests::CreateVariantRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Add Product Variant Controller - 商品バリエーション追加の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct AddProductVariantController;
+
+impl AddProductVariantController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/{id}/variants", post(handle))
+    }
+}
+
+/// POST /products/{id}/variants - 商品バリエーション追加処理
+/// 最初のバリエーションを追加すると、商品の在庫はバリエーションの在庫の合計になる
+#[utoipa::path(
+    post,
+    path = "/products/{id}/variants",
+    impl_for = AddProductVariantController,
+    operation_id = "add_product_variant",
+    params(("id" = u32, Path, description = "商品ID"), ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag")),
+    request_body = CreateVariantRequest,
+    tag = "products",
+    responses(
+        (status = 201, description = "バリエーション追加後の商品", body = ProductPresenter, headers(("ETag" = String, description = "商品のバージョン"))),
+        (status = 400, description = "解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致、またはSKUが使用済み", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なバリエーションデータ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<CreateVariantRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    let add_product_variant_usecase = container.create_add_product_variant_usecase();
+
+    let product = add_product_variant_usecase
+        .add(id, request.into_command(parse_if_match(&headers)?))
+        .await?;
+
+    Ok((StatusCode::CREATED, [(header::ETAG, to_etag(product.version))], Json(product.into())))
+}
@@ -1,12 +1,15 @@
 use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap, StatusCode};
 use axum::{routing::post, Json, Router};
 use std::sync::Arc;
 
 use crate::frameworks_and_drivers::Container;
-use crate::error::{Error, Result};
+use crate::error::{ProblemDetails, Result};
 use crate::application::commands::BuyProductCommand;
-use crate::application::ApplicationError;
+use crate::interface_adapters::orders::presenters::OrderPresenter;
+use crate::interface_adapters::products::etag::parse_if_match;
 use crate::interface_adapters::products::requests::BuyProductRequest;
+use crate::interface_adapters::validation::ValidatedJson;
 
 /// Buy Product Controller - 商品購入の単一責任
 /// Clean Architecture: 1つのユースケースに対して1つのController
@@ -16,29 +19,51 @@ impl BuyProductController {
     /// このControllerのルート定義
     pub fn routes() -> Router<Arc<Container>> {
         Router::new()
-            .route("/products/{id}/buy", post(Self::handle))
+            .route("/products/{id}/buy", post(handle))
     }
+}
 
-    /// POST /products/{id}/buy - 商品購入処理
-    async fn handle(
-        State(container): State<Arc<Container>>,
-        Path(id): Path<u32>, 
-        Json(request): Json<BuyProductRequest>
-    ) -> Result<()> {
-        let buy_product_usecase = container.create_buy_product_usecase();
-        
-        // RequestからCommandへの変換
-        let command = BuyProductCommand {
-            quantity: request.quantity,
-        };
-        
-        buy_product_usecase
-            .buy(id, command)
-            .await
-            .map_err(|e| match e {
-                ApplicationError::ProductNotFound(_) => Error::NotFound,
-                ApplicationError::Domain(_) => Error::BuyProductFailed,
-                _ => Error::InternalServerError,
-            })
-    }
-} 
\ No newline at end of file
+/// POST /products/{id}/buy - 商品購入処理
+/// 購入が確定すると注文が作成され、201と注文の内容を返す
+/// If-Matchヘッダーが指定された場合はそのバージョンの商品に対してのみ購入する
+/// バリエーションのある商品は `sku` で購入するバリエーションを指定する
+#[utoipa::path(
+    post,
+    path = "/products/{id}/buy",
+    impl_for = BuyProductController,
+    operation_id = "buy_product",
+    params(("id" = u32, Path, description = "商品ID"), ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag")),
+    request_body = BuyProductRequest,
+    tag = "products",
+    responses(
+        (status = 201, description = "作成された注文", body = OrderPresenter, headers(("Location" = String, description = "作成された注文のURL"))),
+        (status = 400, description = "在庫不足、または解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品またはバリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致、同時購入による在庫の変化、またはプロモーションが利用できなくなった", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正な購入数量、使えないクーポン、税制が設定されていない地域、またはSKUの指定漏れ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>, 
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<BuyProductRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<OrderPresenter>)> {
+    let buy_product_usecase = container.create_buy_product_usecase();
+    
+    // RequestからCommandへの変換
+    let command = BuyProductCommand {
+        quantity: request.quantity,
+        sku: request.sku,
+        coupon_code: request.coupon_code,
+        region: request.region,
+        expected_version: parse_if_match(&headers)?,
+    };
+    
+    let order = buy_product_usecase
+        .buy(id, command)
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/orders/{}", order.id))];
+    Ok((StatusCode::CREATED, headers, Json(order.into())))
+}
@@ -0,0 +1,53 @@
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::etag::to_etag;
+use crate::interface_adapters::products::presenters::ProductPresenter;
+use crate::interface_adapters::products::requests::CreateProductRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Create Product Controller - 商品作成の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CreateProductController;
+
+impl CreateProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products", post(handle))
+    }
+}
+
+/// POST /products - 商品作成処理
+#[utoipa::path(
+    post,
+    path = "/products",
+    impl_for = CreateProductController,
+    operation_id = "create_product",
+    request_body = CreateProductRequest,
+    tag = "products",
+    responses(
+        (status = 201, description = "作成された商品", body = ProductPresenter, headers(("Location" = String, description = "作成された商品のURL"), ("ETag" = String, description = "商品のバージョン"))),
+        (status = 422, description = "不正な商品データ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CreateProductRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 2], Json<ProductPresenter>)> {
+    let create_product_usecase = container.create_create_product_usecase();
+
+    let product = create_product_usecase
+        .create(request.into_command())
+        .await?;
+
+    let headers = [
+        (header::LOCATION, format!("/products/{}", product.id)),
+        (header::ETAG, to_etag(product.version)),
+    ];
+    Ok((StatusCode::CREATED, headers, Json(product.into())))
+}
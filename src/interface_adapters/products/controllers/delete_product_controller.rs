@@ -0,0 +1,54 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::{routing::delete, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::application::commands::DeleteProductCommand;
+use crate::interface_adapters::products::etag::parse_if_match;
+
+/// Delete Product Controller - 商品削除の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct DeleteProductController;
+
+impl DeleteProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/{id}", delete(handle))
+    }
+}
+
+/// DELETE /products/{id} - 商品削除処理
+#[utoipa::path(
+    delete,
+    path = "/products/{id}",
+    impl_for = DeleteProductController,
+    operation_id = "delete_product",
+    params(("id" = u32, Path, description = "商品ID"), ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag")),
+    tag = "products",
+    responses(
+        (status = 204, description = "削除完了"),
+        (status = 400, description = "解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    headers: HeaderMap,
+) -> Result<StatusCode> {
+    let delete_product_usecase = container.create_delete_product_usecase();
+
+    let command = DeleteProductCommand {
+        expected_version: parse_if_match(&headers)?,
+    };
+
+    delete_product_usecase
+        .delete(id, command)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
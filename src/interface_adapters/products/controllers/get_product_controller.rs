@@ -1,10 +1,11 @@
 use axum::extract::{Path, State};
+use axum::http::header;
 use axum::{routing::get, Json, Router};
 use std::sync::Arc;
 
 use crate::frameworks_and_drivers::Container;
-use crate::error::{Error, Result};
-use crate::application::ApplicationError;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::etag::to_etag;
 use crate::interface_adapters::products::presenters::ProductPresenter;
 
 /// Get Product Controller - 商品詳細取得の単一責任
@@ -15,24 +16,34 @@ impl GetProductController {
     /// このControllerのルート定義
     pub fn routes() -> Router<Arc<Container>> {
         Router::new()
-            .route("/products/{id}", get(Self::handle))
+            .route("/products/{id}", get(handle))
     }
+}
 
-    /// GET /products/{id} - 商品詳細取得処理
-    async fn handle(
-        State(container): State<Arc<Container>>,
-        Path(id): Path<u32>
-    ) -> Result<Json<ProductPresenter>> {
-        let get_product_usecase = container.create_get_product_usecase();
+/// GET /products/{id} - 商品詳細取得処理
+/// レスポンスには商品のバージョンをETagとして付与する
+#[utoipa::path(
+    get,
+    path = "/products/{id}",
+    impl_for = GetProductController,
+    operation_id = "get_product",
+    params(("id" = u32, Path, description = "商品ID")),
+    tag = "products",
+    responses(
+        (status = 200, description = "商品", body = ProductPresenter, headers(("ETag" = String, description = "商品のバージョン"))),
+        (status = 404, description = "商品が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<([(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    let get_product_usecase = container.create_get_product_usecase();
+    
+    let product = get_product_usecase
+        .get_by_id(id)
+        .await?;
         
-        let product = get_product_usecase
-            .get_by_id(id)
-            .await
-            .map_err(|e| match e {
-                ApplicationError::ProductNotFound(_) => Error::NotFound,
-                _ => Error::InternalServerError,
-            })?;
-            
-        Ok(Json(product.into()))
-    }
-} 
\ No newline at end of file
+    let etag = to_etag(product.version);
+    Ok(([(header::ETAG, etag)], Json(product.into())))
+}
@@ -3,8 +3,10 @@ use axum::{routing::get, Json, Router};
 use std::sync::Arc;
 
 use crate::frameworks_and_drivers::Container;
-use crate::error::{Error, Result};
-use crate::interface_adapters::products::presenters::ProductPresenter;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::presenters::ProductListPresenter;
+use crate::interface_adapters::products::requests::ListProductsRequest;
+use crate::interface_adapters::validation::ValidatedQuery;
 
 /// Get All Products Controller - 商品一覧取得の単一責任
 /// Clean Architecture: 1つのユースケースに対して1つのController
@@ -14,21 +16,34 @@ impl GetProductsController {
     /// このControllerのルート定義
     pub fn routes() -> Router<Arc<Container>> {
         Router::new()
-            .route("/products", get(Self::handle))
+            .route("/products", get(handle))
     }
+}
 
-    /// GET /products - 商品一覧取得処理
-    async fn handle(
-        State(container): State<Arc<Container>>
-    ) -> Result<Json<Vec<ProductPresenter>>> {
-        let get_all_products_usecase = container.create_get_all_products_usecase();
+/// GET /products - 商品一覧取得処理
+/// ページネーション・並び替え・絞り込みはクエリパラメータで指定する
+#[utoipa::path(
+    get,
+    path = "/products",
+    impl_for = GetProductsController,
+    operation_id = "list_products",
+    params(ListProductsRequest),
+    tag = "products",
+    responses(
+        (status = 200, description = "商品一覧", body = ProductListPresenter),
+        (status = 422, description = "不正な絞り込み条件", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 400, description = "不正なクエリパラメータ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedQuery(request): ValidatedQuery<ListProductsRequest>
+) -> Result<Json<ProductListPresenter>> {
+    let get_all_products_usecase = container.create_get_all_products_usecase();
+    
+    let products = get_all_products_usecase
+        .get_all(request.into_criteria())
+        .await?;
         
-        let products = get_all_products_usecase
-            .get_all()
-            .await
-            .map_err(|_| Error::InternalServerError)?;
-            
-        let presenters: Vec<ProductPresenter> = products.into_iter().map(|p| p.into()).collect();
-        Ok(Json(presenters))
-    }
-} 
\ No newline at end of file
+    Ok(Json(products.into()))
+}
@@ -1,7 +1,21 @@
 mod get_products_controller;
 mod get_product_controller;
 mod buy_product_controller;
+mod create_product_controller;
+mod update_product_controller;
+mod delete_product_controller;
+mod search_products_controller;
+mod add_product_variant_controller;
+mod update_product_variant_controller;
+mod remove_product_variant_controller;
 
 pub use get_products_controller::GetProductsController;
 pub use get_product_controller::GetProductController;
-pub use buy_product_controller::BuyProductController; 
\ No newline at end of file
+pub use buy_product_controller::BuyProductController;
+pub use create_product_controller::CreateProductController;
+pub use update_product_controller::UpdateProductController;
+pub use delete_product_controller::DeleteProductController;
+pub use search_products_controller::SearchProductsController;
+pub use add_product_variant_controller::AddProductVariantController;
+pub use update_product_variant_controller::UpdateProductVariantController;
+pub use remove_product_variant_controller::RemoveProductVariantController;
@@ -0,0 +1,58 @@
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, StatusCode};
+use axum::{routing::delete, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::application::commands::RemoveProductVariantCommand;
+use crate::interface_adapters::products::etag::parse_if_match;
+
+/// Remove Product Variant Controller - 商品バリエーション削除の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct RemoveProductVariantController;
+
+impl RemoveProductVariantController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/{id}/variants/{sku}", delete(handle))
+    }
+}
+
+/// DELETE /products/{id}/variants/{sku} - 商品バリエーション削除処理
+#[utoipa::path(
+    delete,
+    path = "/products/{id}/variants/{sku}",
+    impl_for = RemoveProductVariantController,
+    operation_id = "remove_product_variant",
+    params(
+        ("id" = u32, Path, description = "商品ID"),
+        ("sku" = String, Path, description = "SKU（大文字・小文字は区別しない）"),
+        ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag"),
+    ),
+    tag = "products",
+    responses(
+        (status = 204, description = "削除完了"),
+        (status = 400, description = "解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品またはバリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku)): Path<(u32, String)>,
+    headers: HeaderMap,
+) -> Result<StatusCode> {
+    let remove_product_variant_usecase = container.create_remove_product_variant_usecase();
+
+    let command = RemoveProductVariantCommand {
+        expected_version: parse_if_match(&headers)?,
+    };
+
+    remove_product_variant_usecase
+        .remove(id, &sku, command)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
@@ -0,0 +1,47 @@
+use axum::extract::State;
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::presenters::ProductSearchPresenter;
+use crate::interface_adapters::products::requests::SearchProductsRequest;
+use crate::interface_adapters::validation::ValidatedQuery;
+
+/// Search Products Controller - 商品検索の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct SearchProductsController;
+
+impl SearchProductsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/search", get(handle))
+    }
+}
+
+/// GET /products/search?q= - 商品の全文検索処理
+#[utoipa::path(
+    get,
+    path = "/products/search",
+    impl_for = SearchProductsController,
+    operation_id = "search_products",
+    params(SearchProductsRequest),
+    tag = "products",
+    responses(
+        (status = 200, description = "検索結果", body = ProductSearchPresenter),
+        (status = 422, description = "不正な検索条件", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedQuery(request): ValidatedQuery<SearchProductsRequest>
+) -> Result<Json<ProductSearchPresenter>> {
+    let search_products_usecase = container.create_search_products_usecase();
+
+    let hits = search_products_usecase
+        .search(&request.q, request.limit())
+        .await?;
+
+    Ok(Json(ProductSearchPresenter::new(request.q, hits)))
+}
@@ -0,0 +1,80 @@
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap};
+use axum::{routing::put, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::application::commands::UpdateProductCommand;
+use crate::interface_adapters::products::etag::{parse_if_match, to_etag};
+use crate::interface_adapters::products::presenters::ProductPresenter;
+use crate::interface_adapters::products::requests::UpdateProductRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Update Product Controller - 商品更新の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct UpdateProductController;
+
+impl UpdateProductController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/{id}", put(handle_put).patch(handle_patch))
+    }
+}
+
+/// PUT /products/{id} - 商品の全項目更新処理
+#[utoipa::path(
+    method(put, patch),
+    path = "/products/{id}",
+    impl_for = UpdateProductController,
+    operation_id = "update_product",
+    params(("id" = u32, Path, description = "商品ID"), ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag")),
+    request_body = UpdateProductRequest,
+    description = "PUTは全項目を置き換え（省略した通貨・課税区分・カテゴリ・タグは作成時の既定値に戻す）、PATCHは指定された項目のみ更新する",
+    tag = "products",
+    responses(
+        (status = 200, description = "更新後の商品", body = ProductPresenter, headers(("ETag" = String, description = "商品のバージョン"))),
+        (status = 400, description = "解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正な商品データ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle_put(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<UpdateProductRequest>
+) -> Result<([(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    request.validate_replace()?;
+
+    let command = request.into_replace_command(parse_if_match(&headers)?);
+    update(container, id, command).await
+}
+
+/// PATCH /products/{id} - 商品の部分更新処理
+async fn handle_patch(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<UpdateProductRequest>
+) -> Result<([(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    let command = request.into_command(parse_if_match(&headers)?);
+    update(container, id, command).await
+}
+
+async fn update(
+    container: Arc<Container>,
+    id: u32,
+    command: UpdateProductCommand,
+) -> Result<([(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    let update_product_usecase = container.create_update_product_usecase();
+
+    let product = update_product_usecase
+        .update(id, command)
+        .await?;
+
+    let etag = to_etag(product.version);
+    Ok(([(header::ETAG, etag)], Json(product.into())))
+}
@@ -0,0 +1,59 @@
+use axum::extract::{Path, State};
+use axum::http::{header, HeaderMap};
+use axum::{routing::patch, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::products::etag::{parse_if_match, to_etag};
+use crate::interface_adapters::products::presenters::ProductPresenter;
+use crate::interface_adapters::products::requests::UpdateVariantRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Update Product Variant Controller - 商品バリエーション更新の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct UpdateProductVariantController;
+
+impl UpdateProductVariantController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/products/{id}/variants/{sku}", patch(handle))
+    }
+}
+
+/// PATCH /products/{id}/variants/{sku} - 商品バリエーションの部分更新処理
+#[utoipa::path(
+    patch,
+    path = "/products/{id}/variants/{sku}",
+    impl_for = UpdateProductVariantController,
+    operation_id = "update_product_variant",
+    params(
+        ("id" = u32, Path, description = "商品ID"),
+        ("sku" = String, Path, description = "SKU（大文字・小文字は区別しない）"),
+        ("If-Match" = Option<String>, Header, description = "楽観的排他制御に使う商品のETag"),
+    ),
+    request_body = UpdateVariantRequest,
+    tag = "products",
+    responses(
+        (status = 200, description = "更新後の商品", body = ProductPresenter, headers(("ETag" = String, description = "商品のバージョン"))),
+        (status = 400, description = "解釈できないIf-Matchヘッダー", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品またはバリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "バージョン不一致", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なバリエーションデータ", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path((id, sku)): Path<(u32, String)>,
+    headers: HeaderMap,
+    ValidatedJson(request): ValidatedJson<UpdateVariantRequest>
+) -> Result<([(header::HeaderName, String); 1], Json<ProductPresenter>)> {
+    let update_product_variant_usecase = container.create_update_product_variant_usecase();
+
+    let product = update_product_variant_usecase
+        .update(id, &sku, request.into_command(parse_if_match(&headers)?))
+        .await?;
+
+    Ok(([(header::ETAG, to_etag(product.version))], Json(product.into())))
+}
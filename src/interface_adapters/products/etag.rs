@@ -0,0 +1,67 @@
+use axum::http::{header, HeaderMap};
+
+use crate::error::{Error, Result};
+
+/// 商品のバージョンからETagヘッダーの値を生成します
+pub fn to_etag(version: u32) -> String {
+    format!("\"{}\"", version)
+}
+
+/// If-Matchヘッダーからクライアントが期待するバージョンを取り出します
+/// ヘッダーがない場合や `*` の場合は `None` を返します
+/// If-Matchは強い比較のため、弱いETag（`W/`）・複数のETag・解釈できない値は400として拒否します
+pub fn parse_if_match(headers: &HeaderMap) -> Result<Option<u32>> {
+    let Some(value) = headers.get(header::IF_MATCH) else {
+        return Ok(None);
+    };
+
+    let invalid = |message: &str| Error::InvalidIfMatch(message.to_string());
+    let value = value
+        .to_str()
+        .map_err(|_| invalid("If-Match must be visible ASCII"))?
+        .trim();
+    if value == "*" {
+        return Ok(None);
+    }
+    if value.starts_with("W/") {
+        return Err(invalid("Weak ETags cannot be used in If-Match"));
+    }
+    if value.contains(',') {
+        return Err(invalid("If-Match must contain a single ETag"));
+    }
+
+    value
+        .strip_prefix('"')
+        .and_then(|value| value.strip_suffix('"'))
+        .and_then(|version| version.parse::<u32>().ok())
+        .map(Some)
+        .ok_or_else(|| invalid("If-Match must be an ETag returned by this API, such as \"3\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use axum::http::HeaderValue;
+
+    use super::*;
+
+    fn parse(value: &str) -> Result<Option<u32>> {
+        let mut headers = HeaderMap::new();
+        headers.insert(header::IF_MATCH, HeaderValue::from_str(value).unwrap());
+        parse_if_match(&headers)
+    }
+
+    #[test]
+    fn parses_strong_etags_and_wildcard() {
+        assert_eq!(parse_if_match(&HeaderMap::new()).unwrap(), None);
+        assert_eq!(parse("\"3\"").unwrap(), Some(3));
+        assert_eq!(parse(" \"3\" ").unwrap(), Some(3));
+        assert_eq!(parse("*").unwrap(), None);
+    }
+
+    #[test]
+    fn rejects_weak_listed_and_malformed_etags() {
+        for value in ["W/\"3\"", "\"3\", \"4\"", "3", "\"abc\"", "\"3", ""] {
+            assert!(matches!(parse(value), Err(Error::InvalidIfMatch(_))), "{value} should be rejected");
+        }
+    }
+}
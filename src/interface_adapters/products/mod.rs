@@ -1,20 +1,42 @@
 pub mod controllers;
 pub mod requests;
 pub mod presenters;
+pub mod etag;
 
 use axum::Router;
 use std::sync::Arc;
 use crate::frameworks_and_drivers::Container;
+use crate::frameworks_and_drivers::config::FeatureToggles;
 
 pub use controllers::{GetProductsController, GetProductController, BuyProductController};
-pub use requests::BuyProductRequest;
-pub use presenters::ProductPresenter;
+pub use controllers::{CreateProductController, UpdateProductController, DeleteProductController};
+pub use controllers::SearchProductsController;
+pub use controllers::{AddProductVariantController, UpdateProductVariantController, RemoveProductVariantController};
+pub use requests::{BuyProductRequest, CreateProductRequest, UpdateProductRequest, ListProductsRequest};
+pub use requests::SearchProductsRequest;
+pub use presenters::{ProductPresenter, ProductListPresenter, ProductSearchPresenter};
 
 /// Products モジュールの全ルート定義
 /// Clean Architecture: 関連するControllerのルートを統合
-pub fn routes() -> Router<Arc<Container>> {
-    Router::new()
+/// 管理系・検索のエンドポイントは機能フラグで有効な場合のみ登録する
+pub fn routes(features: &FeatureToggles) -> Router<Arc<Container>> {
+    let mut router = Router::new()
         .merge(GetProductsController::routes())
         .merge(GetProductController::routes())
-        .merge(BuyProductController::routes())
+        .merge(BuyProductController::routes());
+
+    if features.product_admin {
+        router = router
+            .merge(CreateProductController::routes())
+            .merge(UpdateProductController::routes())
+            .merge(DeleteProductController::routes())
+            .merge(AddProductVariantController::routes())
+            .merge(UpdateProductVariantController::routes())
+            .merge(RemoveProductVariantController::routes());
+    }
+    if features.product_search {
+        router = router.merge(SearchProductsController::routes());
+    }
+
+    router
 } 
\ No newline at end of file
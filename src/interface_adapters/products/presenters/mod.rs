@@ -1,3 +1,7 @@
 mod product_presenter;
+mod product_list_presenter;
+mod product_search_presenter;
 
-pub use product_presenter::ProductPresenter; 
\ No newline at end of file
+pub use product_presenter::{ProductPresenter, ProductVariantPresenter};
+pub use product_list_presenter::ProductListPresenter;
+pub use product_search_presenter::{ProductSearchPresenter, ProductSearchHitPresenter};
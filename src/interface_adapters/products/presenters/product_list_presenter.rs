@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::queries::GetProductQuery;
+use crate::application::repositories::Page;
+use crate::interface_adapters::products::presenters::ProductPresenter;
+
+/// Product List Presenter - 商品一覧レスポンスのエンベロープ
+/// ページネーション情報を商品一覧と一緒に返す
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ProductListPresenter {
+    pub items: Vec<ProductPresenter>,
+    pub page: u32,
+    pub per_page: u32,
+    /// 条件に一致する全件数
+    pub total: u64,
+    /// 次のページ番号（最終ページの場合は `null`）
+    pub next_page: Option<u32>,
+}
+
+impl From<Page<GetProductQuery>> for ProductListPresenter {
+    fn from(page: Page<GetProductQuery>) -> Self {
+        let next_page = page.next_page();
+        ProductListPresenter {
+            page: page.pagination.page,
+            per_page: page.pagination.per_page,
+            total: page.total,
+            next_page,
+            items: page.items.into_iter().map(|p| p.into()).collect(),
+        }
+    }
+}
@@ -1,14 +1,58 @@
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
-use crate::application::queries::GetProductQuery;
+use utoipa::ToSchema;
+use crate::application::queries::{GetProductQuery, ProductVariantQuery};
+use crate::interface_adapters::categories::presenters::CategoryPresenter;
 
 /// Product Presenter - レスポンス形式の整形を担当
 /// Uncle Bob's Clean Architecture における Presenter の役割
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct ProductPresenter {
     pub id: u32,
     pub name: String,
-    pub price: u32,
+    /// 定価（通貨の最小単位）
+    pub price: u64,
+    /// 通貨コード（ISO 4217）
+    pub currency: String,
+    /// 通貨ごとの表示形式の価格（例: `¥1,200`、`$12.34`）
+    pub price_display: String,
+    /// 1点購入時の割引後の価格（クーポンなし。割引がなければ定価と同じ）
+    pub discounted_price: u64,
+    pub discounted_price_display: String,
+    /// 割引後の価格に適用されたプロモーション
+    pub promotion_id: Option<u32>,
+    /// 課税区分（`standard` / `reduced` / `exempt`）
+    pub tax_category: String,
     pub description: String,
+    /// 販売可能な在庫数
+    pub quantity: u32,
+    /// 予約により確保されている在庫数
+    pub reserved: u32,
+    /// 所属するカテゴリ
+    pub categories: Vec<CategoryPresenter>,
+    /// タグ（小文字に正規化済み）
+    pub tags: Vec<String>,
+    /// 属性ごとの選択肢（例: `layout` = `["JIS", "US"]`）。バリエーションがなければ空
+    pub variant_attributes: BTreeMap<String, Vec<String>>,
+    /// バリエーション（在庫はバリエーションごとに管理され、`quantity` はその合計）
+    pub variants: Vec<ProductVariantPresenter>,
+    pub version: u32,
+}
+
+/// 商品のバリエーション
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ProductVariantPresenter {
+    pub sku: String,
+    /// 属性（例: `layout` = `US`、`colour` = `black`）
+    pub attributes: BTreeMap<String, String>,
+    /// 定価（通貨の最小単位。通貨は商品と同じ）
+    pub price: u64,
+    pub price_display: String,
+    /// 1点購入時の割引後の価格（クーポンなし）
+    pub discounted_price: u64,
+    pub discounted_price_display: String,
+    /// 販売可能な在庫数
     pub quantity: u32,
 }
 
@@ -19,25 +63,36 @@ impl From<GetProductQuery> for ProductPresenter {
         ProductPresenter {
             id: query.id,
             name: query.name,
-            price: query.price,
+            price: query.price.amount(),
+            currency: query.price.currency().code().to_string(),
+            price_display: query.price.to_string(),
+            discounted_price: query.discounted_price.amount(),
+            discounted_price_display: query.discounted_price.to_string(),
+            promotion_id: query.promotion_id,
+            tax_category: query.tax_category,
             description: query.description,
             quantity: query.quantity,
+            reserved: query.reserved,
+            categories: query.categories.into_iter().map(CategoryPresenter::from).collect(),
+            tags: query.tags,
+            variant_attributes: query.variant_attributes,
+            variants: query.variants.into_iter().map(ProductVariantPresenter::from).collect(),
+            version: query.version,
         }
     }
 }
 
-impl ProductPresenter {
-    /// 必要に応じてフォーマット処理を追加
-    /// 例: 価格の通貨表示、在庫状況の文字列化など
-    pub fn format_price_display(&self) -> String {
-        format!("¥{}", self.price)
-    }
-
-    pub fn format_availability_status(&self) -> String {
-        if self.quantity > 0 {
-            "在庫あり".to_string()
-        } else {
-            "在庫切れ".to_string()
+impl From<ProductVariantQuery> for ProductVariantPresenter {
+    fn from(query: ProductVariantQuery) -> Self {
+        ProductVariantPresenter {
+            sku: query.sku,
+            attributes: query.attributes,
+            price: query.price.amount(),
+            price_display: query.price.to_string(),
+            discounted_price: query.discounted_price.amount(),
+            discounted_price_display: query.discounted_price.to_string(),
+            quantity: query.quantity,
         }
     }
-} 
\ No newline at end of file
+}
+ 
\ No newline at end of file
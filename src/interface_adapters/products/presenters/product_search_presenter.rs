@@ -0,0 +1,42 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::queries::SearchProductQuery;
+use crate::interface_adapters::products::presenters::ProductPresenter;
+
+/// Product Search Presenter - 商品検索レスポンスの整形を担当
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ProductSearchPresenter {
+    pub query: String,
+    pub items: Vec<ProductSearchHitPresenter>,
+}
+
+/// 検索ヒット1件分のレスポンス
+/// ハイライト部分は `<mark>` タグで囲まれ、それ以外の本文はHTMLエスケープ済み
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ProductSearchHitPresenter {
+    pub product: ProductPresenter,
+    pub score: f64,
+    pub highlighted_name: String,
+    pub snippet: String,
+}
+
+impl From<SearchProductQuery> for ProductSearchHitPresenter {
+    fn from(query: SearchProductQuery) -> Self {
+        ProductSearchHitPresenter {
+            product: query.product.into(),
+            score: query.score,
+            highlighted_name: query.highlighted_name,
+            snippet: query.snippet,
+        }
+    }
+}
+
+impl ProductSearchPresenter {
+    pub fn new(query: String, hits: Vec<SearchProductQuery>) -> Self {
+        ProductSearchPresenter {
+            query,
+            items: hits.into_iter().map(|hit| hit.into()).collect(),
+        }
+    }
+}
@@ -1,22 +1,50 @@
 use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::domain::models::ProductVariant;
+use crate::interface_adapters::orders::requests::validate_region;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
 
 /// Buy Product Request - 商品購入リクエスト専用DTO
 /// Clean Architecture: リクエストの責任を明確化
-#[derive(Serialize, Deserialize)]
+#[derive(Serialize, Deserialize, ToSchema)]
 pub struct BuyProductRequest {
     /// 購入数量
     pub quantity: u32,
+    /// 購入するバリエーションのSKU（バリエーションのある商品では必須。大文字・小文字は区別しない）
+    pub sku: Option<String>,
+    /// クーポンコード（大文字・小文字は区別しない）
+    pub coupon_code: Option<String>,
+    /// 税額の計算に使う地域（省略時は設定 `tax.default_region`）
+    pub region: Option<String>,
 }
 
-impl BuyProductRequest {
-    /// バリデーション処理（必要に応じて）
-    pub fn validate(&self) -> Result<(), String> {
+/// バリエーションを指定するSKUの文字数を検証します（存在するかどうかはユースケースで判定する）
+pub fn validate_sku(field: &str, sku: Option<&str>, errors: &mut ValidationErrors) {
+    if let Some(sku) = sku
+        && (sku.trim().is_empty() || sku.chars().count() > ProductVariant::MAX_SKU_LENGTH)
+    {
+        errors.add(field, format!("SKU must be between 1 and {} characters", ProductVariant::MAX_SKU_LENGTH));
+    }
+}
+
+impl Validate for BuyProductRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
         if self.quantity == 0 {
-            return Err("Quantity must be greater than 0".to_string());
+            errors.add("quantity", "Quantity must be greater than 0");
         }
         if self.quantity > 1000 {
-            return Err("Quantity cannot exceed 1000".to_string());
+            errors.add("quantity", "Quantity cannot exceed 1000");
+        }
+        if let Some(coupon_code) = &self.coupon_code
+            && (coupon_code.trim().is_empty() || coupon_code.chars().count() > 64)
+        {
+            errors.add("coupon_code", "Coupon code must be between 1 and 64 characters");
         }
-        Ok(())
+        validate_sku("sku", self.sku.as_deref(), &mut errors);
+        validate_region(self.region.as_deref(), &mut errors);
+        errors.into_result()
     }
 } 
\ No newline at end of file
@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::CreateProductCommand;
+use crate::domain::models::{Currency, Product};
+use crate::domain::tax::TaxCategory;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Create Product Request - 商品作成リクエスト専用DTO
+/// Clean Architecture: リクエストの責任を明確化
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateProductRequest {
+    /// 商品名
+    pub name: String,
+    /// 価格（通貨の最小単位。JPYは円、USDはセント）
+    pub price: u32,
+    /// 通貨コード（ISO 4217、省略時はJPY）
+    pub currency: Option<String>,
+    /// 課税区分（standard / reduced / exempt、省略時はstandard）
+    pub tax_category: Option<String>,
+    /// 商品説明
+    pub description: String,
+    /// 初期在庫数
+    pub quantity: u32,
+    /// 所属させるカテゴリのID
+    pub category_ids: Option<Vec<u32>>,
+    /// タグ（大文字・小文字は区別しない）
+    pub tags: Option<Vec<String>>,
+}
+
+/// タグの件数と文字数を検証します
+pub fn validate_tags(tags: Option<&[String]>, errors: &mut ValidationErrors) {
+    let Some(tags) = tags else {
+        return;
+    };
+    if tags.len() > Product::MAX_TAGS {
+        errors.add("tags", format!("A product can have at most {} tags", Product::MAX_TAGS));
+    }
+    if tags.iter().any(|tag| tag.trim().is_empty() || tag.trim().chars().count() > Product::MAX_TAG_LENGTH) {
+        errors.add("tags", format!("Each tag must be 1-{} characters", Product::MAX_TAG_LENGTH));
+    }
+}
+
+impl Validate for CreateProductRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.name.trim().is_empty() {
+            errors.add("name", "Name must not be empty");
+        }
+        if self.name.chars().count() > 100 {
+            errors.add("name", "Name cannot exceed 100 characters");
+        }
+        if self.description.chars().count() > 1000 {
+            errors.add("description", "Description cannot exceed 1000 characters");
+        }
+        if let Some(currency) = &self.currency
+            && Currency::parse(currency).is_none()
+        {
+            errors.add("currency", "Currency must be one of JPY, USD, EUR");
+        }
+        if let Some(tax_category) = &self.tax_category
+            && TaxCategory::parse(tax_category).is_none()
+        {
+            errors.add("tax_category", "Tax category must be one of standard, reduced, exempt");
+        }
+        validate_tags(self.tags.as_deref(), &mut errors);
+        errors.into_result()
+    }
+}
+
+impl CreateProductRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self) -> CreateProductCommand {
+        CreateProductCommand {
+            name: self.name,
+            price: self.price.into(),
+            currency: self.currency.as_deref().and_then(Currency::parse).unwrap_or_default(),
+            tax_category: self.tax_category.as_deref().and_then(TaxCategory::parse).unwrap_or_default(),
+            description: self.description,
+            quantity: self.quantity,
+            category_ids: self.category_ids.unwrap_or_default(),
+            tags: self.tags.unwrap_or_default(),
+        }
+    }
+}
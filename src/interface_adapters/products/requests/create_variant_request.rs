@@ -0,0 +1,56 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::AddProductVariantCommand;
+use crate::domain::models::ProductVariant;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Create Variant Request - 商品バリエーション追加リクエスト専用DTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateVariantRequest {
+    /// SKU（全商品で一意。大文字・小文字は区別しない）
+    pub sku: String,
+    /// 属性（例: `{"layout": "US", "colour": "black"}`）。同じ商品のバリエーションは同じ属性名を持つ
+    pub attributes: BTreeMap<String, String>,
+    /// 価格（商品と同じ通貨の最小単位）
+    pub price: u32,
+    /// 在庫数
+    pub quantity: u32,
+}
+
+/// バリエーションの属性を検証します
+pub fn validate_attributes(attributes: &BTreeMap<String, String>, errors: &mut ValidationErrors) {
+    if attributes.is_empty() {
+        errors.add("attributes", "A variant must have at least one attribute");
+    }
+    if attributes.iter().any(|(name, value)| name.trim().is_empty() || value.trim().is_empty()) {
+        errors.add("attributes", "Attribute names and values must not be empty");
+    }
+}
+
+impl Validate for CreateVariantRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.sku.trim().is_empty() || self.sku.chars().count() > ProductVariant::MAX_SKU_LENGTH {
+            errors.add("sku", format!("SKU must be between 1 and {} characters", ProductVariant::MAX_SKU_LENGTH));
+        }
+        validate_attributes(&self.attributes, &mut errors);
+        errors.into_result()
+    }
+}
+
+impl CreateVariantRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self, expected_version: Option<u32>) -> AddProductVariantCommand {
+        AddProductVariantCommand {
+            sku: self.sku,
+            attributes: self.attributes,
+            price: self.price.into(),
+            quantity: self.quantity,
+            expected_version,
+        }
+    }
+}
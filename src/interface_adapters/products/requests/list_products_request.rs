@@ -0,0 +1,122 @@
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::application::repositories::{
+    Pagination, ProductFilter, ProductListCriteria, ProductSortKey, SortOrder,
+};
+use crate::domain::models::{Currency, Product};
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// 1ページあたりのデフォルト件数
+const DEFAULT_PER_PAGE: u32 = 20;
+/// 1ページあたりの最大件数
+const MAX_PER_PAGE: u32 = 100;
+
+/// 並び替えキー（クエリパラメータ表現）
+#[derive(Serialize, Deserialize, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum ProductSortParam {
+    Id,
+    Name,
+    Price,
+    CreatedAt,
+}
+
+/// 並び順（クエリパラメータ表現）
+#[derive(Serialize, Deserialize, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrderParam {
+    Asc,
+    Desc,
+}
+
+/// List Products Request - 商品一覧取得のクエリパラメータDTO
+/// 例: `/products?page=2&per_page=10&sort=price&order=desc&currency=JPY&min_price=1000&in_stock=true&category=3&tag=sale`
+#[derive(Serialize, Deserialize, Default, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ListProductsRequest {
+    /// ページ番号（1始まり）
+    pub page: Option<u32>,
+    /// 1ページあたりの件数
+    pub per_page: Option<u32>,
+    /// 並び替えキー（`price` は通貨ごとにまとめてから価格順）
+    pub sort: Option<ProductSortParam>,
+    /// 並び順
+    pub order: Option<SortOrderParam>,
+    /// 通貨（JPY / USD / EUR）。`min_price`・`max_price` を指定する場合は必須
+    pub currency: Option<String>,
+    /// 最低価格（`currency` の最小単位）
+    pub min_price: Option<u32>,
+    /// 最高価格（`currency` の最小単位）
+    pub max_price: Option<u32>,
+    /// 在庫がある商品のみ
+    pub in_stock: Option<bool>,
+    /// カテゴリID（子孫のカテゴリに属する商品を含む）
+    pub category: Option<u32>,
+    /// タグ（大文字・小文字は区別しない）
+    pub tag: Option<String>,
+}
+
+impl Validate for ListProductsRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.page == Some(0) {
+            errors.add("page", "Page must be greater than 0");
+        }
+        if let Some(per_page) = self.per_page
+            && (per_page == 0 || per_page > MAX_PER_PAGE)
+        {
+            errors.add("per_page", format!("per_page must be between 1 and {}", MAX_PER_PAGE));
+        }
+        match &self.currency {
+            Some(currency) if Currency::parse(currency).is_none() => {
+                errors.add("currency", "Currency must be one of JPY, USD, EUR");
+            }
+            // 価格は通貨ごとの最小単位のため、通貨を指定しないと比較できない
+            None if self.min_price.is_some() || self.max_price.is_some() => {
+                errors.add("currency", "currency is required when filtering by price");
+            }
+            _ => {}
+        }
+        if let Some(tag) = &self.tag
+            && (tag.trim().is_empty() || tag.trim().chars().count() > Product::MAX_TAG_LENGTH)
+        {
+            errors.add("tag", format!("tag must be 1-{} characters", Product::MAX_TAG_LENGTH));
+        }
+        errors.into_result()
+    }
+}
+
+impl ListProductsRequest {
+    /// Requestから取得条件への変換
+    pub fn into_criteria(self) -> ProductListCriteria {
+        let sort_key = match self.sort.unwrap_or(ProductSortParam::Id) {
+            ProductSortParam::Id => ProductSortKey::Id,
+            ProductSortParam::Name => ProductSortKey::Name,
+            ProductSortParam::Price => ProductSortKey::Price,
+            ProductSortParam::CreatedAt => ProductSortKey::CreatedAt,
+        };
+        let sort_order = match self.order.unwrap_or(SortOrderParam::Asc) {
+            SortOrderParam::Asc => SortOrder::Asc,
+            SortOrderParam::Desc => SortOrder::Desc,
+        };
+
+        ProductListCriteria {
+            filter: ProductFilter {
+                currency: self.currency.as_deref().and_then(Currency::parse),
+                min_price: self.min_price,
+                max_price: self.max_price,
+                in_stock_only: self.in_stock.unwrap_or(false),
+                category_id: self.category,
+                tag: self.tag.as_deref().map(Product::normalize_tag),
+            },
+            sort_key,
+            sort_order,
+            pagination: Pagination::new(
+                self.page.unwrap_or(1),
+                self.per_page.unwrap_or(DEFAULT_PER_PAGE),
+            ),
+        }
+    }
+}
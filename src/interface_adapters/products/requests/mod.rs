@@ -1,3 +1,15 @@
 mod buy_product_request;
+mod create_product_request;
+mod update_product_request;
+mod list_products_request;
+mod search_products_request;
+mod create_variant_request;
+mod update_variant_request;
 
-pub use buy_product_request::BuyProductRequest; 
\ No newline at end of file
+pub use buy_product_request::{validate_sku, BuyProductRequest};
+pub use create_product_request::{validate_tags, CreateProductRequest};
+pub use update_product_request::UpdateProductRequest;
+pub use list_products_request::{ListProductsRequest, ProductSortParam, SortOrderParam};
+pub use search_products_request::SearchProductsRequest;
+pub use create_variant_request::{validate_attributes, CreateVariantRequest};
+pub use update_variant_request::UpdateVariantRequest;
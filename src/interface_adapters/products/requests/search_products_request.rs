@@ -0,0 +1,45 @@
+use serde::{Deserialize, Serialize};
+use utoipa::IntoParams;
+
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// 検索結果のデフォルト件数
+const DEFAULT_LIMIT: u32 = 20;
+/// 検索結果の最大件数
+const MAX_LIMIT: u32 = 50;
+
+/// Search Products Request - 商品検索のクエリパラメータDTO
+/// 例: `/products/search?q=keyboard&limit=10`
+#[derive(Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct SearchProductsRequest {
+    /// 検索キーワード（空白区切りで複数指定した場合はAND検索）
+    pub q: String,
+    /// 最大件数
+    pub limit: Option<u32>,
+}
+
+impl Validate for SearchProductsRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.q.trim().is_empty() {
+            errors.add("q", "q must not be empty");
+        }
+        if self.q.chars().count() > 200 {
+            errors.add("q", "q cannot exceed 200 characters");
+        }
+        if let Some(limit) = self.limit
+            && (limit == 0 || limit > MAX_LIMIT)
+        {
+            errors.add("limit", format!("limit must be between 1 and {}", MAX_LIMIT));
+        }
+        errors.into_result()
+    }
+}
+
+impl SearchProductsRequest {
+    pub fn limit(&self) -> u32 {
+        self.limit.unwrap_or(DEFAULT_LIMIT)
+    }
+}
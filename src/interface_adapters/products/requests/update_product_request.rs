@@ -0,0 +1,114 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::UpdateProductCommand;
+use crate::domain::models::Currency;
+use crate::domain::tax::TaxCategory;
+use crate::interface_adapters::products::requests::validate_tags;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Update Product Request - 商品更新リクエスト専用DTO
+/// PUTでは全項目を置き換え（省略した任意項目は作成時と同じ既定値に戻す）、PATCHでは指定された項目のみを更新する
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateProductRequest {
+    /// 商品名
+    pub name: Option<String>,
+    /// 価格（通貨の最小単位。JPYは円、USDはセント）
+    pub price: Option<u32>,
+    /// 通貨コード（ISO 4217。省略時はPUTではJPY、PATCHでは現在の通貨のまま）
+    pub currency: Option<String>,
+    /// 課税区分（standard / reduced / exempt。省略時はPUTではstandard、PATCHでは現在の区分のまま）
+    pub tax_category: Option<String>,
+    /// 商品説明
+    pub description: Option<String>,
+    /// 在庫数
+    pub quantity: Option<u32>,
+    /// 所属させるカテゴリのID（指定した場合はすべて置き換える。PUTで省略した場合はどのカテゴリにも所属しない）
+    pub category_ids: Option<Vec<u32>>,
+    /// タグ（指定した場合はすべて置き換える。PUTで省略した場合はタグなし）
+    pub tags: Option<Vec<String>>,
+}
+
+impl Validate for UpdateProductRequest {
+    /// 指定された項目のバリデーション処理（PUT/PATCH共通）
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(name) = &self.name {
+            if name.trim().is_empty() {
+                errors.add("name", "Name must not be empty");
+            }
+            if name.chars().count() > 100 {
+                errors.add("name", "Name cannot exceed 100 characters");
+            }
+        }
+        if let Some(description) = &self.description
+            && description.chars().count() > 1000
+        {
+            errors.add("description", "Description cannot exceed 1000 characters");
+        }
+        if let Some(currency) = &self.currency {
+            if Currency::parse(currency).is_none() {
+                errors.add("currency", "Currency must be one of JPY, USD, EUR");
+            }
+            // 通貨だけを変えると既存の金額が別の通貨として解釈されてしまう
+            if self.price.is_none() {
+                errors.add("currency", "currency can only be changed together with price");
+            }
+        }
+        if let Some(tax_category) = &self.tax_category
+            && TaxCategory::parse(tax_category).is_none()
+        {
+            errors.add("tax_category", "Tax category must be one of standard, reduced, exempt");
+        }
+        validate_tags(self.tags.as_deref(), &mut errors);
+        errors.into_result()
+    }
+}
+
+impl UpdateProductRequest {
+    /// PUT用の追加バリデーション処理（全項目必須）
+    pub fn validate_replace(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.name.is_none() {
+            errors.add("name", "name is required");
+        }
+        if self.price.is_none() {
+            errors.add("price", "price is required");
+        }
+        if self.description.is_none() {
+            errors.add("description", "description is required");
+        }
+        if self.quantity.is_none() {
+            errors.add("quantity", "quantity is required");
+        }
+        errors.into_result()
+    }
+
+    /// PUT用のRequestからCommandへの変換
+    /// 省略された任意項目は現在の値を残さず、作成時と同じ既定値で置き換える
+    pub fn into_replace_command(self, expected_version: Option<u32>) -> UpdateProductCommand {
+        let command = self.into_command(expected_version);
+        UpdateProductCommand {
+            currency: Some(command.currency.unwrap_or_default()),
+            tax_category: Some(command.tax_category.unwrap_or_default()),
+            category_ids: Some(command.category_ids.unwrap_or_default()),
+            tags: Some(command.tags.unwrap_or_default()),
+            ..command
+        }
+    }
+
+    /// RequestからCommandへの変換
+    pub fn into_command(self, expected_version: Option<u32>) -> UpdateProductCommand {
+        UpdateProductCommand {
+            name: self.name,
+            price: self.price.map(u64::from),
+            currency: self.currency.as_deref().and_then(Currency::parse),
+            tax_category: self.tax_category.as_deref().and_then(TaxCategory::parse),
+            description: self.description,
+            quantity: self.quantity,
+            category_ids: self.category_ids,
+            tags: self.tags,
+            expected_version,
+        }
+    }
+}
@@ -0,0 +1,43 @@
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::UpdateProductVariantCommand;
+use crate::interface_adapters::products::requests::validate_attributes;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// Update Variant Request - 商品バリエーション更新リクエスト専用DTO
+/// 指定された項目のみを更新する
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct UpdateVariantRequest {
+    /// 属性（指定した場合はすべて置き換える）
+    pub attributes: Option<BTreeMap<String, String>>,
+    /// 価格（商品と同じ通貨の最小単位）
+    pub price: Option<u32>,
+    /// 在庫数
+    pub quantity: Option<u32>,
+}
+
+impl Validate for UpdateVariantRequest {
+    /// 指定された項目のバリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if let Some(attributes) = &self.attributes {
+            validate_attributes(attributes, &mut errors);
+        }
+        errors.into_result()
+    }
+}
+
+impl UpdateVariantRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self, expected_version: Option<u32>) -> UpdateProductVariantCommand {
+        UpdateProductVariantCommand {
+            attributes: self.attributes,
+            price: self.price.map(u64::from),
+            quantity: self.quantity,
+            expected_version,
+        }
+    }
+}
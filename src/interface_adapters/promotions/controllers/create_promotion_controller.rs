@@ -0,0 +1,52 @@
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::promotions::presenters::PromotionPresenter;
+use crate::interface_adapters::promotions::requests::CreatePromotionRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Create Promotion Controller - プロモーション作成の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CreatePromotionController;
+
+impl CreatePromotionController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/promotions", post(handle))
+    }
+}
+
+/// POST /promotions - プロモーション作成処理
+/// クーポンコードを指定しない場合は、期間内の購入に自動で適用される
+#[utoipa::path(
+    post,
+    path = "/promotions",
+    impl_for = CreatePromotionController,
+    operation_id = "create_promotion",
+    request_body = CreatePromotionRequest,
+    tag = "promotions",
+    responses(
+        (status = 201, description = "作成されたプロモーション", body = PromotionPresenter, headers(("Location" = String, description = "作成されたプロモーションのURL"))),
+        (status = 404, description = "対象商品が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "クーポンコードが使用済み", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正なプロモーション", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CreatePromotionRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<PromotionPresenter>)> {
+    let create_promotion_usecase = container.create_create_promotion_usecase();
+
+    let promotion = create_promotion_usecase
+        .create(request.into_command())
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/promotions/{}", promotion.id))];
+    Ok((StatusCode::CREATED, headers, Json(promotion.into())))
+}
@@ -0,0 +1,46 @@
+use axum::extract::{Path, State};
+use axum::http::StatusCode;
+use axum::{routing::delete, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+
+/// Delete Promotion Controller - プロモーション削除の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct DeletePromotionController;
+
+impl DeletePromotionController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/promotions/{id}", delete(handle))
+    }
+}
+
+/// DELETE /promotions/{id} - プロモーション削除処理
+/// 作成済みの注文の割引は変わらない
+#[utoipa::path(
+    delete,
+    path = "/promotions/{id}",
+    impl_for = DeletePromotionController,
+    operation_id = "delete_promotion",
+    params(("id" = u32, Path, description = "プロモーションID")),
+    tag = "promotions",
+    responses(
+        (status = 204, description = "削除完了"),
+        (status = 404, description = "プロモーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+) -> Result<StatusCode> {
+    let delete_promotion_usecase = container.create_delete_promotion_usecase();
+
+    delete_promotion_usecase
+        .delete(id)
+        .await?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
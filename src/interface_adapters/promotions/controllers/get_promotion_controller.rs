@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::promotions::presenters::PromotionPresenter;
+
+/// Get Promotion Controller - プロモーション取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetPromotionController;
+
+impl GetPromotionController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/promotions/{id}", get(handle))
+    }
+}
+
+/// GET /promotions/{id} - プロモーション取得処理
+#[utoipa::path(
+    get,
+    path = "/promotions/{id}",
+    impl_for = GetPromotionController,
+    operation_id = "get_promotion",
+    params(("id" = u32, Path, description = "プロモーションID")),
+    tag = "promotions",
+    responses(
+        (status = 200, description = "プロモーション", body = PromotionPresenter),
+        (status = 404, description = "プロモーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<PromotionPresenter>> {
+    let get_promotion_usecase = container.create_get_promotion_usecase();
+
+    let promotion = get_promotion_usecase
+        .get_by_id(id)
+        .await?;
+
+    Ok(Json(promotion.into()))
+}
@@ -0,0 +1,43 @@
+use axum::extract::State;
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::Result;
+use crate::interface_adapters::promotions::presenters::PromotionListPresenter;
+
+/// Get Promotions Controller - プロモーション一覧取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetPromotionsController;
+
+impl GetPromotionsController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/promotions", get(handle))
+    }
+}
+
+/// GET /promotions - プロモーション一覧取得処理
+/// 期間外・上限到達のものも含めて作成順に返す
+#[utoipa::path(
+    get,
+    path = "/promotions",
+    impl_for = GetPromotionsController,
+    operation_id = "list_promotions",
+    tag = "promotions",
+    responses(
+        (status = 200, description = "プロモーション一覧", body = PromotionListPresenter),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+) -> Result<Json<PromotionListPresenter>> {
+    let get_all_promotions_usecase = container.create_get_all_promotions_usecase();
+
+    let promotions = get_all_promotions_usecase
+        .get_all()
+        .await?;
+
+    Ok(Json(promotions.into()))
+}
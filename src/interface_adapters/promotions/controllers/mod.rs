@@ -0,0 +1,9 @@
+mod create_promotion_controller;
+mod get_promotions_controller;
+mod get_promotion_controller;
+mod delete_promotion_controller;
+
+pub use create_promotion_controller::CreatePromotionController;
+pub use get_promotions_controller::GetPromotionsController;
+pub use get_promotion_controller::GetPromotionController;
+pub use delete_promotion_controller::DeletePromotionController;
@@ -0,0 +1,21 @@
+pub mod controllers;
+pub mod requests;
+pub mod presenters;
+
+use axum::Router;
+use std::sync::Arc;
+use crate::frameworks_and_drivers::Container;
+
+pub use controllers::{CreatePromotionController, GetPromotionsController};
+pub use controllers::{GetPromotionController, DeletePromotionController};
+pub use requests::{CreatePromotionRequest, PromotionKindParam};
+pub use presenters::{PromotionPresenter, PromotionListPresenter};
+
+/// Promotions モジュールの全ルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(CreatePromotionController::routes())
+        .merge(GetPromotionsController::routes())
+        .merge(GetPromotionController::routes())
+        .merge(DeletePromotionController::routes())
+}
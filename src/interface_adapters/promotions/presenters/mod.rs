@@ -0,0 +1,3 @@
+mod promotion_presenter;
+
+pub use promotion_presenter::{PromotionPresenter, PromotionListPresenter};
@@ -0,0 +1,77 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+use crate::application::queries::GetPromotionQuery;
+use crate::domain::models::DiscountRule;
+
+/// Promotion Presenter - プロモーションのレスポンス形式
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PromotionPresenter {
+    pub id: u32,
+    pub name: String,
+    /// 割引の種類（`percent_off` / `amount_off` / `buy_n_get_m`）
+    pub kind: String,
+    pub percent: Option<u32>,
+    /// 1点あたりの割引額（通貨の最小単位）
+    pub amount: Option<u64>,
+    pub currency: Option<String>,
+    pub buy_quantity: Option<u32>,
+    pub get_quantity: Option<u32>,
+    /// 対象商品ID（nullは全商品）
+    pub product_id: Option<u32>,
+    /// クーポンコード（nullは自動適用）
+    pub coupon_code: Option<String>,
+    pub starts_at: Option<String>,
+    pub ends_at: Option<String>,
+    pub usage_limit: Option<u32>,
+    /// これまでに適用された注文数
+    pub usage_count: u32,
+}
+
+impl From<GetPromotionQuery> for PromotionPresenter {
+    fn from(query: GetPromotionQuery) -> Self {
+        let (mut percent, mut amount, mut currency) = (None, None, None);
+        let (mut buy_quantity, mut get_quantity) = (None, None);
+        match query.rule {
+            DiscountRule::PercentOff { percent: value } => percent = Some(value),
+            DiscountRule::AmountOff { amount: value } => {
+                amount = Some(value.amount());
+                currency = Some(value.currency().code().to_string());
+            }
+            DiscountRule::BuyNGetM { buy, get } => {
+                buy_quantity = Some(buy);
+                get_quantity = Some(get);
+            }
+        }
+
+        PromotionPresenter {
+            id: query.id,
+            name: query.name,
+            kind: query.rule.kind().to_string(),
+            percent,
+            amount,
+            currency,
+            buy_quantity,
+            get_quantity,
+            product_id: query.product_id,
+            coupon_code: query.coupon_code,
+            starts_at: query.starts_at.map(|at| at.to_rfc3339()),
+            ends_at: query.ends_at.map(|at| at.to_rfc3339()),
+            usage_limit: query.usage_limit,
+            usage_count: query.usage_count,
+        }
+    }
+}
+
+/// プロモーション一覧のレスポンス形式
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct PromotionListPresenter {
+    pub items: Vec<PromotionPresenter>,
+}
+
+impl From<Vec<GetPromotionQuery>> for PromotionListPresenter {
+    fn from(promotions: Vec<GetPromotionQuery>) -> Self {
+        PromotionListPresenter {
+            items: promotions.into_iter().map(PromotionPresenter::from).collect(),
+        }
+    }
+}
@@ -0,0 +1,150 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::CreatePromotionCommand;
+use crate::domain::models::{Currency, DiscountRule, Money};
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// 割引の種類（リクエスト表現）
+#[derive(Serialize, Deserialize, Clone, Copy, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum PromotionKindParam {
+    /// 定率割引（`percent` が必須）
+    PercentOff,
+    /// 1点あたりの定額割引（`amount` が必須、`currency` は省略時JPY）
+    AmountOff,
+    /// N点買うとM点無料（`buy_quantity` と `get_quantity` が必須）
+    BuyNGetM,
+}
+
+/// Create Promotion Request - プロモーション作成リクエスト専用DTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreatePromotionRequest {
+    /// プロモーション名
+    pub name: String,
+    /// 割引の種類
+    pub kind: PromotionKindParam,
+    /// 割引率（%）
+    pub percent: Option<u32>,
+    /// 1点あたりの割引額（通貨の最小単位）
+    pub amount: Option<u32>,
+    /// 割引額の通貨コード（ISO 4217）。同じ通貨の商品にのみ適用される
+    pub currency: Option<String>,
+    /// 購入数（N）
+    pub buy_quantity: Option<u32>,
+    /// 無料になる数（M）
+    pub get_quantity: Option<u32>,
+    /// 対象商品ID（省略時は全商品）
+    pub product_id: Option<u32>,
+    /// クーポンコード（省略時は自動適用）
+    pub coupon_code: Option<String>,
+    /// 適用開始日時（RFC 3339）
+    pub starts_at: Option<String>,
+    /// 適用終了日時（RFC 3339、この日時を含まない）
+    pub ends_at: Option<String>,
+    /// 利用回数の上限（注文単位）
+    pub usage_limit: Option<u32>,
+}
+
+fn parse_timestamp(value: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value).ok().map(|at| at.with_timezone(&Utc))
+}
+
+impl Validate for CreatePromotionRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.name.trim().is_empty() {
+            errors.add("name", "Name must not be empty");
+        }
+        if self.name.chars().count() > 100 {
+            errors.add("name", "Name cannot exceed 100 characters");
+        }
+        match self.kind {
+            PromotionKindParam::PercentOff => match self.percent {
+                None => errors.add("percent", "percent is required for percent_off"),
+                Some(percent) if percent == 0 || percent > 100 => {
+                    errors.add("percent", "Percent must be between 1 and 100");
+                }
+                Some(_) => {}
+            },
+            PromotionKindParam::AmountOff => {
+                match self.amount {
+                    None => errors.add("amount", "amount is required for amount_off"),
+                    Some(0) => errors.add("amount", "Amount must be greater than 0"),
+                    Some(_) => {}
+                }
+                if let Some(currency) = &self.currency
+                    && Currency::parse(currency).is_none()
+                {
+                    errors.add("currency", "Currency must be one of JPY, USD, EUR");
+                }
+            }
+            PromotionKindParam::BuyNGetM => {
+                for (field, value) in [("buy_quantity", self.buy_quantity), ("get_quantity", self.get_quantity)] {
+                    match value {
+                        None => errors.add(field, format!("{} is required for buy_n_get_m", field)),
+                        Some(0) => errors.add(field, "Quantity must be greater than 0"),
+                        Some(_) => {}
+                    }
+                }
+            }
+        }
+        if let Some(coupon_code) = &self.coupon_code
+            && (coupon_code.is_empty()
+                || coupon_code.len() > 64
+                || !coupon_code.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'))
+        {
+            errors.add("coupon_code", "Coupon code must be 1-64 letters, digits, '-' or '_'");
+        }
+        let starts_at = self.starts_at.as_deref().map(parse_timestamp);
+        let ends_at = self.ends_at.as_deref().map(parse_timestamp);
+        if starts_at == Some(None) {
+            errors.add("starts_at", "starts_at must be an RFC 3339 timestamp");
+        }
+        if ends_at == Some(None) {
+            errors.add("ends_at", "ends_at must be an RFC 3339 timestamp");
+        }
+        if let (Some(Some(starts_at)), Some(Some(ends_at))) = (starts_at, ends_at)
+            && ends_at <= starts_at
+        {
+            errors.add("ends_at", "ends_at must be after starts_at");
+        }
+        if self.usage_limit == Some(0) {
+            errors.add("usage_limit", "Usage limit must be greater than 0");
+        }
+        errors.into_result()
+    }
+}
+
+impl CreatePromotionRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self) -> CreatePromotionCommand {
+        let rule = match self.kind {
+            PromotionKindParam::PercentOff => DiscountRule::PercentOff {
+                percent: self.percent.unwrap_or_default(),
+            },
+            PromotionKindParam::AmountOff => {
+                let currency = self.currency.as_deref().and_then(Currency::parse).unwrap_or_default();
+                DiscountRule::AmountOff {
+                    amount: Money::new(self.amount.unwrap_or_default().into(), currency),
+                }
+            }
+            PromotionKindParam::BuyNGetM => DiscountRule::BuyNGetM {
+                buy: self.buy_quantity.unwrap_or_default(),
+                get: self.get_quantity.unwrap_or_default(),
+            },
+        };
+
+        CreatePromotionCommand {
+            name: self.name,
+            rule,
+            product_id: self.product_id,
+            coupon_code: self.coupon_code,
+            starts_at: self.starts_at.as_deref().and_then(parse_timestamp),
+            ends_at: self.ends_at.as_deref().and_then(parse_timestamp),
+            usage_limit: self.usage_limit,
+        }
+    }
+}
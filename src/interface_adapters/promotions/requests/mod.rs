@@ -0,0 +1,3 @@
+mod create_promotion_request;
+
+pub use create_promotion_request::{CreatePromotionRequest, PromotionKindParam};
@@ -0,0 +1,53 @@
+use axum::extract::{Path, State};
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::orders::presenters::OrderPresenter;
+use crate::interface_adapters::orders::requests::TaxRegionQuery;
+use crate::interface_adapters::validation::ValidatedQuery;
+
+/// Confirm Reservation Controller - 在庫予約確定の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct ConfirmReservationController;
+
+impl ConfirmReservationController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/reservations/{id}/confirm", post(handle))
+    }
+}
+
+/// POST /reservations/{id}/confirm - 予約した在庫の注文確定処理
+/// 予約で確保していた在庫から注文が作成され、201と注文の内容を返す
+#[utoipa::path(
+    post,
+    path = "/reservations/{id}/confirm",
+    impl_for = ConfirmReservationController,
+    operation_id = "confirm_reservation",
+    params(("id" = u32, Path, description = "予約ID"), TaxRegionQuery),
+    tag = "reservations",
+    responses(
+        (status = 201, description = "作成された注文", body = OrderPresenter, headers(("Location" = String, description = "作成された注文のURL"))),
+        (status = 404, description = "予約・商品・バリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "予約が期限切れ・確定・取り消し済み、またはプロモーションが利用できなくなった", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "税制が設定されていない地域", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>,
+    ValidatedQuery(query): ValidatedQuery<TaxRegionQuery>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<OrderPresenter>)> {
+    let confirm_reservation_usecase = container.create_confirm_reservation_usecase();
+
+    let order = confirm_reservation_usecase
+        .confirm(id, query.region.as_deref())
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/orders/{}", order.id))];
+    Ok((StatusCode::CREATED, headers, Json(order.into())))
+}
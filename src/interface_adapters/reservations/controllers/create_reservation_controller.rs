@@ -0,0 +1,53 @@
+use axum::extract::State;
+use axum::http::{header, StatusCode};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::reservations::presenters::ReservationPresenter;
+use crate::interface_adapters::reservations::requests::CreateReservationRequest;
+use crate::interface_adapters::validation::ValidatedJson;
+
+/// Create Reservation Controller - 在庫予約の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct CreateReservationController;
+
+impl CreateReservationController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/reservations", post(handle))
+    }
+}
+
+/// POST /reservations - 在庫予約処理
+/// 全商品の在庫を確保できた場合のみ予約され、有効期限までに確定しなければ在庫が戻される
+#[utoipa::path(
+    post,
+    path = "/reservations",
+    impl_for = CreateReservationController,
+    operation_id = "create_reservation",
+    request_body = CreateReservationRequest,
+    tag = "reservations",
+    responses(
+        (status = 201, description = "作成された予約", body = ReservationPresenter, headers(("Location" = String, description = "作成された予約のURL"))),
+        (status = 400, description = "在庫不足", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 404, description = "商品またはバリエーションが存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "他のリクエストと競合", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 422, description = "不正な商品・数量・SKU、またはバリエーションのある商品でSKUが指定されていない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    ValidatedJson(request): ValidatedJson<CreateReservationRequest>
+) -> Result<(StatusCode, [(header::HeaderName, String); 1], Json<ReservationPresenter>)> {
+    let create_reservation_usecase = container.create_create_reservation_usecase();
+
+    let reservation = create_reservation_usecase
+        .create(request.into_command())
+        .await?;
+
+    let headers = [(header::LOCATION, format!("/reservations/{}", reservation.id))];
+    Ok((StatusCode::CREATED, headers, Json(reservation.into())))
+}
@@ -0,0 +1,45 @@
+use axum::extract::{Path, State};
+use axum::{routing::get, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::reservations::presenters::ReservationPresenter;
+
+/// Get Reservation Controller - 在庫予約取得の単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct GetReservationController;
+
+impl GetReservationController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/reservations/{id}", get(handle))
+    }
+}
+
+/// GET /reservations/{id} - 在庫予約取得処理
+#[utoipa::path(
+    get,
+    path = "/reservations/{id}",
+    impl_for = GetReservationController,
+    operation_id = "get_reservation",
+    params(("id" = u32, Path, description = "予約ID")),
+    tag = "reservations",
+    responses(
+        (status = 200, description = "予約", body = ReservationPresenter),
+        (status = 404, description = "予約が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<ReservationPresenter>> {
+    let get_reservation_usecase = container.create_get_reservation_usecase();
+
+    let reservation = get_reservation_usecase
+        .get_by_id(id)
+        .await?;
+
+    Ok(Json(reservation.into()))
+}
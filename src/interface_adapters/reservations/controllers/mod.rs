@@ -0,0 +1,9 @@
+mod create_reservation_controller;
+mod get_reservation_controller;
+mod confirm_reservation_controller;
+mod release_reservation_controller;
+
+pub use create_reservation_controller::CreateReservationController;
+pub use get_reservation_controller::GetReservationController;
+pub use confirm_reservation_controller::ConfirmReservationController;
+pub use release_reservation_controller::ReleaseReservationController;
@@ -0,0 +1,47 @@
+use axum::extract::{Path, State};
+use axum::{routing::post, Json, Router};
+use std::sync::Arc;
+
+use crate::frameworks_and_drivers::Container;
+use crate::error::{ProblemDetails, Result};
+use crate::interface_adapters::reservations::presenters::ReservationPresenter;
+
+/// Release Reservation Controller - 在庫予約取り消しの単一責任
+/// Clean Architecture: 1つのユースケースに対して1つのController
+pub struct ReleaseReservationController;
+
+impl ReleaseReservationController {
+    /// このControllerのルート定義
+    pub fn routes() -> Router<Arc<Container>> {
+        Router::new()
+            .route("/reservations/{id}/release", post(handle))
+    }
+}
+
+/// POST /reservations/{id}/release - 在庫予約の取り消し処理
+/// 確保していた在庫は販売可能な在庫に戻される
+#[utoipa::path(
+    post,
+    path = "/reservations/{id}/release",
+    impl_for = ReleaseReservationController,
+    operation_id = "release_reservation",
+    params(("id" = u32, Path, description = "予約ID")),
+    tag = "reservations",
+    responses(
+        (status = 200, description = "取り消された予約", body = ReservationPresenter),
+        (status = 404, description = "予約が存在しない", body = ProblemDetails, content_type = "application/problem+json"),
+        (status = 409, description = "予約が既に確定・取り消し・失効済み", body = ProblemDetails, content_type = "application/problem+json"),
+    )
+)]
+async fn handle(
+    State(container): State<Arc<Container>>,
+    Path(id): Path<u32>
+) -> Result<Json<ReservationPresenter>> {
+    let release_reservation_usecase = container.create_release_reservation_usecase();
+
+    let reservation = release_reservation_usecase
+        .release(id)
+        .await?;
+
+    Ok(Json(reservation.into()))
+}
@@ -0,0 +1,21 @@
+pub mod controllers;
+pub mod requests;
+pub mod presenters;
+
+use axum::Router;
+use std::sync::Arc;
+use crate::frameworks_and_drivers::Container;
+
+pub use controllers::{CreateReservationController, GetReservationController};
+pub use controllers::{ConfirmReservationController, ReleaseReservationController};
+pub use requests::{CreateReservationRequest, ReservationItemRequest};
+pub use presenters::{ReservationPresenter, ReservationItemPresenter};
+
+/// Reservations モジュールの全ルート定義
+pub fn routes() -> Router<Arc<Container>> {
+    Router::new()
+        .merge(CreateReservationController::routes())
+        .merge(GetReservationController::routes())
+        .merge(ConfirmReservationController::routes())
+        .merge(ReleaseReservationController::routes())
+}
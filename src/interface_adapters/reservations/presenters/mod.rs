@@ -0,0 +1,3 @@
+mod reservation_presenter;
+
+pub use reservation_presenter::{ReservationPresenter, ReservationItemPresenter};
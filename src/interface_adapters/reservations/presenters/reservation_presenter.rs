@@ -0,0 +1,48 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::queries::{GetReservationQuery, ReservationItemQuery};
+
+/// Reservation Presenter - 在庫予約レスポンスの整形を担当
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReservationPresenter {
+    pub id: u32,
+    /// 予約ステータス（`active` / `confirmed` / `released` / `expired`）
+    pub status: String,
+    /// 有効期限（RFC 3339）。過ぎると確定できず、在庫が戻される
+    pub expires_at: String,
+    /// 確定で作成された注文のID
+    pub order_id: Option<u32>,
+    pub items: Vec<ReservationItemPresenter>,
+}
+
+/// 予約された商品1件分のレスポンス
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReservationItemPresenter {
+    pub product_id: u32,
+    /// 予約したバリエーションのSKU
+    pub sku: Option<String>,
+    pub quantity: u32,
+}
+
+impl From<ReservationItemQuery> for ReservationItemPresenter {
+    fn from(query: ReservationItemQuery) -> Self {
+        ReservationItemPresenter {
+            product_id: query.product_id,
+            sku: query.sku,
+            quantity: query.quantity,
+        }
+    }
+}
+
+impl From<GetReservationQuery> for ReservationPresenter {
+    fn from(query: GetReservationQuery) -> Self {
+        ReservationPresenter {
+            id: query.id,
+            status: query.status,
+            expires_at: query.expires_at.to_rfc3339(),
+            order_id: query.order_id,
+            items: query.items.into_iter().map(|item| item.into()).collect(),
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use crate::application::commands::{CreateReservationCommand, ReservationItemCommand};
+use crate::interface_adapters::products::requests::validate_sku;
+use crate::interface_adapters::validation::{Validate, ValidationErrors};
+
+/// 1回の予約で指定できる商品の最大数
+const MAX_ITEMS: usize = 50;
+
+/// Create Reservation Request - 在庫予約リクエスト専用DTO
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct CreateReservationRequest {
+    /// 予約する商品
+    pub items: Vec<ReservationItemRequest>,
+}
+
+/// 予約する商品1件分のリクエスト
+#[derive(Serialize, Deserialize, ToSchema)]
+pub struct ReservationItemRequest {
+    /// 商品ID
+    pub product_id: u32,
+    /// 予約するバリエーションのSKU（バリエーションのある商品では必須。大文字・小文字は区別しない）
+    pub sku: Option<String>,
+    /// 予約する数量
+    pub quantity: u32,
+}
+
+impl Validate for CreateReservationRequest {
+    /// バリデーション処理
+    fn validate(&self) -> Result<(), ValidationErrors> {
+        let mut errors = ValidationErrors::new();
+        if self.items.is_empty() {
+            errors.add("items", "At least one item is required");
+        }
+        if self.items.len() > MAX_ITEMS {
+            errors.add("items", format!("Cannot reserve more than {} items", MAX_ITEMS));
+        }
+        for (index, item) in self.items.iter().enumerate() {
+            if item.quantity == 0 {
+                errors.add(&format!("items[{}].quantity", index), "Quantity must be greater than 0");
+            }
+            if item.quantity > 1000 {
+                errors.add(&format!("items[{}].quantity", index), "Quantity cannot exceed 1000");
+            }
+            validate_sku(&format!("items[{}].sku", index), item.sku.as_deref(), &mut errors);
+        }
+        errors.into_result()
+    }
+}
+
+impl CreateReservationRequest {
+    /// RequestからCommandへの変換
+    pub fn into_command(self) -> CreateReservationCommand {
+        CreateReservationCommand {
+            items: self.items
+                .into_iter()
+                .map(|item| ReservationItemCommand {
+                    product_id: item.product_id,
+                    sku: item.sku,
+                    quantity: item.quantity,
+                })
+                .collect(),
+        }
+    }
+}
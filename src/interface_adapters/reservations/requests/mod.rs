@@ -0,0 +1,3 @@
+mod create_reservation_request;
+
+pub use create_reservation_request::{CreateReservationRequest, ReservationItemRequest};
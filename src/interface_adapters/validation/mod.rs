@@ -0,0 +1,7 @@
+mod validate;
+mod validated_json;
+mod validated_query;
+
+pub use validate::*;
+pub use validated_json::*;
+pub use validated_query::*;
@@ -0,0 +1,61 @@
+use serde::Serialize;
+use utoipa::ToSchema;
+
+/// 項目単位のバリデーションエラー
+#[derive(Debug, Clone, PartialEq, Serialize, ToSchema)]
+pub struct FieldError {
+    /// エラーのある項目名（リクエストのキー名）
+    pub field: String,
+    pub message: String,
+}
+
+/// DTOのバリデーション結果として集めた項目エラーの一覧
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(transparent)]
+pub struct ValidationErrors {
+    errors: Vec<FieldError>,
+}
+
+impl ValidationErrors {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 項目エラーを追加します
+    pub fn add(&mut self, field: &str, message: impl Into<String>) {
+        self.errors.push(FieldError {
+            field: field.to_string(),
+            message: message.into(),
+        });
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.errors.is_empty()
+    }
+
+    pub fn errors(&self) -> &[FieldError] {
+        &self.errors
+    }
+
+    /// エラーが1件もなければ `Ok(())` を返します
+    pub fn into_result(self) -> Result<(), ValidationErrors> {
+        if self.is_empty() { Ok(()) } else { Err(self) }
+    }
+}
+
+impl std::fmt::Display for ValidationErrors {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let messages: Vec<String> = self.errors
+            .iter()
+            .map(|e| format!("{}: {}", e.field, e.message))
+            .collect();
+        write!(f, "{}", messages.join(", "))
+    }
+}
+
+/// リクエストDTOのバリデーション
+/// `ValidatedJson` / `ValidatedQuery` がハンドラー実行前に呼び出す
+pub trait Validate {
+    /// 全項目を検査し、エラーがあればまとめて返します
+    fn validate(&self) -> Result<(), ValidationErrors>;
+}
@@ -0,0 +1,73 @@
+use axum::extract::{FromRequest, Request};
+use axum::Json;
+use serde::de::DeserializeOwned;
+
+use crate::error::Error;
+use crate::interface_adapters::validation::Validate;
+
+/// JSONボディをデシリアライズし、`Validate` を通過した場合のみハンドラーに渡すExtractor
+/// 不正なJSONやContent-Type、バリデーションエラーはproblem+jsonで返す
+pub struct ValidatedJson<T>(pub T);
+
+impl<T, S> FromRequest<S> for ValidatedJson<T>
+where
+    T: DeserializeOwned + Validate,
+    S: Send + Sync,
+{
+    type Rejection = Error;
+
+    async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
+        let Json(value) = Json::<T>::from_request(req, state).await?;
+        value.validate()?;
+
+        Ok(Self(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::body::Body;
+    use axum::http::{header, StatusCode};
+
+    use crate::interface_adapters::products::requests::BuyProductRequest;
+
+    fn json_request(body: &'static str) -> Request {
+        Request::builder()
+            .method("POST")
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body))
+            .unwrap()
+    }
+
+    async fn extract(req: Request) -> Result<ValidatedJson<BuyProductRequest>, Error> {
+        ValidatedJson::<BuyProductRequest>::from_request(req, &()).await
+    }
+
+    #[tokio::test]
+    async fn passes_valid_body_to_handler() {
+        let ValidatedJson(request) = extract(json_request(r#"{"quantity":3}"#)).await.unwrap();
+        assert_eq!(request.quantity, 3);
+    }
+
+    #[tokio::test]
+    async fn collects_field_errors() {
+        let Err(Error::InvalidFields(errors)) = extract(json_request(r#"{"quantity":0}"#)).await else {
+            panic!("expected field errors");
+        };
+        assert_eq!(errors.errors()[0].field, "quantity");
+    }
+
+    #[tokio::test]
+    async fn maps_rejections_to_problem_status() {
+        let malformed = extract(json_request("{")).await.err().unwrap();
+        assert_eq!(malformed.to_problem().status_code(), StatusCode::BAD_REQUEST);
+
+        let req = Request::builder().method("POST").body(Body::from(r#"{"quantity":1}"#)).unwrap();
+        let no_content_type = extract(req).await.err().unwrap();
+        assert_eq!(no_content_type.to_problem().status_code(), StatusCode::UNSUPPORTED_MEDIA_TYPE);
+
+        let wrong_type = extract(json_request(r#"{"quantity":"x"}"#)).await.err().unwrap();
+        assert_eq!(wrong_type.to_problem().status_code(), StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
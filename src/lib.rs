@@ -0,0 +1,8 @@
+pub use error::{Error, ProblemDetails, Result};
+
+pub mod app;
+pub mod error;
+pub mod interface_adapters;
+pub mod application;
+pub mod domain;
+pub mod frameworks_and_drivers;
@@ -1,74 +1,113 @@
-use axum::{
-    Router,
-    middleware,
-    response::Response,
-};
 use clap::{Parser, Subcommand};
+use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
-pub use error::{Error, Result};
-
-mod error;
-mod interface_adapters;
-mod application;
-mod domain;
-mod frameworks_and_drivers;
+use axum_mini_template::{app, frameworks_and_drivers, interface_adapters};
+use frameworks_and_drivers::config::{Config, ConfigArgs, DatabaseBackend, DatabaseConfig};
+use frameworks_and_drivers::database::db::Database;
 
 #[derive(Parser)]
 #[command(version, about)]
 struct Cli {
     #[command(subcommand)]
     command: Option<Commands>,
+    #[command(flatten)]
+    config: ConfigArgs,
 }
 
 #[derive(Subcommand)]
 enum Commands {
     /// Start the server
     Serve,
-    /// Run migrations
-    Migration,
+    /// Manage database migrations (defaults to `up`)
+    Migration {
+        #[command(subcommand)]
+        command: Option<MigrationCommands>,
+    },
     /// Seed the database
     Seed,
     /// Reset the database
     Reset,
+    /// Write the OpenAPI document (to stdout unless --output is given)
+    Openapi {
+        /// Output file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Subcommand)]
+enum MigrationCommands {
+    /// Apply all pending migrations
+    Up,
+    /// Revert the latest applied migrations
+    Down {
+        /// Number of migrations to revert
+        #[arg(default_value_t = 1)]
+        n: usize,
+    },
+    /// Show applied and pending migrations
+    Status,
+    /// Create a new pair of up/down migration files
+    New {
+        /// Migration name (e.g. add_products_sku)
+        name: String,
+    },
 }
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let cli = Cli::parse();
-    let database_url = "sqlite:data/db.sqlite";
-    frameworks_and_drivers::database::db::init_db(database_url).await?;
+    let config = Arc::new(Config::load(&cli.config)?);
+    frameworks_and_drivers::telemetry::init_tracing(&config.log)?;
+    let command = cli.command.unwrap_or(Commands::Serve);
+
+    if let Commands::Openapi { output } = command {
+        let spec = interface_adapters::openapi::openapi_json()?;
+        match output {
+            Some(path) => std::fs::write(&path, spec + "\n")?,
+            None => println!("{spec}"),
+        }
+        return Ok(());
+    }
+
+    // マイグレーションは設定されたデータベースへ個別に接続するため、DBの初期化より前に処理する
+    if let Commands::Migration { command } = command {
+        return run_migration_command(&config.database, command.unwrap_or(MigrationCommands::Up)).await;
+    }
+
+    let database = Arc::new(Database::new(&config.database).await?);
     
     // 依存関係の解決
-    let container = Arc::new(frameworks_and_drivers::get_container());
+    let container = Arc::new(frameworks_and_drivers::get_container(config.clone(), database));
     
-    match cli.command.unwrap_or(Commands::Serve) {
+    match command {
         Commands::Serve => {
-            let app = Router::new()
-                .merge(interface_adapters::products::routes())
-                .layer(middleware::map_response(main_response_mapper))
-                .with_state(container);  // アプリケーション状態としてコンテナを追加
+            let expiry_interval = Duration::from_secs(config.reservations.expiry_interval_seconds);
+            frameworks_and_drivers::jobs::spawn_reservation_expiry(container.clone(), expiry_interval);
+            frameworks_and_drivers::jobs::spawn_idempotency_cleanup(container.clone());
 
-            let addr = "127.0.0.1:4000";
+            let app = app::router(container);
+
+            let addr = config.server.bind_address;
             let listener = tokio::net::TcpListener::bind(addr).await.unwrap();
 
-            println!("->> Listening on {addr}");
+            tracing::info!(%addr, "listening");
             axum::serve(listener, app).await.unwrap();
         },
-        Commands::Migration => {
-            println!("Running migrations...");
-            frameworks_and_drivers::database::migrations::run_migrations(database_url).await?;
-            println!("Migrations completed successfully!");
+        Commands::Migration { .. } | Commands::Openapi { .. } => {
+            unreachable!("handled before database initialization")
         },
         Commands::Seed => {
             println!("Seeding database...");
-            frameworks_and_drivers::database::seed::seed_database().await?;
+            frameworks_and_drivers::database::seed::seed_database(&container.database).await?;
             println!("Database seeded successfully!");
         },
         Commands::Reset => {
             println!("Resetting database...");
-            frameworks_and_drivers::database::clear::clear_database().await?;
-            frameworks_and_drivers::database::seed::seed_database().await?;
+            frameworks_and_drivers::database::clear::clear_database(&container.database).await?;
+            frameworks_and_drivers::database::seed::seed_database(&container.database).await?;
             println!("Database reset successfully!");
         }
     }
@@ -76,9 +115,34 @@ async fn main() -> anyhow::Result<()> {
     Ok(())
 }
 
-async fn main_response_mapper(res: Response) -> Response {
-    println!("->> main response mapper");
-    println!();
+async fn run_migration_command(database: &DatabaseConfig, command: MigrationCommands) -> anyhow::Result<()> {
+    use frameworks_and_drivers::database::migrations;
+
+    match command {
+        MigrationCommands::Up => {
+            println!("Running migrations...");
+            migrations::run_migrations(database).await?;
+        },
+        MigrationCommands::Down { n } => {
+            println!("Reverting {n} migration(s)...");
+            migrations::revert_migrations(database, n).await?;
+        },
+        MigrationCommands::Status => {
+            for status in migrations::migration_status(database).await? {
+                let state = if status.applied { "applied" } else { "pending" };
+                let database = match status.backend {
+                    DatabaseBackend::Sqlite => "",
+                    DatabaseBackend::Postgres => " (postgres)",
+                };
+                println!("{:<8} {} {}{}", state, status.version, status.description, database);
+            }
+        },
+        MigrationCommands::New { name } => {
+            for path in migrations::create_migration(&name)? {
+                println!("Created {path}");
+            }
+        },
+    }
 
-    res
+    Ok(())
 }
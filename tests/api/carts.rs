@@ -0,0 +1,110 @@
+use axum::http::{Method, StatusCode};
+use serde_json::{json, Value};
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    creates_and_gets_a_cart,
+    adds_updates_and_removes_items,
+    rejects_invalid_item_changes,
+    checks_out_a_cart,
+    rejects_invalid_checkouts,
+);
+
+async fn create_cart(app: &TestApp) -> Value {
+    let response = app.request(Method::POST, "/carts", &[], None).await;
+    let cart = response.expect(StatusCode::CREATED).clone();
+    assert_eq!(response.header("location"), format!("/carts/{}", cart["id"]));
+    cart
+}
+
+async fn creates_and_gets_a_cart(app: TestApp) {
+
+    let cart = create_cart(&app).await;
+    assert_eq!(cart["status"], "open");
+    assert_eq!(cart["order_id"], json!(null));
+    assert_eq!(cart["items"], json!([]));
+
+    let body = app.get(&format!("/carts/{}", cart["id"])).await.expect(StatusCode::OK).clone();
+    assert_eq!(body, cart);
+
+    app.get("/carts/99").await.expect_problem(StatusCode::NOT_FOUND, "CART_NOT_FOUND");
+}
+
+async fn adds_updates_and_removes_items(app: TestApp) {
+    let id = create_cart(&app).await["id"].clone();
+
+    let cart = app.post(&format!("/carts/{id}/items"), json!({"product_id": 1, "quantity": 1})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(cart["items"], json!([{"product_id": 1, "sku": null, "quantity": 1}]));
+
+    // 同じ商品を追加すると数量が加算される
+    let cart = app.post(&format!("/carts/{id}/items"), json!({"product_id": 1, "quantity": 2})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(cart["items"], json!([{"product_id": 1, "sku": null, "quantity": 3}]));
+
+    let cart = app.put(&format!("/carts/{id}/items/1"), json!({"quantity": 5})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(cart["items"], json!([{"product_id": 1, "sku": null, "quantity": 5}]));
+
+    let cart = app.delete(&format!("/carts/{id}/items/1")).await.expect(StatusCode::OK).clone();
+    assert_eq!(cart["items"], json!([]));
+}
+
+async fn rejects_invalid_item_changes(app: TestApp) {
+    let id = create_cart(&app).await["id"].clone();
+
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 1, "quantity": 0})).await
+        .expect_invalid_field("quantity");
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 99, "quantity": 1})).await
+        .expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+    app.post("/carts/99/items", json!({"product_id": 1, "quantity": 1})).await
+        .expect_problem(StatusCode::NOT_FOUND, "CART_NOT_FOUND");
+    app.put(&format!("/carts/{id}/items/2"), json!({"quantity": 1})).await
+        .expect_problem(StatusCode::NOT_FOUND, "CART_ITEM_NOT_FOUND");
+    app.delete(&format!("/carts/{id}/items/2")).await
+        .expect_problem(StatusCode::NOT_FOUND, "CART_ITEM_NOT_FOUND");
+}
+
+async fn checks_out_a_cart(app: TestApp) {
+    let id = create_cart(&app).await["id"].clone();
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 1, "quantity": 1})).await.expect(StatusCode::OK);
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 2, "quantity": 2})).await.expect(StatusCode::OK);
+
+    let response = app.request(Method::POST, &format!("/carts/{id}/checkout"), &[], None).await;
+    let order = response.expect(StatusCode::CREATED).clone();
+
+    assert_eq!(response.header("location"), format!("/orders/{}", order["id"]));
+    assert_eq!(order["lines"].as_array().unwrap().len(), 2);
+    assert_eq!(order["total"], 99999 + 2 * 2999);
+
+    let cart = app.get(&format!("/carts/{id}")).await.expect(StatusCode::OK).clone();
+    assert_eq!(cart["status"], "checked_out");
+    assert_eq!(cart["order_id"], order["id"]);
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["quantity"], 48);
+
+    // 注文確定後のカートは変更できない
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 2, "quantity": 1})).await
+        .expect_problem(StatusCode::CONFLICT, "CART_CHECKED_OUT");
+    app.request(Method::POST, &format!("/carts/{id}/checkout"), &[], None).await
+        .expect_problem(StatusCode::CONFLICT, "CART_CHECKED_OUT");
+}
+
+async fn rejects_invalid_checkouts(app: TestApp) {
+    let id = create_cart(&app).await["id"].clone();
+
+    app.request(Method::POST, &format!("/carts/{id}/checkout"), &[], None).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_CART_DATA");
+
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 1, "quantity": 11})).await.expect(StatusCode::OK);
+    app.request(Method::POST, &format!("/carts/{id}/checkout"), &[], None).await
+        .expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY");
+    app.request(Method::POST, &format!("/carts/{id}/checkout?region=FR"), &[], None).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "UNKNOWN_TAX_REGION");
+    app.request(Method::POST, &format!("/carts/{id}/checkout?region=%20"), &[], None).await
+        .expect_invalid_field("region");
+    app.request(Method::POST, "/carts/99/checkout", &[], None).await
+        .expect_problem(StatusCode::NOT_FOUND, "CART_NOT_FOUND");
+}
@@ -0,0 +1,77 @@
+use axum::http::StatusCode;
+use serde_json::{json, Value};
+
+use crate::support::TestApp;
+
+async fn create_category(app: &TestApp, name: &str, parent_id: Option<&Value>) -> Value {
+    let response = app.post("/categories", json!({"name": name, "parent_id": parent_id})).await;
+    let category = response.expect(StatusCode::CREATED).clone();
+    assert_eq!(response.header("location"), format!("/categories/{}", category["id"]));
+    category
+}
+
+#[tokio::test]
+async fn creates_lists_updates_and_deletes_categories() {
+    let app = TestApp::spawn().await;
+
+    let electronics = create_category(&app, "Electronics", None).await;
+    let peripherals = create_category(&app, "Peripherals", Some(&electronics["id"])).await;
+    assert_eq!(peripherals["parent_id"], electronics["id"]);
+
+    let body = app.get(&format!("/categories/{}", peripherals["id"])).await.expect(StatusCode::OK).clone();
+    assert_eq!(body, peripherals);
+    let body = app.get("/categories").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["items"], json!([electronics, peripherals]));
+
+    let body = app.put(&format!("/categories/{}", peripherals["id"]), json!({"name": "Accessories"})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(body["name"], "Accessories");
+    assert_eq!(body["parent_id"], json!(null));
+
+    let response = app.delete(&format!("/categories/{}", peripherals["id"])).await;
+    assert_eq!(response.expect(StatusCode::NO_CONTENT), &json!(null));
+    app.get(&format!("/categories/{}", peripherals["id"])).await
+        .expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn filters_products_by_category_subtree_and_tag() {
+    let app = TestApp::spawn().await;
+    let electronics = create_category(&app, "Electronics", None).await;
+    let peripherals = create_category(&app, "Peripherals", Some(&electronics["id"])).await;
+
+    let product = app.patch("/products/2", json!({"category_ids": [peripherals["id"]], "tags": ["Wireless"]})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(product["categories"], json!([peripherals]));
+    assert_eq!(product["tags"], json!(["wireless"]));
+    app.patch("/products/1", json!({"category_ids": [electronics["id"]]})).await.expect(StatusCode::OK);
+
+    let body = app.get(&format!("/products?category={}", electronics["id"])).await.expect(StatusCode::OK).clone();
+    let ids: Vec<u64> = body["items"].as_array().unwrap().iter().map(|p| p["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [1, 2]);
+
+    let body = app.get("/products?tag=wireless").await.expect(StatusCode::OK).clone();
+    let ids: Vec<u64> = body["items"].as_array().unwrap().iter().map(|p| p["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [2]);
+}
+
+#[tokio::test]
+async fn rejects_invalid_categories() {
+    let app = TestApp::spawn().await;
+    let electronics = create_category(&app, "Electronics", None).await;
+    let peripherals = create_category(&app, "Peripherals", Some(&electronics["id"])).await;
+
+    app.post("/categories", json!({"name": ""})).await.expect_invalid_field("name");
+    app.post("/categories", json!({"name": "Audio", "parent_id": 99})).await
+        .expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+    app.get("/categories/99").await.expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+    app.put("/categories/99", json!({"name": "Audio"})).await
+        .expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+    app.delete("/categories/99").await.expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+
+    // 子孫を親にすると循環する
+    app.put(&format!("/categories/{}", electronics["id"]), json!({"name": "Electronics", "parent_id": peripherals["id"]})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "INVALID_CATEGORY_DATA");
+    app.delete(&format!("/categories/{}", electronics["id"])).await
+        .expect_problem(StatusCode::CONFLICT, "CATEGORY_HAS_CHILDREN");
+}
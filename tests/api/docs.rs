@@ -0,0 +1,48 @@
+use axum::http::{Method, StatusCode};
+
+use crate::support::TestApp;
+
+#[tokio::test]
+async fn serves_the_openapi_document() {
+    let app = TestApp::spawn().await;
+
+    let document = app.get("/openapi.json").await.expect(StatusCode::OK).clone();
+
+    assert!(document["openapi"].as_str().unwrap().starts_with("3."));
+    let paths = document["paths"].as_object().unwrap();
+    for path in ["/products", "/products/{id}/buy", "/carts/{id}/checkout", "/reservations", "/promotions", "/categories"] {
+        assert!(paths.contains_key(path), "{path} is not documented");
+    }
+}
+
+#[tokio::test]
+async fn serves_swagger_ui() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/docs/").await;
+
+    assert_eq!(response.status, StatusCode::OK);
+    assert!(response.header("content-type").starts_with("text/html"));
+}
+
+#[tokio::test]
+async fn unknown_routes_are_problems() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/nothing-here").await;
+    let problem = response.expect_problem(StatusCode::NOT_FOUND, "NOT_FOUND");
+    assert_eq!(problem["instance"], response.header("x-request-id"));
+
+    let response = app.request(Method::DELETE, "/products", &[], None).await;
+    assert_eq!(response.status, StatusCode::METHOD_NOT_ALLOWED);
+}
+
+#[tokio::test]
+async fn propagates_the_client_request_id() {
+    let app = TestApp::spawn().await;
+
+    let response = app.request(Method::GET, "/products/99", &[("x-request-id", "req-123")], None).await;
+
+    assert_eq!(response.header("x-request-id"), "req-123");
+    assert_eq!(response.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND")["instance"], "req-123");
+}
@@ -0,0 +1,60 @@
+use axum::http::{Method, StatusCode};
+use serde_json::json;
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    replays_the_first_response_for_a_repeated_key,
+    replays_error_responses_too,
+    rejects_a_key_reused_for_a_different_request,
+    rejects_invalid_keys,
+);
+
+async fn replays_the_first_response_for_a_repeated_key(app: TestApp) {
+    let headers = [("idempotency-key", "buy-mouse-1")];
+
+    let first = app.request(Method::POST, "/products/2/buy", &headers, Some(json!({"quantity": 1}))).await;
+    first.expect(StatusCode::CREATED);
+    assert!(first.headers.get("idempotent-replayed").is_none());
+
+    let second = app.request(Method::POST, "/products/2/buy", &headers, Some(json!({"quantity": 1}))).await;
+    assert_eq!(second.expect(StatusCode::CREATED), &first.body);
+    assert_eq!(second.header("idempotent-replayed"), "true");
+    assert_eq!(second.header("location"), first.header("location"));
+
+    // 再送では購入を繰り返さない
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["quantity"], 49);
+}
+
+async fn replays_error_responses_too(app: TestApp) {
+    let headers = [("idempotency-key", "buy-laptop-1")];
+
+    let first = app.request(Method::POST, "/products/1/buy", &headers, Some(json!({"quantity": 11}))).await;
+    first.expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY");
+
+    let second = app.request(Method::POST, "/products/1/buy", &headers, Some(json!({"quantity": 11}))).await;
+    second.expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY");
+    assert_eq!(second.body, first.body);
+    // 保存したContent-Typeだけを返し、既定のContent-Typeと重複しない
+    assert_eq!(second.headers.get_all("content-type").iter().count(), 1);
+}
+
+async fn rejects_a_key_reused_for_a_different_request(app: TestApp) {
+    let headers = [("idempotency-key", "buy-mouse-1")];
+
+    app.request(Method::POST, "/products/2/buy", &headers, Some(json!({"quantity": 1}))).await.expect(StatusCode::CREATED);
+
+    let problem = app.request(Method::POST, "/products/2/buy", &headers, Some(json!({"quantity": 2}))).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "IDEMPOTENCY_KEY_MISMATCH").clone();
+    assert_eq!(problem["idempotency_key"], "buy-mouse-1");
+}
+
+async fn rejects_invalid_keys(app: TestApp) {
+
+    app.request(Method::POST, "/products/2/buy", &[("idempotency-key", "")], Some(json!({"quantity": 1}))).await
+        .expect_invalid_field("Idempotency-Key");
+    let long_key = "k".repeat(256);
+    app.request(Method::POST, "/products/2/buy", &[("idempotency-key", &long_key)], Some(json!({"quantity": 1}))).await
+        .expect_invalid_field("Idempotency-Key");
+}
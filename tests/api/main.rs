@@ -0,0 +1,15 @@
+//! HTTP APIの結合テスト
+//! テストごとに一時ファイルのSQLiteを使うアプリケーションを組み立て、
+//! `tower::ServiceExt::oneshot` でリクエストを送ってステータスとJSONボディを検証する
+
+mod support;
+
+mod carts;
+mod categories;
+mod docs;
+mod idempotency;
+mod orders;
+mod products;
+mod promotions;
+mod reservations;
+mod variants;
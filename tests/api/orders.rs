@@ -0,0 +1,46 @@
+use axum::http::StatusCode;
+use serde_json::json;
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    gets_a_placed_order,
+    adds_tax_for_exclusive_regions,
+    missing_order_is_a_problem,
+);
+
+async fn gets_a_placed_order(app: TestApp) {
+    let placed = app.post("/products/1/buy", json!({"quantity": 1})).await.expect(StatusCode::CREATED).clone();
+
+    let order = app.get(&format!("/orders/{}", placed["id"])).await.expect(StatusCode::OK).clone();
+
+    assert_eq!(order, placed);
+    assert_eq!(order["status"], "placed");
+    assert_eq!(order["subtotal"], 99999);
+    // JPは税込価格のため、税額は合計に含まれる
+    assert_eq!(order["tax"], 9090);
+    assert_eq!(order["total"], 99999);
+    assert_eq!(order["lines"][0]["tax_rate"], 10.0);
+}
+
+async fn adds_tax_for_exclusive_regions(app: TestApp) {
+
+    let order = app.post("/products/4/buy", json!({"quantity": 1, "region": "US-NY"})).await
+        .expect(StatusCode::CREATED).clone();
+
+    assert_eq!(order["tax_region"], "US-NY");
+    assert_eq!(order["tax_mode"], "exclusive");
+    assert_eq!(order["currency"], "USD");
+    assert_eq!(order["subtotal"], 4999);
+    assert_eq!(order["tax"], 444);
+    assert_eq!(order["total"], 5443);
+    assert_eq!(order["total_display"], "$54.43");
+}
+
+async fn missing_order_is_a_problem(app: TestApp) {
+
+    let problem = app.get("/orders/42").await.expect_problem(StatusCode::NOT_FOUND, "ORDER_NOT_FOUND").clone();
+    assert_eq!(problem["order_id"], 42);
+
+    app.get("/orders/not-a-number").await.expect(StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,314 @@
+use axum::body::Body;
+use axum::http::{header, Method, Request, StatusCode};
+use serde_json::json;
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    buys_a_product,
+    rejects_invalid_purchases,
+);
+
+fn lamp() -> serde_json::Value {
+    json!({"name": "Desk Lamp", "price": 3500, "description": "LED desk lamp", "quantity": 4})
+}
+
+#[tokio::test]
+async fn lists_fixture_products() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/products").await;
+    let body = response.expect(StatusCode::OK);
+
+    assert_eq!(body["total"], 4);
+    assert_eq!(body["page"], 1);
+    assert_eq!(body["per_page"], 20);
+    assert_eq!(body["next_page"], json!(null));
+    let names: Vec<&str> = body["items"].as_array().unwrap().iter().map(|p| p["name"].as_str().unwrap()).collect();
+    assert_eq!(names, ["Laptop", "Mouse", "Keyboard", "USB-C Hub"]);
+}
+
+#[tokio::test]
+async fn lists_products_filtered_sorted_and_paginated() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/products?sort=price&order=desc&per_page=2").await;
+    let body = response.expect(StatusCode::OK);
+    let ids: Vec<u64> = body["items"].as_array().unwrap().iter().map(|p| p["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [1, 3]);
+    assert_eq!(body["total"], 4);
+    assert_eq!(body["next_page"], 2);
+
+    let response = app.get("/products?currency=JPY&min_price=3000&max_price=10000").await;
+    let body = response.expect(StatusCode::OK);
+    let ids: Vec<u64> = body["items"].as_array().unwrap().iter().map(|p| p["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [3]);
+
+    // 価格順は通貨ごとにまとめる（JPYの後にUSD）
+    let response = app.get("/products?sort=price").await;
+    let body = response.expect(StatusCode::OK);
+    let ids: Vec<u64> = body["items"].as_array().unwrap().iter().map(|p| p["id"].as_u64().unwrap()).collect();
+    assert_eq!(ids, [2, 3, 1, 4]);
+}
+
+#[tokio::test]
+async fn rejects_invalid_list_queries() {
+    let app = TestApp::spawn().await;
+
+    app.get("/products?per_page=0").await.expect_invalid_field("per_page");
+    app.get("/products?page=0").await.expect_invalid_field("page");
+    app.get("/products?min_price=3000").await.expect_invalid_field("currency");
+    app.get("/products?currency=GBP&max_price=3000").await.expect_invalid_field("currency");
+    app.get("/products?sort=colour").await.expect_problem(StatusCode::BAD_REQUEST, "INVALID_QUERY");
+}
+
+#[tokio::test]
+async fn pages_past_the_end_are_empty() {
+    let app = TestApp::spawn().await;
+
+    let body = app.get("/products?page=4294967295&per_page=100").await.expect(StatusCode::OK).clone();
+
+    assert_eq!(body["items"], json!([]));
+    assert_eq!(body["total"], 4);
+    assert_eq!(body["next_page"], json!(null));
+}
+
+#[tokio::test]
+async fn gets_a_product_with_its_etag() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/products/2").await;
+    let body = response.expect(StatusCode::OK);
+
+    assert_eq!(response.header("etag"), "\"0\"");
+    assert_eq!(body["id"], 2);
+    assert_eq!(body["name"], "Mouse");
+    assert_eq!(body["price"], 2999);
+    assert_eq!(body["currency"], "JPY");
+    assert_eq!(body["discounted_price"], 2999);
+    assert_eq!(body["promotion_id"], json!(null));
+    assert_eq!(body["tax_category"], "standard");
+    assert_eq!(body["quantity"], 50);
+    assert_eq!(body["reserved"], 0);
+    assert_eq!(body["variants"], json!([]));
+    assert_eq!(body["version"], 0);
+}
+
+#[tokio::test]
+async fn missing_product_is_a_problem() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/products/99").await;
+    let problem = response.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+
+    assert_eq!(problem["type"], "urn:problem:product-not-found");
+    assert_eq!(problem["product_id"], 99);
+    // レスポンスマッパーがリクエストIDをinstanceに埋め込む
+    assert_eq!(problem["instance"], response.header("x-request-id"));
+}
+
+#[tokio::test]
+async fn creates_a_product() {
+    let app = TestApp::spawn().await;
+
+    let response = app.post("/products", json!({
+        "name": "Desk Lamp",
+        "price": 3500,
+        "description": "LED desk lamp",
+        "quantity": 4,
+        "tax_category": "reduced",
+        "tags": ["Lighting"],
+    })).await;
+    let body = response.expect(StatusCode::CREATED);
+
+    assert_eq!(body["id"], 5);
+    assert_eq!(response.header("location"), "/products/5");
+    assert_eq!(response.header("etag"), "\"0\"");
+    assert_eq!(body["tax_category"], "reduced");
+    assert_eq!(body["tags"], json!(["lighting"]));
+
+    let body = app.get("/products/5").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["name"], "Desk Lamp");
+}
+
+#[tokio::test]
+async fn rejects_invalid_products() {
+    let app = TestApp::spawn().await;
+
+    let response = app.post("/products", json!({"name": " ", "price": 1, "description": "", "quantity": 1, "currency": "GBP"})).await;
+    response.expect_invalid_field("name");
+    response.expect_invalid_field("currency");
+
+    let response = app.post("/products", json!({"name": "Lamp"})).await;
+    response.expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "VALIDATION_FAILED");
+
+    let request = Request::post("/products")
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from("{\"name\":"))
+        .unwrap();
+    app.send(request).await.expect_problem(StatusCode::BAD_REQUEST, "MALFORMED_JSON");
+
+    let request = Request::post("/products").body(Body::from(lamp().to_string())).unwrap();
+    app.send(request).await.expect_problem(StatusCode::UNSUPPORTED_MEDIA_TYPE, "UNSUPPORTED_MEDIA_TYPE");
+
+    app.post("/products", json!({"name": "Lamp", "price": 1, "description": "", "quantity": 1, "category_ids": [42]}))
+        .await
+        .expect_problem(StatusCode::NOT_FOUND, "CATEGORY_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn replaces_and_patches_a_product() {
+    let app = TestApp::spawn().await;
+
+    let response = app.put("/products/2", json!({
+        "name": "Trackball",
+        "price": 4500,
+        "description": "Wireless trackball",
+        "quantity": 12,
+    })).await;
+    let body = response.expect(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"1\"");
+    assert_eq!(body["name"], "Trackball");
+    assert_eq!(body["price"], 4500);
+    assert_eq!(body["quantity"], 12);
+
+    let response = app.patch("/products/2", json!({"quantity": 7})).await;
+    let body = response.expect(StatusCode::OK);
+    assert_eq!(response.header("etag"), "\"2\"");
+    assert_eq!(body["name"], "Trackball");
+    assert_eq!(body["quantity"], 7);
+}
+
+#[tokio::test]
+async fn put_resets_omitted_optional_fields() {
+    let app = TestApp::spawn().await;
+    let category = app.post("/categories", json!({"name": "Peripherals"})).await.expect(StatusCode::CREATED).clone();
+    app.patch("/products/4", json!({"tax_category": "reduced", "category_ids": [category["id"]], "tags": ["sale"]})).await
+        .expect(StatusCode::OK);
+    let replacement = json!({"name": "USB-C Dock", "price": 9999, "description": "10-in-1 dock", "quantity": 5});
+
+    // PATCHは指定しない項目を変更しない
+    let body = app.patch("/products/4", json!({"name": "USB-C Dock"})).await.expect(StatusCode::OK).clone();
+    assert_eq!(body["currency"], "USD");
+    assert_eq!(body["tags"], json!(["sale"]));
+
+    // PUTは商品全体を置き換え、省略した任意項目は作成時の既定値になる
+    let body = app.put("/products/4", replacement.clone()).await.expect(StatusCode::OK).clone();
+    assert_eq!(body["currency"], "JPY");
+    assert_eq!(body["price"], 9999);
+    assert_eq!(body["tax_category"], "standard");
+    assert_eq!(body["categories"], json!([]));
+    assert_eq!(body["tags"], json!([]));
+
+    let mut usd = replacement;
+    usd["currency"] = json!("USD");
+    usd["tags"] = json!(["dock"]);
+    let body = app.put("/products/4", usd).await.expect(StatusCode::OK).clone();
+    assert_eq!(body["currency"], "USD");
+    assert_eq!(body["tags"], json!(["dock"]));
+}
+
+#[tokio::test]
+async fn rejects_invalid_updates() {
+    let app = TestApp::spawn().await;
+
+    let response = app.put("/products/2", json!({"name": "Trackball"})).await;
+    response.expect_invalid_field("price");
+    response.expect_invalid_field("quantity");
+
+    app.patch("/products/2", json!({"currency": "USD"})).await.expect_invalid_field("currency");
+    app.patch("/products/99", json!({"quantity": 1})).await.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn updates_require_the_current_version_in_if_match() {
+    let app = TestApp::spawn().await;
+
+    let response = app.request(Method::PATCH, "/products/2", &[("if-match", "\"0\"")], Some(json!({"quantity": 40}))).await;
+    response.expect(StatusCode::OK);
+
+    let response = app.request(Method::PATCH, "/products/2", &[("if-match", "\"0\"")], Some(json!({"quantity": 30}))).await;
+    response.expect_problem(StatusCode::CONFLICT, "VERSION_CONFLICT");
+
+    let response = app.request(Method::DELETE, "/products/2", &[("if-match", "\"0\"")], None).await;
+    response.expect_problem(StatusCode::CONFLICT, "VERSION_CONFLICT");
+
+    let body = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["quantity"], 40);
+}
+
+#[tokio::test]
+async fn rejects_unusable_if_match_headers() {
+    let app = TestApp::spawn().await;
+
+    for if_match in ["W/\"0\"", "\"0\", \"1\"", "zero"] {
+        let response = app.request(Method::PATCH, "/products/2", &[("if-match", if_match)], Some(json!({"quantity": 40}))).await;
+        response.expect_problem(StatusCode::BAD_REQUEST, "INVALID_IF_MATCH");
+    }
+
+    let body = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["quantity"], 50);
+}
+
+#[tokio::test]
+async fn deletes_a_product() {
+    let app = TestApp::spawn().await;
+
+    let response = app.request(Method::DELETE, "/products/1", &[("if-match", "\"0\"")], None).await;
+    assert_eq!(response.expect(StatusCode::NO_CONTENT), &json!(null));
+
+    app.get("/products/1").await.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+    app.delete("/products/1").await.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+}
+
+#[tokio::test]
+async fn searches_products() {
+    let app = TestApp::spawn().await;
+
+    let response = app.get("/products/search?q=mouse").await;
+    let body = response.expect(StatusCode::OK);
+
+    assert_eq!(body["query"], "mouse");
+    let hits = body["items"].as_array().unwrap();
+    assert_eq!(hits.len(), 1);
+    assert_eq!(hits[0]["product"]["id"], 2);
+    assert_eq!(hits[0]["highlighted_name"], "<mark>Mouse</mark>");
+
+    app.get("/products/search?q=%20").await.expect_invalid_field("q");
+    app.get("/products/search?q=mouse&limit=51").await.expect_invalid_field("limit");
+    app.get("/products/search").await.expect_problem(StatusCode::BAD_REQUEST, "INVALID_QUERY");
+}
+
+async fn buys_a_product(app: TestApp) {
+
+    let response = app.post("/products/2/buy", json!({"quantity": 2})).await;
+    let order = response.expect(StatusCode::CREATED);
+
+    assert_eq!(response.header("location"), format!("/orders/{}", order["id"]));
+    assert_eq!(order["tax_region"], "JP");
+    assert_eq!(order["tax_mode"], "inclusive");
+    assert_eq!(order["currency"], "JPY");
+    assert_eq!(order["total"], 5998);
+    assert_eq!(order["lines"][0]["product_id"], 2);
+    assert_eq!(order["lines"][0]["product_name"], "Mouse");
+    assert_eq!(order["lines"][0]["quantity"], 2);
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["quantity"], 48);
+}
+
+async fn rejects_invalid_purchases(app: TestApp) {
+
+    let problem = app.post("/products/1/buy", json!({"quantity": 11})).await
+        .expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY").clone();
+    assert_eq!(problem["requested"], 11);
+    assert_eq!(problem["available"], 10);
+
+    app.post("/products/1/buy", json!({"quantity": 0})).await.expect_invalid_field("quantity");
+    app.post("/products/99/buy", json!({"quantity": 1})).await.expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+    app.post("/products/3/buy", json!({"quantity": 1})).await.expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "VARIANT_REQUIRED");
+    app.post("/products/1/buy", json!({"quantity": 1, "region": "FR"})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "UNKNOWN_TAX_REGION");
+    app.post("/products/1/buy", json!({"quantity": 1, "coupon_code": "NOPE"})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "COUPON_NOT_APPLICABLE");
+}
@@ -0,0 +1,99 @@
+use axum::http::StatusCode;
+use serde_json::{json, Value};
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    automatic_promotions_discount_products,
+    coupons_apply_only_when_given,
+    rejects_invalid_promotions,
+);
+
+async fn create_promotion(app: &TestApp, body: Value) -> Value {
+    let response = app.post("/promotions", body).await;
+    let promotion = response.expect(StatusCode::CREATED).clone();
+    assert_eq!(response.header("location"), format!("/promotions/{}", promotion["id"]));
+    promotion
+}
+
+#[tokio::test]
+async fn creates_lists_and_deletes_promotions() {
+    let app = TestApp::spawn().await;
+
+    let promotion = create_promotion(&app, json!({"name": "Mouse sale", "kind": "amount_off", "amount": 500, "product_id": 2})).await;
+    assert_eq!(promotion["kind"], "amount_off");
+    assert_eq!(promotion["amount"], 500);
+    assert_eq!(promotion["currency"], "JPY");
+    assert_eq!(promotion["usage_count"], 0);
+
+    let id = promotion["id"].clone();
+    let body = app.get(&format!("/promotions/{id}")).await.expect(StatusCode::OK).clone();
+    assert_eq!(body, promotion);
+    let body = app.get("/promotions").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["items"], json!([promotion]));
+
+    let response = app.delete(&format!("/promotions/{id}")).await;
+    assert_eq!(response.expect(StatusCode::NO_CONTENT), &json!(null));
+    app.get(&format!("/promotions/{id}")).await.expect_problem(StatusCode::NOT_FOUND, "PROMOTION_NOT_FOUND");
+    app.delete(&format!("/promotions/{id}")).await.expect_problem(StatusCode::NOT_FOUND, "PROMOTION_NOT_FOUND");
+}
+
+async fn automatic_promotions_discount_products(app: TestApp) {
+    let promotion = create_promotion(&app, json!({"name": "Mouse sale", "kind": "amount_off", "amount": 500, "product_id": 2})).await;
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["price"], 2999);
+    assert_eq!(product["discounted_price"], 2499);
+    assert_eq!(product["promotion_id"], promotion["id"]);
+
+    let order = app.post("/products/2/buy", json!({"quantity": 2})).await.expect(StatusCode::CREATED).clone();
+    assert_eq!(order["lines"][0]["discount"], 1000);
+    assert_eq!(order["lines"][0]["promotion_id"], promotion["id"]);
+    assert_eq!(order["total"], 2 * 2499);
+}
+
+async fn coupons_apply_only_when_given(app: TestApp) {
+    let promotion = create_promotion(&app, json!({
+        "name": "Welcome",
+        "kind": "amount_off",
+        "amount": 1000,
+        "coupon_code": "WELCOME",
+        "usage_limit": 1,
+    })).await;
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["discounted_price"], 2999);
+
+    let order = app.post("/products/2/buy", json!({"quantity": 1, "coupon_code": "WELCOME"})).await
+        .expect(StatusCode::CREATED).clone();
+    assert_eq!(order["total"], 1999);
+
+    let body = app.get(&format!("/promotions/{}", promotion["id"])).await.expect(StatusCode::OK).clone();
+    assert_eq!(body["usage_count"], 1);
+
+    // 利用上限に達したクーポンは使えない
+    app.post("/products/2/buy", json!({"quantity": 1, "coupon_code": "WELCOME"})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "COUPON_NOT_APPLICABLE");
+}
+
+async fn rejects_invalid_promotions(app: TestApp) {
+
+    app.post("/promotions", json!({"name": "Sale", "kind": "percent_off"})).await.expect_invalid_field("percent");
+    app.post("/promotions", json!({"name": "Sale", "kind": "percent_off", "percent": 101})).await.expect_invalid_field("percent");
+    app.post("/promotions", json!({"name": "Sale", "kind": "buy_n_get_m", "buy_quantity": 2})).await.expect_invalid_field("get_quantity");
+    app.post("/promotions", json!({
+        "name": "Sale",
+        "kind": "percent_off",
+        "percent": 10,
+        "starts_at": "2025-02-01T00:00:00Z",
+        "ends_at": "2025-01-01T00:00:00Z",
+    })).await.expect_invalid_field("ends_at");
+    app.post("/promotions", json!({"name": "Sale", "kind": "free_shipping"})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "VALIDATION_FAILED");
+    app.post("/promotions", json!({"name": "Sale", "kind": "percent_off", "percent": 10, "product_id": 99})).await
+        .expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+
+    create_promotion(&app, json!({"name": "Sale", "kind": "percent_off", "percent": 10, "coupon_code": "SALE"})).await;
+    app.post("/promotions", json!({"name": "Sale again", "kind": "percent_off", "percent": 20, "coupon_code": "SALE"})).await
+        .expect_problem(StatusCode::CONFLICT, "COUPON_CODE_TAKEN");
+}
@@ -0,0 +1,92 @@
+use axum::http::{Method, StatusCode};
+use serde_json::{json, Value};
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    reserves_stock,
+    confirms_a_reservation,
+    releases_a_reservation,
+    rejects_invalid_reservations,
+);
+
+async fn reserve(app: &TestApp, product_id: u32, quantity: u32) -> Value {
+    let response = app.post("/reservations", json!({"items": [{"product_id": product_id, "quantity": quantity}]})).await;
+    let reservation = response.expect(StatusCode::CREATED).clone();
+    assert_eq!(response.header("location"), format!("/reservations/{}", reservation["id"]));
+    reservation
+}
+
+async fn reserves_stock(app: TestApp) {
+
+    let reservation = reserve(&app, 1, 4).await;
+    assert_eq!(reservation["status"], "active");
+    assert_eq!(reservation["order_id"], json!(null));
+    assert_eq!(reservation["items"], json!([{"product_id": 1, "sku": null, "quantity": 4}]));
+
+    let body = app.get(&format!("/reservations/{}", reservation["id"])).await.expect(StatusCode::OK).clone();
+    assert_eq!(body, reservation);
+
+    let product = app.get("/products/1").await.expect(StatusCode::OK).clone();
+    // 予約した数量は在庫から引かれ、購入できなくなる
+    assert_eq!(product["quantity"], 6);
+    assert_eq!(product["reserved"], 4);
+    let problem = app.post("/products/1/buy", json!({"quantity": 7})).await
+        .expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY").clone();
+    assert_eq!(problem["available"], 6);
+}
+
+async fn confirms_a_reservation(app: TestApp) {
+    let id = reserve(&app, 2, 3).await["id"].clone();
+
+    let response = app.request(Method::POST, &format!("/reservations/{id}/confirm"), &[], None).await;
+    let order = response.expect(StatusCode::CREATED).clone();
+    assert_eq!(response.header("location"), format!("/orders/{}", order["id"]));
+    assert_eq!(order["total"], 3 * 2999);
+
+    let reservation = app.get(&format!("/reservations/{id}")).await.expect(StatusCode::OK).clone();
+    assert_eq!(reservation["status"], "confirmed");
+    assert_eq!(reservation["order_id"], order["id"]);
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["quantity"], 47);
+    assert_eq!(product["reserved"], 0);
+
+    app.request(Method::POST, &format!("/reservations/{id}/confirm"), &[], None).await
+        .expect_problem(StatusCode::CONFLICT, "RESERVATION_NOT_ACTIVE");
+}
+
+async fn releases_a_reservation(app: TestApp) {
+    let id = reserve(&app, 2, 3).await["id"].clone();
+
+    let reservation = app.request(Method::POST, &format!("/reservations/{id}/release"), &[], None).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(reservation["status"], "released");
+
+    let product = app.get("/products/2").await.expect(StatusCode::OK).clone();
+    assert_eq!(product["reserved"], 0);
+
+    app.request(Method::POST, &format!("/reservations/{id}/release"), &[], None).await
+        .expect_problem(StatusCode::CONFLICT, "RESERVATION_NOT_ACTIVE");
+}
+
+async fn rejects_invalid_reservations(app: TestApp) {
+
+    app.post("/reservations", json!({"items": []})).await.expect_invalid_field("items");
+    app.post("/reservations", json!({"items": [{"product_id": 1, "quantity": 0}]})).await
+        .expect_invalid_field("items[0].quantity");
+    app.post("/reservations", json!({"items": [{"product_id": 99, "quantity": 1}]})).await
+        .expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+    app.post("/reservations", json!({"items": [{"product_id": 1, "quantity": 11}]})).await
+        .expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY");
+
+    app.get("/reservations/99").await.expect_problem(StatusCode::NOT_FOUND, "RESERVATION_NOT_FOUND");
+    app.request(Method::POST, "/reservations/99/confirm", &[], None).await
+        .expect_problem(StatusCode::NOT_FOUND, "RESERVATION_NOT_FOUND");
+    app.request(Method::POST, "/reservations/99/release", &[], None).await
+        .expect_problem(StatusCode::NOT_FOUND, "RESERVATION_NOT_FOUND");
+
+    let id = reserve(&app, 1, 1).await["id"].clone();
+    app.request(Method::POST, &format!("/reservations/{id}/confirm?region=FR"), &[], None).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "UNKNOWN_TAX_REGION");
+}
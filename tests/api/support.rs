@@ -0,0 +1,226 @@
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use axum::body::{to_bytes, Body};
+use axum::http::{header, HeaderMap, Method, Request, StatusCode};
+use axum::Router;
+use serde_json::Value;
+use tower::ServiceExt;
+
+use axum_mini_template::app;
+use axum_mini_template::frameworks_and_drivers::config::{Config, ConfigArgs, RepositoryBackend};
+use axum_mini_template::frameworks_and_drivers::database::db::Database;
+use axum_mini_template::frameworks_and_drivers::database::migrations::run_migrations;
+use axum_mini_template::frameworks_and_drivers::database::seed::{seed_database, seed_products};
+use axum_mini_template::frameworks_and_drivers::get_container;
+
+/// テストごとのデータベースファイルを区別する連番
+static NEXT_DATABASE: AtomicUsize = AtomicUsize::new(0);
+
+/// 一時ファイルのSQLiteを使うテスト用アプリケーション
+/// マイグレーションとフィクスチャ（seed）を適用済みで、テスト間でデータを共有しない
+///
+/// フィクスチャの商品:
+/// 1. Laptop（99,999円、在庫10）
+/// 2. Mouse（2,999円、在庫50）
+/// 3. Keyboard（7,999円、在庫25。SKU KB-US-BLK / KB-US-WHT / KB-JIS-BLK のバリエーションを持つ）
+/// 4. USB-C Hub（$49.99、在庫30）
+pub struct TestApp {
+    router: Router,
+    path: PathBuf,
+}
+
+/// ボディをJSONとして読み込んだレスポンス
+pub struct TestResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    /// ボディが空の場合は `Value::Null`
+    pub body: Value,
+}
+
+impl TestApp {
+    pub async fn spawn() -> Self {
+        Self::spawn_with(RepositoryBackend::Database).await
+    }
+
+    /// 商品・注文・カート・予約・プロモーションをメモリ上に保持するアプリケーション
+    /// （`database.product_repository = "memory"`）。フィクスチャの商品は `spawn` と同じ
+    pub async fn spawn_in_memory() -> Self {
+        Self::spawn_with(RepositoryBackend::Memory).await
+    }
+
+    async fn spawn_with(backend: RepositoryBackend) -> Self {
+        let path = std::env::temp_dir().join(format!(
+            "axum-mini-template-api-{}-{}.sqlite",
+            std::process::id(),
+            NEXT_DATABASE.fetch_add(1, Ordering::Relaxed),
+        ));
+        remove_database_files(&path);
+
+        // リポジトリの config.toml（税制など）を読み込み、データベースだけを差し替える
+        let args = ConfigArgs {
+            database_url: Some(format!("sqlite://{}", path.display())),
+            ..Default::default()
+        };
+        let mut config = Config::load(&args).expect("config can be loaded");
+        config.database.product_repository = backend;
+        let config = Arc::new(config);
+        run_migrations(&config.database).await.expect("migrations can be applied");
+
+        let database = Arc::new(Database::new(&config.database).await.expect("database can be opened"));
+        let container = match backend {
+            RepositoryBackend::Database => {
+                seed_database(&database).await.expect("fixtures can be inserted");
+                get_container(config, database)
+            }
+            RepositoryBackend::Memory => {
+                let container = get_container(config, database);
+                seed_products(container.product_repository.as_ref()).await.expect("fixtures can be inserted");
+                container
+            }
+        };
+
+        Self { router: app::router(Arc::new(container)), path }
+    }
+
+    pub async fn get(&self, uri: &str) -> TestResponse {
+        self.request(Method::GET, uri, &[], None).await
+    }
+
+    pub async fn post(&self, uri: &str, body: Value) -> TestResponse {
+        self.request(Method::POST, uri, &[], Some(body)).await
+    }
+
+    pub async fn put(&self, uri: &str, body: Value) -> TestResponse {
+        self.request(Method::PUT, uri, &[], Some(body)).await
+    }
+
+    pub async fn patch(&self, uri: &str, body: Value) -> TestResponse {
+        self.request(Method::PATCH, uri, &[], Some(body)).await
+    }
+
+    pub async fn delete(&self, uri: &str) -> TestResponse {
+        self.request(Method::DELETE, uri, &[], None).await
+    }
+
+    /// 任意のヘッダー（If-Match、Idempotency-Keyなど）を付けてリクエストを送ります
+    /// ボディはJSONとして送ります
+    pub async fn request(
+        &self,
+        method: Method,
+        uri: &str,
+        headers: &[(&str, &str)],
+        body: Option<Value>,
+    ) -> TestResponse {
+        let mut builder = Request::builder().method(method).uri(uri);
+        for (name, value) in headers {
+            builder = builder.header(*name, *value);
+        }
+        let body = match body {
+            Some(body) => {
+                builder = builder.header(header::CONTENT_TYPE, "application/json");
+                Body::from(body.to_string())
+            }
+            None => Body::empty(),
+        };
+
+        self.send(builder.body(body).unwrap()).await
+    }
+
+    /// 組み立て済みのリクエストを送ります（不正なJSONやContent-Typeの検証用）
+    pub async fn send(&self, request: Request<Body>) -> TestResponse {
+        let response = self.router.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = if bytes.is_empty() {
+            Value::Null
+        } else {
+            serde_json::from_slice(&bytes).unwrap_or_else(|_| Value::String(String::from_utf8_lossy(&bytes).into_owned()))
+        };
+
+        TestResponse { status, headers, body }
+    }
+}
+
+impl Drop for TestApp {
+    fn drop(&mut self) {
+        remove_database_files(&self.path);
+    }
+}
+
+impl TestResponse {
+    /// ヘッダーの値を返します（ない場合はパニック）
+    pub fn header(&self, name: &str) -> &str {
+        self.headers
+            .get(name)
+            .unwrap_or_else(|| panic!("missing header {name}"))
+            .to_str()
+            .unwrap()
+    }
+
+    /// ステータスを検証し、ボディを返します
+    #[track_caller]
+    pub fn expect(&self, status: StatusCode) -> &Value {
+        assert_eq!(self.status, status, "unexpected status, body: {}", self.body);
+        &self.body
+    }
+
+    /// Problem Detailsのエラーレスポンスであることを検証し、ボディを返します
+    #[track_caller]
+    pub fn expect_problem(&self, status: StatusCode, code: &str) -> &Value {
+        self.expect(status);
+        assert_eq!(self.header("content-type"), "application/problem+json");
+        assert_eq!(self.body["status"], status.as_u16());
+        assert_eq!(self.body["code"], code, "unexpected problem: {}", self.body);
+        &self.body
+    }
+
+    /// バリデーションエラーのうち、指定した項目のものがあることを検証します
+    #[track_caller]
+    pub fn expect_invalid_field(&self, field: &str) {
+        let problem = self.expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "VALIDATION_FAILED");
+        let fields: Vec<&str> = problem["errors"]
+            .as_array()
+            .unwrap_or_else(|| panic!("no field errors: {problem}"))
+            .iter()
+            .filter_map(|error| error["field"].as_str())
+            .collect();
+        assert!(fields.contains(&field), "expected an error for {field}, got {fields:?}");
+    }
+}
+
+// SQLiteのWALファイルも含めて削除する
+fn remove_database_files(path: &std::path::Path) {
+    for suffix in ["", "-wal", "-shm"] {
+        let mut file = path.as_os_str().to_owned();
+        file.push(suffix);
+        let _ = std::fs::remove_file(file);
+    }
+}
+
+/// シナリオ（`async fn(TestApp)`）ごとに、データベースに保存する設定とメモリ上に保持する設定の両方でテストを生成する
+/// 在庫を変更する購入・チェックアウト・予約が、どちらの保存先でも同じ結果になることを確かめるために使う
+macro_rules! backend_tests {
+    ($($scenario:ident),* $(,)?) => {
+        mod database {
+            $(
+                #[tokio::test]
+                async fn $scenario() {
+                    super::$scenario($crate::support::TestApp::spawn().await).await;
+                }
+            )*
+        }
+
+        mod in_memory {
+            $(
+                #[tokio::test]
+                async fn $scenario() {
+                    super::$scenario($crate::support::TestApp::spawn_in_memory().await).await;
+                }
+            )*
+        }
+    };
+}
+pub(crate) use backend_tests;
@@ -0,0 +1,155 @@
+use axum::http::{Method, StatusCode};
+use serde_json::{json, Value};
+
+use crate::support::{backend_tests, TestApp};
+
+backend_tests!(
+    buys_a_variant_by_sku,
+    checks_out_variants_from_a_cart,
+    reserves_variants,
+);
+
+#[tokio::test]
+async fn fixture_keyboard_has_variants() {
+    let app = TestApp::spawn().await;
+
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+
+    assert_eq!(body["quantity"], 25);
+    let skus: Vec<&str> = body["variants"].as_array().unwrap().iter().map(|v| v["sku"].as_str().unwrap()).collect();
+    assert_eq!(skus, ["KB-US-BLK", "KB-US-WHT", "KB-JIS-BLK"]);
+    assert_eq!(body["variant_attributes"], json!({"colour": ["black", "white"], "layout": ["JIS", "US"]}));
+}
+
+#[tokio::test]
+async fn adds_updates_and_removes_variants() {
+    let app = TestApp::spawn().await;
+
+    let response = app.post("/products/2/variants", json!({
+        "sku": "MS-RED",
+        "attributes": {"colour": "red"},
+        "price": 3200,
+        "quantity": 6,
+    })).await;
+    let body = response.expect(StatusCode::CREATED);
+    assert_eq!(response.header("etag"), "\"1\"");
+    assert_eq!(body["variants"][0]["sku"], "MS-RED");
+    assert_eq!(body["variants"][0]["price"], 3200);
+    assert_eq!(body["quantity"], 6);
+
+    let response = app.patch("/products/3/variants/KB-US-WHT", json!({"quantity": 9})).await;
+    let body = response.expect(StatusCode::OK);
+    assert_eq!(body["quantity"], 29);
+
+    let response = app.delete("/products/3/variants/KB-JIS-BLK").await;
+    assert_eq!(response.expect(StatusCode::NO_CONTENT), &json!(null));
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+    assert_eq!(body["variants"].as_array().unwrap().len(), 2);
+    assert_eq!(body["quantity"], 19);
+}
+
+#[tokio::test]
+async fn rejects_invalid_variants() {
+    let app = TestApp::spawn().await;
+
+    app.post("/products/2/variants", json!({"sku": "KB-US-BLK", "attributes": {"colour": "black"}, "price": 1, "quantity": 1}))
+        .await
+        .expect_problem(StatusCode::CONFLICT, "SKU_TAKEN");
+    app.post("/products/2/variants", json!({"sku": "MS-RED", "attributes": {}, "price": 1, "quantity": 1}))
+        .await
+        .expect_invalid_field("attributes");
+    app.post("/products/99/variants", json!({"sku": "MS-RED", "attributes": {"colour": "red"}, "price": 1, "quantity": 1}))
+        .await
+        .expect_problem(StatusCode::NOT_FOUND, "PRODUCT_NOT_FOUND");
+    app.patch("/products/3/variants/KB-UK-BLK", json!({"quantity": 1}))
+        .await
+        .expect_problem(StatusCode::NOT_FOUND, "VARIANT_NOT_FOUND");
+    app.request(Method::DELETE, "/products/3/variants/KB-US-BLK", &[("if-match", "\"7\"")], None)
+        .await
+        .expect_problem(StatusCode::CONFLICT, "VERSION_CONFLICT");
+}
+
+async fn buys_a_variant_by_sku(app: TestApp) {
+
+    let order = app.post("/products/3/buy", json!({"quantity": 2, "sku": "KB-US-WHT"})).await
+        .expect(StatusCode::CREATED).clone();
+
+    assert_eq!(order["lines"][0]["sku"], "KB-US-WHT");
+    assert_eq!(order["lines"][0]["unit_price"], 8499);
+    assert_eq!(order["total"], 16998);
+
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+    let white = body["variants"].as_array().unwrap().iter().find(|v| v["sku"] == "KB-US-WHT").unwrap().clone();
+    assert_eq!(white["quantity"], 3);
+
+    app.post("/products/3/buy", json!({"quantity": 1, "sku": "KB-UK-BLK"})).await
+        .expect_problem(StatusCode::NOT_FOUND, "VARIANT_NOT_FOUND");
+}
+
+async fn checks_out_variants_from_a_cart(app: TestApp) {
+    let id = app.request(Method::POST, "/carts", &[], None).await.expect(StatusCode::CREATED)["id"].clone();
+
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 3, "quantity": 1})).await
+        .expect_problem(StatusCode::UNPROCESSABLE_ENTITY, "VARIANT_REQUIRED");
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 3, "sku": "KB-UK-BLK", "quantity": 1})).await
+        .expect_problem(StatusCode::NOT_FOUND, "VARIANT_NOT_FOUND");
+
+    // SKUは大文字・小文字を区別せず、バリエーションごとに別の行になる
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 3, "sku": "kb-us-wht", "quantity": 1})).await
+        .expect(StatusCode::OK);
+    app.post(&format!("/carts/{id}/items"), json!({"product_id": 3, "sku": "KB-JIS-BLK", "quantity": 1})).await
+        .expect(StatusCode::OK);
+    let cart = app.put(&format!("/carts/{id}/items/3?sku=kb-us-wht"), json!({"quantity": 2})).await
+        .expect(StatusCode::OK).clone();
+    assert_eq!(cart["items"], json!([
+        {"product_id": 3, "sku": "KB-US-WHT", "quantity": 2},
+        {"product_id": 3, "sku": "KB-JIS-BLK", "quantity": 1},
+    ]));
+    app.put(&format!("/carts/{id}/items/3"), json!({"quantity": 2})).await
+        .expect_problem(StatusCode::NOT_FOUND, "CART_ITEM_NOT_FOUND");
+    app.delete(&format!("/carts/{id}/items/3?sku=KB-JIS-BLK")).await.expect(StatusCode::OK);
+
+    let order = app.request(Method::POST, &format!("/carts/{id}/checkout"), &[], None).await
+        .expect(StatusCode::CREATED).clone();
+    assert_eq!(order["lines"][0]["sku"], "KB-US-WHT");
+    assert_eq!(order["total"], 2 * 8499);
+
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+    let white = body["variants"].as_array().unwrap().iter().find(|v| v["sku"] == "KB-US-WHT").unwrap().clone();
+    assert_eq!(white["quantity"], 3);
+    assert_eq!(body["quantity"], 23);
+}
+
+async fn reserves_variants(app: TestApp) {
+
+    let variant_quantity = |body: &Value, sku: &str| {
+        body["variants"].as_array().unwrap().iter().find(|v| v["sku"] == sku).unwrap()["quantity"].clone()
+    };
+
+    app.post("/reservations", json!({"items": [{"product_id": 3, "sku": "KB-US-WHT", "quantity": 6}]})).await
+        .expect_problem(StatusCode::BAD_REQUEST, "INSUFFICIENT_QUANTITY");
+
+    let confirmed = app.post("/reservations", json!({"items": [{"product_id": 3, "sku": "kb-us-wht", "quantity": 2}]})).await
+        .expect(StatusCode::CREATED).clone();
+    assert_eq!(confirmed["items"], json!([{"product_id": 3, "sku": "KB-US-WHT", "quantity": 2}]));
+    let released = app.post("/reservations", json!({"items": [{"product_id": 3, "sku": "KB-JIS-BLK", "quantity": 4}]})).await
+        .expect(StatusCode::CREATED).clone();
+
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+    assert_eq!(variant_quantity(&body, "KB-US-WHT"), 3);
+    assert_eq!(variant_quantity(&body, "KB-JIS-BLK"), 6);
+    assert_eq!((body["quantity"].clone(), body["reserved"].clone()), (json!(19), json!(6)));
+
+    let order = app.request(Method::POST, &format!("/reservations/{}/confirm", confirmed["id"]), &[], None).await
+        .expect(StatusCode::CREATED).clone();
+    assert_eq!(order["lines"][0]["sku"], "KB-US-WHT");
+    assert_eq!(order["total"], 2 * 8499);
+    app.request(Method::POST, &format!("/reservations/{}/release", released["id"]), &[], None).await
+        .expect(StatusCode::OK);
+
+    // 取り消した分だけがバリエーションの在庫に戻る
+    let body = app.get("/products/3").await.expect(StatusCode::OK).clone();
+    assert_eq!(variant_quantity(&body, "KB-US-WHT"), 3);
+    assert_eq!(variant_quantity(&body, "KB-JIS-BLK"), 10);
+    assert_eq!((body["quantity"].clone(), body["reserved"].clone()), (json!(23), json!(0)));
+}